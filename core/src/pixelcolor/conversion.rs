@@ -1,4 +1,7 @@
-use crate::pixelcolor::{binary_color::*, gray_color::*, rgb_color::*};
+use crate::pixelcolor::{
+    binary_color::*, epd_color::*, gray_color::*, hsv_color::*, indexed_color::*, rgb_color::*,
+    rgba_color::*, PixelColor,
+};
 
 /// Convert color channel values from one bit depth to another.
 ///
@@ -66,6 +69,59 @@ impl_rgb_conversion!(Bgr666 => Rgb332, Rgb444, Rgb555, Bgr555, Rgb565, Rgb666, B
 impl_rgb_conversion!(Rgb888 => Rgb332, Rgb444, Rgb555, Bgr555, Rgb565, Rgb666, Bgr666, Bgr565, Bgr888);
 impl_rgb_conversion!(Bgr888 => Rgb332, Rgb444, Rgb555, Bgr555, Rgb565, Rgb666, Bgr666, Bgr565, Rgb888);
 
+/// Macro to implement conversion between RGB color types with and without alpha channel.
+///
+/// The alpha channel is ignored during the conversion to a color type without alpha channel and
+/// colors without alpha channel are converted into fully opaque colors.
+macro_rules! impl_rgba_conversion {
+    ($from_type:ident => $($to_type:ident),+) => {
+        $(impl From<$from_type> for $to_type {
+            fn from(other: $from_type) -> Self {
+                Self::new(
+                    convert_channel::<{$from_type::MAX_R}, {$to_type::MAX_R}>(other.r()),
+                    convert_channel::<{$from_type::MAX_G}, {$to_type::MAX_G}>(other.g()),
+                    convert_channel::<{$from_type::MAX_B}, {$to_type::MAX_B}>(other.b()),
+                )
+            }
+        }
+
+        impl From<$to_type> for $from_type {
+            fn from(other: $to_type) -> Self {
+                Self::new(
+                    convert_channel::<{$to_type::MAX_R}, {$from_type::MAX_R}>(other.r()),
+                    convert_channel::<{$to_type::MAX_G}, {$from_type::MAX_G}>(other.g()),
+                    convert_channel::<{$to_type::MAX_B}, {$from_type::MAX_B}>(other.b()),
+                    $from_type::MAX_A,
+                )
+            }
+        })*
+    };
+}
+
+impl_rgba_conversion!(Rgba4444 => Rgb332, Rgb444, Rgb555, Bgr555, Rgb565, Bgr565, Rgb666, Bgr666, Rgb888, Bgr888);
+impl_rgba_conversion!(Rgba8888 => Rgb332, Rgb444, Rgb555, Bgr555, Rgb565, Bgr565, Rgb666, Bgr666, Rgb888, Bgr888);
+impl_rgba_conversion!(Argb8888 => Rgb332, Rgb444, Rgb555, Bgr555, Rgb565, Bgr565, Rgb666, Bgr666, Rgb888, Bgr888);
+
+/// Macro to implement conversion between RGB color types with alpha channel.
+macro_rules! impl_alpha_conversion {
+    ($from_type:ident => $($to_type:ident),+) => {
+        $(impl From<$from_type> for $to_type {
+            fn from(other: $from_type) -> Self {
+                Self::new(
+                    convert_channel::<{$from_type::MAX_R}, {$to_type::MAX_R}>(other.r()),
+                    convert_channel::<{$from_type::MAX_G}, {$to_type::MAX_G}>(other.g()),
+                    convert_channel::<{$from_type::MAX_B}, {$to_type::MAX_B}>(other.b()),
+                    convert_channel::<{$from_type::MAX_A}, {$to_type::MAX_A}>(other.a()),
+                )
+            }
+        })*
+    };
+}
+
+impl_alpha_conversion!(Rgba4444 => Rgba8888, Argb8888);
+impl_alpha_conversion!(Rgba8888 => Rgba4444, Argb8888);
+impl_alpha_conversion!(Argb8888 => Rgba4444, Rgba8888);
+
 /// Macro to implement conversion between grayscale color types.
 macro_rules! impl_gray_conversion {
     ($from_type:ident => $($to_type:ident),+) => {
@@ -122,8 +178,8 @@ macro_rules! impl_from_binary {
 }
 
 impl_from_binary!(
-    Rgb332, Rgb444, Rgb555, Bgr555, Rgb565, Bgr565, Rgb666, Bgr666, Rgb888, Bgr888, Gray2, Gray4,
-    Gray8
+    Rgb332, Rgb444, Rgb555, Bgr555, Rgb565, Bgr565, Rgb666, Bgr666, Rgb888, Bgr888, Rgba4444,
+    Rgba8888, Argb8888, Gray2, Gray4, Gray8
 );
 
 /// Macro to implement conversion from grayscale types to `BinaryColor`.
@@ -152,6 +208,139 @@ macro_rules! impl_rgb_to_binary {
 
 impl_rgb_to_binary!(Rgb332, Rgb444, Rgb555, Bgr555, Rgb565, Bgr565, Rgb666, Bgr666, Rgb888, Bgr888);
 
+/// Converts a color channel value into a normalized 16 bit value.
+const fn normalize_channel<const MAX: u8>(value: u8) -> u16 {
+    ((value as u32 * 0xFFFF + MAX as u32 / 2) / MAX as u32) as u16
+}
+
+/// Converts a normalized 16 bit value into a color channel value.
+const fn denormalize_channel<const MAX: u8>(value: u16) -> u8 {
+    ((value as u32 * MAX as u32 + 0x7FFF) / 0xFFFF) as u8
+}
+
+/// Macro to implement conversions between RGB color types and `Hsv` and `Hsl`.
+macro_rules! impl_hue_conversion {
+    ($($rgb_type:ident),+ => $hue_type:ident) => {
+        $(impl From<$rgb_type> for $hue_type {
+            fn from(color: $rgb_type) -> Self {
+                Self::from_normalized_rgb([
+                    normalize_channel::<{ $rgb_type::MAX_R }>(color.r()),
+                    normalize_channel::<{ $rgb_type::MAX_G }>(color.g()),
+                    normalize_channel::<{ $rgb_type::MAX_B }>(color.b()),
+                ])
+            }
+        }
+
+        impl From<$hue_type> for $rgb_type {
+            fn from(color: $hue_type) -> Self {
+                let [r, g, b] = color.to_normalized_rgb();
+
+                Self::new(
+                    denormalize_channel::<{ $rgb_type::MAX_R }>(r),
+                    denormalize_channel::<{ $rgb_type::MAX_G }>(g),
+                    denormalize_channel::<{ $rgb_type::MAX_B }>(b),
+                )
+            }
+        })+
+    };
+}
+
+impl_hue_conversion!(Rgb332, Rgb444, Rgb555, Bgr555, Rgb565, Bgr565, Rgb666, Bgr666, Rgb888, Bgr888 => Hsv);
+impl_hue_conversion!(Rgb332, Rgb444, Rgb555, Bgr555, Rgb565, Bgr565, Rgb666, Bgr666, Rgb888, Bgr888 => Hsl);
+
+/// Macro to implement conversions between e-paper colors and `Rgb888` and `BinaryColor`.
+///
+/// Conversions from `Rgb888` use the perceptually closest e-paper color.
+macro_rules! impl_epd_conversion {
+    ($type:ident => $($color:ident: $rgb:expr),+) => {
+        impl From<$type> for Rgb888 {
+            fn from(color: $type) -> Self {
+                match color {
+                    $($type::$color => $rgb),+
+                }
+            }
+        }
+
+        impl From<Rgb888> for $type {
+            fn from(color: Rgb888) -> Self {
+                Palette::new(&[$($type::$color),+])
+                    .nearest(color)
+                    .unwrap_or(Self::White)
+            }
+        }
+
+        impl From<BinaryColor> for $type {
+            fn from(color: BinaryColor) -> Self {
+                color.map_color(Self::Black, Self::White)
+            }
+        }
+    };
+}
+
+impl_epd_conversion!(BwrColor => White: Rgb888::WHITE, Black: Rgb888::BLACK, Red: Rgb888::RED);
+impl_epd_conversion!(BwyColor => White: Rgb888::WHITE, Black: Rgb888::BLACK, Yellow: Rgb888::YELLOW);
+impl_epd_conversion!(AcepColor =>
+    Black: Rgb888::BLACK,
+    White: Rgb888::WHITE,
+    Green: Rgb888::GREEN,
+    Blue: Rgb888::BLUE,
+    Red: Rgb888::RED,
+    Yellow: Rgb888::YELLOW,
+    Orange: Rgb888::new(255, 128, 0)
+);
+
+/// Calculates the perceptual distance between two colors.
+///
+/// Fixed point implementation of the "redmean" weighted euclidean distance, which weights the
+/// red and blue channels based on the mean red value to approximate human color perception.
+/// The returned value is the squared distance scaled by `256`.
+fn color_distance(a: Rgb888, b: Rgb888) -> u32 {
+    let r_mean = (u32::from(a.r()) + u32::from(b.r())) / 2;
+    let dr = u32::from(a.r().abs_diff(b.r()));
+    let dg = u32::from(a.g().abs_diff(b.g()));
+    let db = u32::from(a.b().abs_diff(b.b()));
+
+    // Original formula: (2 + r_mean / 256) * dr² + 4 * dg² + (2 + (255 - r_mean) / 256) * db²
+    (512 + r_mean) * dr * dr + 1024 * dg * dg + (767 - r_mean) * db * db
+}
+
+impl<C> Palette<'_, C>
+where
+    C: PixelColor + Into<Rgb888>,
+{
+    /// Returns the index of the palette color which is perceptually closest to `color`.
+    ///
+    /// If multiple palette colors have the same distance to `color` the lowest index is
+    /// returned. `None` is returned if the palette is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use embedded_graphics::pixelcolor::{Palette, Rgb888};
+    /// use embedded_graphics::prelude::*;
+    ///
+    /// // Colors of a black, white and red e-paper display.
+    /// const PALETTE: Palette<Rgb888> = Palette::new(&[Rgb888::BLACK, Rgb888::WHITE, Rgb888::RED]);
+    ///
+    /// assert_eq!(PALETTE.nearest_index(Rgb888::new(20, 30, 10)), Some(0));
+    /// assert_eq!(PALETTE.nearest_index(Rgb888::new(220, 50, 40)), Some(2));
+    /// ```
+    pub fn nearest_index(&self, color: Rgb888) -> Option<usize> {
+        self.colors()
+            .iter()
+            .enumerate()
+            .min_by_key(|(_, c)| color_distance(color, (**c).into()))
+            .map(|(index, _)| index)
+    }
+
+    /// Returns the palette color which is perceptually closest to `color`.
+    ///
+    /// See [`nearest_index`](Self::nearest_index) for more information.
+    pub fn nearest(&self, color: Rgb888) -> Option<C> {
+        self.nearest_index(color).map(|index| self.colors()[index])
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use core::fmt::Debug;
@@ -429,4 +618,162 @@ mod tests {
         test_channel_conversion::<{ bits_to_max(4) }, { bits_to_max(4) }>();
         test_channel_conversion::<{ bits_to_max(2) }, { bits_to_max(2) }>();
     }
+
+    #[test]
+    fn convert_rgba_to_rgb() {
+        assert_eq!(
+            Rgb888::from(Rgba8888::new(10, 20, 30, 40)),
+            Rgb888::new(10, 20, 30)
+        );
+        assert_eq!(Rgb565::from(Argb8888::new(255, 0, 255, 0)), Rgb565::MAGENTA);
+        assert_eq!(
+            Rgb888::from(Rgba4444::new(0xF, 0x8, 0x0, 0x1)),
+            Rgb888::new(0xFF, 0x88, 0x00)
+        );
+    }
+
+    #[test]
+    fn convert_rgb_to_rgba() {
+        assert_eq!(
+            Rgba8888::from(Rgb888::new(10, 20, 30)),
+            Rgba8888::new(10, 20, 30, 255)
+        );
+        assert_eq!(Argb8888::from(Rgb565::CYAN), Argb8888::CYAN);
+        assert_eq!(Rgba4444::from(BinaryColor::On), Rgba4444::WHITE);
+    }
+
+    #[test]
+    fn convert_between_rgba() {
+        let color = Rgba4444::new(0x1, 0x2, 0x3, 0x4);
+
+        assert_eq!(Rgba8888::from(color), Rgba8888::new(0x11, 0x22, 0x33, 0x44));
+        assert_eq!(Argb8888::from(color), Argb8888::new(0x11, 0x22, 0x33, 0x44));
+        assert_eq!(Rgba4444::from(Argb8888::from(color)), color);
+    }
+
+    #[test]
+    fn perceptual_distance() {
+        let gray = Rgb888::new(128, 128, 128);
+        assert_eq!(color_distance(gray, gray), 0);
+
+        // Differences in the green channel are more visible than in the red and blue channels.
+        let green = color_distance(Rgb888::BLACK, Rgb888::new(0, 50, 0));
+        let red = color_distance(Rgb888::BLACK, Rgb888::new(50, 0, 0));
+        let blue = color_distance(Rgb888::BLACK, Rgb888::new(0, 0, 50));
+        assert!(green > blue && blue > red);
+
+        assert_eq!(
+            color_distance(Rgb888::WHITE, Rgb888::BLACK),
+            color_distance(Rgb888::BLACK, Rgb888::WHITE)
+        );
+    }
+
+    #[test]
+    fn nearest_palette_color() {
+        let palette = Palette::new(&[Rgb565::BLACK, Rgb565::WHITE, Rgb565::RED, Rgb565::YELLOW]);
+
+        assert_eq!(
+            palette.nearest(Rgb888::new(100, 100, 100)),
+            Some(Rgb565::BLACK)
+        );
+        assert_eq!(
+            palette.nearest(Rgb888::new(240, 240, 200)),
+            Some(Rgb565::WHITE)
+        );
+        assert_eq!(palette.nearest(Rgb888::new(200, 30, 40)), Some(Rgb565::RED));
+        assert_eq!(
+            palette.nearest(Rgb888::new(255, 165, 0)),
+            Some(Rgb565::YELLOW)
+        );
+        assert_eq!(palette.nearest_index(Rgb888::new(255, 255, 10)), Some(3));
+    }
+
+    #[test]
+    fn nearest_palette_color_edge_cases() {
+        // The lowest index is returned if multiple colors have the same distance.
+        let palette = Palette::new(&[BinaryColor::On, BinaryColor::Off, BinaryColor::On]);
+        assert_eq!(palette.nearest_index(Rgb888::WHITE), Some(0));
+
+        assert_eq!(Palette::<Gray8>::new(&[]).nearest(Rgb888::WHITE), None);
+    }
+
+    #[test]
+    fn convert_epd_colors() {
+        assert_eq!(BwrColor::from(Rgb888::new(230, 20, 50)), BwrColor::Red);
+        assert_eq!(BwrColor::from(Rgb888::new(40, 40, 40)), BwrColor::Black);
+        assert_eq!(BwyColor::from(Rgb888::new(255, 200, 0)), BwyColor::Yellow);
+        assert_eq!(BwyColor::from(Rgb888::new(200, 200, 220)), BwyColor::White);
+        assert_eq!(
+            AcepColor::from(Rgb888::new(240, 120, 20)),
+            AcepColor::Orange
+        );
+        assert_eq!(AcepColor::from(Rgb888::new(20, 30, 200)), AcepColor::Blue);
+
+        assert_eq!(BwrColor::from(BinaryColor::Off), BwrColor::Black);
+        assert_eq!(BwyColor::from(BinaryColor::On), BwyColor::White);
+        assert_eq!(AcepColor::from(BinaryColor::On), AcepColor::White);
+
+        assert_eq!(Rgb888::from(BwrColor::Red), Rgb888::RED);
+        assert_eq!(Rgb888::from(BwyColor::Yellow), Rgb888::YELLOW);
+        assert_eq!(Rgb888::from(AcepColor::Green), Rgb888::GREEN);
+    }
+
+    #[test]
+    fn convert_rgb888_to_hsv_and_hsl_and_back() {
+        for r in (0..=255).step_by(5) {
+            for g in (0..=255).step_by(3) {
+                for b in (0..=255).step_by(7) {
+                    let color = Rgb888::new(r, g, b);
+
+                    assert_eq!(Rgb888::from(Hsv::from(color)), color);
+                    assert_eq!(Rgb888::from(Hsl::from(color)), color);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn convert_rgb_to_hsv_and_hsl_and_back() {
+        macro_rules! test_round_trip {
+            ($($type:ident),+) => {
+                $(for r in 0..=$type::MAX_R {
+                    for g in 0..=$type::MAX_G {
+                        for b in (0..=$type::MAX_B).step_by(3) {
+                            let color = $type::new(r, g, b);
+
+                            assert_eq!($type::from(Hsv::from(color)), color);
+                            assert_eq!($type::from(Hsl::from(color)), color);
+                        }
+                    }
+                })+
+            };
+        }
+
+        test_round_trip!(Rgb332, Rgb444, Bgr555, Rgb565, Bgr666);
+    }
+
+    #[test]
+    fn convert_to_hsv_and_hsl() {
+        assert_eq!(Hsv::from(Rgb888::BLACK), Hsv::new(0, 0, 0));
+        assert_eq!(Hsv::from(Rgb565::WHITE), Hsv::new(0, 0, 0xFFFF));
+        assert_eq!(Hsv::from(Rgb888::BLUE), Hsv::new(0xAAAB, 0xFFFF, 0xFFFF));
+        assert_eq!(Hsl::from(Bgr888::RED), Hsl::new(0, 0xFFFF, 0x8000));
+        assert_eq!(
+            Hsl::from(Rgb555::new(0, 31, 31)),
+            Hsl::new(0x8000, 0xFFFF, 0x8000)
+        );
+
+        assert_eq!(
+            Rgb888::from(Hsv::new(0x5555, 0xFFFF, 0xFFFF)),
+            Rgb888::GREEN
+        );
+        assert_eq!(
+            Rgb888::from(Hsl::new(0x5555, 0xFFFF, 0x8000)),
+            Rgb888::GREEN
+        );
+        assert_eq!(
+            Rgb888::from(Hsv::new(0x1234, 0, 0x8080)),
+            Rgb888::new(0x80, 0x80, 0x80)
+        );
+    }
 }
@@ -0,0 +1,173 @@
+use crate::pixelcolor::{
+    raw::{RawData, RawU2, RawU4},
+    PixelColor,
+};
+
+/// Three color e-paper color.
+///
+/// Three color e-paper displays can show black, white and one accent color, like red or yellow.
+/// The controllers of these displays usually expect the image data in two separate bitplanes: a
+/// black and white plane, in which black pixels are stored as `0` and all other pixels as `1`,
+/// and a color plane, in which pixels with the accent color are stored as `1`.
+pub trait TriColor: PixelColor {
+    /// Returns `true` if this color is black.
+    fn is_black(&self) -> bool;
+
+    /// Returns `true` if this color is the accent color.
+    fn is_accent(&self) -> bool;
+}
+
+macro_rules! tri_color {
+    ($type:ident, $accent:ident, $accent_str:expr) => {
+        #[doc = "Black, white and"]
+        #[doc = $accent_str]
+        #[doc = "e-paper color."]
+        #[doc = ""]
+        #[doc = "The color is stored with 2 bits per pixel: `0b00` is white, `0b01` is black and"]
+        #[doc = "`0b10` is the accent color. The unused encoding `0b11` is interpreted as the"]
+        #[doc = "accent color."]
+        #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+        #[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+        pub enum $type {
+            /// White.
+            #[default]
+            White,
+
+            /// Black.
+            Black,
+
+            #[doc = "Accent color."]
+            $accent,
+        }
+
+        impl PixelColor for $type {
+            type Raw = RawU2;
+        }
+
+        impl TriColor for $type {
+            fn is_black(&self) -> bool {
+                *self == Self::Black
+            }
+
+            fn is_accent(&self) -> bool {
+                *self == Self::$accent
+            }
+        }
+
+        impl From<RawU2> for $type {
+            fn from(data: RawU2) -> Self {
+                match data.into_inner() {
+                    0 => Self::White,
+                    1 => Self::Black,
+                    _ => Self::$accent,
+                }
+            }
+        }
+
+        impl From<$type> for RawU2 {
+            fn from(color: $type) -> Self {
+                RawU2::new(color as u8)
+            }
+        }
+    };
+}
+
+tri_color!(BwrColor, Red, "red");
+tri_color!(BwyColor, Yellow, "yellow");
+
+/// Seven color ACeP e-paper color.
+///
+/// Color type for seven color Advanced Color ePaper (ACeP) displays. The color is stored with
+/// 4 bits per pixel using the encoding expected by the display controllers, which makes it
+/// possible to transfer the data of a `Framebuffer` directly to the display. The unused encodings
+/// `0x7` to `0xF` are interpreted as white.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub enum AcepColor {
+    /// Black.
+    Black,
+
+    /// White.
+    #[default]
+    White,
+
+    /// Green.
+    Green,
+
+    /// Blue.
+    Blue,
+
+    /// Red.
+    Red,
+
+    /// Yellow.
+    Yellow,
+
+    /// Orange.
+    Orange,
+}
+
+impl PixelColor for AcepColor {
+    type Raw = RawU4;
+}
+
+impl From<RawU4> for AcepColor {
+    fn from(data: RawU4) -> Self {
+        match data.into_inner() {
+            0 => Self::Black,
+            2 => Self::Green,
+            3 => Self::Blue,
+            4 => Self::Red,
+            5 => Self::Yellow,
+            6 => Self::Orange,
+            _ => Self::White,
+        }
+    }
+}
+
+impl From<AcepColor> for RawU4 {
+    fn from(color: AcepColor) -> Self {
+        RawU4::new(color as u8)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pixelcolor::IntoStorage;
+
+    #[test]
+    fn tri_color_raw_data() {
+        assert_eq!(BwrColor::from(RawU2::new(0b00)), BwrColor::White);
+        assert_eq!(BwrColor::from(RawU2::new(0b01)), BwrColor::Black);
+        assert_eq!(BwrColor::from(RawU2::new(0b10)), BwrColor::Red);
+        assert_eq!(BwrColor::from(RawU2::new(0b11)), BwrColor::Red);
+        assert_eq!(BwyColor::from(RawU2::new(0b10)), BwyColor::Yellow);
+
+        assert_eq!(BwrColor::White.into_storage(), 0b00);
+        assert_eq!(BwrColor::Black.into_storage(), 0b01);
+        assert_eq!(BwyColor::Yellow.into_storage(), 0b10);
+        assert_eq!(BwyColor::default(), BwyColor::White);
+    }
+
+    #[test]
+    fn tri_color_planes() {
+        assert!(BwrColor::Black.is_black());
+        assert!(!BwrColor::Black.is_accent());
+        assert!(!BwrColor::White.is_black());
+        assert!(!BwrColor::White.is_accent());
+        assert!(!BwyColor::Yellow.is_black());
+        assert!(BwyColor::Yellow.is_accent());
+    }
+
+    #[test]
+    fn acep_raw_data() {
+        for value in 0..=6 {
+            assert_eq!(AcepColor::from(RawU4::new(value)).into_storage(), value);
+        }
+
+        assert_eq!(AcepColor::from(RawU4::new(7)), AcepColor::White);
+        assert_eq!(AcepColor::from(RawU4::new(0xF)), AcepColor::White);
+        assert_eq!(AcepColor::default(), AcepColor::White);
+    }
+}
@@ -0,0 +1,321 @@
+/// Maximum value of the normalized RGB channels used during conversions.
+const MAX: u32 = 0xFFFF;
+
+macro_rules! hue_color {
+    ($type:ident, $third:ident, $type_str:expr, $third_str:expr) => {
+        #[doc = $type_str]
+        #[doc = ""]
+        #[doc = "All components are stored as 16 bit values, which makes it possible to convert"]
+        #[doc = "RGB colors with up to 8 bits per channel to this type and back without any loss."]
+        #[doc = "The hue is stored as a fraction of a full turn, where `0x10000` corresponds to"]
+        #[doc = "360°, and wraps around on overflow. The saturation and"]
+        #[doc = $third_str]
+        #[doc = "range from `0` for 0% to `0xFFFF` for 100%."]
+        #[doc = ""]
+        #[doc = "# Examples"]
+        #[doc = ""]
+        #[doc = "```"]
+        #[doc = concat!("use embedded_graphics::pixelcolor::{", stringify!($type), ", Rgb565};")]
+        #[doc = "use embedded_graphics::prelude::*;"]
+        #[doc = ""]
+        #[doc = concat!("let color = ", stringify!($type), "::from(Rgb565::RED);")]
+        #[doc = ""]
+        #[doc = "// Rotate the hue by 120° from red to green."]
+        #[doc = "let color = color.rotate_hue(0x10000 / 3);"]
+        #[doc = "assert_eq!(Rgb565::from(color), Rgb565::GREEN);"]
+        #[doc = "```"]
+        #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+        #[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+        pub struct $type {
+            hue: u16,
+            saturation: u16,
+            $third: u16,
+        }
+
+        impl $type {
+            #[doc = concat!("Creates a new ", stringify!($type), " color.")]
+            pub const fn new(hue: u16, saturation: u16, $third: u16) -> Self {
+                Self {
+                    hue,
+                    saturation,
+                    $third,
+                }
+            }
+
+            /// Returns the hue.
+            pub const fn hue(&self) -> u16 {
+                self.hue
+            }
+
+            /// Returns the saturation.
+            pub const fn saturation(&self) -> u16 {
+                self.saturation
+            }
+
+            #[doc = concat!("Returns the ", $third_str, ".")]
+            pub const fn $third(&self) -> u16 {
+                self.$third
+            }
+
+            /// Returns a copy of this color with a different hue.
+            pub const fn with_hue(self, hue: u16) -> Self {
+                Self { hue, ..self }
+            }
+
+            /// Rotates the hue.
+            ///
+            /// A positive `delta` rotates the hue from red to yellow and green and a negative
+            /// `delta` in the opposite direction. The hue wraps around after a full turn of
+            /// `0x10000`.
+            pub const fn rotate_hue(self, delta: i32) -> Self {
+                self.with_hue(self.hue.wrapping_add(delta as u16))
+            }
+        }
+    };
+}
+
+hue_color!(Hsv, value, "HSV (hue, saturation, value) color.", "value");
+hue_color!(
+    Hsl,
+    lightness,
+    "HSL (hue, saturation, lightness) color.",
+    "lightness"
+);
+
+/// Calculates the hue of a normalized RGB color.
+///
+/// Returns `0` for achromatic colors.
+fn rgb_to_hue([r, g, b]: [u32; 3], max: u32, chroma: u32) -> u16 {
+    if chroma == 0 {
+        return 0;
+    }
+
+    // Offset of the sector in sixths of a full turn and the channels which determine the
+    // position inside the sector.
+    let (offset, a, b) = if max == r {
+        (0, g, b)
+    } else if max == g {
+        (2, b, r)
+    } else {
+        (4, r, g)
+    };
+
+    // The hue in sixths of a full turn, multiplied by `chroma`.
+    let chroma = i64::from(chroma);
+    let sixths = offset * chroma + i64::from(a) - i64::from(b);
+
+    let divisor = 6 * chroma;
+    let hue = (sixths * 0x10000 + divisor / 2).div_euclid(divisor);
+
+    hue.rem_euclid(0x10000) as u16
+}
+
+/// Calculates the normalized RGB color for a hue, chroma and minimum channel value.
+fn hue_to_rgb(hue: u16, chroma: u32, min: u32) -> [u16; 3] {
+    let sixths = u32::from(hue) * 6;
+    let sector = sixths >> 16;
+    let fraction = sixths & 0xFFFF;
+
+    // Value of the channel between the largest and smallest channel.
+    let fraction = if sector % 2 == 0 {
+        fraction
+    } else {
+        0x10000 - fraction
+    };
+    let mid = ((u64::from(chroma) * u64::from(fraction) + 0x8000) >> 16) as u32;
+
+    let (r, g, b) = match sector {
+        0 => (chroma, mid, 0),
+        1 => (mid, chroma, 0),
+        2 => (0, chroma, mid),
+        3 => (0, mid, chroma),
+        4 => (mid, 0, chroma),
+        _ => (chroma, 0, mid),
+    };
+
+    [r, g, b].map(|c| (c + min) as u16)
+}
+
+/// Returns the largest and smallest channel value.
+fn max_min(rgb: [u32; 3]) -> (u32, u32) {
+    let [r, g, b] = rgb;
+
+    (r.max(g).max(b), r.min(g).min(b))
+}
+
+/// Calculates `a * b / MAX` with rounding.
+const fn mul_div(a: u32, b: u32) -> u32 {
+    (a * b + MAX / 2) / MAX
+}
+
+/// Calculates `a * MAX / b` with rounding.
+const fn div_mul(a: u32, b: u32) -> u32 {
+    (a * MAX + b / 2) / b
+}
+
+impl Hsv {
+    /// Converts a normalized RGB color into an HSV color.
+    pub(crate) fn from_normalized_rgb(rgb: [u16; 3]) -> Self {
+        let rgb = rgb.map(u32::from);
+        let (max, min) = max_min(rgb);
+        let chroma = max - min;
+
+        let saturation = if max == 0 { 0 } else { div_mul(chroma, max) };
+
+        Self::new(rgb_to_hue(rgb, max, chroma), saturation as u16, max as u16)
+    }
+
+    /// Converts this color into a normalized RGB color.
+    pub(crate) fn to_normalized_rgb(self) -> [u16; 3] {
+        let value = u32::from(self.value);
+        let chroma = mul_div(value, u32::from(self.saturation));
+
+        hue_to_rgb(self.hue, chroma, value - chroma)
+    }
+}
+
+impl Hsl {
+    /// Converts a normalized RGB color into an HSL color.
+    pub(crate) fn from_normalized_rgb(rgb: [u16; 3]) -> Self {
+        let rgb = rgb.map(u32::from);
+        let (max, min) = max_min(rgb);
+        let chroma = max - min;
+
+        // The sum of the largest and smallest channel is twice the lightness.
+        let sum = max + min;
+        let divisor = sum.min(2 * MAX - sum);
+        let saturation = if divisor == 0 {
+            0
+        } else {
+            div_mul(chroma, divisor)
+        };
+
+        Self::new(
+            rgb_to_hue(rgb, max, chroma),
+            saturation as u16,
+            (max - chroma / 2) as u16,
+        )
+    }
+
+    /// Converts this color into a normalized RGB color.
+    pub(crate) fn to_normalized_rgb(self) -> [u16; 3] {
+        let lightness = u32::from(self.lightness);
+        let chroma = mul_div(
+            2 * lightness.min(MAX - lightness),
+            u32::from(self.saturation),
+        );
+
+        hue_to_rgb(self.hue, chroma, lightness - chroma / 2)
+    }
+}
+
+impl From<Hsl> for Hsv {
+    fn from(color: Hsl) -> Self {
+        let lightness = u32::from(color.lightness);
+        let value =
+            lightness + mul_div(lightness.min(MAX - lightness), u32::from(color.saturation));
+
+        let saturation = if value == 0 {
+            0
+        } else {
+            div_mul(2 * (value - lightness), value)
+        };
+
+        Self::new(color.hue, saturation as u16, value as u16)
+    }
+}
+
+impl From<Hsv> for Hsl {
+    fn from(color: Hsv) -> Self {
+        let value = u32::from(color.value);
+        let lightness = value - mul_div(value, u32::from(color.saturation)) / 2;
+
+        let divisor = lightness.min(MAX - lightness);
+        let saturation = if divisor == 0 {
+            0
+        } else {
+            div_mul(value - lightness, divisor)
+        };
+
+        Self::new(color.hue, saturation as u16, lightness as u16)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hue_rotation() {
+        let color = Hsv::new(0xF000, 0x8000, 0xFFFF);
+
+        assert_eq!(color.rotate_hue(0x0800).hue(), 0xF800);
+        assert_eq!(color.rotate_hue(0x2000).hue(), 0x1000);
+        assert_eq!(color.rotate_hue(-0xF800).hue(), 0xF800);
+        assert_eq!(color.rotate_hue(0x10000), color);
+        assert_eq!(color.with_hue(0x1234).saturation(), 0x8000);
+
+        let color = Hsl::new(0x0100, 0xFFFF, 0x8000);
+        assert_eq!(color.rotate_hue(-0x0200).hue(), 0xFF00);
+        assert_eq!(color.rotate_hue(-0x0200).lightness(), 0x8000);
+    }
+
+    #[test]
+    fn normalized_rgb() {
+        // Red, yellow, green, cyan, blue and magenta.
+        let colors = [
+            [MAX, 0, 0],
+            [MAX, MAX, 0],
+            [0, MAX, 0],
+            [0, MAX, MAX],
+            [0, 0, MAX],
+            [MAX, 0, MAX],
+        ]
+        .map(|rgb| rgb.map(|c| c as u16));
+
+        for (i, rgb) in colors.into_iter().enumerate() {
+            let hue = ((i as u32 * 0x10000 + 3) / 6) as u16;
+
+            assert_eq!(Hsv::from_normalized_rgb(rgb), Hsv::new(hue, 0xFFFF, 0xFFFF));
+            assert_eq!(Hsl::from_normalized_rgb(rgb), Hsl::new(hue, 0xFFFF, 0x8000));
+
+            // Hues of the secondary colors can't be represented exactly.
+            let converted = Hsv::new(hue, 0xFFFF, 0xFFFF).to_normalized_rgb();
+            for (c, expected) in converted.into_iter().zip(rgb) {
+                assert!(c.abs_diff(expected) <= 2, "{:?} != {:?}", converted, rgb);
+            }
+        }
+
+        let gray = [0x4000; 3];
+        assert_eq!(Hsv::from_normalized_rgb(gray), Hsv::new(0, 0, 0x4000));
+        assert_eq!(Hsl::from_normalized_rgb(gray), Hsl::new(0, 0, 0x4000));
+        assert_eq!(Hsl::new(0x1234, 0, 0x4000).to_normalized_rgb(), gray);
+    }
+
+    #[test]
+    fn hsv_to_hsl_and_back() {
+        let colors = [
+            Hsv::new(0x1234, 0xFFFF, 0xFFFF),
+            Hsv::new(0x8000, 0x8000, 0x8000),
+            Hsv::new(0xC000, 0x2000, 0xF000),
+            Hsv::new(0x4000, 0xFFFF, 0x1000),
+        ];
+
+        for color in colors {
+            let hsl = Hsl::from(color);
+            assert_eq!(hsl.hue(), color.hue());
+
+            let hsv = Hsv::from(hsl);
+            assert_eq!(hsv.hue(), color.hue());
+            assert!(hsv.saturation().abs_diff(color.saturation()) <= 2);
+            assert!(hsv.value().abs_diff(color.value()) <= 1);
+        }
+
+        assert_eq!(Hsl::from(Hsv::new(0, 0xFFFF, 0xFFFF)).lightness(), 0x8000);
+        assert_eq!(Hsl::from(Hsv::new(0, 0, 0)), Hsl::new(0, 0, 0));
+        assert_eq!(
+            Hsv::from(Hsl::new(0, 0xFFFF, 0xFFFF)),
+            Hsv::new(0, 0, 0xFFFF)
+        );
+    }
+}
@@ -0,0 +1,175 @@
+use crate::pixelcolor::{
+    raw::{RawData, RawU1, RawU2, RawU4, RawU8},
+    PixelColor,
+};
+
+/// Palette indexed color.
+///
+/// Indexed colors don't describe a color directly, but store an index into a [`Palette`]. The
+/// palette is used to look up the actual color when an indexed color is drawn.
+pub trait IndexedColor: PixelColor {
+    /// Returns the palette index.
+    fn index(&self) -> u8;
+
+    /// The largest index that can be stored in this color type.
+    const MAX_INDEX: u8;
+}
+
+macro_rules! indexed_color {
+    ($type:ident, $raw_type:ident, $bpp_str:expr) => {
+        #[doc = $bpp_str]
+        #[doc = "palette indexed color."]
+        #[doc = ""]
+        #[doc = "See the [`Palette`] documentation for an example."]
+        #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+        #[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+        pub struct $type($raw_type);
+
+        impl $type {
+            /// Creates a new indexed color.
+            ///
+            /// Too large index values are masked to the valid range by setting
+            /// the upper bits to `0`.
+            pub const fn new(index: u8) -> Self {
+                Self($raw_type::new(index))
+            }
+        }
+
+        impl PixelColor for $type {
+            type Raw = $raw_type;
+        }
+
+        impl IndexedColor for $type {
+            fn index(&self) -> u8 {
+                self.0.into_inner()
+            }
+
+            const MAX_INDEX: u8 = 0xFF >> (8 - $raw_type::BITS_PER_PIXEL);
+        }
+
+        impl From<$raw_type> for $type {
+            fn from(data: $raw_type) -> Self {
+                Self(data)
+            }
+        }
+
+        impl From<$type> for $raw_type {
+            fn from(color: $type) -> Self {
+                color.0
+            }
+        }
+    };
+}
+
+indexed_color!(Indexed1, RawU1, "1 bit");
+indexed_color!(Indexed2, RawU2, "2 bit");
+indexed_color!(Indexed4, RawU4, "4 bit");
+indexed_color!(Indexed8, RawU8, "8 bit");
+
+/// Color palette.
+///
+/// A palette maps the indices of an [`IndexedColor`] to colors of another color type. Palettes
+/// are usually defined as constants and can contain fewer colors than the indexed color type can
+/// address. Looking up an index outside of the palette returns `None`.
+///
+/// # Examples
+///
+/// ```
+/// use embedded_graphics::pixelcolor::{Indexed4, Palette, Rgb565};
+/// use embedded_graphics::prelude::*;
+///
+/// const PALETTE: Palette<Rgb565> = Palette::new(&[Rgb565::BLACK, Rgb565::RED, Rgb565::WHITE]);
+///
+/// assert_eq!(PALETTE.get(Indexed4::new(1)), Some(Rgb565::RED));
+/// assert_eq!(PALETTE.get(Indexed4::new(3)), None);
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub struct Palette<'a, C> {
+    colors: &'a [C],
+}
+
+impl<'a, C> Palette<'a, C>
+where
+    C: PixelColor,
+{
+    /// Creates a new palette.
+    ///
+    /// The color at position `n` in `colors` is used for index `n`.
+    pub const fn new(colors: &'a [C]) -> Self {
+        Self { colors }
+    }
+
+    /// Returns the colors in this palette.
+    pub const fn colors(&self) -> &'a [C] {
+        self.colors
+    }
+
+    /// Returns the number of colors in this palette.
+    pub const fn len(&self) -> usize {
+        self.colors.len()
+    }
+
+    /// Returns `true` if the palette doesn't contain any colors.
+    pub const fn is_empty(&self) -> bool {
+        self.colors.is_empty()
+    }
+
+    /// Returns `true` if the palette contains a color for every index of `I`.
+    pub fn is_complete<I: IndexedColor>(&self) -> bool {
+        self.colors.len() > usize::from(I::MAX_INDEX)
+    }
+
+    /// Returns the color for an indexed color.
+    ///
+    /// `None` is returned if the index is outside of the palette.
+    pub fn get<I: IndexedColor>(&self, color: I) -> Option<C> {
+        self.colors.get(usize::from(color.index())).copied()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pixelcolor::{IntoStorage, Rgb888, RgbColor};
+
+    #[test]
+    fn new_masks_index() {
+        assert_eq!(Indexed1::new(255).index(), 0x1);
+        assert_eq!(Indexed2::new(255).index(), 0x3);
+        assert_eq!(Indexed4::new(255).index(), 0xF);
+        assert_eq!(Indexed8::new(255).index(), 0xFF);
+    }
+
+    #[test]
+    fn max_index() {
+        assert_eq!(Indexed1::MAX_INDEX, 0x1);
+        assert_eq!(Indexed2::MAX_INDEX, 0x3);
+        assert_eq!(Indexed4::MAX_INDEX, 0xF);
+        assert_eq!(Indexed8::MAX_INDEX, 0xFF);
+    }
+
+    #[test]
+    fn raw_data_conversions() {
+        assert_eq!(Indexed1::from(RawU1::new(0x1)), Indexed1::new(0x1));
+        assert_eq!(Indexed4::from(RawU4::new(0x9)), Indexed4::new(0x9));
+        assert_eq!(RawU2::from(Indexed2::new(0x2)), RawU2::new(0x2));
+        assert_eq!(Indexed8::new(0x7E).into_storage(), 0x7Eu8);
+    }
+
+    #[test]
+    fn palette_lookup() {
+        let palette = Palette::new(&[Rgb888::BLACK, Rgb888::RED, Rgb888::GREEN]);
+
+        assert_eq!(palette.len(), 3);
+        assert!(!palette.is_empty());
+        assert_eq!(palette.get(Indexed2::new(0)), Some(Rgb888::BLACK));
+        assert_eq!(palette.get(Indexed2::new(2)), Some(Rgb888::GREEN));
+        assert_eq!(palette.get(Indexed2::new(3)), None);
+        assert_eq!(palette.get(Indexed8::new(200)), None);
+
+        assert!(palette.is_complete::<Indexed1>());
+        assert!(!palette.is_complete::<Indexed2>());
+        assert!(Palette::<Rgb888>::new(&[]).is_empty());
+    }
+}
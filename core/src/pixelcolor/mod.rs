@@ -108,15 +108,23 @@
 
 mod binary_color;
 mod conversion;
+mod epd_color;
 mod gray_color;
+mod hsv_color;
+mod indexed_color;
 pub mod raw;
 mod rgb_color;
+mod rgba_color;
 mod web_colors;
 
 pub use binary_color::*;
+pub use epd_color::*;
 pub use gray_color::*;
+pub use hsv_color::{Hsl, Hsv};
+pub use indexed_color::*;
 use raw::RawData;
 pub use rgb_color::*;
+pub use rgba_color::*;
 pub use web_colors::WebColors;
 
 /// Pixel color trait.
@@ -113,7 +113,7 @@ impl<C: PixelColor> ToBytes for C {
 mod tests {
     use super::*;
     use crate::pixelcolor::{
-        Bgr565, Bgr666, Bgr888, BinaryColor, Gray2, Gray4, Gray8, Rgb565, Rgb666, Rgb888,
+        Bgr565, Bgr666, Bgr888, BinaryColor, Gray2, Gray4, Gray8, Rgb565, Rgb666, Rgb888, Rgba8888,
     };
 
     fn assert_all_orders<T>(value: T, bytes: T::Bytes)
@@ -344,20 +344,16 @@ mod tests {
 
     #[test]
     fn bpp32_be() {
-        // This test uses `RawU32` instead of a color, because no color included
-        // in this crate uses 32 bpp.
         assert_eq!(
-            RawU32::new(0x11223344).to_be_bytes(),
+            Rgba8888::new(0x11, 0x22, 0x33, 0x44).to_be_bytes(),
             [0x11, 0x22, 0x33, 0x44]
         );
     }
 
     #[test]
     fn bpp32_le() {
-        // This test uses `RawU32` instead of a color, because no color included
-        // in this crate uses 32 bpp.
         assert_eq!(
-            RawU32::new(0x11223344).to_le_bytes(),
+            Rgba8888::new(0x11, 0x22, 0x33, 0x44).to_le_bytes(),
             [0x44, 0x33, 0x22, 0x11]
         );
     }
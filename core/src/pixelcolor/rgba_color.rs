@@ -0,0 +1,260 @@
+use crate::pixelcolor::{
+    raw::{RawData, RawU16, RawU32},
+    PixelColor, RgbColor,
+};
+use core::fmt;
+
+/// Color with an alpha channel.
+///
+/// The alpha channel defines the opacity of a color. A value of `0` is fully transparent and a
+/// value of [`MAX_A`] is fully opaque.
+///
+/// The predefined color constants of the [`RgbColor`] trait are fully opaque for all color types
+/// with an alpha channel.
+///
+/// [`MAX_A`]: AlphaColor::MAX_A
+pub trait AlphaColor: PixelColor {
+    /// Returns the alpha channel value.
+    fn a(&self) -> u8;
+
+    /// The maximum value in the alpha channel.
+    const MAX_A: u8;
+
+    /// Fully transparent color.
+    const TRANSPARENT: Self;
+}
+
+/// Macro to implement a RGB color type with an alpha channel.
+macro_rules! impl_rgba_color {
+    (
+        $type:ident,
+        $data_type:ty,
+        $storage_type:ty,
+        $bits:expr,
+        ($r_pos:expr, $g_pos:expr, $b_pos:expr, $a_pos:expr),
+        $type_str:expr
+    ) => {
+        #[doc = $type_str]
+        #[doc = "color with an alpha channel."]
+        #[doc = ""]
+        #[doc = "Use the methods provided by the [`RgbColor`] and [`AlphaColor`] traits to"]
+        #[doc = "access individual color channels and predefined color constants."]
+        #[doc = ""]
+        #[doc = "See the [module-level documentation](super) for more information about"]
+        #[doc = "conversion between this type and raw data."]
+        #[doc = ""]
+        #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Default)]
+        pub struct $type($storage_type);
+
+        impl fmt::Debug for $type {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(
+                    f,
+                    "{}(r: {}, g: {}, b: {}, a: {})",
+                    stringify!($type),
+                    self.r(),
+                    self.g(),
+                    self.b(),
+                    self.a()
+                )
+            }
+        }
+
+        #[cfg(feature = "defmt")]
+        impl ::defmt::Format for $type {
+            fn format(&self, f: ::defmt::Formatter) {
+                ::defmt::write!(
+                    f,
+                    "{}(r: {=u8}, g: {=u8}, b: {=u8}, a: {=u8})",
+                    stringify!($type),
+                    self.r(),
+                    self.g(),
+                    self.b(),
+                    self.a()
+                )
+            }
+        }
+
+        impl $type {
+            #[doc = "Creates a new"]
+            #[doc = $type_str]
+            #[doc = "color.\n"]
+            #[doc = "Too large channel values will be limited by setting the"]
+            #[doc = "unused most significant bits to zero."]
+            #[allow(trivial_numeric_casts)]
+            pub const fn new(r: u8, g: u8, b: u8, a: u8) -> Self {
+                let r_shifted = (r & Self::MAX_R) as $storage_type << $r_pos;
+                let g_shifted = (g & Self::MAX_G) as $storage_type << $g_pos;
+                let b_shifted = (b & Self::MAX_B) as $storage_type << $b_pos;
+                let a_shifted = (a & Self::MAX_A) as $storage_type << $a_pos;
+
+                Self(r_shifted | g_shifted | b_shifted | a_shifted)
+            }
+
+            /// Returns a copy of this color with a different alpha channel value.
+            pub const fn with_alpha(self, a: u8) -> Self {
+                #![allow(trivial_numeric_casts)]
+
+                let mask = (Self::MAX_A as $storage_type) << $a_pos;
+                let a_shifted = (a & Self::MAX_A) as $storage_type << $a_pos;
+
+                Self(self.0 & !mask | a_shifted)
+            }
+
+            /// Returns `true` if this color is fully opaque.
+            pub fn is_opaque(&self) -> bool {
+                self.a() == Self::MAX_A
+            }
+
+            /// Returns `true` if this color is fully transparent.
+            pub fn is_transparent(&self) -> bool {
+                self.a() == 0
+            }
+        }
+
+        impl RgbColor for $type {
+            fn r(&self) -> u8 {
+                #![allow(trivial_numeric_casts)]
+
+                (self.0 >> $r_pos) as u8 & Self::MAX_R
+            }
+
+            fn g(&self) -> u8 {
+                #![allow(trivial_numeric_casts)]
+
+                (self.0 >> $g_pos) as u8 & Self::MAX_G
+            }
+
+            fn b(&self) -> u8 {
+                #![allow(trivial_numeric_casts)]
+
+                (self.0 >> $b_pos) as u8 & Self::MAX_B
+            }
+
+            const MAX_R: u8 = ((1usize << $bits) - 1) as u8;
+            const MAX_G: u8 = ((1usize << $bits) - 1) as u8;
+            const MAX_B: u8 = ((1usize << $bits) - 1) as u8;
+
+            const BLACK: Self = Self::new(0, 0, 0, Self::MAX_A);
+            const RED: Self = Self::new(Self::MAX_R, 0, 0, Self::MAX_A);
+            const GREEN: Self = Self::new(0, Self::MAX_G, 0, Self::MAX_A);
+            const BLUE: Self = Self::new(0, 0, Self::MAX_B, Self::MAX_A);
+            const YELLOW: Self = Self::new(Self::MAX_R, Self::MAX_G, 0, Self::MAX_A);
+            const MAGENTA: Self = Self::new(Self::MAX_R, 0, Self::MAX_B, Self::MAX_A);
+            const CYAN: Self = Self::new(0, Self::MAX_G, Self::MAX_B, Self::MAX_A);
+            const WHITE: Self = Self::new(Self::MAX_R, Self::MAX_G, Self::MAX_B, Self::MAX_A);
+        }
+
+        impl AlphaColor for $type {
+            fn a(&self) -> u8 {
+                #![allow(trivial_numeric_casts)]
+
+                (self.0 >> $a_pos) as u8 & Self::MAX_A
+            }
+
+            const MAX_A: u8 = ((1usize << $bits) - 1) as u8;
+
+            const TRANSPARENT: Self = Self::new(0, 0, 0, 0);
+        }
+
+        impl PixelColor for $type {
+            type Raw = $data_type;
+        }
+
+        impl From<$data_type> for $type {
+            fn from(data: $data_type) -> Self {
+                Self(data.into_inner())
+            }
+        }
+
+        impl From<$type> for $data_type {
+            fn from(color: $type) -> Self {
+                Self::new(color.0)
+            }
+        }
+    };
+
+    (
+        $type:ident,
+        $data_type:ty,
+        $storage_type:ty,
+        $bits:expr,
+        ($r_pos:expr, $g_pos:expr, $b_pos:expr, $a_pos:expr)
+    ) => {
+        impl_rgba_color!(
+            $type,
+            $data_type,
+            $storage_type,
+            $bits,
+            ($r_pos, $g_pos, $b_pos, $a_pos),
+            stringify!($type)
+        );
+    };
+}
+
+impl_rgba_color!(Rgba4444, RawU16, u16, 4, (12, 8, 4, 0));
+impl_rgba_color!(Rgba8888, RawU32, u32, 8, (24, 16, 8, 0));
+impl_rgba_color!(Argb8888, RawU32, u32, 8, (16, 8, 0, 24));
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pixelcolor::IntoStorage;
+
+    #[test]
+    fn bit_positions_rgba4444() {
+        assert_eq!(Rgba4444::new(0b1001, 0, 0, 0).into_storage(), 0b1001 << 12);
+        assert_eq!(Rgba4444::new(0, 0b1001, 0, 0).into_storage(), 0b1001 << 8);
+        assert_eq!(Rgba4444::new(0, 0, 0b1001, 0).into_storage(), 0b1001 << 4);
+        assert_eq!(Rgba4444::new(0, 0, 0, 0b1001).into_storage(), 0b1001);
+    }
+
+    #[test]
+    fn bit_positions_rgba8888() {
+        let color = Rgba8888::new(0x11, 0x22, 0x33, 0x44);
+
+        assert_eq!(color.into_storage(), 0x11223344);
+        assert_eq!(Rgba8888::from(RawU32::new(0x11223344)), color);
+    }
+
+    #[test]
+    fn bit_positions_argb8888() {
+        let color = Argb8888::new(0x11, 0x22, 0x33, 0x44);
+
+        assert_eq!(color.into_storage(), 0x44112233);
+        assert_eq!(Argb8888::from(RawU32::new(0x44112233)), color);
+    }
+
+    #[test]
+    fn channels() {
+        let color = Rgba4444::new(1, 2, 3, 4);
+
+        assert_eq!((color.r(), color.g(), color.b(), color.a()), (1, 2, 3, 4));
+        assert_eq!(
+            Rgba4444::new(0xFF, 0, 0, 0x1F),
+            Rgba4444::new(0xF, 0, 0, 0xF)
+        );
+    }
+
+    #[test]
+    fn with_alpha() {
+        let color = Argb8888::new(10, 20, 30, 40);
+
+        assert_eq!(color.with_alpha(200), Argb8888::new(10, 20, 30, 200));
+        assert_eq!(
+            Rgba4444::WHITE.with_alpha(0x13),
+            Rgba4444::new(0xF, 0xF, 0xF, 0x3)
+        );
+    }
+
+    #[test]
+    fn constants_are_opaque() {
+        assert!(Rgba8888::RED.is_opaque());
+        assert_eq!(Rgba8888::RED, Rgba8888::new(255, 0, 0, 255));
+        assert_eq!(Argb8888::WHITE.into_storage(), 0xFFFFFFFF);
+        assert_eq!(Rgba4444::BLACK.into_storage(), 0x000F);
+
+        assert!(Rgba8888::TRANSPARENT.is_transparent());
+        assert!(!Rgba8888::BLACK.is_transparent());
+    }
+}
@@ -8,7 +8,8 @@ pub use crate::{
     image::ImageDrawable,
     pixelcolor::{
         raw::{RawData, ToBytes as _},
-        GrayColor, IntoStorage, PixelColor, RgbColor, WebColors,
+        AlphaColor, GrayColor, IndexedColor, IntoStorage, PixelColor, RgbColor, TriColor,
+        WebColors,
     },
     primitives::PointsIter,
 };
@@ -0,0 +1,244 @@
+use crate::{
+    draw_target::DrawTarget,
+    geometry::{Dimensions, Point},
+    image::GetPixel,
+    pixelcolor::{AlphaColor, Rgb888, RgbColor},
+    primitives::{PointsIter, Rectangle},
+    Pixel,
+};
+use core::{iter, marker::PhantomData};
+
+/// Alpha blending draw target.
+///
+/// Created by calling [`alpha_blended`] on any [`DrawTarget`] which also implements
+/// [`GetPixel`].
+/// See the [`alpha_blended`] method documentation for more information.
+///
+/// [`alpha_blended`]: crate::draw_target::DrawTargetExt::alpha_blended
+#[derive(Debug)]
+pub struct AlphaBlended<'a, T, C> {
+    /// The parent draw target.
+    parent: &'a mut T,
+
+    /// The input color type.
+    color_type: PhantomData<C>,
+}
+
+impl<'a, T, C> AlphaBlended<'a, T, C>
+where
+    T: DrawTarget + GetPixel<Color = <T as DrawTarget>::Color>,
+    <T as DrawTarget>::Color: From<Rgb888> + Into<Rgb888>,
+    C: AlphaColor + Into<Rgb888>,
+{
+    pub(super) fn new(parent: &'a mut T) -> Self {
+        Self {
+            parent,
+            color_type: PhantomData,
+        }
+    }
+
+    /// Returns the color of a pixel after blending it with the current parent pixel color.
+    ///
+    /// Returns `None` if the color is fully transparent or if the parent pixel color couldn't be
+    /// read.
+    fn blend(&self, point: Point, color: C) -> Option<<T as DrawTarget>::Color> {
+        let foreground: Rgb888 = color.into();
+
+        match normalized_alpha(color) {
+            0 => None,
+            255 => Some(foreground.into()),
+            alpha => {
+                let background = self.parent.pixel(point)?.into();
+
+                Some(blend_rgb888(foreground, background, alpha).into())
+            }
+        }
+    }
+}
+
+impl<T, C> DrawTarget for AlphaBlended<'_, T, C>
+where
+    T: DrawTarget + GetPixel<Color = <T as DrawTarget>::Color>,
+    <T as DrawTarget>::Color: From<Rgb888> + Into<Rgb888>,
+    C: AlphaColor + Into<Rgb888>,
+{
+    type Color = C;
+    type Error = <T as DrawTarget>::Error;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        for Pixel(point, color) in pixels {
+            if let Some(color) = self.blend(point, color) {
+                self.parent.draw_iter(iter::once(Pixel(point, color)))?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn fill_solid(&mut self, area: &Rectangle, color: Self::Color) -> Result<(), Self::Error> {
+        match normalized_alpha(color) {
+            0 => Ok(()),
+            255 => {
+                let color: Rgb888 = color.into();
+                self.parent.fill_solid(area, color.into())
+            }
+            _ => {
+                let area = area.intersection(&self.bounding_box());
+                self.draw_iter(area.points().map(|point| Pixel(point, color)))
+            }
+        }
+    }
+
+    fn clear(&mut self, color: Self::Color) -> Result<(), Self::Error> {
+        self.fill_solid(&self.bounding_box(), color)
+    }
+}
+
+impl<T, C> Dimensions for AlphaBlended<'_, T, C>
+where
+    T: DrawTarget,
+{
+    fn bounding_box(&self) -> Rectangle {
+        self.parent.bounding_box()
+    }
+}
+
+/// Returns the alpha channel value of a color scaled to the range `0..=255`.
+fn normalized_alpha<C: AlphaColor>(color: C) -> u8 {
+    (u16::from(color.a()) * 255 / u16::from(C::MAX_A)) as u8
+}
+
+/// Blends two colors.
+fn blend_rgb888(foreground: Rgb888, background: Rgb888, alpha: u8) -> Rgb888 {
+    let alpha = u16::from(alpha);
+
+    let channel = |foreground: u8, background: u8| {
+        let sum = u16::from(foreground) * alpha + u16::from(background) * (255 - alpha);
+
+        ((sum + 127) / 255) as u8
+    };
+
+    Rgb888::new(
+        channel(foreground.r(), background.r()),
+        channel(foreground.g(), background.g()),
+        channel(foreground.b(), background.b()),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        draw_target::DrawTargetExt,
+        framebuffer::{buffer_size, Framebuffer},
+        geometry::Size,
+        pixelcolor::{
+            raw::{LittleEndianMsb0, RawU16, RawU24},
+            Argb8888, Rgb565, Rgba4444, Rgba8888,
+        },
+    };
+
+    fn framebuffer(
+    ) -> Framebuffer<Rgb888, RawU24, LittleEndianMsb0, 8, 8, { buffer_size::<Rgb888>(8, 8) }> {
+        let mut fb = Framebuffer::new();
+        fb.clear(Rgb888::new(0, 0, 200)).unwrap();
+        fb
+    }
+
+    #[test]
+    fn blend() {
+        assert_eq!(
+            blend_rgb888(Rgb888::new(255, 100, 0), Rgb888::new(0, 0, 200), 128),
+            Rgb888::new(128, 50, 100)
+        );
+        assert_eq!(blend_rgb888(Rgb888::WHITE, Rgb888::BLACK, 0), Rgb888::BLACK);
+        assert_eq!(
+            blend_rgb888(Rgb888::WHITE, Rgb888::BLACK, 255),
+            Rgb888::WHITE
+        );
+    }
+
+    #[test]
+    fn draw_iter() {
+        let mut fb = framebuffer();
+
+        fb.alpha_blended()
+            .draw_iter([
+                Pixel(Point::new(0, 0), Rgba8888::new(255, 100, 0, 255)),
+                Pixel(Point::new(1, 0), Rgba8888::new(255, 100, 0, 128)),
+                Pixel(Point::new(2, 0), Rgba8888::new(255, 100, 0, 0)),
+                Pixel(Point::new(20, 0), Rgba8888::new(255, 100, 0, 128)),
+            ])
+            .unwrap();
+
+        assert_eq!(fb.pixel(Point::new(0, 0)), Some(Rgb888::new(255, 100, 0)));
+        assert_eq!(fb.pixel(Point::new(1, 0)), Some(Rgb888::new(128, 50, 100)));
+        assert_eq!(fb.pixel(Point::new(2, 0)), Some(Rgb888::new(0, 0, 200)));
+    }
+
+    #[test]
+    fn fill_solid() {
+        let mut fb = framebuffer();
+        let area = Rectangle::new(Point::new(1, 2), Size::new(3, 2));
+
+        fb.alpha_blended()
+            .fill_solid(&area, Argb8888::new(255, 0, 0, 51))
+            .unwrap();
+
+        for point in fb.bounding_box().points() {
+            let expected = if area.contains(point) {
+                Rgb888::new(51, 0, 160)
+            } else {
+                Rgb888::new(0, 0, 200)
+            };
+
+            assert_eq!(fb.pixel(point), Some(expected), "{:?}", point);
+        }
+    }
+
+    #[test]
+    fn fill_solid_opaque() {
+        let mut fb = framebuffer();
+        let area = Rectangle::new(Point::new(1, 2), Size::new(3, 2));
+
+        fb.alpha_blended().fill_solid(&area, Rgba4444::RED).unwrap();
+
+        assert_eq!(fb.pixel(Point::new(1, 2)), Some(Rgb888::RED));
+        assert_eq!(fb.pixel(Point::new(0, 2)), Some(Rgb888::new(0, 0, 200)));
+    }
+
+    #[test]
+    fn clear() {
+        let mut fb = framebuffer();
+
+        fb.alpha_blended()
+            .clear(Rgba4444::new(0xF, 0xF, 0xF, 0x5))
+            .unwrap();
+
+        assert_eq!(fb.pixel(Point::new(0, 0)), Some(Rgb888::new(85, 85, 218)));
+        assert_eq!(fb.pixel(Point::new(7, 7)), Some(Rgb888::new(85, 85, 218)));
+    }
+
+    #[test]
+    fn native_color_type() {
+        let mut fb: Framebuffer<
+            Rgb565,
+            RawU16,
+            LittleEndianMsb0,
+            4,
+            4,
+            { buffer_size::<Rgb565>(4, 4) },
+        > = Framebuffer::new();
+        fb.clear(Rgb565::BLACK).unwrap();
+
+        let area = fb.bounding_box();
+        fb.alpha_blended()
+            .fill_solid(&area, Rgba8888::WHITE.with_alpha(128))
+            .unwrap();
+
+        assert_eq!(fb.pixel(Point::zero()), Some(Rgb565::new(16, 32, 16)));
+    }
+}
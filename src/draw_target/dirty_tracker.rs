@@ -0,0 +1,475 @@
+use crate::{
+    draw_target::DrawTarget,
+    geometry::{Dimensions, Point},
+    primitives::Rectangle,
+    Pixel,
+};
+
+/// Region which was changed by drawing operations.
+///
+/// The region is stored as a list of up to `N` non overlapping rectangles. Overlapping or
+/// adjacent rectangles are merged into their envelope and if more than `N` rectangles would be
+/// required the new rectangle is merged with the existing rectangle which results in the smallest
+/// increase in area. A region with `N = 1` is the bounding box of all changed pixels.
+///
+/// See [`DirtyTracker`] for more information.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub struct DirtyRegion<const N: usize> {
+    /// The rectangles after `len` are always zero to make sure that the derived traits only
+    /// depend on the rectangles in the region.
+    rectangles: [Rectangle; N],
+    len: usize,
+}
+
+impl<const N: usize> DirtyRegion<N> {
+    /// Creates a new empty region.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `N` is zero.
+    pub const fn new() -> Self {
+        assert!(N > 0, "dirty regions must contain at least one rectangle");
+
+        Self {
+            rectangles: [Rectangle::zero(); N],
+            len: 0,
+        }
+    }
+
+    /// Returns the rectangles in this region.
+    pub fn rectangles(&self) -> &[Rectangle] {
+        &self.rectangles[0..self.len]
+    }
+
+    /// Returns `true` if the region is empty.
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Adds an area to the region.
+    ///
+    /// Zero sized areas are ignored.
+    pub fn add(&mut self, area: &Rectangle) {
+        if area.is_zero_sized() {
+            return;
+        }
+
+        let mut area = *area;
+
+        loop {
+            // Overlapping or adjacent rectangles are always merged. If there is no such rectangle
+            // and the list is already full the rectangle with the smallest increase in area is
+            // used.
+            let expanded = area.offset(1);
+            let index = match self
+                .rectangles()
+                .iter()
+                .position(|rectangle| !rectangle.intersection(&expanded).is_zero_sized())
+            {
+                Some(index) => index,
+                None if self.len < N => {
+                    self.rectangles[self.len] = area;
+                    self.len += 1;
+                    return;
+                }
+                None => self.smallest_envelope(&area),
+            };
+
+            // Merging the rectangles can lead to new overlaps, which is why the merged area is
+            // added again after the original rectangle was removed.
+            area = area.envelope(&self.rectangles[index]);
+            self.len -= 1;
+            self.rectangles.swap(index, self.len);
+            self.rectangles[self.len] = Rectangle::zero();
+        }
+    }
+
+    /// Removes all rectangles from the region.
+    pub fn clear(&mut self) {
+        self.rectangles[0..self.len].fill(Rectangle::zero());
+        self.len = 0;
+    }
+
+    /// Returns the index of the rectangle with the smallest increase in area if it is merged with
+    /// `area`.
+    fn smallest_envelope(&self, area: &Rectangle) -> usize {
+        let size = |rectangle: &Rectangle| {
+            u64::from(rectangle.size.width) * u64::from(rectangle.size.height)
+        };
+
+        self.rectangles()
+            .iter()
+            .enumerate()
+            .min_by_key(|(_, rectangle)| size(&rectangle.envelope(area)) - size(rectangle))
+            .map(|(index, _)| index)
+            .unwrap_or_default()
+    }
+}
+
+impl<const N: usize> Default for DirtyRegion<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> Dimensions for DirtyRegion<N> {
+    /// Returns the envelope of all rectangles in the region.
+    ///
+    /// A zero sized rectangle at the origin is returned if the region is empty.
+    fn bounding_box(&self) -> Rectangle {
+        self.rectangles()
+            .iter()
+            .copied()
+            .reduce(|a, b| a.envelope(&b))
+            .unwrap_or_else(Rectangle::zero)
+    }
+}
+
+/// Dirty rectangle tracking draw target.
+///
+/// A `DirtyTracker` wraps a draw target and records the areas which were changed by drawing
+/// operations. The changed areas are stored in a [`DirtyRegion`] with up to `N` rectangles. By
+/// default a single rectangle is used, which contains all changed pixels.
+///
+/// This can be used to only transfer the changed parts of a [`Framebuffer`] to a display or to do
+/// a partial refresh of an e-paper display. Only pixels inside the bounding box of the wrapped
+/// draw target are recorded and drawing operations which are performed directly on the wrapped
+/// draw target, by using [`target_mut`], aren't tracked.
+///
+/// # Examples
+///
+/// ```
+/// use embedded_graphics::{
+///     draw_target::DirtyTracker,
+///     framebuffer::{buffer_size, Framebuffer},
+///     image::Image,
+///     mock_display::MockDisplay,
+///     pixelcolor::{raw::LittleEndianMsb0, BinaryColor},
+///     prelude::*,
+///     primitives::{Circle, PrimitiveStyle, Rectangle},
+/// };
+///
+/// let framebuffer = Framebuffer::<
+///     BinaryColor,
+///     _,
+///     LittleEndianMsb0,
+///     64,
+///     64,
+///     { buffer_size::<BinaryColor>(64, 64) },
+/// >::new();
+///
+/// // Track up to 4 separate changed areas.
+/// let mut tracker = DirtyTracker::<_, 4>::new(framebuffer);
+///
+/// Circle::new(Point::new(5, 5), 10)
+///     .into_styled(PrimitiveStyle::with_fill(BinaryColor::On))
+///     .draw(&mut tracker)?;
+///
+/// Rectangle::new(Point::new(40, 30), Size::new(20, 3))
+///     .into_styled(PrimitiveStyle::with_fill(BinaryColor::On))
+///     .draw(&mut tracker)?;
+///
+/// let dirty_region = tracker.take_dirty_region();
+/// assert_eq!(
+///     dirty_region.rectangles(),
+///     &[
+///         Rectangle::new(Point::new(5, 5), Size::new(10, 10)),
+///         Rectangle::new(Point::new(40, 30), Size::new(20, 3)),
+///     ]
+/// );
+///
+/// // Only transfer the changed parts of the framebuffer to the display.
+/// let mut display = MockDisplay::new();
+/// let image = tracker.target().as_image();
+/// for area in dirty_region.rectangles() {
+///     Image::new(&image.sub_image(area), area.top_left).draw(&mut display)?;
+/// }
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+///
+/// [`Framebuffer`]: crate::framebuffer::Framebuffer
+/// [`target_mut`]: DirtyTracker::target_mut
+#[derive(Debug)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub struct DirtyTracker<T, const N: usize = 1> {
+    target: T,
+    dirty_region: DirtyRegion<N>,
+}
+
+impl<T, const N: usize> DirtyTracker<T, N>
+where
+    T: DrawTarget,
+{
+    /// Creates a new dirty tracker for the given draw target.
+    ///
+    /// The dirty region of a new tracker is empty.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `N` is zero.
+    pub fn new(target: T) -> Self {
+        Self {
+            target,
+            dirty_region: DirtyRegion::new(),
+        }
+    }
+
+    /// Returns the current dirty region.
+    pub const fn dirty_region(&self) -> &DirtyRegion<N> {
+        &self.dirty_region
+    }
+
+    /// Returns the current dirty region and resets it.
+    pub fn take_dirty_region(&mut self) -> DirtyRegion<N> {
+        let dirty_region = self.dirty_region;
+        self.dirty_region.clear();
+
+        dirty_region
+    }
+
+    /// Resets the dirty region.
+    pub fn reset(&mut self) {
+        self.dirty_region.clear();
+    }
+
+    /// Marks an area as dirty.
+    ///
+    /// This can be used to force an update of an area, e.g. after drawing operations were
+    /// performed directly on the wrapped draw target.
+    pub fn mark_dirty(&mut self, area: &Rectangle) {
+        let area = area.intersection(&self.target.bounding_box());
+        self.dirty_region.add(&area);
+    }
+
+    /// Returns a reference to the wrapped draw target.
+    pub const fn target(&self) -> &T {
+        &self.target
+    }
+
+    /// Returns a mutable reference to the wrapped draw target.
+    ///
+    /// Drawing operations which are performed by using the returned reference aren't tracked.
+    pub fn target_mut(&mut self) -> &mut T {
+        &mut self.target
+    }
+
+    /// Returns the wrapped draw target.
+    pub fn into_inner(self) -> T {
+        self.target
+    }
+}
+
+impl<T, const N: usize> DrawTarget for DirtyTracker<T, N>
+where
+    T: DrawTarget,
+{
+    type Color = T::Color;
+    type Error = T::Error;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        let bounding_box = self.target.bounding_box();
+
+        // The corners of the envelope of all drawn pixels inside the bounding box.
+        let mut corners: Option<(Point, Point)> = None;
+
+        let pixels = pixels.into_iter().inspect(|Pixel(point, _)| {
+            if bounding_box.contains(*point) {
+                corners = Some(match corners {
+                    Some((min, max)) => (min.component_min(*point), max.component_max(*point)),
+                    None => (*point, *point),
+                });
+            }
+        });
+
+        let result = self.target.draw_iter(pixels);
+
+        if let Some((min, max)) = corners {
+            self.dirty_region.add(&Rectangle::with_corners(min, max));
+        }
+
+        result
+    }
+
+    fn fill_contiguous<I>(&mut self, area: &Rectangle, colors: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Self::Color>,
+    {
+        self.mark_dirty(area);
+        self.target.fill_contiguous(area, colors)
+    }
+
+    fn fill_solid(&mut self, area: &Rectangle, color: Self::Color) -> Result<(), Self::Error> {
+        self.mark_dirty(area);
+        self.target.fill_solid(area, color)
+    }
+
+    fn clear(&mut self, color: Self::Color) -> Result<(), Self::Error> {
+        self.mark_dirty(&self.target.bounding_box());
+        self.target.clear(color)
+    }
+}
+
+impl<T, const N: usize> Dimensions for DirtyTracker<T, N>
+where
+    T: DrawTarget,
+{
+    fn bounding_box(&self) -> Rectangle {
+        self.target.bounding_box()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        geometry::Size,
+        mock_display::MockDisplay,
+        pixelcolor::BinaryColor,
+        primitives::{Line, Primitive, PrimitiveStyle},
+        Drawable,
+    };
+
+    fn rect(x: i32, y: i32, width: u32, height: u32) -> Rectangle {
+        Rectangle::new(Point::new(x, y), Size::new(width, height))
+    }
+
+    #[test]
+    fn region_union() {
+        let mut region = DirtyRegion::<1>::new();
+        assert!(region.is_empty());
+
+        region.add(&rect(1, 2, 3, 4));
+        region.add(&rect(10, 10, 2, 2));
+        region.add(&rect(5, 5, 0, 0));
+
+        assert_eq!(region.rectangles(), &[rect(1, 2, 11, 10)]);
+        assert_eq!(region.bounding_box(), rect(1, 2, 11, 10));
+    }
+
+    #[test]
+    fn region_list() {
+        let mut region = DirtyRegion::<3>::new();
+
+        region.add(&rect(0, 0, 4, 4));
+        region.add(&rect(20, 0, 4, 4));
+        region.add(&rect(0, 20, 4, 4));
+        assert_eq!(
+            region.rectangles(),
+            &[rect(0, 0, 4, 4), rect(20, 0, 4, 4), rect(0, 20, 4, 4)]
+        );
+
+        // Overlapping rectangles are merged.
+        region.add(&rect(2, 2, 4, 4));
+        assert_eq!(
+            region.rectangles(),
+            &[rect(0, 20, 4, 4), rect(20, 0, 4, 4), rect(0, 0, 6, 6)]
+        );
+
+        // The new rectangle is merged with the closest rectangle if the list is full.
+        region.add(&rect(21, 6, 3, 2));
+        assert_eq!(
+            region.rectangles(),
+            &[rect(0, 20, 4, 4), rect(0, 0, 6, 6), rect(20, 0, 4, 8)]
+        );
+
+        // Merged rectangles which overlap other rectangles are merged again.
+        region.add(&rect(3, 5, 1, 16));
+        assert_eq!(region.rectangles(), &[rect(20, 0, 4, 8), rect(0, 0, 6, 24)]);
+
+        region.clear();
+        assert!(region.is_empty());
+        assert_eq!(region.bounding_box(), Rectangle::zero());
+
+        // Adjacent rectangles are merged.
+        region.add(&rect(0, 0, 2, 2));
+        region.add(&rect(2, 0, 2, 1));
+        assert_eq!(region.rectangles(), &[rect(0, 0, 4, 2)]);
+    }
+
+    #[test]
+    fn region_equality() {
+        let mut region = DirtyRegion::<3>::new();
+        region.add(&rect(0, 0, 4, 4));
+        region.add(&rect(20, 0, 4, 4));
+        region.clear();
+        assert_eq!(region, DirtyRegion::new());
+
+        // Merging removes a rectangle from the list.
+        region.add(&rect(0, 0, 4, 4));
+        region.add(&rect(20, 0, 4, 4));
+        region.add(&rect(4, 0, 16, 4));
+
+        let mut expected = DirtyRegion::new();
+        expected.add(&rect(0, 0, 24, 4));
+        assert_eq!(region, expected);
+    }
+
+    #[test]
+    #[should_panic(expected = "dirty regions must contain at least one rectangle")]
+    fn zero_sized_region() {
+        DirtyRegion::<0>::new();
+    }
+
+    #[test]
+    fn draw_iter() {
+        let mut tracker = DirtyTracker::<_>::new(MockDisplay::<BinaryColor>::new());
+        assert!(tracker.dirty_region().is_empty());
+
+        Line::new(Point::new(10, 5), Point::new(3, 8))
+            .into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 1))
+            .draw(&mut tracker)
+            .unwrap();
+
+        assert_eq!(tracker.dirty_region().rectangles(), &[rect(3, 5, 8, 4)]);
+    }
+
+    #[test]
+    fn pixels_outside_are_ignored() {
+        let mut display = MockDisplay::<BinaryColor>::new();
+        display.set_allow_out_of_bounds_drawing(true);
+        let mut tracker = DirtyTracker::<_>::new(display);
+
+        tracker
+            .draw_iter([
+                Pixel(Point::new(-1, 0), BinaryColor::On),
+                Pixel(Point::new(100, 0), BinaryColor::On),
+            ])
+            .unwrap();
+        assert!(tracker.dirty_region().is_empty());
+
+        tracker
+            .fill_solid(&rect(60, 60, 10, 10), BinaryColor::On)
+            .unwrap();
+        assert_eq!(tracker.dirty_region().rectangles(), &[rect(60, 60, 4, 4)]);
+    }
+
+    #[test]
+    fn fill_and_clear() {
+        let mut tracker = DirtyTracker::<_, 2>::new(MockDisplay::<BinaryColor>::new());
+        tracker.target_mut().set_allow_overdraw(true);
+
+        tracker
+            .fill_contiguous(&rect(1, 1, 2, 2), [BinaryColor::On; 4])
+            .unwrap();
+        tracker
+            .fill_solid(&rect(10, 10, 5, 1), BinaryColor::On)
+            .unwrap();
+
+        let dirty_region = tracker.take_dirty_region();
+        assert_eq!(
+            dirty_region.rectangles(),
+            &[rect(1, 1, 2, 2), rect(10, 10, 5, 1)]
+        );
+        assert!(tracker.dirty_region().is_empty());
+
+        tracker.clear(BinaryColor::Off).unwrap();
+        assert_eq!(tracker.dirty_region().rectangles(), &[rect(0, 0, 64, 64)]);
+
+        tracker.reset();
+        assert!(tracker.dirty_region().is_empty());
+    }
+}
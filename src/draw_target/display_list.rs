@@ -0,0 +1,439 @@
+use crate::{
+    draw_target::{DrawTarget, DrawTargetExt},
+    geometry::{Dimensions, Point, Size},
+    pixelcolor::PixelColor,
+    primitives::{PointsIter, Rectangle},
+    Pixel,
+};
+
+/// Drawing command stored in a [`DisplayList`].
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub enum DisplayCommand<C>
+where
+    C: PixelColor,
+{
+    /// Draws a single pixel.
+    Pixel(Pixel<C>),
+
+    /// Fills an area with a solid color.
+    ///
+    /// Runs of equal colors in `draw_iter` and `fill_contiguous` calls are also stored as
+    /// `FillSolid` commands with a height of one pixel.
+    FillSolid(Rectangle, C),
+}
+
+impl<C> Default for DisplayCommand<C>
+where
+    C: PixelColor + Default,
+{
+    /// Returns a command which fills a zero sized area, which doesn't draw anything.
+    ///
+    /// The default value can be used to initialize the buffer of a [`DisplayList`].
+    fn default() -> Self {
+        Self::FillSolid(Rectangle::zero(), C::default())
+    }
+}
+
+/// Error returned by [`DisplayList`] if the buffer is full.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub enum DisplayListError {
+    /// The buffer is too small to store all drawing commands.
+    BufferTooSmall,
+}
+
+/// Display list draw target.
+///
+/// A display list records the drawing operations which are performed on it into a caller
+/// provided buffer of [`DisplayCommand`]s. The recorded commands can later be replayed onto any
+/// draw target with the same color type, without running the code which generated the drawing
+/// operations again. This can, for example, be used to render a scene once and replay it for
+/// every frame or for every band of a display which is rendered in multiple parts.
+///
+/// Consecutive pixels with the same color in the same row, which are drawn by `draw_iter` or
+/// `fill_contiguous`, are combined into a single command. Drawing operations outside the bounding
+/// box, which is set when the display list is created, are clipped during recording and calling
+/// `clear` discards all previously recorded commands.
+///
+/// A [`DisplayListError::BufferTooSmall`] error is returned if the buffer is full. All commands
+/// which were recorded before the error occurred are kept in the display list.
+///
+/// # Examples
+///
+/// ```
+/// use embedded_graphics::{
+///     draw_target::{DisplayCommand, DisplayList},
+///     mock_display::MockDisplay,
+///     pixelcolor::BinaryColor,
+///     prelude::*,
+///     primitives::{Circle, PrimitiveStyle, Rectangle},
+/// };
+///
+/// let mut buffer = [DisplayCommand::default(); 32];
+/// let mut display_list = DisplayList::new(
+///     &mut buffer,
+///     Rectangle::new(Point::zero(), Size::new(16, 16)),
+/// );
+///
+/// // Record the drawing operations.
+/// Circle::new(Point::new(2, 2), 8)
+///     .into_styled(PrimitiveStyle::with_fill(BinaryColor::On))
+///     .draw(&mut display_list)?;
+///
+/// // Replay the recorded commands twice.
+/// let mut display = MockDisplay::new();
+/// display_list.replay(&mut display).unwrap();
+/// display_list
+///     .replay_translated(&mut display, Point::new(20, 0))
+///     .unwrap();
+/// # Ok::<(), embedded_graphics::draw_target::DisplayListError>(())
+/// ```
+#[derive(Debug)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub struct DisplayList<'a, C>
+where
+    C: PixelColor,
+{
+    buffer: &'a mut [DisplayCommand<C>],
+    len: usize,
+    bounding_box: Rectangle,
+}
+
+impl<'a, C> DisplayList<'a, C>
+where
+    C: PixelColor,
+{
+    /// Creates a new empty display list.
+    ///
+    /// The commands are stored in `buffer` and drawing operations outside `bounding_box` are
+    /// clipped.
+    pub fn new(buffer: &'a mut [DisplayCommand<C>], bounding_box: Rectangle) -> Self {
+        Self {
+            buffer,
+            len: 0,
+            bounding_box,
+        }
+    }
+
+    /// Returns the recorded commands.
+    pub fn commands(&self) -> &[DisplayCommand<C>] {
+        &self.buffer[0..self.len]
+    }
+
+    /// Returns the number of recorded commands.
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if no commands have been recorded.
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Removes all recorded commands.
+    pub fn reset(&mut self) {
+        self.len = 0;
+    }
+
+    /// Replays the recorded commands onto a draw target.
+    ///
+    /// Consecutive [`DisplayCommand::Pixel`] commands are drawn using a single `draw_iter` call.
+    pub fn replay<D>(&self, target: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        let mut commands = self.commands();
+
+        while let Some(command) = commands.first() {
+            match command {
+                DisplayCommand::Pixel(_) => {
+                    let len = commands
+                        .iter()
+                        .position(|command| !matches!(command, DisplayCommand::Pixel(_)))
+                        .unwrap_or(commands.len());
+
+                    target.draw_iter(commands[0..len].iter().filter_map(
+                        |command| match command {
+                            DisplayCommand::Pixel(pixel) => Some(*pixel),
+                            DisplayCommand::FillSolid(..) => None,
+                        },
+                    ))?;
+
+                    commands = &commands[len..];
+                }
+                DisplayCommand::FillSolid(area, color) => {
+                    target.fill_solid(area, *color)?;
+
+                    commands = &commands[1..];
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Replays the recorded commands onto a draw target with an offset.
+    ///
+    /// All commands are translated by `offset` before they are drawn to `target`.
+    pub fn replay_translated<D>(&self, target: &mut D, offset: Point) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        self.replay(&mut target.translated(offset))
+    }
+
+    /// Appends a command to the display list.
+    fn push(&mut self, command: DisplayCommand<C>) -> Result<(), DisplayListError> {
+        let slot = self
+            .buffer
+            .get_mut(self.len)
+            .ok_or(DisplayListError::BufferTooSmall)?;
+
+        *slot = command;
+        self.len += 1;
+
+        Ok(())
+    }
+
+    /// Appends a pixel to the display list.
+    ///
+    /// The pixel is combined with the last command if it continues a row of pixels with the same
+    /// color.
+    fn push_pixel(&mut self, point: Point, color: C) -> Result<(), DisplayListError> {
+        if !self.bounding_box.contains(point) {
+            return Ok(());
+        }
+
+        if let Some(last) = self.len.checked_sub(1).map(|index| &mut self.buffer[index]) {
+            match *last {
+                DisplayCommand::Pixel(Pixel(last_point, last_color))
+                    if last_color == color && last_point + Point::new(1, 0) == point =>
+                {
+                    let area = Rectangle::new(last_point, Size::new(2, 1));
+                    *last = DisplayCommand::FillSolid(area, color);
+
+                    return Ok(());
+                }
+                DisplayCommand::FillSolid(ref mut area, last_color)
+                    if last_color == color
+                        && area.size.height == 1
+                        && area.top_left.y == point.y
+                        && area.columns().end == point.x =>
+                {
+                    area.size.width += 1;
+
+                    return Ok(());
+                }
+                _ => {}
+            }
+        }
+
+        self.push(DisplayCommand::Pixel(Pixel(point, color)))
+    }
+}
+
+impl<C> DrawTarget for DisplayList<'_, C>
+where
+    C: PixelColor,
+{
+    type Color = C;
+    type Error = DisplayListError;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        for Pixel(point, color) in pixels {
+            self.push_pixel(point, color)?;
+        }
+
+        Ok(())
+    }
+
+    fn fill_contiguous<I>(&mut self, area: &Rectangle, colors: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Self::Color>,
+    {
+        for (point, color) in area.points().zip(colors) {
+            self.push_pixel(point, color)?;
+        }
+
+        Ok(())
+    }
+
+    fn fill_solid(&mut self, area: &Rectangle, color: Self::Color) -> Result<(), Self::Error> {
+        let area = area.intersection(&self.bounding_box);
+
+        if area.is_zero_sized() {
+            return Ok(());
+        }
+
+        self.push(DisplayCommand::FillSolid(area, color))
+    }
+
+    fn clear(&mut self, color: Self::Color) -> Result<(), Self::Error> {
+        // All previous commands would be overdrawn and can be discarded.
+        self.reset();
+
+        self.fill_solid(&self.bounding_box(), color)
+    }
+}
+
+impl<C> Dimensions for DisplayList<'_, C>
+where
+    C: PixelColor,
+{
+    fn bounding_box(&self) -> Rectangle {
+        self.bounding_box
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        iterator::PixelIteratorExt,
+        mock_display::MockDisplay,
+        pixelcolor::BinaryColor,
+        primitives::{Circle, Line, Primitive, PrimitiveStyle},
+        transform::Transform,
+        Drawable,
+    };
+
+    const AREA: Rectangle = Rectangle::new(Point::zero(), Size::new(32, 32));
+
+    #[test]
+    fn draw_iter() {
+        let mut buffer = [DisplayCommand::default(); 8];
+        let mut display_list = DisplayList::new(&mut buffer, AREA);
+
+        [
+            Pixel(Point::new(1, 1), BinaryColor::On),
+            Pixel(Point::new(2, 1), BinaryColor::On),
+            Pixel(Point::new(3, 1), BinaryColor::On),
+            Pixel(Point::new(4, 1), BinaryColor::Off),
+            Pixel(Point::new(5, 2), BinaryColor::Off),
+            Pixel(Point::new(-1, 2), BinaryColor::Off),
+        ]
+        .into_iter()
+        .draw(&mut display_list)
+        .unwrap();
+
+        assert_eq!(
+            display_list.commands(),
+            &[
+                DisplayCommand::FillSolid(
+                    Rectangle::new(Point::new(1, 1), Size::new(3, 1)),
+                    BinaryColor::On
+                ),
+                DisplayCommand::Pixel(Pixel(Point::new(4, 1), BinaryColor::Off)),
+                DisplayCommand::Pixel(Pixel(Point::new(5, 2), BinaryColor::Off)),
+            ]
+        );
+    }
+
+    #[test]
+    fn fill_and_clear() {
+        let mut buffer = [DisplayCommand::default(); 8];
+        let mut display_list = DisplayList::new(&mut buffer, AREA);
+
+        display_list
+            .fill_contiguous(
+                &Rectangle::new(Point::new(30, 0), Size::new(3, 2)),
+                [
+                    BinaryColor::On,
+                    BinaryColor::On,
+                    BinaryColor::On,
+                    BinaryColor::Off,
+                    BinaryColor::On,
+                    BinaryColor::On,
+                ],
+            )
+            .unwrap();
+        display_list
+            .fill_solid(
+                &Rectangle::new(Point::new(20, 30), Size::new(20, 20)),
+                BinaryColor::Off,
+            )
+            .unwrap();
+
+        assert_eq!(
+            display_list.commands(),
+            &[
+                DisplayCommand::FillSolid(
+                    Rectangle::new(Point::new(30, 0), Size::new(2, 1)),
+                    BinaryColor::On
+                ),
+                DisplayCommand::Pixel(Pixel(Point::new(30, 1), BinaryColor::Off)),
+                DisplayCommand::Pixel(Pixel(Point::new(31, 1), BinaryColor::On)),
+                DisplayCommand::FillSolid(
+                    Rectangle::new(Point::new(20, 30), Size::new(12, 2)),
+                    BinaryColor::Off
+                ),
+            ]
+        );
+
+        display_list.clear(BinaryColor::On).unwrap();
+        assert_eq!(
+            display_list.commands(),
+            &[DisplayCommand::FillSolid(AREA, BinaryColor::On)]
+        );
+
+        display_list.reset();
+        assert!(display_list.is_empty());
+    }
+
+    #[test]
+    fn buffer_too_small() {
+        let mut buffer = [DisplayCommand::default(); 2];
+        let mut display_list = DisplayList::new(&mut buffer, AREA);
+
+        let result = [
+            Pixel(Point::new(1, 1), BinaryColor::On),
+            Pixel(Point::new(1, 2), BinaryColor::On),
+            Pixel(Point::new(1, 3), BinaryColor::On),
+        ]
+        .into_iter()
+        .draw(&mut display_list);
+
+        assert_eq!(result, Err(DisplayListError::BufferTooSmall));
+        assert_eq!(display_list.len(), 2);
+    }
+
+    #[test]
+    fn replay() {
+        let circle = Circle::new(Point::new(2, 3), 9)
+            .into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 2));
+        let line = Line::new(Point::new(0, 20), Point::new(15, 25))
+            .into_styled(PrimitiveStyle::with_stroke(BinaryColor::Off, 1));
+
+        let mut buffer = [DisplayCommand::default(); 64];
+        let mut display_list = DisplayList::new(&mut buffer, AREA);
+        circle.draw(&mut display_list).unwrap();
+        line.draw(&mut display_list).unwrap();
+
+        let mut expected = MockDisplay::new();
+        circle.draw(&mut expected).unwrap();
+        line.draw(&mut expected).unwrap();
+
+        let mut display = MockDisplay::new();
+        display_list.replay(&mut display).unwrap();
+        display.assert_eq(&expected);
+
+        let mut display = MockDisplay::new();
+        display_list
+            .replay_translated(&mut display, Point::new(30, 10))
+            .unwrap();
+
+        let mut expected = MockDisplay::new();
+        circle
+            .translate(Point::new(30, 10))
+            .draw(&mut expected)
+            .unwrap();
+        line.translate(Point::new(30, 10))
+            .draw(&mut expected)
+            .unwrap();
+        display.assert_eq(&expected);
+    }
+}
@@ -0,0 +1,519 @@
+use crate::{
+    draw_target::DrawTarget,
+    geometry::{Dimensions, Point},
+    iterator::contiguous::Cropped,
+    pixelcolor::{
+        Bgr555, Bgr565, Bgr666, Bgr888, BinaryColor, Gray2, Gray4, Gray8, PixelColor, Rgb332,
+        Rgb444, Rgb555, Rgb565, Rgb666, Rgb888, RgbColor,
+    },
+    primitives::{PointsIter, Rectangle},
+    transform::Transform,
+    Pixel,
+};
+use core::{iter, marker::PhantomData};
+
+/// Color type which can be used as the output of a dithering draw target.
+///
+/// The dithering draw target uses the `From<Rgb888>` implementation to find the closest color
+/// and the `Into<Rgb888>` implementation to calculate the quantization error.
+///
+/// This trait is implemented for all built-in grayscale and RGB color types and
+/// [`BinaryColor`].
+pub trait DitherColor: PixelColor + From<Rgb888> + Into<Rgb888> {
+    /// Distance between two adjacent levels of the red, green and blue channels.
+    ///
+    /// The distance is given in the range `0..=255` of a [`Rgb888`] color channel.
+    const LEVEL_STEP: [u8; 3];
+}
+
+macro_rules! impl_dither_color_rgb {
+    ($($type:ident),*) => {
+        $(impl DitherColor for $type {
+            const LEVEL_STEP: [u8; 3] = [
+                255 / Self::MAX_R,
+                255 / Self::MAX_G,
+                255 / Self::MAX_B,
+            ];
+        })*
+    };
+}
+
+impl_dither_color_rgb!(
+    Rgb332, Rgb444, Rgb555, Bgr555, Rgb565, Bgr565, Rgb666, Bgr666, Rgb888, Bgr888
+);
+
+macro_rules! impl_dither_color_gray {
+    ($($type:ident => $max_luma:expr),*) => {
+        $(impl DitherColor for $type {
+            const LEVEL_STEP: [u8; 3] = [255 / $max_luma; 3];
+        })*
+    };
+}
+
+impl_dither_color_gray!(BinaryColor => 1, Gray2 => 3, Gray4 => 15, Gray8 => 255);
+
+/// Ordered dithering threshold matrix.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub enum OrderedDither {
+    /// 2x2 Bayer matrix.
+    Bayer2x2,
+
+    /// 4x4 Bayer matrix.
+    Bayer4x4,
+
+    /// 8x8 Bayer matrix.
+    Bayer8x8,
+}
+
+impl OrderedDither {
+    /// Returns the base 2 logarithm of the matrix size.
+    const fn order(self) -> u32 {
+        match self {
+            Self::Bayer2x2 => 1,
+            Self::Bayer4x4 => 2,
+            Self::Bayer8x8 => 3,
+        }
+    }
+
+    /// Returns the color of a pixel after ordered dithering.
+    fn apply<C, D>(self, point: Point, color: C) -> D
+    where
+        C: Into<Rgb888>,
+        D: DitherColor,
+    {
+        let order = self.order();
+        let mask = (1 << order) - 1;
+        let threshold = i32::from(bayer(point.x & mask, point.y & mask, order));
+        let levels = 1 << (2 * order);
+
+        let color: Rgb888 = color.into();
+        let channel = |value: u8, step: u8| {
+            // Offset the value by a bias in the range `-step / 2..step / 2`.
+            let step = i32::from(step);
+            let bias = (2 * threshold + 1) * step / (2 * levels) - step / 2;
+
+            (i32::from(value) + bias).clamp(0, 255) as u8
+        };
+
+        D::from(Rgb888::new(
+            channel(color.r(), D::LEVEL_STEP[0]),
+            channel(color.g(), D::LEVEL_STEP[1]),
+            channel(color.b(), D::LEVEL_STEP[2]),
+        ))
+    }
+}
+
+/// Returns the value of a Bayer matrix with the size `2^order` at the given position.
+const fn bayer(x: i32, y: i32, order: u32) -> u8 {
+    let mut value = 0;
+
+    let mut i = 0;
+    while i < order {
+        let x_bit = (x >> i) & 1;
+        let y_bit = (y >> i) & 1;
+
+        value = value << 2 | ((x_bit ^ y_bit) << 1 | y_bit);
+        i += 1;
+    }
+
+    value as u8
+}
+
+/// Error diffusion algorithm.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub enum ErrorDiffusion {
+    /// Floyd–Steinberg dithering.
+    FloydSteinberg,
+
+    /// Atkinson dithering.
+    ///
+    /// Atkinson dithering only distributes 3/4 of the quantization error, which results in
+    /// higher contrast than Floyd–Steinberg dithering.
+    Atkinson,
+}
+
+impl ErrorDiffusion {
+    /// Returns the required error buffer length for areas with the given width.
+    pub const fn buffer_len(self, width: usize) -> usize {
+        self.rows() * width
+    }
+
+    /// Returns the number of rows the error is distributed to, including the current row.
+    const fn rows(self) -> usize {
+        match self {
+            Self::FloydSteinberg => 2,
+            Self::Atkinson => 3,
+        }
+    }
+
+    /// Returns the weights of the distributed error as `(dx, dy, weight)` and the divisor.
+    const fn kernel(self) -> (&'static [(isize, usize, i16)], i16) {
+        match self {
+            Self::FloydSteinberg => (&[(1, 0, 7), (-1, 1, 3), (0, 1, 5), (1, 1, 1)], 16),
+            Self::Atkinson => (
+                &[
+                    (1, 0, 1),
+                    (2, 0, 1),
+                    (-1, 1, 1),
+                    (0, 1, 1),
+                    (1, 1, 1),
+                    (0, 2, 1),
+                ],
+                8,
+            ),
+        }
+    }
+}
+
+/// Dithering draw target.
+///
+/// Created by calling [`dithered`] or [`error_diffused`] on any [`DrawTarget`].
+/// See the [`dithered`] and [`error_diffused`] method documentation for more information.
+///
+/// [`dithered`]: crate::draw_target::DrawTargetExt::dithered
+/// [`error_diffused`]: crate::draw_target::DrawTargetExt::error_diffused
+#[derive(Debug)]
+pub struct Dithered<'a, T, C> {
+    /// The parent draw target.
+    parent: &'a mut T,
+
+    /// The ordered dithering matrix.
+    ordered: OrderedDither,
+
+    /// The error diffusion algorithm and the error buffer.
+    diffusion: Option<(ErrorDiffusion, &'a mut [[i16; 3]])>,
+
+    /// The input color type.
+    color_type: PhantomData<C>,
+}
+
+impl<'a, T, C> Dithered<'a, T, C>
+where
+    T: DrawTarget,
+    T::Color: DitherColor,
+    C: PixelColor + Into<Rgb888>,
+{
+    pub(super) fn new_ordered(parent: &'a mut T, ordered: OrderedDither) -> Self {
+        Self {
+            parent,
+            ordered,
+            diffusion: None,
+            color_type: PhantomData,
+        }
+    }
+
+    pub(super) fn new_error_diffused(
+        parent: &'a mut T,
+        diffusion: ErrorDiffusion,
+        buffer: &'a mut [[i16; 3]],
+    ) -> Self {
+        Self {
+            parent,
+            ordered: OrderedDither::Bayer4x4,
+            diffusion: Some((diffusion, buffer)),
+            color_type: PhantomData,
+        }
+    }
+}
+
+impl<T, C> DrawTarget for Dithered<'_, T, C>
+where
+    T: DrawTarget,
+    T::Color: DitherColor,
+    C: PixelColor + Into<Rgb888>,
+{
+    type Color = C;
+    type Error = T::Error;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        let ordered = self.ordered;
+        let pixels = pixels
+            .into_iter()
+            .map(|Pixel(p, c)| Pixel(p, ordered.apply(p, c)));
+
+        self.parent.draw_iter(pixels)
+    }
+
+    fn fill_contiguous<I>(&mut self, area: &Rectangle, colors: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Self::Color>,
+    {
+        // Error diffusion is only applied to the visible part of the area to limit the required
+        // buffer size.
+        let intersection = self.bounding_box().intersection(area);
+        if intersection.is_zero_sized() {
+            return Ok(());
+        }
+
+        let crop_area = intersection.translate(-area.top_left);
+        let colors = Cropped::new(colors.into_iter(), area.size, &crop_area);
+        let area = intersection;
+        let width = area.size.width as usize;
+
+        match &mut self.diffusion {
+            Some((diffusion, buffer)) if buffer.len() >= diffusion.buffer_len(width) => {
+                let buffer = &mut buffer[0..diffusion.buffer_len(width)];
+                buffer.fill([0; 3]);
+
+                let mut diffuser = Diffuser {
+                    kernel: *diffusion,
+                    buffer,
+                    width,
+                    row: 0,
+                    x: 0,
+                };
+
+                self.parent
+                    .fill_contiguous(&area, colors.map(|color| diffuser.next(color)))
+            }
+            _ => {
+                let ordered = self.ordered;
+                let colors = area.points().zip(colors).map(|(p, c)| ordered.apply(p, c));
+
+                self.parent.fill_contiguous(&area, colors)
+            }
+        }
+    }
+
+    fn fill_solid(&mut self, area: &Rectangle, color: Self::Color) -> Result<(), Self::Error> {
+        // Dithering isn't required if the color can be represented exactly.
+        let rgb: Rgb888 = color.into();
+        let quantized = T::Color::from(rgb);
+        if Into::<Rgb888>::into(quantized) == rgb {
+            return self.parent.fill_solid(area, quantized);
+        }
+
+        let area = area.intersection(&self.bounding_box());
+
+        if self.diffusion.is_some() {
+            self.fill_contiguous(&area, iter::repeat(color))
+        } else {
+            let ordered = self.ordered;
+            let colors = area.points().map(|p| ordered.apply(p, color));
+
+            self.parent.fill_contiguous(&area, colors)
+        }
+    }
+
+    fn clear(&mut self, color: Self::Color) -> Result<(), Self::Error> {
+        self.fill_solid(&self.bounding_box(), color)
+    }
+}
+
+impl<T, C> Dimensions for Dithered<'_, T, C>
+where
+    T: DrawTarget,
+{
+    fn bounding_box(&self) -> Rectangle {
+        self.parent.bounding_box()
+    }
+}
+
+/// Error diffusion state for a single `fill_contiguous` call.
+struct Diffuser<'a> {
+    kernel: ErrorDiffusion,
+    buffer: &'a mut [[i16; 3]],
+    width: usize,
+    row: usize,
+    x: usize,
+}
+
+impl Diffuser<'_> {
+    /// Returns the dithered color of the next pixel.
+    fn next<C, D>(&mut self, color: C) -> D
+    where
+        C: Into<Rgb888>,
+        D: DitherColor,
+    {
+        let rows = self.kernel.rows();
+
+        if self.x == self.width {
+            // The buffer row of the finished row is reused for the row `rows` rows below it.
+            let start = self.row % rows * self.width;
+            self.buffer[start..start + self.width].fill([0; 3]);
+
+            self.row += 1;
+            self.x = 0;
+        }
+
+        let row_start = |offset: usize| (self.row + offset) % rows * self.width;
+
+        let error = self.buffer[row_start(0) + self.x];
+        let color: Rgb888 = color.into();
+        let desired = [
+            (i16::from(color.r()) + error[0]).clamp(0, 255),
+            (i16::from(color.g()) + error[1]).clamp(0, 255),
+            (i16::from(color.b()) + error[2]).clamp(0, 255),
+        ];
+
+        let quantized = D::from(Rgb888::new(
+            desired[0] as u8,
+            desired[1] as u8,
+            desired[2] as u8,
+        ));
+        let actual: Rgb888 = quantized.into();
+        let error = [
+            desired[0] - i16::from(actual.r()),
+            desired[1] - i16::from(actual.g()),
+            desired[2] - i16::from(actual.b()),
+        ];
+
+        let (weights, divisor) = self.kernel.kernel();
+        for &(dx, dy, weight) in weights {
+            let x = self.x as isize + dx;
+            if x < 0 || x >= self.width as isize {
+                continue;
+            }
+
+            let target = &mut self.buffer[row_start(dy) + x as usize];
+            for (target, error) in target.iter_mut().zip(error) {
+                *target += error * weight / divisor;
+            }
+        }
+
+        self.x += 1;
+
+        quantized
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        draw_target::DrawTargetExt, geometry::Size, iterator::PixelIteratorExt,
+        mock_display::MockDisplay,
+    };
+
+    const GRAY: Rgb888 = Rgb888::new(128, 128, 128);
+
+    #[test]
+    fn bayer_matrix() {
+        let matrix_4x4 = [
+            [0, 8, 2, 10],  //
+            [12, 4, 14, 6], //
+            [3, 11, 1, 9],  //
+            [15, 7, 13, 5], //
+        ];
+
+        for (y, row) in matrix_4x4.iter().enumerate() {
+            for (x, value) in row.iter().enumerate() {
+                assert_eq!(bayer(x as i32, y as i32, 2), *value, "({}, {})", x, y);
+            }
+        }
+
+        assert_eq!(bayer(7, 7, 3), 21);
+    }
+
+    #[test]
+    fn ordered_draw_iter() {
+        let mut display = MockDisplay::<BinaryColor>::new();
+
+        Rectangle::new(Point::zero(), Size::new(4, 4))
+            .points()
+            .map(|p| Pixel(p, GRAY))
+            .draw(&mut display.dithered(OrderedDither::Bayer4x4))
+            .unwrap();
+
+        display.assert_pattern(&[
+            ".#.#", //
+            "#.#.", //
+            ".#.#", //
+            "#.#.", //
+        ]);
+    }
+
+    #[test]
+    fn ordered_gray() {
+        let area = Rectangle::new(Point::zero(), Size::new(4, 2));
+
+        // Halfway between the Gray2 levels 1 (85) and 2 (170).
+        let mut display = MockDisplay::<Gray2>::new();
+        display
+            .dithered(OrderedDither::Bayer2x2)
+            .fill_solid(&area, GRAY)
+            .unwrap();
+
+        display.assert_pattern(&[
+            "1212", //
+            "2121", //
+        ]);
+    }
+
+    #[test]
+    fn exact_colors() {
+        let area = Rectangle::new(Point::zero(), Size::new(4, 2));
+
+        let mut display = MockDisplay::<Gray2>::new();
+        display
+            .dithered(OrderedDither::Bayer8x8)
+            .fill_solid(&area, Rgb888::new(170, 170, 170))
+            .unwrap();
+
+        display.assert_pattern(&[
+            "2222", //
+            "2222", //
+        ]);
+    }
+
+    #[test]
+    fn floyd_steinberg() {
+        let mut buffer = [[0; 3]; 8];
+        let mut display = MockDisplay::<BinaryColor>::new();
+
+        display
+            .error_diffused(ErrorDiffusion::FloydSteinberg, &mut buffer)
+            .fill_solid(&Rectangle::new(Point::zero(), Size::new(4, 2)), GRAY)
+            .unwrap();
+
+        display.assert_pattern(&[
+            "#.#.", //
+            ".#.#", //
+        ]);
+    }
+
+    #[test]
+    fn atkinson() {
+        let mut buffer = [[0; 3]; ErrorDiffusion::Atkinson.buffer_len(8)];
+        let mut display = MockDisplay::<BinaryColor>::new();
+
+        display
+            .error_diffused(ErrorDiffusion::Atkinson, &mut buffer)
+            .fill_contiguous(
+                &Rectangle::new(Point::zero(), Size::new(8, 8)),
+                iter::repeat(GRAY),
+            )
+            .unwrap();
+
+        let on_pixels = Rectangle::new(Point::zero(), Size::new(8, 8))
+            .points()
+            .filter(|p| display.get_pixel(*p) == Some(BinaryColor::On))
+            .count();
+        assert!((28..=36).contains(&on_pixels), "{}", on_pixels);
+    }
+
+    #[test]
+    fn buffer_too_small() {
+        let area = Rectangle::new(Point::zero(), Size::new(8, 4));
+
+        let mut expected = MockDisplay::<BinaryColor>::new();
+        expected
+            .dithered(OrderedDither::Bayer4x4)
+            .fill_solid(&area, GRAY)
+            .unwrap();
+
+        let mut buffer = [[0; 3]; 15];
+        let mut display = MockDisplay::<BinaryColor>::new();
+        display
+            .error_diffused(ErrorDiffusion::FloydSteinberg, &mut buffer)
+            .fill_solid(&area, GRAY)
+            .unwrap();
+
+        display.assert_eq(&expected);
+    }
+}
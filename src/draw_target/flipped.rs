@@ -0,0 +1,188 @@
+use crate::{
+    draw_target::{mapping::Mapping, DrawTarget},
+    geometry::Dimensions,
+    primitives::Rectangle,
+    Pixel,
+};
+
+/// Flipped draw target.
+///
+/// Created by calling [`flipped`] on any [`DrawTarget`].
+/// See the [`flipped`] method documentation for more.
+///
+/// [`flipped`]: crate::draw_target::DrawTargetExt::flipped
+#[derive(Debug)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub struct Flipped<'a, T>
+where
+    T: DrawTarget,
+{
+    parent: &'a mut T,
+    mapping: Mapping,
+}
+
+impl<'a, T> Flipped<'a, T>
+where
+    T: DrawTarget,
+{
+    pub(super) fn new(parent: &'a mut T, horizontal: bool, vertical: bool) -> Self {
+        let mapping = Mapping::flip(horizontal, vertical, parent.bounding_box());
+
+        Self { parent, mapping }
+    }
+}
+
+impl<T> DrawTarget for Flipped<'_, T>
+where
+    T: DrawTarget,
+{
+    type Color = T::Color;
+    type Error = T::Error;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        let mapping = self.mapping;
+        let pixels = pixels
+            .into_iter()
+            .map(|Pixel(point, color)| Pixel(mapping.map_point(point), color));
+
+        self.parent.draw_iter(pixels)
+    }
+
+    fn fill_contiguous<I>(&mut self, area: &Rectangle, colors: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Self::Color>,
+    {
+        self.mapping.fill_contiguous(self.parent, area, colors)
+    }
+
+    fn fill_solid(&mut self, area: &Rectangle, color: Self::Color) -> Result<(), Self::Error> {
+        match self.mapping.map_area(area) {
+            Some(area) => self.parent.fill_solid(&area, color),
+            None => Ok(()),
+        }
+    }
+
+    fn clear(&mut self, color: Self::Color) -> Result<(), Self::Error> {
+        self.parent.clear(color)
+    }
+}
+
+impl<T> Dimensions for Flipped<'_, T>
+where
+    T: DrawTarget,
+{
+    fn bounding_box(&self) -> Rectangle {
+        self.mapping.bounding_box()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        draw_target::{mapping, DrawTargetExt},
+        geometry::{Point, Size},
+        iterator::PixelIteratorExt,
+        mock_display::MockDisplay,
+        pixelcolor::BinaryColor,
+    };
+
+    const FLAGS: [(bool, bool); 4] = [(false, false), (true, false), (false, true), (true, true)];
+
+    /// Area of the parent display used in the tests.
+    const AREA: Rectangle = Rectangle::new(Point::new(2, 1), Size::new(6, 4));
+
+    #[test]
+    fn draw_iter() {
+        let expected_patterns = [
+            [
+                "        ", //
+                "  #.    ", //
+                "        ", //
+                "  .     ", //
+                "        ", //
+            ],
+            [
+                "        ", //
+                "      .#", //
+                "        ", //
+                "       .", //
+                "        ", //
+            ],
+            [
+                "        ", //
+                "        ", //
+                "  .     ", //
+                "        ", //
+                "  #.    ", //
+            ],
+            [
+                "        ", //
+                "        ", //
+                "       .", //
+                "        ", //
+                "      .#", //
+            ],
+        ];
+
+        for ((horizontal, vertical), expected) in FLAGS.into_iter().zip(expected_patterns) {
+            let mut display = MockDisplay::new();
+            [
+                Pixel(Point::new(2, 1), BinaryColor::On),
+                Pixel(Point::new(3, 1), BinaryColor::Off),
+                Pixel(Point::new(2, 3), BinaryColor::Off),
+            ]
+            .into_iter()
+            .draw(&mut display.clipped(&AREA).flipped(horizontal, vertical))
+            .unwrap();
+
+            let expected = MockDisplay::from_pattern(&expected);
+            display.assert_eq_with_message(&expected, |f| {
+                write!(f, "horizontal: {}, vertical: {}", horizontal, vertical)
+            });
+        }
+    }
+
+    #[test]
+    fn fill_contiguous() {
+        for (horizontal, vertical) in FLAGS {
+            let mut display = MockDisplay::new();
+            let mut expected = MockDisplay::new();
+            mapping::tests::fill_contiguous(
+                &mut display.flipped(horizontal, vertical),
+                &mut expected.flipped(horizontal, vertical),
+            );
+
+            display.assert_eq_with_message(&expected, |f| {
+                write!(f, "horizontal: {}, vertical: {}", horizontal, vertical)
+            });
+        }
+    }
+
+    #[test]
+    fn fill_solid() {
+        for (horizontal, vertical) in FLAGS {
+            let mut display = MockDisplay::new();
+            let mut expected = MockDisplay::new();
+            mapping::tests::fill_solid(
+                &mut display.flipped(horizontal, vertical),
+                &mut expected.flipped(horizontal, vertical),
+            );
+
+            display.assert_eq_with_message(&expected, |f| {
+                write!(f, "horizontal: {}, vertical: {}", horizontal, vertical)
+            });
+        }
+    }
+
+    #[test]
+    fn bounding_box() {
+        let mut display = MockDisplay::<BinaryColor>::new();
+        let mut clipped = display.clipped(&AREA);
+
+        assert_eq!(clipped.flipped(true, true).bounding_box(), AREA);
+    }
+}
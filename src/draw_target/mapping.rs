@@ -0,0 +1,220 @@
+use crate::{
+    draw_target::{DrawTarget, Rotation},
+    geometry::{Point, Size},
+    primitives::{PointsIter, Rectangle},
+    Pixel,
+};
+use az::SaturatingAs;
+
+/// Mapping from a rotated or flipped coordinate system to the parent coordinate system.
+///
+/// Every combination of quarter turns and mirroring can be described by optionally swapping the
+/// X and Y coordinates, followed by optionally mirroring the X and Y coordinates inside the
+/// parent area.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub(super) struct Mapping {
+    transpose: bool,
+    mirror_x: bool,
+    mirror_y: bool,
+    parent_area: Rectangle,
+}
+
+impl Mapping {
+    /// Creates a mapping for a clockwise rotation.
+    pub const fn rotation(rotation: Rotation, parent_area: Rectangle) -> Self {
+        let (transpose, mirror_x, mirror_y) = match rotation {
+            Rotation::Deg90 => (true, true, false),
+            Rotation::Deg180 => (false, true, true),
+            Rotation::Deg270 => (true, false, true),
+        };
+
+        Self {
+            transpose,
+            mirror_x,
+            mirror_y,
+            parent_area,
+        }
+    }
+
+    /// Creates a mapping for horizontal and vertical flipping.
+    pub const fn flip(horizontal: bool, vertical: bool, parent_area: Rectangle) -> Self {
+        Self {
+            transpose: false,
+            mirror_x: horizontal,
+            mirror_y: vertical,
+            parent_area,
+        }
+    }
+
+    /// Returns the bounding box in the mapped coordinate system.
+    pub fn bounding_box(&self) -> Rectangle {
+        let size = if self.transpose {
+            self.parent_area.size.swap_xy()
+        } else {
+            self.parent_area.size
+        };
+
+        Rectangle::new(self.parent_area.top_left, size)
+    }
+
+    /// Maps a point in the mapped coordinate system to the parent coordinate system.
+    pub fn map_point(&self, point: Point) -> Point {
+        let delta = point - self.parent_area.top_left;
+        let delta = if self.transpose {
+            Point::new(delta.y, delta.x)
+        } else {
+            delta
+        };
+
+        let right: i32 = self
+            .parent_area
+            .size
+            .width
+            .saturating_sub(1)
+            .saturating_as();
+        let bottom: i32 = self
+            .parent_area
+            .size
+            .height
+            .saturating_sub(1)
+            .saturating_as();
+
+        let delta = Point::new(
+            if self.mirror_x {
+                right - delta.x
+            } else {
+                delta.x
+            },
+            if self.mirror_y {
+                bottom - delta.y
+            } else {
+                delta.y
+            },
+        );
+
+        self.parent_area.top_left + delta
+    }
+
+    /// Maps an area in the mapped coordinate system to the parent coordinate system.
+    pub fn map_area(&self, area: &Rectangle) -> Option<Rectangle> {
+        area.bottom_right().map(|bottom_right| {
+            Rectangle::with_corners(self.map_point(area.top_left), self.map_point(bottom_right))
+        })
+    }
+
+    /// Fills an area in the mapped coordinate system on the parent draw target.
+    ///
+    /// Rows which are mapped to a row or column of the parent target, in which the pixels keep
+    /// their order, are passed on as separate `fill_contiguous` calls to keep the fast path of
+    /// the parent target. All other areas are drawn pixel by pixel.
+    pub fn fill_contiguous<T, I>(
+        &self,
+        parent: &mut T,
+        area: &Rectangle,
+        colors: I,
+    ) -> Result<(), T::Error>
+    where
+        T: DrawTarget,
+        I: IntoIterator<Item = T::Color>,
+    {
+        if area.is_zero_sized() {
+            return Ok(());
+        }
+
+        let keeps_order = if self.transpose {
+            !self.mirror_y
+        } else {
+            !self.mirror_x
+        };
+
+        if !self.transpose && !self.mirror_x && !self.mirror_y {
+            parent.fill_contiguous(area, colors)
+        } else if keeps_order {
+            let mut colors = colors.into_iter();
+            let row_size = if self.transpose {
+                Size::new(1, area.size.width)
+            } else {
+                Size::new(area.size.width, 1)
+            };
+
+            for row in area.rows() {
+                let top_left = self.map_point(Point::new(area.top_left.x, row));
+
+                parent.fill_contiguous(
+                    &Rectangle::new(top_left, row_size),
+                    colors.by_ref().take(area.size.width as usize),
+                )?;
+            }
+
+            Ok(())
+        } else {
+            let pixels = area
+                .points()
+                .zip(colors)
+                .map(|(point, color)| Pixel(self.map_point(point), color));
+
+            parent.draw_iter(pixels)
+        }
+    }
+}
+
+#[cfg(test)]
+pub(super) mod tests {
+    use super::*;
+    use crate::pixelcolor::BinaryColor;
+    use core::fmt::Debug;
+
+    /// Area used by the fill tests.
+    const FILL_AREA: Rectangle = Rectangle::new(Point::new(3, 2), Size::new(3, 4));
+
+    /// Fills the same area with `fill_contiguous` on `target` and with `draw_iter` on `expected`.
+    pub fn fill_contiguous<T>(target: &mut T, expected: &mut T)
+    where
+        T: DrawTarget<Color = BinaryColor>,
+        T::Error: Debug,
+    {
+        let colors = [
+            1, 1, 0, //
+            0, 1, 0, //
+            1, 1, 1, //
+            0, 0, 1, //
+        ]
+        .map(|c| BinaryColor::from(c != 0));
+
+        target.fill_contiguous(&FILL_AREA, colors).unwrap();
+        expected
+            .draw_iter(FILL_AREA.points().zip(colors).map(|(p, c)| Pixel(p, c)))
+            .unwrap();
+    }
+
+    /// Fills the same area with `fill_solid` on `target` and with `draw_iter` on `expected`.
+    pub fn fill_solid<T>(target: &mut T, expected: &mut T)
+    where
+        T: DrawTarget<Color = BinaryColor>,
+        T::Error: Debug,
+    {
+        target.fill_solid(&FILL_AREA, BinaryColor::On).unwrap();
+        expected
+            .draw_iter(FILL_AREA.points().map(|p| Pixel(p, BinaryColor::On)))
+            .unwrap();
+    }
+
+    #[test]
+    fn rotation_and_flip() {
+        let area = Rectangle::new(Point::new(2, 1), Size::new(6, 4));
+
+        // Rotating by 180° is the same as flipping in both directions.
+        let rotation = Mapping::rotation(Rotation::Deg180, area);
+        let flip = Mapping::flip(true, true, area);
+        for point in area.points() {
+            assert_eq!(rotation.map_point(point), flip.map_point(point));
+        }
+
+        assert_eq!(
+            Mapping::rotation(Rotation::Deg90, area).bounding_box(),
+            Rectangle::new(Point::new(2, 1), Size::new(4, 6))
+        );
+        assert_eq!(Mapping::flip(true, false, area).bounding_box(), area);
+    }
+}
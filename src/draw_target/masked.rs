@@ -0,0 +1,320 @@
+use crate::{
+    draw_target::DrawTarget,
+    geometry::{Dimensions, Point, Size},
+    image::GetPixel,
+    pixelcolor::BinaryColor,
+    primitives::Rectangle,
+    Pixel,
+};
+
+/// Masked draw target.
+///
+/// Created by calling [`masked`] on any [`DrawTarget`].
+/// See the [`masked`] method documentation for more.
+///
+/// [`masked`]: crate::draw_target::DrawTargetExt::masked
+#[derive(Debug)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub struct Masked<'a, T, M>
+where
+    T: DrawTarget,
+{
+    parent: &'a mut T,
+    mask: &'a M,
+}
+
+impl<'a, T, M> Masked<'a, T, M>
+where
+    T: DrawTarget,
+    M: GetPixel<Color = BinaryColor> + Dimensions,
+{
+    pub(super) fn new(parent: &'a mut T, mask: &'a M) -> Self {
+        Self { parent, mask }
+    }
+}
+
+impl<T, M> DrawTarget for Masked<'_, T, M>
+where
+    T: DrawTarget,
+    M: GetPixel<Color = BinaryColor> + Dimensions,
+{
+    type Color = T::Color;
+    type Error = T::Error;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        let mask = self.mask;
+        let pixels = pixels
+            .into_iter()
+            .filter(|Pixel(p, _)| mask.pixel(*p) == Some(BinaryColor::On));
+
+        self.parent.draw_iter(pixels)
+    }
+
+    fn fill_contiguous<I>(&mut self, area: &Rectangle, colors: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Self::Color>,
+    {
+        let mut colors = colors.into_iter();
+
+        for (run, is_visible) in MaskRuns::new(self.mask, area) {
+            let length = run.size.width as usize;
+
+            if is_visible {
+                self.parent
+                    .fill_contiguous(&run, colors.by_ref().take(length))?;
+            } else {
+                colors.by_ref().take(length).for_each(drop);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn fill_solid(&mut self, area: &Rectangle, color: Self::Color) -> Result<(), Self::Error> {
+        let area = area.intersection(&self.mask.bounding_box());
+
+        for (run, is_visible) in MaskRuns::new(self.mask, &area) {
+            if is_visible {
+                self.parent.fill_solid(&run, color)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn clear(&mut self, color: Self::Color) -> Result<(), Self::Error> {
+        self.fill_solid(&self.bounding_box(), color)
+    }
+}
+
+impl<T, M> Dimensions for Masked<'_, T, M>
+where
+    T: DrawTarget,
+    M: Dimensions,
+{
+    fn bounding_box(&self) -> Rectangle {
+        self.parent
+            .bounding_box()
+            .intersection(&self.mask.bounding_box())
+    }
+}
+
+/// Iterator over the runs of visible and hidden pixels in an area.
+///
+/// Each run is a part of a row in which all pixels are either visible or hidden.
+struct MaskRuns<'a, M> {
+    mask: &'a M,
+    area: Rectangle,
+    point: Point,
+}
+
+impl<'a, M> MaskRuns<'a, M>
+where
+    M: GetPixel<Color = BinaryColor>,
+{
+    fn new(mask: &'a M, area: &Rectangle) -> Self {
+        // Zero sized areas are replaced by an area without any rows to make sure that no runs
+        // are returned.
+        let area = if area.is_zero_sized() {
+            Rectangle::zero()
+        } else {
+            *area
+        };
+
+        Self {
+            mask,
+            area,
+            point: area.top_left,
+        }
+    }
+
+    fn is_visible(&self, point: Point) -> bool {
+        self.mask.pixel(point) == Some(BinaryColor::On)
+    }
+}
+
+impl<M> Iterator for MaskRuns<'_, M>
+where
+    M: GetPixel<Color = BinaryColor>,
+{
+    type Item = (Rectangle, bool);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if !self.area.rows().contains(&self.point.y) {
+            return None;
+        }
+
+        let start = self.point;
+        let is_visible = self.is_visible(start);
+        let end = self.area.columns().end;
+
+        let mut x = start.x + 1;
+        while x < end && self.is_visible(Point::new(x, start.y)) == is_visible {
+            x += 1;
+        }
+
+        self.point = if x < end {
+            Point::new(x, start.y)
+        } else {
+            Point::new(self.area.top_left.x, start.y + 1)
+        };
+
+        let run = Rectangle::new(start, Size::new((x - start.x) as u32, 1));
+
+        Some((run, is_visible))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        draw_target::DrawTargetExt,
+        framebuffer::{buffer_size, Framebuffer},
+        image::ImageRaw,
+        iterator::PixelIteratorExt,
+        mock_display::MockDisplay,
+        pixelcolor::raw::LittleEndianMsb0,
+        primitives::{Circle, PointsIter, Primitive, PrimitiveStyle},
+        Drawable,
+    };
+
+    /// 6x4 pixel mask image.
+    const MASK: ImageRaw<BinaryColor> = ImageRaw::new_const(
+        &[
+            0b01100000, //
+            0b11110000, //
+            0b11011100, //
+            0b00000100, //
+        ],
+        Size::new(6, 4),
+    );
+
+    #[test]
+    fn runs() {
+        let area = Rectangle::new(Point::new(1, 1), Size::new(6, 2));
+
+        assert!(MaskRuns::new(&MASK, &area).eq([
+            (Rectangle::new(Point::new(1, 1), Size::new(3, 1)), true),
+            (Rectangle::new(Point::new(4, 1), Size::new(3, 1)), false),
+            (Rectangle::new(Point::new(1, 2), Size::new(1, 1)), true),
+            (Rectangle::new(Point::new(2, 2), Size::new(1, 1)), false),
+            (Rectangle::new(Point::new(3, 2), Size::new(3, 1)), true),
+            (Rectangle::new(Point::new(6, 2), Size::new(1, 1)), false),
+        ]));
+
+        assert_eq!(MaskRuns::new(&MASK, &Rectangle::zero()).next(), None);
+    }
+
+    #[test]
+    fn draw_iter() {
+        let mut display = MockDisplay::new();
+
+        Rectangle::new(Point::zero(), Size::new(8, 5))
+            .points()
+            .map(|p| Pixel(p, BinaryColor::On))
+            .draw(&mut display.masked(&MASK))
+            .unwrap();
+
+        display.assert_pattern(&[
+            " ##   ", //
+            "####  ", //
+            "## ###", //
+            "     #", //
+        ]);
+    }
+
+    #[test]
+    fn fill_contiguous() {
+        let area = Rectangle::new(Point::new(1, 0), Size::new(3, 3));
+        let colors = [
+            1, 0, 1, //
+            0, 1, 0, //
+            1, 1, 1, //
+        ]
+        .map(|c| BinaryColor::from(c != 0));
+
+        let mut display = MockDisplay::new();
+        display
+            .masked(&MASK)
+            .fill_contiguous(&area, colors)
+            .unwrap();
+
+        display.assert_pattern(&[
+            " #. ", //
+            " .#.", //
+            " # #", //
+        ]);
+    }
+
+    #[test]
+    fn fill_solid_and_clear() {
+        let mut display = MockDisplay::new();
+        display
+            .masked(&MASK)
+            .fill_solid(
+                &Rectangle::new(Point::new(-2, 1), Size::new(5, 10)),
+                BinaryColor::On,
+            )
+            .unwrap();
+
+        display.assert_pattern(&[
+            "   ", //
+            "###", //
+            "## ", //
+        ]);
+
+        let mut display = MockDisplay::new();
+        display.masked(&MASK).clear(BinaryColor::Off).unwrap();
+
+        display.assert_pattern(&[
+            " ..   ", //
+            "....  ", //
+            ".. ...", //
+            "     .", //
+        ]);
+    }
+
+    #[test]
+    fn rendered_mask() {
+        let circle = Circle::new(Point::new(1, 1), 5)
+            .into_styled(PrimitiveStyle::with_fill(BinaryColor::On));
+
+        let mut mask = Framebuffer::<
+            BinaryColor,
+            _,
+            LittleEndianMsb0,
+            8,
+            8,
+            { buffer_size::<BinaryColor>(8, 8) },
+        >::new();
+        circle.draw(&mut mask).unwrap();
+
+        let mut display = MockDisplay::new();
+        display
+            .masked(&mask)
+            .fill_solid(
+                &Rectangle::new(Point::zero(), Size::new(10, 10)),
+                BinaryColor::On,
+            )
+            .unwrap();
+
+        let mut expected = MockDisplay::new();
+        circle.draw(&mut expected).unwrap();
+
+        display.assert_eq(&expected);
+    }
+
+    #[test]
+    fn bounding_box() {
+        let mut display = MockDisplay::<BinaryColor>::new();
+
+        assert_eq!(
+            display.masked(&MASK).bounding_box(),
+            Rectangle::new(Point::zero(), Size::new(6, 4))
+        );
+    }
+}
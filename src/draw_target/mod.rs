@@ -1,15 +1,41 @@
 //! A target for embedded-graphics drawing operations.
 
+mod alpha_blended;
 mod clipped;
 mod color_converted;
 mod cropped;
+mod dirty_tracker;
+mod display_list;
+mod dithered;
+mod flipped;
+mod mapping;
+mod masked;
+mod palette_mapped;
+mod quantized;
+mod rotated;
+mod scaled;
 mod translated;
 
-use crate::{geometry::Point, pixelcolor::PixelColor, primitives::Rectangle};
+use crate::{
+    geometry::{Dimensions, Point},
+    image::GetPixel,
+    pixelcolor::{AlphaColor, BinaryColor, IndexedColor, Palette, PixelColor, Rgb888},
+    primitives::Rectangle,
+};
 
+pub use alpha_blended::AlphaBlended;
 pub use clipped::Clipped;
 pub use color_converted::ColorConverted;
 pub use cropped::Cropped;
+pub use dirty_tracker::{DirtyRegion, DirtyTracker};
+pub use display_list::{DisplayCommand, DisplayList, DisplayListError};
+pub use dithered::{DitherColor, Dithered, ErrorDiffusion, OrderedDither};
+pub use flipped::Flipped;
+pub use masked::Masked;
+pub use palette_mapped::PaletteMapped;
+pub use quantized::Quantized;
+pub use rotated::{Rotated, Rotation};
+pub use scaled::Scaled;
 pub use translated::Translated;
 
 pub use embedded_graphics_core::draw_target::DrawTarget;
@@ -210,6 +236,428 @@ pub trait DrawTargetExt: DrawTarget + Sized {
     fn color_converted<C>(&mut self) -> ColorConverted<'_, Self, C>
     where
         C: PixelColor + Into<Self::Color>;
+
+    /// Creates a rotated draw target based on this draw target.
+    ///
+    /// Everything drawn to the rotated draw target is rotated clockwise by the given `rotation`
+    /// before being passed to the parent draw target. For rotations by 90° and 270° the width and
+    /// height of the bounding box are swapped, which makes it possible to use a display that is
+    /// mounted in portrait orientation like a landscape display, or vice versa.
+    ///
+    /// # Performance
+    ///
+    /// `fill_solid` calls are passed on as a single `fill_solid` call to the parent draw target.
+    /// `fill_contiguous` is passed on as one `fill_contiguous` call per row for `Rotation::Deg90`
+    /// and falls back to `draw_iter` for the other rotations, because the order of the pixels in
+    /// the parent draw target is reversed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use embedded_graphics::{
+    ///     draw_target::Rotation,
+    ///     mock_display::MockDisplay,
+    ///     pixelcolor::BinaryColor,
+    ///     prelude::*,
+    ///     primitives::{Line, PrimitiveStyle},
+    /// };
+    ///
+    /// let mut display = MockDisplay::new();
+    /// let mut rotated_display = display.rotated(Rotation::Deg90);
+    ///
+    /// // Draws a line along the top edge of the rotated display
+    /// Line::new(Point::new(0, 0), Point::new(3, 0))
+    ///     .into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 1))
+    ///     .draw(&mut rotated_display)?;
+    ///
+    /// // The line is drawn along the right edge of the display
+    /// let right_edge = display.bounding_box().size.width as i32 - 1;
+    /// assert_eq!(
+    ///     display.get_pixel(Point::new(right_edge, 3)),
+    ///     Some(BinaryColor::On)
+    /// );
+    /// # Ok::<(), core::convert::Infallible>(())
+    /// ```
+    fn rotated(&mut self, rotation: Rotation) -> Rotated<'_, Self>;
+
+    /// Creates a flipped draw target based on this draw target.
+    ///
+    /// Everything drawn to the flipped draw target is mirrored horizontally, if `horizontal` is
+    /// `true`, and vertically, if `vertical` is `true`, before being passed to the parent draw
+    /// target. The bounding box is the same as the bounding box of the parent draw target.
+    ///
+    /// # Performance
+    ///
+    /// `fill_solid` calls are passed on as a single `fill_solid` call to the parent draw target.
+    /// `fill_contiguous` is passed on as one `fill_contiguous` call per row if the target is only
+    /// flipped vertically and falls back to `draw_iter` if it is flipped horizontally.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use embedded_graphics::{
+    ///     mock_display::MockDisplay,
+    ///     pixelcolor::BinaryColor,
+    ///     prelude::*,
+    ///     primitives::{PrimitiveStyle, Rectangle},
+    /// };
+    ///
+    /// let mut display = MockDisplay::new();
+    /// let mut flipped_display = display.flipped(true, false);
+    ///
+    /// // Draws a square in the top left corner of the flipped display
+    /// Rectangle::new(Point::zero(), Size::new(2, 2))
+    ///     .into_styled(PrimitiveStyle::with_fill(BinaryColor::On))
+    ///     .draw(&mut flipped_display)?;
+    ///
+    /// // The square is drawn in the top right corner of the display
+    /// let right_edge = display.bounding_box().size.width as i32 - 1;
+    /// assert_eq!(
+    ///     display.get_pixel(Point::new(right_edge, 0)),
+    ///     Some(BinaryColor::On)
+    /// );
+    /// # Ok::<(), core::convert::Infallible>(())
+    /// ```
+    fn flipped(&mut self, horizontal: bool, vertical: bool) -> Flipped<'_, Self>;
+
+    /// Creates a scaled draw target based on this draw target.
+    ///
+    /// Every pixel drawn to the scaled draw target is drawn as a block of `factor_x` by
+    /// `factor_y` pixels to the parent draw target. This makes it possible to draw low resolution
+    /// content, like text using a small [`MonoFont`], enlarged on high resolution displays.
+    ///
+    /// The bounding box of the scaled draw target contains all pixels which are completely inside
+    /// the bounding box of the parent draw target.
+    ///
+    /// # Performance
+    ///
+    /// `fill_solid` calls are passed on as a single `fill_solid` call to the parent draw target.
+    /// `fill_contiguous` is passed on as a single `fill_contiguous` call if `factor_y` is 1 and
+    /// as one `fill_solid` call per run of equal colors in each row otherwise. All pixels drawn
+    /// by `draw_iter` are also drawn by using `fill_solid`.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `factor_x` or `factor_y` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use embedded_graphics::{
+    ///     mock_display::MockDisplay,
+    ///     pixelcolor::BinaryColor,
+    ///     prelude::*,
+    ///     primitives::{PrimitiveStyle, Rectangle},
+    /// };
+    ///
+    /// let mut display = MockDisplay::new();
+    /// let mut scaled_display = display.scaled(3, 2);
+    ///
+    /// // The 64x64 pixel display is turned into a 21x32 pixel display
+    /// assert_eq!(scaled_display.bounding_box().size, Size::new(21, 32));
+    ///
+    /// Rectangle::new(Point::new(1, 0), Size::new(2, 1))
+    ///     .into_styled(PrimitiveStyle::with_fill(BinaryColor::On))
+    ///     .draw(&mut scaled_display)?;
+    ///
+    /// display.assert_pattern(&[
+    ///     "   ######", //
+    ///     "   ######", //
+    /// ]);
+    /// # Ok::<(), core::convert::Infallible>(())
+    /// ```
+    ///
+    /// [`MonoFont`]: crate::mono_font::MonoFont
+    fn scaled(&mut self, factor_x: u32, factor_y: u32) -> Scaled<'_, Self>;
+
+    /// Creates an alpha blending draw target based on this draw target.
+    ///
+    /// An alpha blending draw target is used to draw drawables which use a color type with an
+    /// alpha channel, like [`Rgba8888`], to a draw target which supports reading pixels, like a
+    /// [`Framebuffer`]. The colors are composited with the current pixel colors of the parent
+    /// draw target, based on the alpha channel of the drawn colors, and converted into the color
+    /// type of the parent.
+    ///
+    /// Fully transparent pixels are skipped and fully opaque pixels are drawn without reading the
+    /// parent draw target. Pixels which can't be read from the parent draw target, because they
+    /// are outside the parent bounding box, are also skipped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use embedded_graphics::{
+    ///     framebuffer::{buffer_size, Framebuffer},
+    ///     image::GetPixel,
+    ///     pixelcolor::{raw::LittleEndianMsb0, Rgb565, Rgba8888},
+    ///     prelude::*,
+    ///     primitives::{PrimitiveStyle, Rectangle},
+    /// };
+    ///
+    /// let mut fb =
+    ///     Framebuffer::<Rgb565, _, LittleEndianMsb0, 64, 64, { buffer_size::<Rgb565>(64, 64) }>::new();
+    /// fb.clear(Rgb565::BLUE)?;
+    ///
+    /// // Draw a translucent white popup on top of the blue background.
+    /// Rectangle::new(Point::new(8, 8), Size::new(48, 32))
+    ///     .into_styled(PrimitiveStyle::with_fill(Rgba8888::WHITE.with_alpha(128)))
+    ///     .draw(&mut fb.alpha_blended())?;
+    ///
+    /// assert_eq!(fb.pixel(Point::new(0, 0)), Some(Rgb565::BLUE));
+    /// assert_eq!(fb.pixel(Point::new(8, 8)), Some(Rgb565::new(16, 32, 31)));
+    /// # Ok::<(), core::convert::Infallible>(())
+    /// ```
+    ///
+    /// [`Rgba8888`]: crate::pixelcolor::Rgba8888
+    /// [`Framebuffer`]: crate::framebuffer::Framebuffer
+    fn alpha_blended<C>(&mut self) -> AlphaBlended<'_, Self, C>
+    where
+        Self: GetPixel<Color = <Self as DrawTarget>::Color>,
+        <Self as DrawTarget>::Color: From<Rgb888> + Into<Rgb888>,
+        C: AlphaColor + Into<Rgb888>;
+
+    /// Creates a masked draw target based on this draw target.
+    ///
+    /// A masked draw target clips all drawing operations against a mask, which makes it possible
+    /// to clip drawing operations to non rectangular areas, like round displays or windows with
+    /// rounded corners. Only pixels for which the mask returns [`BinaryColor::On`] are drawn to
+    /// the parent draw target. The mask uses the same coordinate system as the parent target and
+    /// pixels outside the mask are clipped.
+    ///
+    /// Any type which implements [`GetPixel`] with `BinaryColor` as the color type can be used as a
+    /// mask, e.g. an [`ImageRaw`] or a [`Framebuffer`] into which a shape has been drawn. The
+    /// bounding box of the returned target is the intersection of the bounding boxes of the parent
+    /// target and the mask.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use embedded_graphics::{
+    ///     framebuffer::{buffer_size, Framebuffer},
+    ///     mock_display::MockDisplay,
+    ///     pixelcolor::{raw::LittleEndianMsb0, BinaryColor},
+    ///     prelude::*,
+    ///     primitives::{PrimitiveStyle, Rectangle, RoundedRectangle},
+    /// };
+    ///
+    /// // Render the mask for a window with rounded corners.
+    /// let mut mask = Framebuffer::<
+    ///     BinaryColor,
+    ///     _,
+    ///     LittleEndianMsb0,
+    ///     64,
+    ///     64,
+    ///     { buffer_size::<BinaryColor>(64, 64) },
+    /// >::new();
+    ///
+    /// let window = Rectangle::new(Point::new(2, 2), Size::new(20, 8));
+    /// RoundedRectangle::with_equal_corners(window, Size::new(4, 4))
+    ///     .into_styled(PrimitiveStyle::with_fill(BinaryColor::On))
+    ///     .draw(&mut mask)?;
+    ///
+    /// let mut display = MockDisplay::new();
+    /// let mut masked_display = display.masked(&mask);
+    ///
+    /// // The corners of the window contents are clipped by the mask.
+    /// window
+    ///     .into_styled(PrimitiveStyle::with_fill(BinaryColor::On))
+    ///     .draw(&mut masked_display)?;
+    ///
+    /// assert_eq!(display.get_pixel(Point::new(2, 2)), None);
+    /// assert_eq!(display.get_pixel(Point::new(12, 2)), Some(BinaryColor::On));
+    /// # Ok::<(), core::convert::Infallible>(())
+    /// ```
+    ///
+    /// [`ImageRaw`]: crate::image::ImageRaw
+    /// [`Framebuffer`]: crate::framebuffer::Framebuffer
+    fn masked<'a, M>(&'a mut self, mask: &'a M) -> Masked<'a, Self, M>
+    where
+        M: GetPixel<Color = BinaryColor> + Dimensions;
+
+    /// Creates an ordered dithering draw target based on this draw target.
+    ///
+    /// A dithering draw target is used to draw drawables with a color type that has a higher
+    /// color depth than the parent draw target, e.g. to draw `Rgb888` images to a `BinaryColor`
+    /// or `Gray2` display. Unlike [`color_converted`], which maps each color to the closest
+    /// available color, dithering approximates colors which aren't available by a pattern of the
+    /// available colors.
+    ///
+    /// Ordered dithering uses a threshold matrix to decide which of the two closest colors is
+    /// used for each pixel. The result only depends on the position and color of a pixel, which
+    /// makes it possible to use it for all drawing operations. See [`error_diffused`] for a
+    /// dithering method which produces better results for images.
+    ///
+    /// The parent draw target color type must implement [`DitherColor`], which is implemented for
+    /// all built-in grayscale and RGB color types and for `BinaryColor`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use embedded_graphics::{
+    ///     draw_target::OrderedDither,
+    ///     mock_display::MockDisplay,
+    ///     pixelcolor::{BinaryColor, Rgb888},
+    ///     prelude::*,
+    ///     primitives::{PrimitiveStyle, Rectangle},
+    /// };
+    ///
+    /// let mut display = MockDisplay::<BinaryColor>::new();
+    ///
+    /// // 50% gray is approximated by a checkerboard pattern.
+    /// Rectangle::new(Point::zero(), Size::new(6, 3))
+    ///     .into_styled(PrimitiveStyle::with_fill(Rgb888::new(128, 128, 128)))
+    ///     .draw(&mut display.dithered(OrderedDither::Bayer2x2))?;
+    ///
+    /// display.assert_pattern(&[
+    ///     ".#.#.#", //
+    ///     "#.#.#.", //
+    ///     ".#.#.#", //
+    /// ]);
+    /// # Ok::<(), core::convert::Infallible>(())
+    /// ```
+    ///
+    /// [`color_converted`]: DrawTargetExt::color_converted
+    /// [`error_diffused`]: DrawTargetExt::error_diffused
+    fn dithered<C>(&mut self, matrix: OrderedDither) -> Dithered<'_, Self, C>
+    where
+        Self::Color: DitherColor,
+        C: PixelColor + Into<Rgb888>;
+
+    /// Creates an error diffusion dithering draw target based on this draw target.
+    ///
+    /// Error diffusion dithering distributes the difference between the drawn color and the
+    /// closest available color to the neighboring pixels, which produces less visible patterns
+    /// than ordered dithering (see [`dithered`]). The quantization error of the pixels in the
+    /// following rows is stored in `buffer`, which must contain at least
+    /// [`ErrorDiffusion::buffer_len`] entries for the width of the drawn areas.
+    ///
+    /// Error diffusion requires the pixels to be drawn in row-major order and is therefore only
+    /// used for `fill_contiguous` and `fill_solid`, which is used to draw images and filled
+    /// primitives. Pixels drawn by using `draw_iter` and areas which are too wide for the buffer
+    /// are drawn using ordered dithering with a 4x4 Bayer matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use embedded_graphics::{
+    ///     draw_target::ErrorDiffusion,
+    ///     image::{Image, ImageRaw},
+    ///     mock_display::MockDisplay,
+    ///     pixelcolor::{Gray2, Gray8},
+    ///     prelude::*,
+    /// };
+    ///
+    /// // A horizontal 8 bit grayscale gradient.
+    /// let data: [u8; 16] = core::array::from_fn(|i| (i * 17) as u8);
+    /// let image = ImageRaw::<Gray8>::new(&data, Size::new(16, 1))?;
+    ///
+    /// const METHOD: ErrorDiffusion = ErrorDiffusion::FloydSteinberg;
+    /// let mut buffer = [[0; 3]; METHOD.buffer_len(16)];
+    ///
+    /// let mut display = MockDisplay::<Gray2>::new();
+    /// Image::new(&image, Point::zero())
+    ///     .draw(&mut display.error_diffused(METHOD, &mut buffer))
+    ///     .unwrap();
+    ///
+    /// assert_eq!(display.get_pixel(Point::new(0, 0)), Some(Gray2::BLACK));
+    /// assert_eq!(display.get_pixel(Point::new(15, 0)), Some(Gray2::WHITE));
+    /// # Ok::<(), embedded_graphics::image::ImageRawError>(())
+    /// ```
+    ///
+    /// [`dithered`]: DrawTargetExt::dithered
+    fn error_diffused<'a, C>(
+        &'a mut self,
+        method: ErrorDiffusion,
+        buffer: &'a mut [[i16; 3]],
+    ) -> Dithered<'a, Self, C>
+    where
+        Self::Color: DitherColor,
+        C: PixelColor + Into<Rgb888>;
+
+    /// Creates a palette lookup draw target based on this draw target.
+    ///
+    /// The returned draw target uses an [`IndexedColor`] type, like [`Indexed4`], as its color
+    /// type and converts all drawn colors to the color type of the parent target by looking them
+    /// up in `palette`. This makes it possible to store images in a compact indexed format, e.g.
+    /// as an [`ImageRaw`] or a [`Framebuffer`], and draw them to any display.
+    ///
+    /// Indices outside of the palette are treated as transparent and aren't drawn. If the palette
+    /// doesn't contain a color for every possible index, `fill_contiguous` is forwarded to the
+    /// parent target as a call to `draw_iter`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use embedded_graphics::{
+    ///     image::{Image, ImageRaw},
+    ///     mock_display::MockDisplay,
+    ///     pixelcolor::{Indexed4, Palette, Rgb565},
+    ///     prelude::*,
+    /// };
+    ///
+    /// const PALETTE: Palette<Rgb565> = Palette::new(&[
+    ///     Rgb565::BLACK,
+    ///     Rgb565::RED,
+    ///     Rgb565::GREEN,
+    ///     Rgb565::BLUE,
+    /// ]);
+    ///
+    /// // A 4x1 pixel image with 4 bits per pixel.
+    /// let data = [0x01, 0x23];
+    /// let image = ImageRaw::<Indexed4>::new(&data, Size::new(4, 1))?;
+    ///
+    /// let mut display = MockDisplay::<Rgb565>::new();
+    /// Image::new(&image, Point::zero())
+    ///     .draw(&mut display.palette_mapped(PALETTE))
+    ///     .unwrap();
+    ///
+    /// display.assert_pattern(&["KRGB"]);
+    /// # Ok::<(), embedded_graphics::image::ImageRawError>(())
+    /// ```
+    ///
+    /// [`Indexed4`]: crate::pixelcolor::Indexed4
+    /// [`ImageRaw`]: crate::image::ImageRaw
+    /// [`Framebuffer`]: crate::framebuffer::Framebuffer
+    fn palette_mapped<'a, I>(
+        &'a mut self,
+        palette: Palette<'a, Self::Color>,
+    ) -> PaletteMapped<'a, Self, I>
+    where
+        I: IndexedColor;
+
+    /// Creates a palette quantization draw target based on this draw target.
+    ///
+    /// All colors drawn to the returned draw target are replaced by the perceptually closest
+    /// color in `palette` before they are drawn to the parent target, see [`Palette::nearest`]
+    /// for more information. This can be used to draw arbitrary colors to displays which only
+    /// support a few fixed colors, like black, white and red e-paper displays. Nothing is drawn
+    /// if the palette is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use embedded_graphics::{
+    ///     mock_display::MockDisplay,
+    ///     pixelcolor::{Palette, Rgb565, Rgb888},
+    ///     prelude::*,
+    ///     primitives::{Circle, PrimitiveStyle},
+    /// };
+    ///
+    /// const PALETTE: Palette<Rgb888> = Palette::new(&[Rgb888::BLACK, Rgb888::WHITE, Rgb888::RED]);
+    ///
+    /// let mut display = MockDisplay::<Rgb888>::new();
+    ///
+    /// // The orange circle is drawn in red, which is the closest color in the palette.
+    /// Circle::new(Point::zero(), 5)
+    ///     .into_styled(PrimitiveStyle::with_fill(Rgb565::CSS_ORANGE_RED))
+    ///     .draw(&mut display.quantized(PALETTE))?;
+    ///
+    /// assert_eq!(display.get_pixel(Point::new(2, 2)), Some(Rgb888::RED));
+    /// # Ok::<(), core::convert::Infallible>(())
+    /// ```
+    fn quantized<'a, C>(&'a mut self, palette: Palette<'a, Self::Color>) -> Quantized<'a, Self, C>
+    where
+        Self::Color: Into<Rgb888>,
+        C: PixelColor + Into<Rgb888>;
 }
 
 impl<T> DrawTargetExt for T
@@ -234,6 +682,72 @@ where
     {
         ColorConverted::new(self)
     }
+
+    fn rotated(&mut self, rotation: Rotation) -> Rotated<'_, Self> {
+        Rotated::new(self, rotation)
+    }
+
+    fn flipped(&mut self, horizontal: bool, vertical: bool) -> Flipped<'_, Self> {
+        Flipped::new(self, horizontal, vertical)
+    }
+
+    fn scaled(&mut self, factor_x: u32, factor_y: u32) -> Scaled<'_, Self> {
+        Scaled::new(self, factor_x, factor_y)
+    }
+
+    fn alpha_blended<C>(&mut self) -> AlphaBlended<'_, Self, C>
+    where
+        Self: GetPixel<Color = <Self as DrawTarget>::Color>,
+        <Self as DrawTarget>::Color: From<Rgb888> + Into<Rgb888>,
+        C: AlphaColor + Into<Rgb888>,
+    {
+        AlphaBlended::new(self)
+    }
+
+    fn masked<'a, M>(&'a mut self, mask: &'a M) -> Masked<'a, Self, M>
+    where
+        M: GetPixel<Color = BinaryColor> + Dimensions,
+    {
+        Masked::new(self, mask)
+    }
+
+    fn dithered<C>(&mut self, matrix: OrderedDither) -> Dithered<'_, Self, C>
+    where
+        Self::Color: DitherColor,
+        C: PixelColor + Into<Rgb888>,
+    {
+        Dithered::new_ordered(self, matrix)
+    }
+
+    fn error_diffused<'a, C>(
+        &'a mut self,
+        method: ErrorDiffusion,
+        buffer: &'a mut [[i16; 3]],
+    ) -> Dithered<'a, Self, C>
+    where
+        Self::Color: DitherColor,
+        C: PixelColor + Into<Rgb888>,
+    {
+        Dithered::new_error_diffused(self, method, buffer)
+    }
+
+    fn palette_mapped<'a, I>(
+        &'a mut self,
+        palette: Palette<'a, Self::Color>,
+    ) -> PaletteMapped<'a, Self, I>
+    where
+        I: IndexedColor,
+    {
+        PaletteMapped::new(self, palette)
+    }
+
+    fn quantized<'a, C>(&'a mut self, palette: Palette<'a, Self::Color>) -> Quantized<'a, Self, C>
+    where
+        Self::Color: Into<Rgb888>,
+        C: PixelColor + Into<Rgb888>,
+    {
+        Quantized::new(self, palette)
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,213 @@
+use crate::{
+    draw_target::DrawTarget,
+    geometry::Dimensions,
+    pixelcolor::{IndexedColor, Palette},
+    primitives::{PointsIter, Rectangle},
+    Pixel,
+};
+use core::marker::PhantomData;
+
+/// Palette lookup draw target.
+///
+/// Created by calling [`palette_mapped`] on any [`DrawTarget`].
+/// See the [`palette_mapped`] method documentation for more.
+///
+/// [`palette_mapped`]: crate::draw_target::DrawTargetExt::palette_mapped
+#[derive(Debug)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub struct PaletteMapped<'a, T, I>
+where
+    T: DrawTarget,
+{
+    parent: &'a mut T,
+    palette: Palette<'a, T::Color>,
+    color_type: PhantomData<I>,
+}
+
+impl<'a, T, I> PaletteMapped<'a, T, I>
+where
+    T: DrawTarget,
+    I: IndexedColor,
+{
+    pub(super) fn new(parent: &'a mut T, palette: Palette<'a, T::Color>) -> Self {
+        Self {
+            parent,
+            palette,
+            color_type: PhantomData,
+        }
+    }
+}
+
+impl<T, I> DrawTarget for PaletteMapped<'_, T, I>
+where
+    T: DrawTarget,
+    I: IndexedColor,
+{
+    type Color = I;
+    type Error = T::Error;
+
+    fn draw_iter<P>(&mut self, pixels: P) -> Result<(), Self::Error>
+    where
+        P: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        let palette = self.palette;
+        let pixels = pixels
+            .into_iter()
+            .filter_map(|Pixel(p, c)| palette.get(c).map(|c| Pixel(p, c)));
+
+        self.parent.draw_iter(pixels)
+    }
+
+    fn fill_contiguous<C>(&mut self, area: &Rectangle, colors: C) -> Result<(), Self::Error>
+    where
+        C: IntoIterator<Item = Self::Color>,
+    {
+        let palette = self.palette;
+
+        // Indices outside the palette can only occur if the palette is incomplete. In this case
+        // the colors can no longer be passed on as a contiguous stream.
+        if palette.is_complete::<I>() {
+            let colors = colors.into_iter().filter_map(|c| palette.get(c));
+
+            self.parent.fill_contiguous(area, colors)
+        } else {
+            let pixels = area
+                .points()
+                .zip(colors)
+                .filter_map(|(p, c)| palette.get(c).map(|c| Pixel(p, c)));
+
+            self.parent.draw_iter(pixels)
+        }
+    }
+
+    fn fill_solid(&mut self, area: &Rectangle, color: Self::Color) -> Result<(), Self::Error> {
+        match self.palette.get(color) {
+            Some(color) => self.parent.fill_solid(area, color),
+            None => Ok(()),
+        }
+    }
+
+    fn clear(&mut self, color: Self::Color) -> Result<(), Self::Error> {
+        match self.palette.get(color) {
+            Some(color) => self.parent.clear(color),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<T, I> Dimensions for PaletteMapped<'_, T, I>
+where
+    T: DrawTarget,
+{
+    fn bounding_box(&self) -> Rectangle {
+        self.parent.bounding_box()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        draw_target::DrawTargetExt,
+        framebuffer::{buffer_size, Framebuffer},
+        geometry::{Point, Size},
+        image::{Image, ImageRaw},
+        iterator::PixelIteratorExt,
+        mock_display::MockDisplay,
+        pixelcolor::{
+            raw::LittleEndianMsb0, BinaryColor, Indexed1, Indexed4, Rgb565, Rgb888, RgbColor,
+        },
+        primitives::{Primitive, PrimitiveStyle},
+        Drawable,
+    };
+
+    const PALETTE: Palette<Rgb888> =
+        Palette::new(&[Rgb888::BLACK, Rgb888::RED, Rgb888::GREEN, Rgb888::BLUE]);
+
+    #[test]
+    fn draw_iter() {
+        let mut display = MockDisplay::new();
+
+        [0, 1, 2, 3, 4]
+            .map(Indexed4::new)
+            .into_iter()
+            .zip(0..)
+            .map(|(c, x)| Pixel(Point::new(x, 0), c))
+            .draw(&mut display.palette_mapped(PALETTE))
+            .unwrap();
+
+        display.assert_pattern(&["KRGB "]);
+    }
+
+    #[test]
+    fn indexed_image() {
+        // 4x2 pixel 4bpp image, index 15 is outside the palette.
+        let data = [
+            0x01, 0x23, //
+            0xF3, 0x2F, //
+        ];
+        let image = ImageRaw::<Indexed4>::new(&data, Size::new(4, 2)).unwrap();
+
+        let mut display = MockDisplay::new();
+        Image::new(&image, Point::new(1, 1))
+            .draw(&mut display.palette_mapped(PALETTE))
+            .unwrap();
+
+        display.assert_pattern(&[
+            "     ", //
+            " KRGB", //
+            "  BG ", //
+        ]);
+    }
+
+    #[test]
+    fn complete_palette() {
+        const PALETTE: Palette<Rgb565> = Palette::new(&[Rgb565::BLACK, Rgb565::WHITE]);
+
+        let mut framebuffer = Framebuffer::<
+            Indexed1,
+            _,
+            LittleEndianMsb0,
+            4,
+            2,
+            { buffer_size::<Indexed1>(4, 2) },
+        >::new();
+        Rectangle::new(Point::new(1, 0), Size::new(2, 2))
+            .into_styled(PrimitiveStyle::with_fill(Indexed1::new(1)))
+            .draw(&mut framebuffer)
+            .unwrap();
+
+        let mut display = MockDisplay::new();
+        Image::new(&framebuffer.as_image(), Point::zero())
+            .draw(&mut display.palette_mapped(PALETTE))
+            .unwrap();
+
+        display.assert_pattern(&[
+            "KWWK", //
+            "KWWK", //
+        ]);
+    }
+
+    #[test]
+    fn fill_solid_and_clear() {
+        let mut display = MockDisplay::<BinaryColor>::new();
+        let palette = Palette::new(&[BinaryColor::On]);
+        let mut mapped = display.palette_mapped(palette);
+
+        mapped
+            .fill_solid(
+                &Rectangle::new(Point::new(1, 0), Size::new(2, 1)),
+                Indexed4::new(0),
+            )
+            .unwrap();
+        mapped
+            .fill_solid(
+                &Rectangle::new(Point::new(0, 1), Size::new(2, 1)),
+                Indexed4::new(1),
+            )
+            .unwrap();
+        mapped.clear(Indexed4::new(5)).unwrap();
+
+        display.assert_pattern(&[" ##"]);
+    }
+}
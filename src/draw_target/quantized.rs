@@ -0,0 +1,231 @@
+use crate::{
+    draw_target::DrawTarget,
+    geometry::Dimensions,
+    pixelcolor::{Palette, PixelColor, Rgb888},
+    primitives::Rectangle,
+    Pixel,
+};
+use core::marker::PhantomData;
+
+/// Palette quantization draw target.
+///
+/// Created by calling [`quantized`] on any [`DrawTarget`].
+/// See the [`quantized`] method documentation for more.
+///
+/// [`quantized`]: crate::draw_target::DrawTargetExt::quantized
+#[derive(Debug)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub struct Quantized<'a, T, C>
+where
+    T: DrawTarget,
+{
+    parent: &'a mut T,
+    palette: Palette<'a, T::Color>,
+    color_type: PhantomData<C>,
+}
+
+impl<'a, T, C> Quantized<'a, T, C>
+where
+    T: DrawTarget,
+    T::Color: Into<Rgb888>,
+    C: PixelColor + Into<Rgb888>,
+{
+    pub(super) fn new(parent: &'a mut T, palette: Palette<'a, T::Color>) -> Self {
+        Self {
+            parent,
+            palette,
+            color_type: PhantomData,
+        }
+    }
+}
+
+impl<T, C> DrawTarget for Quantized<'_, T, C>
+where
+    T: DrawTarget,
+    T::Color: Into<Rgb888>,
+    C: PixelColor + Into<Rgb888>,
+{
+    type Color = C;
+    type Error = T::Error;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        let mut nearest = NearestColor::new(self.palette);
+        let pixels = pixels
+            .into_iter()
+            .filter_map(|Pixel(p, c)| nearest.get(c).map(|c| Pixel(p, c)));
+
+        self.parent.draw_iter(pixels)
+    }
+
+    fn fill_contiguous<I>(&mut self, area: &Rectangle, colors: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Self::Color>,
+    {
+        // Nothing can be drawn with an empty palette.
+        if self.palette.is_empty() {
+            return Ok(());
+        }
+
+        let mut nearest = NearestColor::new(self.palette);
+        let colors = colors.into_iter().filter_map(|c| nearest.get(c));
+
+        self.parent.fill_contiguous(area, colors)
+    }
+
+    fn fill_solid(&mut self, area: &Rectangle, color: Self::Color) -> Result<(), Self::Error> {
+        match self.palette.nearest(color.into()) {
+            Some(color) => self.parent.fill_solid(area, color),
+            None => Ok(()),
+        }
+    }
+
+    fn clear(&mut self, color: Self::Color) -> Result<(), Self::Error> {
+        match self.palette.nearest(color.into()) {
+            Some(color) => self.parent.clear(color),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<T, C> Dimensions for Quantized<'_, T, C>
+where
+    T: DrawTarget,
+{
+    fn bounding_box(&self) -> Rectangle {
+        self.parent.bounding_box()
+    }
+}
+
+/// Nearest palette color lookup.
+///
+/// The result of the last lookup is cached, because images and primitives often contain runs of
+/// pixels with the same color.
+struct NearestColor<'a, C, D> {
+    palette: Palette<'a, D>,
+    last: Option<(C, D)>,
+}
+
+impl<'a, C, D> NearestColor<'a, C, D>
+where
+    C: PixelColor + Into<Rgb888>,
+    D: PixelColor + Into<Rgb888>,
+{
+    const fn new(palette: Palette<'a, D>) -> Self {
+        Self {
+            palette,
+            last: None,
+        }
+    }
+
+    fn get(&mut self, color: C) -> Option<D> {
+        match self.last {
+            Some((last_color, nearest)) if last_color == color => Some(nearest),
+            _ => {
+                let nearest = self.palette.nearest(color.into())?;
+                self.last = Some((color, nearest));
+
+                Some(nearest)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        draw_target::DrawTargetExt,
+        geometry::{Point, Size},
+        iterator::PixelIteratorExt,
+        mock_display::MockDisplay,
+        pixelcolor::{BinaryColor, Rgb565, RgbColor},
+        Drawable,
+    };
+
+    const PALETTE: Palette<Rgb888> =
+        Palette::new(&[Rgb888::BLACK, Rgb888::WHITE, Rgb888::RED, Rgb888::YELLOW]);
+
+    #[test]
+    fn draw_iter() {
+        let mut display = MockDisplay::new();
+
+        [
+            Rgb565::new(3, 5, 2),
+            Rgb565::new(28, 60, 26),
+            Rgb565::new(25, 4, 5),
+            Rgb565::new(31, 40, 0),
+        ]
+        .into_iter()
+        .zip(0..)
+        .map(|(c, x)| Pixel(Point::new(x, 0), c))
+        .draw(&mut display.quantized(PALETTE))
+        .unwrap();
+
+        display.assert_pattern(&["KWRY"]);
+    }
+
+    #[test]
+    fn fill_contiguous() {
+        let colors = [
+            Rgb888::new(10, 10, 10),
+            Rgb888::new(10, 10, 10),
+            Rgb888::new(250, 40, 20),
+            Rgb888::new(250, 240, 20),
+            Rgb888::new(250, 240, 20),
+            Rgb888::new(200, 200, 200),
+        ];
+
+        let mut display = MockDisplay::new();
+        display
+            .quantized(PALETTE)
+            .fill_contiguous(&Rectangle::new(Point::new(1, 1), Size::new(3, 2)), colors)
+            .unwrap();
+
+        display.assert_pattern(&[
+            "    ", //
+            " KKR", //
+            " YYW", //
+        ]);
+    }
+
+    #[test]
+    fn fill_solid_and_clear() {
+        let mut display = MockDisplay::new();
+        display
+            .quantized(PALETTE)
+            .fill_solid(
+                &Rectangle::new(Point::new(1, 0), Size::new(2, 1)),
+                Rgb888::new(255, 220, 50),
+            )
+            .unwrap();
+
+        display.assert_pattern(&[" YY"]);
+
+        let mut display = MockDisplay::new();
+        display.quantized(PALETTE).clear(Rgb888::WHITE).unwrap();
+
+        assert_eq!(display.get_pixel(Point::zero()), Some(Rgb888::WHITE));
+    }
+
+    #[test]
+    fn empty_palette() {
+        let mut display = MockDisplay::<BinaryColor>::new();
+        let mut quantized = display.quantized(Palette::new(&[]));
+
+        quantized.clear(Rgb888::WHITE).unwrap();
+        quantized
+            .fill_contiguous(
+                &Rectangle::new(Point::zero(), Size::new(2, 1)),
+                [Rgb888::BLACK, Rgb888::WHITE],
+            )
+            .unwrap();
+        Pixel(Point::zero(), Rgb888::RED)
+            .draw(&mut quantized)
+            .unwrap();
+
+        display.assert_pattern(&[]);
+    }
+}
@@ -0,0 +1,218 @@
+use crate::{
+    draw_target::{mapping::Mapping, DrawTarget},
+    geometry::Dimensions,
+    primitives::Rectangle,
+    Pixel,
+};
+
+/// Clockwise rotation of a draw target.
+///
+/// See the [`rotated`] method documentation for more.
+///
+/// [`rotated`]: crate::draw_target::DrawTargetExt::rotated
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub enum Rotation {
+    /// Rotation by 90°.
+    Deg90,
+    /// Rotation by 180°.
+    Deg180,
+    /// Rotation by 270°.
+    Deg270,
+}
+
+/// Rotated draw target.
+///
+/// Created by calling [`rotated`] on any [`DrawTarget`].
+/// See the [`rotated`] method documentation for more.
+///
+/// [`rotated`]: crate::draw_target::DrawTargetExt::rotated
+#[derive(Debug)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub struct Rotated<'a, T>
+where
+    T: DrawTarget,
+{
+    parent: &'a mut T,
+    mapping: Mapping,
+}
+
+impl<'a, T> Rotated<'a, T>
+where
+    T: DrawTarget,
+{
+    pub(super) fn new(parent: &'a mut T, rotation: Rotation) -> Self {
+        let mapping = Mapping::rotation(rotation, parent.bounding_box());
+
+        Self { parent, mapping }
+    }
+}
+
+impl<T> DrawTarget for Rotated<'_, T>
+where
+    T: DrawTarget,
+{
+    type Color = T::Color;
+    type Error = T::Error;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        let mapping = self.mapping;
+        let pixels = pixels
+            .into_iter()
+            .map(|Pixel(point, color)| Pixel(mapping.map_point(point), color));
+
+        self.parent.draw_iter(pixels)
+    }
+
+    fn fill_contiguous<I>(&mut self, area: &Rectangle, colors: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Self::Color>,
+    {
+        self.mapping.fill_contiguous(self.parent, area, colors)
+    }
+
+    fn fill_solid(&mut self, area: &Rectangle, color: Self::Color) -> Result<(), Self::Error> {
+        match self.mapping.map_area(area) {
+            Some(area) => self.parent.fill_solid(&area, color),
+            None => Ok(()),
+        }
+    }
+
+    fn clear(&mut self, color: Self::Color) -> Result<(), Self::Error> {
+        self.parent.clear(color)
+    }
+}
+
+impl<T> Dimensions for Rotated<'_, T>
+where
+    T: DrawTarget,
+{
+    fn bounding_box(&self) -> Rectangle {
+        self.mapping.bounding_box()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        draw_target::{mapping, DrawTargetExt},
+        geometry::{Point, Size},
+        iterator::PixelIteratorExt,
+        mock_display::MockDisplay,
+        pixelcolor::BinaryColor,
+    };
+
+    const ROTATIONS: [Rotation; 3] = [Rotation::Deg90, Rotation::Deg180, Rotation::Deg270];
+
+    /// Area of the parent display used in the tests.
+    const AREA: Rectangle = Rectangle::new(Point::new(2, 1), Size::new(6, 4));
+
+    fn draw_pattern<T: DrawTarget<Color = BinaryColor>>(target: &mut T) -> Result<(), T::Error> {
+        let top_left = target.bounding_box().top_left;
+
+        [
+            Pixel(top_left, BinaryColor::On),
+            Pixel(top_left + Point::new(1, 0), BinaryColor::Off),
+            Pixel(top_left + Point::new(0, 2), BinaryColor::Off),
+        ]
+        .into_iter()
+        .draw(target)
+    }
+
+    #[test]
+    fn draw_iter() {
+        let expected_patterns = [
+            [
+                "        ", //
+                "     . #", //
+                "       .", //
+                "        ", //
+                "        ", //
+            ],
+            [
+                "        ", //
+                "        ", //
+                "       .", //
+                "        ", //
+                "      .#", //
+            ],
+            [
+                "        ", //
+                "        ", //
+                "        ", //
+                "  .     ", //
+                "  # .   ", //
+            ],
+        ];
+
+        for (rotation, expected) in ROTATIONS.into_iter().zip(expected_patterns) {
+            let mut display = MockDisplay::new();
+            draw_pattern(&mut display.clipped(&AREA).rotated(rotation)).unwrap();
+
+            let expected = MockDisplay::from_pattern(&expected);
+            display.assert_eq_with_message(&expected, |f| write!(f, "{:?}", rotation));
+        }
+    }
+
+    #[test]
+    fn bounding_box() {
+        let mut display = MockDisplay::<BinaryColor>::new();
+        let mut clipped = display.clipped(&AREA);
+
+        assert_eq!(
+            clipped.rotated(Rotation::Deg90).bounding_box(),
+            Rectangle::new(Point::new(2, 1), Size::new(4, 6))
+        );
+        assert_eq!(clipped.rotated(Rotation::Deg180).bounding_box(), AREA);
+        assert_eq!(
+            clipped.rotated(Rotation::Deg270).bounding_box(),
+            Rectangle::new(Point::new(2, 1), Size::new(4, 6))
+        );
+    }
+
+    #[test]
+    fn fill_contiguous() {
+        for rotation in ROTATIONS {
+            let mut display = MockDisplay::new();
+            let mut expected = MockDisplay::new();
+            mapping::tests::fill_contiguous(
+                &mut display.rotated(rotation),
+                &mut expected.rotated(rotation),
+            );
+
+            display.assert_eq_with_message(&expected, |f| write!(f, "{:?}", rotation));
+        }
+    }
+
+    #[test]
+    fn fill_solid() {
+        for rotation in ROTATIONS {
+            let mut display = MockDisplay::new();
+            let mut expected = MockDisplay::new();
+            mapping::tests::fill_solid(
+                &mut display.rotated(rotation),
+                &mut expected.rotated(rotation),
+            );
+
+            display.assert_eq_with_message(&expected, |f| write!(f, "{:?}", rotation));
+        }
+    }
+
+    #[test]
+    fn empty_area() {
+        let mut display = MockDisplay::<BinaryColor>::new();
+        let mut rotated = display.rotated(Rotation::Deg90);
+
+        let area = Rectangle::new(Point::new(3, 2), Size::new(3, 0));
+        rotated.fill_solid(&area, BinaryColor::On).unwrap();
+        rotated
+            .fill_contiguous(&area, core::iter::repeat(BinaryColor::On))
+            .unwrap();
+
+        display.assert_pattern(&[]);
+    }
+}
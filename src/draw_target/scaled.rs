@@ -0,0 +1,363 @@
+use crate::{
+    draw_target::DrawTarget,
+    geometry::{Dimensions, Point, Size},
+    primitives::Rectangle,
+    Pixel,
+};
+use az::SaturatingAs;
+use core::ops::Range;
+
+/// Scaled draw target.
+///
+/// Created by calling [`scaled`] on any [`DrawTarget`].
+/// See the [`scaled`] method documentation for more.
+///
+/// [`scaled`]: crate::draw_target::DrawTargetExt::scaled
+#[derive(Debug)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub struct Scaled<'a, T>
+where
+    T: DrawTarget,
+{
+    parent: &'a mut T,
+    factor: Size,
+}
+
+impl<'a, T> Scaled<'a, T>
+where
+    T: DrawTarget,
+{
+    pub(super) fn new(parent: &'a mut T, factor_x: u32, factor_y: u32) -> Self {
+        assert!(
+            factor_x > 0 && factor_y > 0,
+            "scaling factors must be greater than zero"
+        );
+
+        Self {
+            parent,
+            factor: Size::new(factor_x, factor_y),
+        }
+    }
+
+    /// Returns the area in the parent draw target which is covered by `area`.
+    fn map_area(&self, area: &Rectangle) -> Rectangle {
+        let factor_x: i32 = self.factor.width.saturating_as();
+        let factor_y: i32 = self.factor.height.saturating_as();
+
+        Rectangle::new(
+            Point::new(
+                area.top_left.x.saturating_mul(factor_x),
+                area.top_left.y.saturating_mul(factor_y),
+            ),
+            Size::new(
+                area.size.width.saturating_mul(self.factor.width),
+                area.size.height.saturating_mul(self.factor.height),
+            ),
+        )
+    }
+
+    /// Returns the block of pixels in the parent draw target which is covered by `point`.
+    fn map_point(&self, point: Point) -> Rectangle {
+        self.map_area(&Rectangle::new(point, Size::new(1, 1)))
+    }
+
+    /// Fills the pixels in the range of columns `x` in row `y` with a single color.
+    fn fill_run(&mut self, x: Range<i32>, y: i32, color: T::Color) -> Result<(), T::Error> {
+        let run = Rectangle::new(
+            Point::new(x.start, y),
+            Size::new(x.end.saturating_sub(x.start).saturating_as(), 1),
+        );
+
+        self.parent.fill_solid(&self.map_area(&run), color)
+    }
+}
+
+impl<T> DrawTarget for Scaled<'_, T>
+where
+    T: DrawTarget,
+{
+    type Color = T::Color;
+    type Error = T::Error;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        for Pixel(point, color) in pixels {
+            let block = self.map_point(point);
+            self.parent.fill_solid(&block, color)?;
+        }
+
+        Ok(())
+    }
+
+    fn fill_contiguous<I>(&mut self, area: &Rectangle, colors: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Self::Color>,
+    {
+        if self.factor.height == 1 {
+            // Without vertical scaling each row can be passed on to the parent target by
+            // repeating every color horizontally.
+            let factor_x = self.factor.width;
+            let colors = colors
+                .into_iter()
+                .flat_map(move |color| (0..factor_x).map(move |_| color));
+
+            self.parent.fill_contiguous(&self.map_area(area), colors)
+        } else {
+            // Repeating rows would require a buffer, which is why runs of pixels with the same
+            // color in each row are drawn as separate blocks.
+            let mut colors = colors.into_iter();
+
+            for y in area.rows() {
+                let mut run: Option<(i32, Self::Color)> = None;
+                let mut end = area.top_left.x;
+
+                for (x, color) in area.columns().zip(colors.by_ref()) {
+                    match run {
+                        Some((start, run_color)) if run_color != color => {
+                            self.fill_run(start..x, y, run_color)?;
+                            run = Some((x, color));
+                        }
+                        None => run = Some((x, color)),
+                        _ => {}
+                    }
+                    end = x + 1;
+                }
+
+                match run {
+                    Some((start, color)) => self.fill_run(start..end, y, color)?,
+                    None => break,
+                }
+            }
+
+            Ok(())
+        }
+    }
+
+    fn fill_solid(&mut self, area: &Rectangle, color: Self::Color) -> Result<(), Self::Error> {
+        let area = self.map_area(area);
+        self.parent.fill_solid(&area, color)
+    }
+
+    fn clear(&mut self, color: Self::Color) -> Result<(), Self::Error> {
+        self.parent.clear(color)
+    }
+}
+
+impl<T> Dimensions for Scaled<'_, T>
+where
+    T: DrawTarget,
+{
+    /// Returns the bounding box of all pixels which are completely inside the parent bounding
+    /// box.
+    fn bounding_box(&self) -> Rectangle {
+        let parent_area = self.parent.bounding_box();
+        let factor_x: i32 = self.factor.width.saturating_as();
+        let factor_y: i32 = self.factor.height.saturating_as();
+
+        let top_left = parent_area.top_left;
+        let bottom_right = top_left + parent_area.size;
+
+        let top_left = Point::new(
+            div_ceil(top_left.x, factor_x),
+            div_ceil(top_left.y, factor_y),
+        );
+        let bottom_right = Point::new(
+            bottom_right.x.div_euclid(factor_x),
+            bottom_right.y.div_euclid(factor_y),
+        );
+
+        Rectangle::new(
+            top_left,
+            Size::new(
+                bottom_right.x.saturating_sub(top_left.x).saturating_as(),
+                bottom_right.y.saturating_sub(top_left.y).saturating_as(),
+            ),
+        )
+    }
+}
+
+/// Divides `value` by `divisor` and rounds the result towards positive infinity.
+const fn div_ceil(value: i32, divisor: i32) -> i32 {
+    -(-value).div_euclid(divisor)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        draw_target::DrawTargetExt, iterator::PixelIteratorExt, mock_display::MockDisplay,
+        pixelcolor::BinaryColor, primitives::PointsIter,
+    };
+
+    #[test]
+    fn draw_iter() {
+        let mut display = MockDisplay::new();
+
+        [
+            Pixel(Point::new(0, 0), BinaryColor::On),
+            Pixel(Point::new(2, 1), BinaryColor::Off),
+        ]
+        .into_iter()
+        .draw(&mut display.scaled(3, 2))
+        .unwrap();
+
+        display.assert_pattern(&[
+            "###      ", //
+            "###      ", //
+            "      ...", //
+            "      ...", //
+        ]);
+    }
+
+    #[test]
+    fn fill_contiguous() {
+        let area = Rectangle::new(Point::new(1, 2), Size::new(3, 2));
+        let colors = [
+            1, 0, 1, //
+            0, 1, 1, //
+        ]
+        .map(|c| BinaryColor::from(c != 0));
+
+        for (factor_x, factor_y) in [(1, 1), (2, 1), (1, 3), (3, 2)] {
+            let mut expected = MockDisplay::new();
+            expected
+                .scaled(factor_x, factor_y)
+                .draw_iter(area.points().zip(colors).map(|(p, c)| Pixel(p, c)))
+                .unwrap();
+
+            let mut display = MockDisplay::new();
+            display
+                .scaled(factor_x, factor_y)
+                .fill_contiguous(&area, colors)
+                .unwrap();
+
+            display.assert_eq_with_message(&expected, |f| {
+                write!(f, "factor: {}x{}", factor_x, factor_y)
+            });
+        }
+    }
+
+    /// Draw target which counts the calls to `fill_solid`.
+    struct FillCounter {
+        display: MockDisplay<BinaryColor>,
+        fills: usize,
+    }
+
+    impl DrawTarget for FillCounter {
+        type Color = BinaryColor;
+        type Error = core::convert::Infallible;
+
+        fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+        where
+            I: IntoIterator<Item = Pixel<Self::Color>>,
+        {
+            self.display.draw_iter(pixels)
+        }
+
+        fn fill_solid(&mut self, area: &Rectangle, color: Self::Color) -> Result<(), Self::Error> {
+            self.fills += 1;
+            self.display.fill_solid(area, color)
+        }
+    }
+
+    impl Dimensions for FillCounter {
+        fn bounding_box(&self) -> Rectangle {
+            self.display.bounding_box()
+        }
+    }
+
+    #[test]
+    fn fill_contiguous_merges_runs() {
+        let area = Rectangle::new(Point::new(1, 0), Size::new(4, 3));
+        let colors = [
+            1, 1, 1, 1, //
+            1, 1, 0, 0, //
+            0, 1, 0, 1, //
+        ]
+        .map(|c| BinaryColor::from(c != 0));
+
+        let mut counter = FillCounter {
+            display: MockDisplay::new(),
+            fills: 0,
+        };
+        counter.scaled(2, 2).fill_contiguous(&area, colors).unwrap();
+
+        // One block for the first row, two for the second and four for the third row.
+        assert_eq!(counter.fills, 7);
+
+        counter.display.assert_pattern(&[
+            "  ########", //
+            "  ########", //
+            "  ####....", //
+            "  ####....", //
+            "  ..##..##", //
+            "  ..##..##", //
+        ]);
+    }
+
+    #[test]
+    fn fill_contiguous_too_few_colors() {
+        let area = Rectangle::new(Point::zero(), Size::new(3, 2));
+
+        let mut display = MockDisplay::new();
+        display
+            .scaled(1, 2)
+            .fill_contiguous(&area, [BinaryColor::On; 4])
+            .unwrap();
+
+        display.assert_pattern(&[
+            "###", //
+            "###", //
+            "#  ", //
+            "#  ", //
+        ]);
+    }
+
+    #[test]
+    fn fill_solid() {
+        let mut display = MockDisplay::new();
+
+        display
+            .scaled(2, 3)
+            .fill_solid(
+                &Rectangle::new(Point::new(1, 1), Size::new(2, 1)),
+                BinaryColor::On,
+            )
+            .unwrap();
+
+        display.assert_pattern(&[
+            "      ", //
+            "      ", //
+            "      ", //
+            "  ####", //
+            "  ####", //
+            "  ####", //
+        ]);
+    }
+
+    #[test]
+    fn bounding_box() {
+        let mut display = MockDisplay::<BinaryColor>::new();
+
+        assert_eq!(
+            display.scaled(3, 2).bounding_box(),
+            Rectangle::new(Point::zero(), Size::new(21, 32))
+        );
+
+        // Only pixels which are completely inside the parent bounding box are included.
+        let mut clipped = display.clipped(&Rectangle::new(Point::new(2, 3), Size::new(9, 7)));
+        assert_eq!(
+            clipped.scaled(3, 2).bounding_box(),
+            Rectangle::new(Point::new(1, 2), Size::new(2, 3))
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "scaling factors must be greater than zero")]
+    fn zero_factor() {
+        let mut display = MockDisplay::<BinaryColor>::new();
+        display.scaled(0, 1);
+    }
+}
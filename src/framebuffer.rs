@@ -3,18 +3,19 @@
 use core::{convert::Infallible, marker::PhantomData};
 
 use crate::{
-    draw_target::DrawTarget,
+    draw_target::{DrawTarget, DrawTargetExt, Translated},
     geometry::{OriginDimensions, Point, Size},
-    image::{GetPixel, ImageRaw},
+    image::{GetPixel, Image, ImageRaw},
     iterator::raw::RawDataSlice,
     pixelcolor::{
         raw::{
             BigEndianLsb0, DataOrder, LittleEndianMsb0, RawData, RawU1, RawU16, RawU2, RawU24,
             RawU32, RawU4, RawU8, ToBytes,
         },
-        PixelColor,
+        PixelColor, TriColor,
     },
-    Pixel,
+    primitives::Rectangle,
+    Drawable, Pixel,
 };
 
 /// Calculates the required buffer size.
@@ -123,6 +124,170 @@ where
         )
         .unwrap()
     }
+
+    /// Returns an iterator over the changed areas compared to a previous frame.
+    ///
+    /// The returned iterator yields the row spans which contain pixels that have a different
+    /// color in `previous`. Each span is a rectangle with a height of one pixel, which contains a
+    /// contiguous run of changed pixels in a single row. The spans are returned in row-major
+    /// order.
+    ///
+    /// `previous` is usually a shadow copy of the framebuffer, which contains the content that
+    /// was last transferred to the display. See [`draw_changed`] for an example.
+    ///
+    /// [`draw_changed`]: Framebuffer::draw_changed
+    pub fn changed_spans<'a>(&'a self, previous: &'a Self) -> ChangedSpans<'a> {
+        ChangedSpans::new(
+            &self.data[0..Self::BUFFER_SIZE],
+            &previous.data[0..Self::BUFFER_SIZE],
+            WIDTH,
+            C::Raw::BITS_PER_PIXEL,
+        )
+    }
+
+    /// Draws the changed areas compared to a previous frame to a draw target.
+    ///
+    /// Every span returned by [`changed_spans`] is drawn by a separate call to
+    /// [`fill_contiguous`]. Unchanged pixels aren't drawn, which can be used to reduce the
+    /// amount of data that needs to be transferred to a display.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use embedded_graphics::{
+    ///     framebuffer::{buffer_size, Framebuffer},
+    ///     mock_display::MockDisplay,
+    ///     pixelcolor::{
+    ///         raw::{LittleEndianMsb0, RawU16},
+    ///         Rgb565,
+    ///     },
+    ///     prelude::*,
+    ///     primitives::{Line, PrimitiveStyle, Rectangle},
+    /// };
+    ///
+    /// type Fb =
+    ///     Framebuffer<Rgb565, RawU16, LittleEndianMsb0, 64, 64, { buffer_size::<Rgb565>(64, 64) }>;
+    ///
+    /// let mut fb = Fb::new();
+    /// let mut shadow = Fb::new();
+    /// let mut display = MockDisplay::new();
+    ///
+    /// Line::new(Point::new(10, 5), Point::new(20, 5))
+    ///     .into_styled(PrimitiveStyle::with_stroke(Rgb565::RED, 1))
+    ///     .draw(&mut fb)?;
+    ///
+    /// // Only the line is transferred to the display.
+    /// fb.draw_changed(&shadow, &mut display)?;
+    /// assert_eq!(
+    ///     display.affected_area(),
+    ///     Rectangle::new(Point::new(10, 5), Size::new(11, 1))
+    /// );
+    ///
+    /// // Update the shadow copy to reflect the content of the display.
+    /// shadow.clone_from(&fb);
+    /// assert_eq!(fb.changed_spans(&shadow).count(), 0);
+    /// # Ok::<(), core::convert::Infallible>(())
+    /// ```
+    ///
+    /// [`changed_spans`]: Framebuffer::changed_spans
+    /// [`fill_contiguous`]: DrawTarget::fill_contiguous
+    pub fn draw_changed<D>(&self, previous: &Self, target: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        let bytes_per_row = buffer_size::<C>(WIDTH, 1);
+
+        for span in self.changed_spans(previous) {
+            let row_start = span.top_left.y as usize * bytes_per_row;
+            let row = &self.data[row_start..row_start + bytes_per_row];
+
+            let colors = RawDataSlice::<C::Raw, O>::new(row)
+                .into_iter()
+                .skip(span.top_left.x as usize)
+                .take(span.size.width as usize)
+                .map(C::from);
+
+            target.fill_contiguous(&span, colors)?;
+        }
+
+        Ok(())
+    }
+
+    /// Draws to a draw target by rendering the content in bands.
+    ///
+    /// This method makes it possible to use a framebuffer which is smaller than the target, e.g.
+    /// a 320x16 pixel strip for a 320x240 pixel display. The bounding box of the target is split
+    /// into bands with the size of the framebuffer and for each band the framebuffer is cleared
+    /// to the `background` color, the `draw` closure is called to draw the content and the
+    /// framebuffer is transferred to the target. If the framebuffer is narrower than the target
+    /// each band is split into multiple tiles.
+    ///
+    /// The `draw` closure is called once for every band and receives a draw target which uses the
+    /// coordinate system of `target`. Its bounding box is set to the area of the current band,
+    /// which allows drawables to skip content outside the band, and all drawing operations
+    /// outside the band are clipped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use embedded_graphics::{
+    ///     framebuffer::{buffer_size, Framebuffer},
+    ///     mock_display::MockDisplay,
+    ///     pixelcolor::{
+    ///         raw::{LittleEndianMsb0, RawU16},
+    ///         Rgb565,
+    ///     },
+    ///     prelude::*,
+    ///     primitives::{Circle, PrimitiveStyle},
+    /// };
+    ///
+    /// // A 64x8 pixel strip is used to draw to a 64x64 pixel display.
+    /// let mut band =
+    ///     Framebuffer::<Rgb565, RawU16, LittleEndianMsb0, 64, 8, { buffer_size::<Rgb565>(64, 8) }>::new();
+    /// let mut display = MockDisplay::new();
+    ///
+    /// band.draw_banded(&mut display, Rgb565::BLACK, |band| {
+    ///     Circle::new(Point::new(10, 10), 40)
+    ///         .into_styled(PrimitiveStyle::with_fill(Rgb565::GREEN))
+    ///         .draw(band)
+    /// })?;
+    ///
+    /// assert_eq!(display.get_pixel(Point::new(0, 0)), Some(Rgb565::BLACK));
+    /// assert_eq!(display.get_pixel(Point::new(30, 30)), Some(Rgb565::GREEN));
+    /// # Ok::<(), core::convert::Infallible>(())
+    /// ```
+    pub fn draw_banded<D, F>(
+        &mut self,
+        target: &mut D,
+        background: C,
+        mut draw: F,
+    ) -> Result<(), D::Error>
+    where
+        Self: DrawTarget<Color = C, Error = Infallible>,
+        D: DrawTarget<Color = C>,
+        F: FnMut(&mut Translated<'_, Self>) -> Result<(), Infallible>,
+    {
+        if WIDTH == 0 || HEIGHT == 0 {
+            return Ok(());
+        }
+
+        let target_area = target.bounding_box();
+        let band_size = self.size();
+
+        for y in target_area.rows().step_by(HEIGHT) {
+            for x in target_area.columns().step_by(WIDTH) {
+                let band_area = Rectangle::new(Point::new(x, y), band_size);
+
+                self.clear(background).unwrap();
+                draw(&mut self.translated(-band_area.top_left)).unwrap();
+
+                Image::new(&self.as_image(), band_area.top_left)
+                    .draw(&mut target.clipped(&band_area))?;
+            }
+        }
+
+        Ok(())
+    }
 }
 
 impl<C, O, const WIDTH: usize, const HEIGHT: usize, const N: usize> GetPixel
@@ -193,6 +358,64 @@ impl_bit!(RawU1);
 impl_bit!(RawU2);
 impl_bit!(RawU4);
 
+impl<C, BO, const WIDTH: usize, const HEIGHT: usize, const N: usize>
+    Framebuffer<C, RawU2, BO, WIDTH, HEIGHT, N>
+where
+    C: TriColor + PixelColor<Raw = RawU2> + From<RawU2>,
+{
+    /// Returns an iterator over the bytes of the black and white bitplane.
+    ///
+    /// Three color e-paper controllers expect the image data in two separate bitplanes with one
+    /// bit per pixel. In the black and white plane black pixels are stored as `0` and all other
+    /// pixels are stored as `1`. The bits are packed with the leftmost pixel in the most
+    /// significant bit and each row is padded to a whole number of bytes.
+    ///
+    /// Controllers which use a different polarity can invert the returned bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use embedded_graphics::{
+    ///     framebuffer::{buffer_size, Framebuffer},
+    ///     pixelcolor::{raw::LittleEndianMsb0, BwrColor},
+    ///     prelude::*,
+    ///     primitives::{PrimitiveStyle, Rectangle},
+    /// };
+    ///
+    /// let mut fb = Framebuffer::<
+    ///     BwrColor,
+    ///     _,
+    ///     LittleEndianMsb0,
+    ///     16,
+    ///     2,
+    ///     { buffer_size::<BwrColor>(16, 2) },
+    /// >::new();
+    ///
+    /// Rectangle::new(Point::new(0, 0), Size::new(4, 2))
+    ///     .into_styled(PrimitiveStyle::with_fill(BwrColor::Black))
+    ///     .draw(&mut fb)?;
+    /// Rectangle::new(Point::new(12, 1), Size::new(4, 1))
+    ///     .into_styled(PrimitiveStyle::with_fill(BwrColor::Red))
+    ///     .draw(&mut fb)?;
+    ///
+    /// assert!(fb.black_plane().eq([0x0F, 0xFF, 0x0F, 0xFF]));
+    /// assert!(fb.color_plane().eq([0x00, 0x00, 0x00, 0x0F]));
+    /// # Ok::<(), core::convert::Infallible>(())
+    /// ```
+    pub fn black_plane(&self) -> Bitplane<'_, C> {
+        Bitplane::new(&self.data[0..Self::BUFFER_SIZE], WIDTH, false)
+    }
+
+    /// Returns an iterator over the bytes of the color bitplane.
+    ///
+    /// In the color plane pixels with the accent color, e.g. red or yellow, are stored as `1`
+    /// and all other pixels are stored as `0`. See [`black_plane`](Self::black_plane) for more
+    /// information about the data format.
+    pub fn color_plane(&self) -> Bitplane<'_, C> {
+        Bitplane::new(&self.data[0..Self::BUFFER_SIZE], WIDTH, true)
+    }
+}
+
 impl<C, BO, const WIDTH: usize, const HEIGHT: usize, const N: usize>
     Framebuffer<C, RawU8, BO, WIDTH, HEIGHT, N>
 where
@@ -291,6 +514,184 @@ impl_bytes!(RawU16);
 impl_bytes!(RawU24);
 impl_bytes!(RawU32);
 
+/// Iterator over the changed row spans of a framebuffer.
+///
+/// Created by calling [`Framebuffer::changed_spans`].
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub struct ChangedSpans<'a> {
+    current: &'a [u8],
+    previous: &'a [u8],
+    width: usize,
+    bits_per_pixel: usize,
+    bytes_per_row: usize,
+
+    x: usize,
+    y: usize,
+}
+
+impl<'a> ChangedSpans<'a> {
+    fn new(current: &'a [u8], previous: &'a [u8], width: usize, bits_per_pixel: usize) -> Self {
+        Self {
+            current,
+            previous,
+            width,
+            bits_per_pixel,
+            bytes_per_row: buffer_size_bpp(width, 1, bits_per_pixel),
+            x: 0,
+            y: 0,
+        }
+    }
+
+    /// Returns `true` if the pixel at `x` in the current row has changed.
+    fn is_changed(&self, x: usize) -> bool {
+        let row_start = self.y * self.bytes_per_row;
+
+        if self.bits_per_pixel < 8 {
+            let index = row_start + x * self.bits_per_pixel / 8;
+            let pixels_per_byte = 8 / self.bits_per_pixel;
+            let shift = 8 - (x % pixels_per_byte + 1) * self.bits_per_pixel;
+            let mask = ((1 << self.bits_per_pixel) - 1) << shift;
+
+            (self.current[index] ^ self.previous[index]) & mask != 0
+        } else {
+            let bytes_per_pixel = self.bits_per_pixel / 8;
+            let range = row_start + x * bytes_per_pixel..row_start + (x + 1) * bytes_per_pixel;
+
+            self.current[range.clone()] != self.previous[range]
+        }
+    }
+
+    /// Returns the first changed pixel in the current row, starting from `x`.
+    ///
+    /// Returns the width if there are no changed pixels. The remaining pixels in a byte of
+    /// packed pixels are skipped at once if the byte doesn't contain any changes.
+    fn skip_unchanged(&self, mut x: usize) -> usize {
+        let row_start = self.y * self.bytes_per_row;
+
+        while x < self.width {
+            if self.bits_per_pixel < 8 {
+                let byte = x * self.bits_per_pixel / 8;
+                let index = row_start + byte;
+
+                if self.current[index] == self.previous[index] {
+                    x = (byte + 1) * 8 / self.bits_per_pixel;
+                    continue;
+                }
+            }
+
+            if self.is_changed(x) {
+                break;
+            }
+
+            x += 1;
+        }
+
+        x.min(self.width)
+    }
+}
+
+impl Iterator for ChangedSpans<'_> {
+    type Item = Rectangle;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.y * self.bytes_per_row < self.current.len() && self.width > 0 {
+            let start = self.skip_unchanged(self.x);
+
+            if start == self.width {
+                self.x = 0;
+                self.y += 1;
+                continue;
+            }
+
+            let mut end = start + 1;
+            while end < self.width && self.is_changed(end) {
+                end += 1;
+            }
+            self.x = end;
+
+            return Some(Rectangle::new(
+                Point::new(start as i32, self.y as i32),
+                Size::new((end - start) as u32, 1),
+            ));
+        }
+
+        None
+    }
+}
+
+/// Iterator over the bytes of a three color framebuffer bitplane.
+///
+/// Created by calling [`Framebuffer::black_plane`] or [`Framebuffer::color_plane`].
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub struct Bitplane<'a, C> {
+    data: &'a [u8],
+    width: usize,
+    bytes_per_row: usize,
+    is_color_plane: bool,
+
+    x: usize,
+    y: usize,
+    color_type: PhantomData<C>,
+}
+
+impl<'a, C> Bitplane<'a, C>
+where
+    C: TriColor + From<RawU2>,
+{
+    fn new(data: &'a [u8], width: usize, is_color_plane: bool) -> Self {
+        Self {
+            data,
+            width,
+            bytes_per_row: buffer_size_bpp(width, 1, RawU2::BITS_PER_PIXEL),
+            is_color_plane,
+            x: 0,
+            y: 0,
+            color_type: PhantomData,
+        }
+    }
+
+    /// Returns the bit for the pixel at `x` in the current row.
+    fn bit(&self, x: usize) -> bool {
+        let byte = self.data[self.y * self.bytes_per_row + x / 4];
+        let color = C::from(RawU2::new(byte >> (6 - (x % 4) * 2)));
+
+        if self.is_color_plane {
+            color.is_accent()
+        } else {
+            !color.is_black()
+        }
+    }
+}
+
+impl<C> Iterator for Bitplane<'_, C>
+where
+    C: TriColor + From<RawU2>,
+{
+    type Item = u8;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.width == 0 || self.y * self.bytes_per_row >= self.data.len() {
+            return None;
+        }
+
+        let end = (self.x + 8).min(self.width);
+        let byte = (self.x..end)
+            .filter(|x| self.bit(*x))
+            .fold(0, |byte, x| byte | 0x80 >> (x - self.x));
+
+        if end == self.width {
+            self.x = 0;
+            self.y += 1;
+        } else {
+            self.x = end;
+        }
+
+        Some(byte)
+    }
+}
+
 impl<C, R, BO, const WIDTH: usize, const HEIGHT: usize, const N: usize> OriginDimensions
     for Framebuffer<C, R, BO, WIDTH, HEIGHT, N>
 {
@@ -310,8 +711,10 @@ mod tests {
         geometry::Point,
         image::Image,
         mock_display::MockDisplay,
-        pixelcolor::{BinaryColor, Gray2, Gray4, Gray8, Rgb565, Rgb888, RgbColor},
-        primitives::{Primitive, PrimitiveStyle},
+        pixelcolor::{
+            AcepColor, BinaryColor, BwyColor, Gray2, Gray4, Gray8, Rgb565, Rgb888, RgbColor,
+        },
+        primitives::{Circle, Line, Primitive, PrimitiveStyle},
         Drawable,
     };
 
@@ -730,6 +1133,147 @@ mod tests {
         <framebuffer!(U32Color, 10, 10)>::new().set_pixel(Point::zero(), U32Color(0));
     }
 
+    #[test]
+    fn changed_spans_packed() {
+        let previous = <framebuffer!(Gray2, 10, 3)>::new();
+        let mut fb = previous.clone();
+
+        fb.draw_iter(
+            [
+                ((1, 0), 1), //
+                ((2, 0), 3), //
+                ((3, 0), 0), //
+                ((5, 0), 2), //
+                ((8, 1), 1), //
+                ((9, 1), 1), //
+                ((0, 2), 3), //
+            ]
+            .iter()
+            .map(|(p, c)| Pixel(Point::from(*p), Gray2::new(*c))),
+        )
+        .unwrap();
+
+        assert!(fb.changed_spans(&previous).eq([
+            Rectangle::new(Point::new(1, 0), Size::new(2, 1)),
+            Rectangle::new(Point::new(5, 0), Size::new(1, 1)),
+            Rectangle::new(Point::new(8, 1), Size::new(2, 1)),
+            Rectangle::new(Point::new(0, 2), Size::new(1, 1)),
+        ]));
+        assert_eq!(fb.changed_spans(&fb).next(), None);
+    }
+
+    #[test]
+    fn changed_spans_bytes() {
+        let mut previous = <framebuffer!(Rgb888, 4, 2)>::new();
+        previous.set_pixel(Point::new(3, 0), Rgb888::BLUE);
+        let mut fb = previous.clone();
+
+        fb.set_pixel(Point::new(0, 0), Rgb888::new(0, 0, 1));
+        fb.set_pixel(Point::new(3, 0), Rgb888::RED);
+        fb.set_pixel(Point::new(1, 1), Rgb888::RED);
+        fb.set_pixel(Point::new(2, 1), Rgb888::GREEN);
+        fb.set_pixel(Point::new(3, 1), Rgb888::BLACK);
+
+        assert!(fb.changed_spans(&previous).eq([
+            Rectangle::new(Point::new(0, 0), Size::new(1, 1)),
+            Rectangle::new(Point::new(3, 0), Size::new(1, 1)),
+            Rectangle::new(Point::new(1, 1), Size::new(2, 1)),
+        ]));
+    }
+
+    #[test]
+    fn draw_changed() {
+        let mut previous = <framebuffer!(BinaryColor, 8, 3)>::new();
+        previous.set_pixel(Point::new(2, 1), BinaryColor::On);
+        previous.set_pixel(Point::new(6, 2), BinaryColor::On);
+
+        let mut fb = previous.clone();
+        fb.set_pixel(Point::new(1, 0), BinaryColor::On);
+        fb.set_pixel(Point::new(2, 0), BinaryColor::On);
+        fb.set_pixel(Point::new(2, 1), BinaryColor::Off);
+        fb.set_pixel(Point::new(3, 1), BinaryColor::On);
+
+        let mut display = MockDisplay::new();
+        fb.draw_changed(&previous, &mut display).unwrap();
+
+        display.assert_pattern(&[
+            " ## ", //
+            "  .#", //
+        ]);
+    }
+
+    #[test]
+    fn draw_banded() {
+        let area = Rectangle::new(Point::new(3, 2), Size::new(25, 14));
+        let circle = Circle::new(Point::new(5, 4), 10)
+            .into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 1));
+        let line = Line::new(Point::new(0, 0), Point::new(40, 20))
+            .into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 1));
+
+        let mut expected = MockDisplay::new();
+        expected.set_allow_overdraw(true);
+        {
+            let mut clipped = expected.clipped(&area);
+            clipped.clear(BinaryColor::Off).unwrap();
+            circle.draw(&mut clipped).unwrap();
+            line.draw(&mut clipped).unwrap();
+        }
+
+        let mut band = <framebuffer!(BinaryColor, 10, 4)>::new();
+        let mut display = MockDisplay::new();
+        let mut band_areas = 0;
+
+        band.draw_banded(&mut display.clipped(&area), BinaryColor::Off, |band| {
+            // The bounding box is set to the area of the current band.
+            let band_area = band.bounding_box();
+            assert_eq!(band_area.size, Size::new(10, 4));
+            assert_eq!(
+                band_area.top_left - area.top_left,
+                Point::new(band_areas % 3 * 10, band_areas / 3 * 4)
+            );
+            band_areas += 1;
+
+            circle.draw(band)?;
+            line.draw(band)
+        })
+        .unwrap();
+
+        assert_eq!(band_areas, 12);
+        display.assert_eq(&expected);
+    }
+
+    #[test]
+    fn tri_color_bitplanes() {
+        let mut fb = <framebuffer!(BwyColor, 10, 3)>::new();
+
+        // A new framebuffer is white.
+        assert!(fb.black_plane().eq([0xFF, 0xC0, 0xFF, 0xC0, 0xFF, 0xC0]));
+        assert!(fb.color_plane().eq([0x00; 6]));
+
+        fb.set_pixel(Point::new(0, 0), BwyColor::Black);
+        fb.set_pixel(Point::new(9, 0), BwyColor::Black);
+        fb.set_pixel(Point::new(8, 1), BwyColor::Yellow);
+        Line::new(Point::new(2, 2), Point::new(5, 2))
+            .into_styled(PrimitiveStyle::with_stroke(BwyColor::Yellow, 1))
+            .draw(&mut fb)
+            .unwrap();
+
+        assert!(fb.black_plane().eq([0x7F, 0x80, 0xFF, 0xC0, 0xFF, 0xC0]));
+        assert!(fb.color_plane().eq([0x00, 0x00, 0x00, 0x80, 0x3C, 0x00]));
+    }
+
+    #[test]
+    fn acep_raw_data() {
+        let mut fb = <framebuffer!(AcepColor, 4, 1)>::new();
+        fb.set_pixel(Point::new(0, 0), AcepColor::White);
+        fb.set_pixel(Point::new(1, 0), AcepColor::Orange);
+        fb.set_pixel(Point::new(2, 0), AcepColor::Green);
+
+        // The data uses the native 4 bit encoding of ACeP controllers.
+        assert_eq!(fb.data(), &[0x16, 0x20]);
+        assert_eq!(fb.pixel(Point::new(1, 0)), Some(AcepColor::Orange));
+    }
+
     #[test]
     fn oversized_buffer() {
         let fb = Framebuffer::<
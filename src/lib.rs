@@ -16,8 +16,12 @@
 //!     * [Ellipses]
 //!     * [Arcs]
 //!     * [Sectors]
+//!     * [Rings]
 //!     * [Triangles]
 //!     * [Polylines]
+//!     * [Polygons]
+//!     * [Bézier curves]
+//!     * [Paths]
 //!     * [Rounded rectangles]
 //! * [Text]
 //! * [Monospaced fonts]
@@ -247,8 +251,12 @@
 //! [Ellipses]: https://docs.rs/embedded-graphics/latest/embedded_graphics/primitives/ellipse/struct.Ellipse.html
 //! [Arcs]: https://docs.rs/embedded-graphics/latest/embedded_graphics/primitives/arc/struct.Arc.html
 //! [Sectors]: https://docs.rs/embedded-graphics/latest/embedded_graphics/primitives/sector/struct.Sector.html
+//! [Rings]: https://docs.rs/embedded-graphics/latest/embedded_graphics/primitives/ring/struct.Ring.html
 //! [Triangles]: https://docs.rs/embedded-graphics/latest/embedded_graphics/primitives/triangle/struct.Triangle.html
 //! [Polylines]: https://docs.rs/embedded-graphics/latest/embedded_graphics/primitives/polyline/struct.Polyline.html
+//! [Polygons]: https://docs.rs/embedded-graphics/latest/embedded_graphics/primitives/polygon/struct.Polygon.html
+//! [Bézier curves]: https://docs.rs/embedded-graphics/latest/embedded_graphics/primitives/bezier/index.html
+//! [Paths]: https://docs.rs/embedded-graphics/latest/embedded_graphics/primitives/path/struct.Path.html
 //! [Rounded rectangles]: https://docs.rs/embedded-graphics/latest/embedded_graphics/primitives/rounded_rectangle/struct.RoundedRectangle.html
 //! [Text]: https://docs.rs/embedded-graphics/latest/embedded_graphics/text/index.html
 //! [Monospaced fonts]: https://docs.rs/embedded-graphics/latest/embedded_graphics/mono_font/index.html
@@ -267,8 +275,12 @@
 //! [Ellipses]: primitives::ellipse::Ellipse
 //! [Arcs]: primitives::arc::Arc
 //! [Sectors]: primitives::sector::Sector
+//! [Rings]: primitives::ring::Ring
 //! [Triangles]: primitives::triangle::Triangle
 //! [Polylines]: primitives::polyline::Polyline
+//! [Polygons]: primitives::polygon::Polygon
+//! [Bézier curves]: primitives::bezier
+//! [Paths]: primitives::path::Path
 //! [Rounded rectangles]: primitives::rounded_rectangle::RoundedRectangle
 //! [Text]: text
 //! [Monospaced fonts]: mono_font
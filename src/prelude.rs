@@ -8,7 +8,8 @@ pub use crate::{
     iterator::{ContiguousIteratorExt, PixelIteratorExt},
     pixelcolor::{
         raw::{RawData, ToBytes as _},
-        GrayColor, IntoStorage, PixelColor, RgbColor, WebColors,
+        AlphaColor, GrayColor, IndexedColor, IntoStorage, PixelColor, RgbColor, TriColor,
+        WebColors,
     },
     primitives::{ContainsPoint, OffsetOutline, PointsIter, Primitive},
     transform::Transform,
@@ -0,0 +1,175 @@
+use crate::{
+    draw_target::DrawTarget,
+    image::GetPixel,
+    pixelcolor::{PixelColor, Rgb888},
+    primitives::{
+        common::{draw_anti_aliased, draw_blended, AntiAliasedShape},
+        styled::{StyledDimensions, StyledDrawable},
+        Arc, Circle, Ellipse, Line, OffsetOutline, Polyline, PrimitiveStyle, Rectangle, Styled,
+    },
+};
+
+/// Anti-aliased style.
+///
+/// `AntiAliasedStyle` can be applied to a [`Line`], [`Polyline`], [`Circle`], [`Ellipse`] or
+/// [`Arc`] to draw the primitive with smooth edges. Pixels on the edge of the stroke or fill are
+/// drawn in a color that is interpolated between the stroke or fill color and a background color,
+/// based on how much of the pixel is covered by the shape.
+///
+/// Drawing an anti-aliased primitive with [`draw`] blends the edge pixels with the
+/// `background_color` of the style. This works on all draw targets, but only looks right if the
+/// primitive is drawn on top of a solid background color. Draw targets that can read back their
+/// pixels, like a [`Framebuffer`], can use the `draw_blended` method of the styled primitive
+/// instead, which blends the edge pixels with the current colors of the target.
+///
+/// The colors are blended in the [`Rgb888`] color space and converted back to the target color
+/// type. Dashed and dotted stroke styles aren't supported and are drawn as solid strokes. Joins
+/// between the segments of thick polylines are always rounded.
+///
+/// # Examples
+///
+/// ## Draw an anti-aliased circle on a black background
+///
+/// ```
+/// use embedded_graphics::{
+///     pixelcolor::Rgb565,
+///     prelude::*,
+///     primitives::{AntiAliasedStyle, Circle, PrimitiveStyle},
+/// };
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display = MockDisplay::<Rgb565>::new();
+///
+/// let style = AntiAliasedStyle::new(PrimitiveStyle::with_stroke(Rgb565::RED, 3), Rgb565::BLACK);
+///
+/// Circle::new(Point::new(10, 10), 40)
+///     .into_styled(style)
+///     .draw(&mut display)?;
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+///
+/// ## Blend an anti-aliased line with the contents of a framebuffer
+///
+/// ```
+/// use embedded_graphics::{
+///     framebuffer::{buffer_size, Framebuffer},
+///     pixelcolor::{raw::LittleEndianMsb0, Rgb565},
+///     prelude::*,
+///     primitives::{AntiAliasedStyle, Line, PrimitiveStyle},
+/// };
+///
+/// let mut fb =
+///     Framebuffer::<Rgb565, _, LittleEndianMsb0, 64, 64, { buffer_size::<Rgb565>(64, 64) }>::new();
+/// fb.clear(Rgb565::BLUE).unwrap();
+///
+/// let style = AntiAliasedStyle::new(PrimitiveStyle::with_stroke(Rgb565::WHITE, 1), Rgb565::BLACK);
+///
+/// Line::new(Point::new(2, 5), Point::new(60, 40))
+///     .into_styled(style)
+///     .draw_blended(&mut fb)
+///     .unwrap();
+/// ```
+///
+/// [`Line`]: crate::primitives::Line
+/// [`Polyline`]: crate::primitives::Polyline
+/// [`Circle`]: crate::primitives::Circle
+/// [`Ellipse`]: crate::primitives::Ellipse
+/// [`Arc`]: crate::primitives::Arc
+/// [`draw`]: crate::Drawable::draw
+/// [`Framebuffer`]: crate::framebuffer::Framebuffer
+/// [`Rgb888`]: crate::pixelcolor::Rgb888
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub struct AntiAliasedStyle<C>
+where
+    C: PixelColor,
+{
+    /// Primitive style.
+    pub primitive_style: PrimitiveStyle<C>,
+
+    /// Background color.
+    ///
+    /// The background color is blended with the stroke and fill colors at the edges of the
+    /// primitive.
+    pub background_color: C,
+}
+
+impl<C> AntiAliasedStyle<C>
+where
+    C: PixelColor,
+{
+    /// Creates a new anti-aliased style.
+    pub const fn new(primitive_style: PrimitiveStyle<C>, background_color: C) -> Self {
+        Self {
+            primitive_style,
+            background_color,
+        }
+    }
+
+    /// Returns the outside and inside edge of the stroke of a closed shape.
+    ///
+    /// Both edges are equal if the style has no visible stroke.
+    pub(in crate::primitives) fn stroke_edges<P: OffsetOutline>(&self, primitive: &P) -> (P, P) {
+        let inside_edge = self.primitive_style.stroke_inner_area(primitive);
+
+        let outside_edge = if self.primitive_style.effective_stroke_color().is_some() {
+            self.primitive_style.stroke_area(primitive)
+        } else {
+            self.primitive_style.stroke_inner_area(primitive)
+        };
+
+        (outside_edge, inside_edge)
+    }
+}
+
+macro_rules! impl_anti_aliased {
+    ($($primitive:ty),*) => {
+        $(
+            impl<C> StyledDrawable<AntiAliasedStyle<C>> for $primitive
+            where
+                C: PixelColor + From<Rgb888> + Into<Rgb888>,
+            {
+                type Color = C;
+                type Output = ();
+
+                fn draw_styled<D>(
+                    &self,
+                    style: &AntiAliasedStyle<C>,
+                    target: &mut D,
+                ) -> Result<Self::Output, D::Error>
+                where
+                    D: DrawTarget<Color = C>,
+                {
+                    draw_anti_aliased(self.anti_aliased(style), style, target)
+                }
+            }
+
+            impl<C> StyledDimensions<AntiAliasedStyle<C>> for $primitive
+            where
+                C: PixelColor,
+            {
+                fn styled_bounding_box(&self, style: &AntiAliasedStyle<C>) -> Rectangle {
+                    self.anti_aliased(style).area()
+                }
+            }
+
+            impl<C> Styled<$primitive, AntiAliasedStyle<C>>
+            where
+                C: PixelColor + From<Rgb888> + Into<Rgb888>,
+            {
+                /// Draws the primitive by blending it with the current colors of the target.
+                ///
+                /// Pixels on the edge of the primitive are blended with the color that is returned
+                /// by [`GetPixel::pixel`]. The background color of the style is used for pixels
+                /// that can't be read from the target.
+                pub fn draw_blended<D>(&self, target: &mut D) -> Result<(), D::Error>
+                where
+                    D: DrawTarget<Color = C> + GetPixel<Color = C>,
+                {
+                    draw_blended(self.primitive.anti_aliased(&self.style), &self.style, target)
+                }
+            }
+        )*
+    };
+}
+
+impl_anti_aliased!(Line, Polyline<'_>, Circle, Ellipse, Arc);
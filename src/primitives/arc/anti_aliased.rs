@@ -0,0 +1,155 @@
+//! Anti-aliased arcs.
+
+use core::ops::Range;
+
+use crate::{
+    geometry::Point,
+    pixelcolor::PixelColor,
+    primitives::{
+        arc::Arc,
+        circle::anti_aliased::AntiAliasedCircle,
+        common::{edge_coverage, AntiAliasedShape, Coverage, PlaneSector},
+        AntiAliasedStyle, PrimitiveStyle, Rectangle,
+    },
+};
+
+/// Anti-aliased arc.
+///
+/// The arc is the intersection of the anti-aliased stroke of the circle and a plane sector. The
+/// ends of the arc are therefore cut off along lines through the center of the circle.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub(in crate::primitives) struct AntiAliasedArc {
+    circle: AntiAliasedCircle,
+    center_2x: Point,
+    plane_sector: PlaneSector,
+}
+
+impl AntiAliasedShape for AntiAliasedArc {
+    fn area(&self) -> Rectangle {
+        self.circle.area()
+    }
+
+    fn run(&self, y: i32, x: i32) -> Option<Range<i32>> {
+        self.circle.run(y, x)
+    }
+
+    fn coverage(&self, point: Point) -> Coverage {
+        let stroke = self.circle.stroke_coverage(point);
+        if stroke == 0 {
+            return Coverage::default();
+        }
+
+        // The plane sector distance is scaled by `NORMAL_VECTOR_SCALE` and uses half pixel
+        // coordinates, which is converted into 1/256 px by dividing it by 8.
+        let delta = point * 2 - self.center_2x;
+        let distance = i64::from(self.plane_sector.distance(delta)) / 8;
+
+        Coverage::stroke(stroke * edge_coverage(distance) / 256)
+    }
+}
+
+impl Arc {
+    /// Returns the anti-aliased shape of the arc.
+    pub(in crate::primitives) fn anti_aliased<C: PixelColor>(
+        &self,
+        style: &AntiAliasedStyle<C>,
+    ) -> AntiAliasedArc {
+        let circle = self.to_circle();
+
+        // Arcs are open shapes and are never filled.
+        let stroke_style = AntiAliasedStyle::new(
+            PrimitiveStyle {
+                fill_color: None,
+                ..style.primitive_style
+            },
+            style.background_color,
+        );
+
+        AntiAliasedArc {
+            circle: circle.anti_aliased(&stroke_style),
+            center_2x: circle.center_2x(),
+            plane_sector: PlaneSector::new(self.angle_start, self.angle_sweep),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        geometry::{AngleUnit, Dimensions},
+        mock_display::MockDisplay,
+        pixelcolor::{Gray8, GrayColor},
+        primitives::{Circle, Primitive, PrimitiveStyleBuilder},
+        Drawable,
+    };
+
+    fn style(stroke_width: u32) -> AntiAliasedStyle<Gray8> {
+        AntiAliasedStyle::new(
+            PrimitiveStyleBuilder::new()
+                .stroke_color(Gray8::WHITE)
+                .stroke_width(stroke_width)
+                .fill_color(Gray8::new(100))
+                .build(),
+            Gray8::BLACK,
+        )
+    }
+
+    #[test]
+    fn full_arc_matches_circle_stroke() {
+        let mut expected = MockDisplay::new();
+        Circle::new(Point::new(5, 5), 40)
+            .into_styled(AntiAliasedStyle::new(
+                PrimitiveStyle::with_stroke(Gray8::WHITE, 3),
+                Gray8::BLACK,
+            ))
+            .draw(&mut expected)
+            .unwrap();
+
+        let mut display = MockDisplay::new();
+        Arc::new(Point::new(5, 5), 40, 0.0.deg(), 360.0.deg())
+            .into_styled(style(3))
+            .draw(&mut display)
+            .unwrap();
+
+        display.assert_eq(&expected);
+    }
+
+    #[test]
+    fn quadrant() {
+        let mut display = MockDisplay::new();
+        Arc::with_center(Point::new(30, 30), 41, 0.0.deg(), 90.0.deg())
+            .into_styled(style(2))
+            .draw(&mut display)
+            .unwrap();
+
+        // The arc is drawn in the lower right quadrant and the straight ends are anti-aliased.
+        let area = display.affected_area();
+        assert!(area.top_left.x >= 29 && area.top_left.y >= 29, "{:?}", area);
+        assert_eq!(display.get_pixel(Point::new(31, 50)), Some(Gray8::WHITE));
+        assert_eq!(display.get_pixel(Point::new(50, 31)), Some(Gray8::WHITE));
+        assert_eq!(display.get_pixel(Point::new(30, 50)), Some(Gray8::new(128)));
+        assert_eq!(display.get_pixel(Point::new(29, 50)), None);
+        assert_eq!(display.get_pixel(Point::new(10, 30)), None);
+    }
+
+    #[test]
+    fn bounding_box_contains_drawn_pixels() {
+        for stroke_width in [0, 1, 5] {
+            let styled = Arc::new(Point::new(10, 10), 40, 30.0.deg(), 200.0.deg())
+                .into_styled(style(stroke_width));
+
+            let mut display = MockDisplay::new();
+            styled.draw(&mut display).unwrap();
+
+            let area = display.affected_area();
+            assert_eq!(
+                styled.bounding_box().intersection(&area),
+                area,
+                "{} px",
+                stroke_width
+            );
+        }
+    }
+}
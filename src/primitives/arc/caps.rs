@@ -0,0 +1,123 @@
+//! Line caps for arcs.
+
+use crate::{
+    geometry::{Angle, Point, PointExt, Real, Trigonometry},
+    primitives::{
+        arc::Arc,
+        common::{isqrt, NORMAL_VECTOR_SCALE},
+        Circle, LineCap,
+    },
+};
+
+/// Square or round caps at both ends of a thick arc.
+///
+/// All coordinates are relative to the center of the arc and use half pixel units.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub(in crate::primitives::arc) struct Caps {
+    line_cap: LineCap,
+
+    /// Unit vectors from the center to the start and end of the arc.
+    ///
+    /// The vectors are scaled by `NORMAL_VECTOR_SCALE`.
+    radial: [Point; 2],
+
+    /// Unit vectors that are tangential to the arc and point away from the arc.
+    ///
+    /// The vectors are scaled by `NORMAL_VECTOR_SCALE`.
+    tangential: [Point; 2],
+
+    inside_diameter: i64,
+    outside_diameter: i64,
+    stroke_width: i64,
+}
+
+impl Caps {
+    /// Creates new caps.
+    ///
+    /// Returns `None` if the caps don't add any pixels to the arc.
+    pub fn new(
+        arc: &Arc,
+        outside_edge: &Circle,
+        inside_edge: &Circle,
+        line_cap: LineCap,
+    ) -> Option<Self> {
+        if line_cap == LineCap::Butt || arc.angle_sweep.abs() >= Angle::from_degrees(360.0) {
+            return None;
+        }
+
+        let direction = if arc.angle_sweep < Angle::zero() {
+            -1
+        } else {
+            1
+        };
+
+        let start = unit_vector(arc.angle_start);
+        let end = unit_vector(arc.angle_start + arc.angle_sweep);
+
+        Some(Self {
+            line_cap,
+            radial: [start, end],
+            tangential: [start.rotate_90() * -direction, end.rotate_90() * direction],
+            inside_diameter: i64::from(inside_edge.diameter),
+            outside_diameter: i64::from(outside_edge.diameter),
+            stroke_width: (i64::from(outside_edge.diameter) - i64::from(inside_edge.diameter)) / 2,
+        })
+    }
+
+    /// Returns `true` if a point is inside one of the caps.
+    ///
+    /// `delta` is the vector between the center of the arc and the point in half pixels.
+    pub fn contains(&self, delta: Point) -> bool {
+        let scale = i64::from(NORMAL_VECTOR_SCALE);
+        let x = i64::from(delta.x);
+        let y = i64::from(delta.y);
+
+        self.radial
+            .iter()
+            .zip(self.tangential.iter())
+            .any(|(radial, tangential)| {
+                let r = x * i64::from(radial.x) + y * i64::from(radial.y);
+                let t = x * i64::from(tangential.x) + y * i64::from(tangential.y);
+
+                match self.line_cap {
+                    LineCap::Butt => false,
+                    LineCap::Square => {
+                        (0..self.stroke_width * scale).contains(&t)
+                            && (self.inside_diameter * scale..self.outside_diameter * scale)
+                                .contains(&r)
+                    }
+                    LineCap::Round => {
+                        let center = (self.inside_diameter + self.outside_diameter) / 2 * scale;
+
+                        (r - center).pow(2) + t.pow(2) < (self.stroke_width * scale).pow(2)
+                    }
+                }
+            })
+    }
+}
+
+/// Returns the offset that is required to enlarge the outside edge to include square caps.
+pub(in crate::primitives::arc) fn square_cap_offset(
+    outside_edge: &Circle,
+    stroke_width: u32,
+) -> u32 {
+    let diameter = u64::from(outside_edge.diameter);
+    let width = u64::from(stroke_width);
+
+    // Distance between the center and the outer corners of the caps in half pixels.
+    let corner = isqrt(diameter.pow(2) + width.pow(2)) + 1;
+    let extra = corner - diameter;
+
+    (extra / 2 + extra % 2) as u32
+}
+
+/// Returns a unit vector with the given angle, scaled by `NORMAL_VECTOR_SCALE`.
+///
+/// The components are rounded to make sure that the caps at both ends are symmetric.
+fn unit_vector(angle: Angle) -> Point {
+    Point::new(
+        i32::from((angle.cos() * Real::from(NORMAL_VECTOR_SCALE)).round()),
+        i32::from((angle.sin() * Real::from(NORMAL_VECTOR_SCALE)).round()),
+    )
+}
@@ -1,11 +1,15 @@
 //! The arc primitive
 
 use crate::{
-    geometry::{Angle, Dimensions, Point, Size},
+    geometry::{Angle, Dimensions, Point, Size, Trigonometry},
     primitives::{Circle, PointsIter, Primitive, Rectangle},
-    transform::Transform,
+    transform::{GeometricTransform, Transform, Transformation},
 };
+#[allow(unused_imports)]
+use micromath::F32Ext;
 
+mod anti_aliased;
+mod caps;
 mod points;
 mod styled;
 
@@ -110,6 +114,37 @@ impl Arc {
     pub fn center(&self) -> Point {
         self.bounding_box().center()
     }
+
+    /// Flattens the arc into line segments and returns the vertices.
+    ///
+    /// The vertices are placed on the center line of a 1px wide stroke. The number of segments
+    /// is chosen to keep the distance between the arc and the flattened arc below 1/8 of a pixel.
+    /// Unlike Bézier curves, arcs aren't limited to a maximum number of segments, because the
+    /// required number of segments grows with the square root of the radius.
+    pub(in crate::primitives) fn flattened_vertices(&self) -> impl Iterator<Item = Point> {
+        let center_2x = self.to_circle().center_2x();
+        let radius_2x = self.diameter.saturating_sub(1) as f32;
+
+        let angle_start = self.angle_start.to_radians();
+        let angle_sweep = self.angle_sweep.to_radians();
+
+        // The maximum angle between two vertices is `sqrt(1 / radius)`.
+        let segments = (angle_sweep.abs() * (radius_2x / 2.0).sqrt()).ceil() as u32;
+        let n = i64::from(segments.max(1));
+
+        (0..=n).map(move |index| {
+            let angle = Angle::from_radians(angle_start + angle_sweep * index as f32 / n as f32);
+
+            let coordinate = |center: i32, value| {
+                ((center as f32 + radius_2x * f32::from(value)) / 2.0).round() as i32
+            };
+
+            Point::new(
+                coordinate(center_2x.x, angle.cos()),
+                coordinate(center_2x.y, angle.sin()),
+            )
+        })
+    }
 }
 
 impl Primitive for Arc {}
@@ -164,6 +199,21 @@ impl Transform for Arc {
     }
 }
 
+impl GeometricTransform for Arc {
+    type Output = Arc;
+
+    fn transform(&self, transformation: &Transformation) -> Self::Output {
+        let (angle_start, angle_sweep) =
+            transformation.transform_angles(self.angle_start, self.angle_sweep);
+
+        Self::from_circle(
+            self.to_circle().transform(transformation),
+            angle_start,
+            angle_sweep,
+        )
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -216,4 +266,28 @@ mod tests {
         let arc = Arc::with_center(Point::new(10, 10), 6, 0.0.deg(), 90.0.deg());
         assert_eq!(arc.center(), Point::new(10, 10));
     }
+
+    #[test]
+    fn geometric_transform() {
+        let arc = Arc::new(Point::new(0, 0), 11, 10.0.deg(), 30.0.deg());
+
+        for (transformed, expected) in [
+            (
+                arc.mirror_horizontal(20),
+                Arc::new(Point::new(30, 0), 11, 170.0.deg(), -30.0.deg()),
+            ),
+            (
+                arc.mirror_vertical(0),
+                Arc::new(Point::new(0, -10), 11, -10.0.deg(), -30.0.deg()),
+            ),
+            (
+                arc.rotate(Point::new(5, 5), 90.0.deg()),
+                Arc::new(Point::new(0, 0), 11, 100.0.deg(), 30.0.deg()),
+            ),
+        ] {
+            assert_eq!(transformed.to_circle(), expected.to_circle());
+            assert!((transformed.angle_start - expected.angle_start).abs() < 0.001.deg());
+            assert!((transformed.angle_sweep - expected.angle_sweep).abs() < 0.001.deg());
+        }
+    }
 }
@@ -3,10 +3,13 @@ use crate::{
     geometry::Dimensions,
     pixelcolor::PixelColor,
     primitives::{
-        arc::Arc,
-        common::{DistanceIterator, PlaneSector},
+        arc::{
+            caps::{square_cap_offset, Caps},
+            Arc,
+        },
+        common::{Band, CircleOutline, Dashes, DistanceIterator, Dots, PlaneSector, StrokePattern},
         styled::{StyledDimensions, StyledDrawable, StyledPixels},
-        OffsetOutline, PrimitiveStyle, Rectangle,
+        Circle, LineCap, OffsetOutline, PrimitiveStyle, Rectangle, StrokeStyle,
     },
     Pixel,
 };
@@ -20,11 +23,14 @@ pub struct StyledPixelsIterator<C> {
     iter: DistanceIterator,
 
     plane_sector: PlaneSector,
+    caps: Option<Caps>,
 
     outer_threshold: u32,
     inner_threshold: u32,
 
     stroke_color: Option<C>,
+
+    pattern: Option<StrokePattern<CircleOutline, Band<Circle>>>,
 }
 
 impl<C: PixelColor> StyledPixelsIterator<C> {
@@ -34,21 +40,48 @@ impl<C: PixelColor> StyledPixelsIterator<C> {
         let outside_edge = circle.offset(style.outside_stroke_width().saturating_as());
         let inside_edge = circle.offset(-style.inside_stroke_width().saturating_as::<i32>());
 
+        let line_cap = style.effective_line_cap();
+        let caps = Caps::new(primitive, &outside_edge, &inside_edge, line_cap);
+
         let iter = if !style.is_transparent() {
             // PERF: The distance iterator should use the smaller arc bounding box
-            outside_edge.distances()
+            if line_cap == LineCap::Square {
+                outside_edge
+                    .offset(square_cap_offset(&outside_edge, style.stroke_width).saturating_as())
+                    .distances()
+            } else {
+                outside_edge.distances()
+            }
         } else {
             DistanceIterator::empty()
         };
 
         let plane_sector = PlaneSector::new(primitive.angle_start, primitive.angle_sweep);
 
+        let outline = CircleOutline::arc(
+            &style.stroke_center_outline(&circle),
+            primitive.angle_start,
+            primitive.angle_sweep,
+        );
+
+        let pattern = if let Some(dot_size) = style.dot_size() {
+            let area = Band::new(outside_edge, inside_edge);
+
+            Some(StrokePattern::Dots(Dots::new(outline, area, dot_size)))
+        } else {
+            style
+                .dash_pattern()
+                .map(|pattern| StrokePattern::Dashes(Dashes::new(outline, pattern)))
+        };
+
         Self {
             iter,
             plane_sector,
+            caps,
             outer_threshold: outside_edge.threshold(),
             inner_threshold: inside_edge.threshold(),
             stroke_color: style.stroke_color,
+            pattern,
         }
     }
 }
@@ -60,10 +93,13 @@ impl<C: PixelColor> Iterator for StyledPixelsIterator<C> {
         let stroke_color = self.stroke_color?;
 
         self.iter
-            .find(|(_, delta, distance)| {
-                *distance < self.outer_threshold
+            .find(|(point, delta, distance)| {
+                let in_stroke = *distance < self.outer_threshold
                     && *distance >= self.inner_threshold
-                    && self.plane_sector.contains(*delta)
+                    && self.plane_sector.contains(*delta);
+
+                (in_stroke || self.caps.iter().any(|caps| caps.contains(*delta)))
+                    && self.pattern.iter().all(|pattern| pattern.contains(*point))
             })
             .map(|(point, ..)| Pixel(point, stroke_color))
     }
@@ -96,9 +132,15 @@ impl<C: PixelColor> StyledPixels<PrimitiveStyle<C>> for Arc {
 impl<C: PixelColor> StyledDimensions<PrimitiveStyle<C>> for Arc {
     // FIXME: This doesn't take into account start/end angles. This should be fixed to close #405.
     fn styled_bounding_box(&self, style: &PrimitiveStyle<C>) -> Rectangle {
-        let offset = style.outside_stroke_width().saturating_as();
+        let mut offset = style.outside_stroke_width();
+
+        // Square caps can extend past the outside edge of the circle.
+        if style.stroke_style == StrokeStyle::Solid && style.line_cap == LineCap::Square {
+            let outside_edge = self.to_circle().offset(offset.saturating_as());
+            offset += square_cap_offset(&outside_edge, style.stroke_width);
+        }
 
-        self.bounding_box().offset(offset)
+        self.bounding_box().offset(offset.saturating_as())
     }
 }
 
@@ -110,7 +152,10 @@ mod tests {
         geometry::{AnchorPoint, AngleUnit, Point, Size},
         mock_display::MockDisplay,
         pixelcolor::BinaryColor,
-        primitives::{Circle, Primitive, PrimitiveStyle, PrimitiveStyleBuilder, StrokeAlignment},
+        primitives::{
+            Circle, DashPattern, PointsIter, Primitive, PrimitiveStyle, PrimitiveStyleBuilder,
+            StrokeAlignment, StrokeStyle,
+        },
         Drawable,
     };
 
@@ -259,4 +304,166 @@ mod tests {
 
         assert_eq!(transparent_arc.bounding_box(), stroked_arc.bounding_box(),);
     }
+
+    #[test]
+    fn dashed() {
+        let arc = Arc::new(Point::new(4, 4), 30, 45.0.deg(), 200.0.deg());
+
+        for stroke_width in 1..5 {
+            let solid_style = PrimitiveStyle::with_stroke(BinaryColor::On, stroke_width);
+            let dashed_style = PrimitiveStyleBuilder::from(&solid_style)
+                .stroke_style(StrokeStyle::Dashed(DashPattern::new(4, 4)))
+                .build();
+
+            let mut solid = MockDisplay::new();
+            arc.into_styled(solid_style).draw(&mut solid).unwrap();
+
+            let mut dashed = MockDisplay::new();
+            arc.into_styled(dashed_style).draw(&mut dashed).unwrap();
+
+            let dashed_pixels = dashed
+                .affected_area()
+                .points()
+                .filter(|p| dashed.get_pixel(*p).is_some())
+                .count();
+            let solid_pixels = solid
+                .affected_area()
+                .points()
+                .filter(|p| solid.get_pixel(*p).is_some())
+                .count();
+
+            // Half of the arc is covered by dashes.
+            assert!(
+                dashed_pixels * 2 >= solid_pixels - solid_pixels / 8
+                    && dashed_pixels * 2 <= solid_pixels + solid_pixels / 8,
+                "{} px: {} of {} pixels",
+                stroke_width,
+                dashed_pixels,
+                solid_pixels
+            );
+
+            for point in dashed.affected_area().points() {
+                if dashed.get_pixel(point).is_some() {
+                    assert_eq!(solid.get_pixel(point), Some(BinaryColor::On));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn dotted() {
+        crate::primitives::common::check_dotted_stroke(Arc::new(
+            Point::new(6, 7),
+            30,
+            45.0.deg(),
+            200.0.deg(),
+        ));
+    }
+
+    fn cap_style(line_cap: LineCap) -> PrimitiveStyle<BinaryColor> {
+        PrimitiveStyleBuilder::new()
+            .stroke_color(BinaryColor::On)
+            .stroke_width(5)
+            .line_cap(line_cap)
+            .build()
+    }
+
+    #[test]
+    fn square_caps() {
+        let mut display = MockDisplay::new();
+        Arc::with_center(Point::new(2, 2), 17, 0.0.deg(), 90.0.deg())
+            .into_styled(cap_style(LineCap::Square))
+            .draw(&mut display)
+            .unwrap();
+
+        display.assert_pattern(&[
+            "        #####", //
+            "        #####", //
+            "        #####", //
+            "        #####", //
+            "        #####", //
+            "       ######", //
+            "      ###### ", //
+            "     ####### ", //
+            "###########  ", //
+            "##########   ", //
+            "#########    ", //
+            "########     ", //
+            "######       ", //
+        ]);
+    }
+
+    #[test]
+    fn round_caps() {
+        let mut display = MockDisplay::new();
+        Arc::with_center(Point::new(2, 2), 17, 0.0.deg(), 90.0.deg())
+            .into_styled(cap_style(LineCap::Round))
+            .draw(&mut display)
+            .unwrap();
+
+        display.assert_pattern(&[
+            "         ### ", //
+            "        #####", //
+            "        #####", //
+            "        #####", //
+            "        #####", //
+            "       ######", //
+            "      ###### ", //
+            "     ####### ", //
+            " ##########  ", //
+            "##########   ", //
+            "#########    ", //
+            "########     ", //
+            " #####       ", //
+        ]);
+    }
+
+    #[test]
+    fn caps_are_independent_of_sweep_direction() {
+        for line_cap in [LineCap::Butt, LineCap::Square, LineCap::Round] {
+            let mut expected = MockDisplay::new();
+            Arc::with_center(Point::new(30, 30), 31, 20.0.deg(), 130.0.deg())
+                .into_styled(cap_style(line_cap))
+                .draw(&mut expected)
+                .unwrap();
+
+            let mut display = MockDisplay::new();
+            Arc::with_center(Point::new(30, 30), 31, 150.0.deg(), -130.0.deg())
+                .into_styled(cap_style(line_cap))
+                .draw(&mut display)
+                .unwrap();
+
+            display.assert_eq_with_message(&expected, |f| write!(f, "{:?}", line_cap));
+        }
+    }
+
+    #[test]
+    fn caps_bounding_box() {
+        for line_cap in [LineCap::Square, LineCap::Round] {
+            for angle_start in [0.0, 30.0, 85.0, 180.0, 265.0] {
+                for angle_sweep in [10.0, 100.0, 200.0, -60.0, 360.0] {
+                    let styled = Arc::with_center(
+                        Point::new(32, 32),
+                        41,
+                        angle_start.deg(),
+                        angle_sweep.deg(),
+                    )
+                    .into_styled(cap_style(line_cap));
+
+                    let mut display = MockDisplay::new();
+                    styled.draw(&mut display).unwrap();
+
+                    let area = display.affected_area();
+                    assert_eq!(
+                        styled.bounding_box().intersection(&area),
+                        area,
+                        "{:?}, {}, {}",
+                        line_cap,
+                        angle_start,
+                        angle_sweep
+                    );
+                }
+            }
+        }
+    }
 }
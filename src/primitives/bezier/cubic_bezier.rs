@@ -0,0 +1,267 @@
+use crate::{
+    geometry::{Dimensions, Point},
+    primitives::{
+        bezier::{
+            flattened::{div_round, second_difference, segment_count, Flattened},
+            Points,
+        },
+        PointsIter, Primitive, Rectangle,
+    },
+    transform::{GeometricTransform, Transform, Transformation},
+};
+
+/// Cubic Bézier curve primitive
+///
+/// A cubic Bézier curve starts at `start` and ends at `end`. The curve leaves the start point in
+/// the direction of `control1` and arrives at the end point from the direction of `control2`.
+/// Cubic curves can be used to draw S-shaped curves and to smoothly connect multiple curves.
+///
+/// # Examples
+///
+/// ## Draw an S-shaped curve
+///
+/// ```rust
+/// use embedded_graphics::{
+///     pixelcolor::Rgb565,
+///     prelude::*,
+///     primitives::{CubicBezier, PrimitiveStyle},
+/// };
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display = MockDisplay::default();
+///
+/// CubicBezier::new(
+///     Point::new(5, 55),
+///     Point::new(60, 55),
+///     Point::new(5, 5),
+///     Point::new(60, 5),
+/// )
+/// .into_styled(PrimitiveStyle::with_stroke(Rgb565::RED, 3))
+/// .draw(&mut display)?;
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub struct CubicBezier {
+    /// Start point
+    pub start: Point,
+
+    /// First control point
+    pub control1: Point,
+
+    /// Second control point
+    pub control2: Point,
+
+    /// End point
+    pub end: Point,
+}
+
+impl CubicBezier {
+    /// Creates a new cubic Bézier curve.
+    pub const fn new(start: Point, control1: Point, control2: Point, end: Point) -> Self {
+        Self {
+            start,
+            control1,
+            control2,
+            end,
+        }
+    }
+
+    /// Flattens the curve into line segments.
+    pub(in crate::primitives) fn flattened(&self) -> Flattened {
+        let Self {
+            start,
+            control1,
+            control2,
+            end,
+        } = *self;
+
+        let segments = segment_count(
+            second_difference(start, control1, control2)
+                .component_max(second_difference(control1, control2, end)),
+            3,
+        );
+
+        Flattened::new(segments, |index, n| {
+            let s = n - index;
+            let weights = [
+                s * s * s,
+                3 * s * s * index,
+                3 * s * index * index,
+                index * index * index,
+            ];
+
+            let coordinate = |c: [i32; 4]| {
+                let sum = weights
+                    .iter()
+                    .zip(c.iter())
+                    .map(|(w, c)| w * i64::from(*c))
+                    .sum();
+
+                div_round(sum, n * n * n)
+            };
+
+            Point::new(
+                coordinate([start.x, control1.x, control2.x, end.x]),
+                coordinate([start.y, control1.y, control2.y, end.y]),
+            )
+        })
+    }
+}
+
+impl Primitive for CubicBezier {}
+
+impl PointsIter for CubicBezier {
+    type Iter = Points;
+
+    fn points(&self) -> Self::Iter {
+        Points::new(self.flattened())
+    }
+}
+
+impl Dimensions for CubicBezier {
+    fn bounding_box(&self) -> Rectangle {
+        self.flattened().bounding_box()
+    }
+}
+
+impl Transform for CubicBezier {
+    /// Translate the curve from its current position to a new position by (x, y) pixels,
+    /// returning a new `CubicBezier`. For a mutating transform, see `translate_mut`.
+    ///
+    /// ```
+    /// # use embedded_graphics::primitives::CubicBezier;
+    /// # use embedded_graphics::prelude::*;
+    /// let curve = CubicBezier::new(
+    ///     Point::new(5, 10),
+    ///     Point::new(10, 0),
+    ///     Point::new(15, 20),
+    ///     Point::new(20, 10),
+    /// );
+    /// let moved = curve.translate(Point::new(10, 10));
+    ///
+    /// assert_eq!(
+    ///     moved,
+    ///     CubicBezier::new(
+    ///         Point::new(15, 20),
+    ///         Point::new(20, 10),
+    ///         Point::new(25, 30),
+    ///         Point::new(30, 20),
+    ///     )
+    /// );
+    /// ```
+    fn translate(&self, by: Point) -> Self {
+        Self {
+            start: self.start + by,
+            control1: self.control1 + by,
+            control2: self.control2 + by,
+            end: self.end + by,
+        }
+    }
+
+    /// Translate the curve from its current position to a new position by (x, y) pixels.
+    ///
+    /// ```
+    /// # use embedded_graphics::primitives::CubicBezier;
+    /// # use embedded_graphics::prelude::*;
+    /// let mut curve = CubicBezier::new(
+    ///     Point::new(5, 10),
+    ///     Point::new(10, 0),
+    ///     Point::new(15, 20),
+    ///     Point::new(20, 10),
+    /// );
+    /// curve.translate_mut(Point::new(10, 10));
+    ///
+    /// assert_eq!(
+    ///     curve,
+    ///     CubicBezier::new(
+    ///         Point::new(15, 20),
+    ///         Point::new(20, 10),
+    ///         Point::new(25, 30),
+    ///         Point::new(30, 20),
+    ///     )
+    /// );
+    /// ```
+    fn translate_mut(&mut self, by: Point) -> &mut Self {
+        self.start += by;
+        self.control1 += by;
+        self.control2 += by;
+        self.end += by;
+
+        self
+    }
+}
+
+impl GeometricTransform for CubicBezier {
+    type Output = CubicBezier;
+
+    fn transform(&self, transformation: &Transformation) -> Self::Output {
+        Self::new(
+            transformation.transform_point(self.start),
+            transformation.transform_point(self.control1),
+            transformation.transform_point(self.control2),
+            transformation.transform_point(self.end),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::primitives::QuadraticBezier;
+
+    #[test]
+    fn end_points() {
+        let curve = CubicBezier::new(
+            Point::new(3, 20),
+            Point::new(17, -5),
+            Point::new(30, 50),
+            Point::new(40, 12),
+        );
+        let vertices = curve.flattened();
+
+        assert_eq!(vertices.vertices().first(), Some(&curve.start));
+        assert_eq!(vertices.vertices().last(), Some(&curve.end));
+    }
+
+    #[test]
+    fn degree_elevated_quadratic_curve() {
+        // A quadratic curve can be represented as a cubic curve by placing the control points
+        // 2/3 of the way from the end points to the quadratic control point.
+        let quadratic =
+            QuadraticBezier::new(Point::new(0, 30), Point::new(30, 0), Point::new(60, 30));
+        let cubic = CubicBezier::new(
+            Point::new(0, 30),
+            Point::new(20, 10),
+            Point::new(40, 10),
+            Point::new(60, 30),
+        );
+
+        assert_eq!(cubic.bounding_box(), quadratic.bounding_box());
+
+        // Both curves are flattened into a different number of segments, which can cause small
+        // differences in the rasterized curve.
+        for p in cubic.points() {
+            assert!(
+                quadratic
+                    .points()
+                    .any(|q| (q.x - p.x).abs() <= 1 && (q.y - p.y).abs() <= 1),
+                "{:?}",
+                p
+            );
+        }
+    }
+
+    #[test]
+    fn large_curve_does_not_overflow() {
+        let curve = CubicBezier::new(
+            Point::new(-100_000, 0),
+            Point::new(-50_000, 100_000),
+            Point::new(50_000, -100_000),
+            Point::new(100_000, 0),
+        );
+
+        let vertices = curve.flattened();
+        assert_eq!(vertices.vertices().len(), 33);
+        assert_eq!(vertices.vertices()[16], Point::zero());
+    }
+}
@@ -0,0 +1,164 @@
+//! Flattened Bézier curves.
+
+use crate::{
+    geometry::{Dimensions, Point, Size},
+    primitives::{common::isqrt, Polyline, Rectangle},
+};
+
+/// Maximum number of line segments a curve is flattened into.
+pub(in crate::primitives) const MAX_SEGMENTS: usize = 32;
+
+/// Curve flattened into a sequence of line segments.
+///
+/// The vertices are stored inline to make it possible to draw curves without allocating memory.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub(in crate::primitives) struct Flattened {
+    vertices: [Point; MAX_SEGMENTS + 1],
+    len: usize,
+}
+
+impl Flattened {
+    /// Creates a flattened curve.
+    ///
+    /// The `point_at` function is called with the index of each vertex and returns the point on
+    /// the curve at `t = index / segments`. Consecutive duplicate vertices are skipped, but the
+    /// curve always contains at least a start and an end vertex.
+    pub fn new<F>(segments: u32, point_at: F) -> Self
+    where
+        F: Fn(i64, i64) -> Point,
+    {
+        let segments = segments.clamp(1, MAX_SEGMENTS as u32);
+        let n = i64::from(segments);
+
+        let mut vertices = [Point::zero(); MAX_SEGMENTS + 1];
+        vertices[0] = point_at(0, n);
+        let mut len = 1;
+
+        for index in 1..n {
+            let point = point_at(index, n);
+
+            if point != vertices[len - 1] {
+                vertices[len] = point;
+                len += 1;
+            }
+        }
+
+        let end = point_at(n, n);
+        if len == 1 || end != vertices[len - 1] {
+            vertices[len] = end;
+            len += 1;
+        }
+
+        Self { vertices, len }
+    }
+
+    /// Returns the vertices of the flattened curve.
+    pub fn vertices(&self) -> &[Point] {
+        &self.vertices[0..self.len]
+    }
+
+    /// Returns a polyline through the vertices.
+    pub fn polyline(&self) -> Polyline<'_> {
+        Polyline::new(self.vertices())
+    }
+}
+
+impl AsRef<[Point]> for Flattened {
+    fn as_ref(&self) -> &[Point] {
+        self.vertices()
+    }
+}
+
+impl Dimensions for Flattened {
+    fn bounding_box(&self) -> Rectangle {
+        let (min, max) = self.vertices().iter().fold(
+            (Point::new_equal(i32::MAX), Point::new_equal(i32::MIN)),
+            |(min, max), v| (min.component_min(*v), max.component_max(*v)),
+        );
+
+        Rectangle::with_corners(min, max)
+    }
+}
+
+/// Returns the number of line segments that are required to flatten a curve.
+///
+/// `max_second_difference` is the length of the longest second difference of the control points
+/// and `degree` is the degree of the curve. The number of segments is chosen to keep the
+/// distance between the curve and the flattened curve below 1/8 of a pixel.
+pub(in crate::primitives::bezier) fn segment_count(
+    max_second_difference: Size,
+    degree: u32,
+) -> u32 {
+    let Size { width, height } = max_second_difference;
+    let length = isqrt(
+        u64::from(width)
+            .pow(2)
+            .saturating_add(u64::from(height).pow(2)),
+    ) + 1;
+
+    // Wang's formula: n = sqrt(degree * (degree - 1) / 8 * length / tolerance)
+    let squared = u64::from(degree * (degree - 1)) * length;
+
+    (isqrt(squared) + 1).min(MAX_SEGMENTS as u64) as u32
+}
+
+/// Returns the absolute value of the second difference of three control points.
+pub(in crate::primitives::bezier) fn second_difference(p0: Point, p1: Point, p2: Point) -> Size {
+    let delta = |c0: i32, c1: i32, c2: i32| {
+        let delta = i64::from(c0) - 2 * i64::from(c1) + i64::from(c2);
+
+        delta.unsigned_abs().min(u64::from(u32::MAX)) as u32
+    };
+
+    Size::new(delta(p0.x, p1.x, p2.x), delta(p0.y, p1.y, p2.y))
+}
+
+/// Divides `numerator` by `denominator` and rounds the result to the nearest integer.
+pub(in crate::primitives::bezier) fn div_round(numerator: i64, denominator: i64) -> i32 {
+    (numerator * 2 + denominator).div_euclid(denominator * 2) as i32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn segment_count_of_straight_curve() {
+        assert_eq!(segment_count(Size::zero(), 2), 2);
+        assert_eq!(segment_count(Size::zero(), 3), 3);
+    }
+
+    #[test]
+    fn segment_count_is_limited() {
+        assert_eq!(
+            segment_count(Size::new(100_000, 100_000), 3),
+            MAX_SEGMENTS as u32
+        );
+    }
+
+    #[test]
+    fn duplicate_vertices_are_skipped() {
+        let flattened = Flattened::new(8, |index, n| Point::new(div_round(index * 2, n), 0));
+
+        assert_eq!(
+            flattened.vertices(),
+            &[Point::new(0, 0), Point::new(1, 0), Point::new(2, 0)]
+        );
+    }
+
+    #[test]
+    fn single_point() {
+        let flattened = Flattened::new(4, |_, _| Point::new(3, 4));
+
+        assert_eq!(flattened.vertices(), &[Point::new(3, 4), Point::new(3, 4)]);
+    }
+
+    #[test]
+    fn rounding() {
+        assert_eq!(div_round(5, 2), 3);
+        assert_eq!(div_round(-5, 2), -2);
+        assert_eq!(div_round(4, 3), 1);
+        assert_eq!(div_round(-4, 3), -1);
+    }
+}
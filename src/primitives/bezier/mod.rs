@@ -0,0 +1,21 @@
+//! The Bézier curve primitives
+//!
+//! Curves are drawn by flattening them into a sequence of up to 32 line segments. Thin strokes
+//! contain the same pixels as a [`Polyline`] through the end points of the segments, but pixels
+//! that are covered by more than one segment are only drawn once. Thick strokes use round joins
+//! between the segments to get a smooth outline. The stroke alignment and the fill
+//! color are ignored, because curves are open shapes.
+//!
+//! [`Polyline`]: crate::primitives::Polyline
+
+mod cubic_bezier;
+pub(in crate::primitives) mod flattened;
+mod points;
+mod quadratic_bezier;
+mod scanline_iterator;
+mod styled;
+
+pub use cubic_bezier::CubicBezier;
+pub use points::Points;
+pub use quadratic_bezier::QuadraticBezier;
+pub use styled::StyledPixelsIterator;
@@ -0,0 +1,50 @@
+use crate::{
+    geometry::Point,
+    primitives::{
+        bezier::flattened::Flattened,
+        line::{self, Line},
+        PointsIter,
+    },
+};
+
+/// An iterator over all pixel positions on a Bézier curve
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub struct Points {
+    flattened: Flattened,
+    end_index: usize,
+    segment_iter: line::Points,
+}
+
+impl Points {
+    pub(in crate::primitives::bezier) fn new(flattened: Flattened) -> Self {
+        let vertices = flattened.vertices();
+        let segment_iter = Line::new(vertices[0], vertices[1]).points();
+
+        Self {
+            flattened,
+            end_index: 1,
+            segment_iter,
+        }
+    }
+}
+
+impl Iterator for Points {
+    type Item = Point;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(p) = self.segment_iter.next() {
+            Some(p)
+        } else {
+            let vertices = self.flattened.vertices();
+            let start = vertices[self.end_index];
+            let end = *vertices.get(self.end_index + 1)?;
+
+            self.end_index += 1;
+            self.segment_iter = Line::new(start, end).points();
+
+            // Skip first point of next line, otherwise we overlap with the previous line
+            self.nth(1)
+        }
+    }
+}
@@ -0,0 +1,212 @@
+use crate::{
+    geometry::{Dimensions, Point},
+    primitives::{
+        bezier::{
+            flattened::{div_round, second_difference, segment_count, Flattened},
+            Points,
+        },
+        PointsIter, Primitive, Rectangle,
+    },
+    transform::{GeometricTransform, Transform, Transformation},
+};
+
+/// Quadratic Bézier curve primitive
+///
+/// A quadratic Bézier curve starts at `start`, ends at `end` and is pulled towards the `control`
+/// point. The curve doesn't pass through the control point, unless all points are on a straight
+/// line.
+///
+/// # Examples
+///
+/// ## Draw a curve with a thick stroke
+///
+/// ```rust
+/// use embedded_graphics::{
+///     pixelcolor::Rgb565,
+///     prelude::*,
+///     primitives::{PrimitiveStyle, QuadraticBezier},
+/// };
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display = MockDisplay::default();
+///
+/// QuadraticBezier::new(Point::new(5, 50), Point::new(32, 0), Point::new(59, 50))
+///     .into_styled(PrimitiveStyle::with_stroke(Rgb565::GREEN, 5))
+///     .draw(&mut display)?;
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub struct QuadraticBezier {
+    /// Start point
+    pub start: Point,
+
+    /// Control point
+    pub control: Point,
+
+    /// End point
+    pub end: Point,
+}
+
+impl QuadraticBezier {
+    /// Creates a new quadratic Bézier curve.
+    pub const fn new(start: Point, control: Point, end: Point) -> Self {
+        Self {
+            start,
+            control,
+            end,
+        }
+    }
+
+    /// Flattens the curve into line segments.
+    pub(in crate::primitives) fn flattened(&self) -> Flattened {
+        let Self {
+            start,
+            control,
+            end,
+        } = *self;
+
+        let segments = segment_count(second_difference(start, control, end), 2);
+
+        Flattened::new(segments, |index, n| {
+            let s = n - index;
+            let weights = [s * s, 2 * s * index, index * index];
+
+            let coordinate = |c: [i32; 3]| {
+                let sum = weights
+                    .iter()
+                    .zip(c.iter())
+                    .map(|(w, c)| w * i64::from(*c))
+                    .sum();
+
+                div_round(sum, n * n)
+            };
+
+            Point::new(
+                coordinate([start.x, control.x, end.x]),
+                coordinate([start.y, control.y, end.y]),
+            )
+        })
+    }
+}
+
+impl Primitive for QuadraticBezier {}
+
+impl PointsIter for QuadraticBezier {
+    type Iter = Points;
+
+    fn points(&self) -> Self::Iter {
+        Points::new(self.flattened())
+    }
+}
+
+impl Dimensions for QuadraticBezier {
+    fn bounding_box(&self) -> Rectangle {
+        self.flattened().bounding_box()
+    }
+}
+
+impl Transform for QuadraticBezier {
+    /// Translate the curve from its current position to a new position by (x, y) pixels,
+    /// returning a new `QuadraticBezier`. For a mutating transform, see `translate_mut`.
+    ///
+    /// ```
+    /// # use embedded_graphics::primitives::QuadraticBezier;
+    /// # use embedded_graphics::prelude::*;
+    /// let curve = QuadraticBezier::new(Point::new(5, 10), Point::new(10, 0), Point::new(15, 10));
+    /// let moved = curve.translate(Point::new(10, 10));
+    ///
+    /// assert_eq!(
+    ///     moved,
+    ///     QuadraticBezier::new(Point::new(15, 20), Point::new(20, 10), Point::new(25, 20))
+    /// );
+    /// ```
+    fn translate(&self, by: Point) -> Self {
+        Self {
+            start: self.start + by,
+            control: self.control + by,
+            end: self.end + by,
+        }
+    }
+
+    /// Translate the curve from its current position to a new position by (x, y) pixels.
+    ///
+    /// ```
+    /// # use embedded_graphics::primitives::QuadraticBezier;
+    /// # use embedded_graphics::prelude::*;
+    /// let mut curve = QuadraticBezier::new(Point::new(5, 10), Point::new(10, 0), Point::new(15, 10));
+    /// curve.translate_mut(Point::new(10, 10));
+    ///
+    /// assert_eq!(
+    ///     curve,
+    ///     QuadraticBezier::new(Point::new(15, 20), Point::new(20, 10), Point::new(25, 20))
+    /// );
+    /// ```
+    fn translate_mut(&mut self, by: Point) -> &mut Self {
+        self.start += by;
+        self.control += by;
+        self.end += by;
+
+        self
+    }
+}
+
+impl GeometricTransform for QuadraticBezier {
+    type Output = QuadraticBezier;
+
+    fn transform(&self, transformation: &Transformation) -> Self::Output {
+        Self::new(
+            transformation.transform_point(self.start),
+            transformation.transform_point(self.control),
+            transformation.transform_point(self.end),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::primitives::Line;
+
+    #[test]
+    fn end_points() {
+        let curve = QuadraticBezier::new(Point::new(3, 20), Point::new(17, -5), Point::new(40, 12));
+        let vertices = curve.flattened();
+
+        assert_eq!(vertices.vertices().first(), Some(&curve.start));
+        assert_eq!(vertices.vertices().last(), Some(&curve.end));
+    }
+
+    #[test]
+    fn apex() {
+        let curve =
+            QuadraticBezier::new(Point::new(0, 10), Point::new(10, -10), Point::new(20, 10));
+
+        // The apex of the curve is halfway between the control point and the chord.
+        assert_eq!(curve.bounding_box().top_left, Point::new(0, 0));
+        assert!(curve.points().any(|p| p == Point::new(10, 0)));
+    }
+
+    #[test]
+    fn straight_curve_is_line() {
+        let curve = QuadraticBezier::new(Point::new(5, 5), Point::new(15, 10), Point::new(25, 15));
+        let line = Line::new(curve.start, curve.end);
+
+        assert!(curve.points().eq(line.points()));
+    }
+
+    #[test]
+    fn points_are_connected() {
+        let curve =
+            QuadraticBezier::new(Point::new(2, 60), Point::new(50, -40), Point::new(60, 60));
+
+        let mut points = curve.points();
+        let mut previous = points.next().unwrap();
+        for p in points {
+            let delta = p - previous;
+            assert!(delta.x.abs() <= 1 && delta.y.abs() <= 1, "{:?}", p);
+            assert_ne!(delta, Point::zero());
+
+            previous = p;
+        }
+    }
+}
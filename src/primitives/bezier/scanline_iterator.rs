@@ -0,0 +1,133 @@
+//! Scanline iterator for curve strokes.
+
+use core::ops::Range;
+
+use crate::{
+    geometry::{Dimensions, Point},
+    primitives::{
+        bezier::flattened::Flattened,
+        common::{disc_run, first_run, isqrt, merge_runs, solve, Scanline, StrokeArea},
+        Line,
+    },
+};
+
+/// Iterates over all scanlines of a curve stroke.
+///
+/// A 1 px wide stroke is the union of the Bresenham lines between the vertices of the flattened
+/// curve. A thick stroke is the union of a band around each line segment of the flattened curve and a disc
+/// at each vertex between two segments. The discs fill the gaps on the outside of the joins, which
+/// results in round joins. Round joins are used instead of the miter and bevel joins of polylines,
+/// because the segments of flattened curves are often shorter than the stroke width.
+///
+/// The runs of each scanline are merged before they are returned to prevent overdraw.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub struct ScanlineIterator {
+    flattened: Flattened,
+    stroke_width: u32,
+    rows: Range<i32>,
+    scanline_y: i32,
+
+    /// Start of the next run.
+    x: i32,
+}
+
+impl ScanlineIterator {
+    /// Creates a new scanline iterator.
+    pub fn new(flattened: Flattened, stroke_width: u32) -> Self {
+        let offset = (stroke_width / 2 + 1) as i32;
+        let mut rows = flattened.bounding_box().offset(offset).rows();
+        let scanline_y = rows.next().unwrap_or_default();
+
+        Self {
+            flattened,
+            stroke_width,
+            rows,
+            scanline_y,
+            x: i32::MIN,
+        }
+    }
+
+    /// Returns the run of pixels in row `y` inside the band around a line segment.
+    ///
+    /// The band ends with flat caps at the start and end point of the segment. For 1 px wide
+    /// strokes the run of the Bresenham line is returned instead.
+    fn segment_run(&self, start: Point, end: Point, y: i32) -> Option<Range<i32>> {
+        if self.stroke_width <= 1 {
+            let mut scanline = Scanline::new_empty(y);
+            scanline.bresenham_intersection(&Line::new(start, end));
+
+            return (!scanline.is_empty()).then_some(scanline.x);
+        }
+
+        let delta = end - start;
+        let dx = i64::from(delta.x);
+        let dy = i64::from(delta.y);
+        let v = i64::from(y - start.y);
+
+        let length_squared = dx * dx + dy * dy;
+        if length_squared == 0 {
+            return self.join_run(start, y);
+        }
+
+        // The projection of `x - start.x` onto the segment direction must be inside the segment.
+        let along = solve(dx, v * dy, 0, length_squared)?;
+
+        // The distance to the line, scaled by 16, must be in `-width / 2..width / 2`. The range is
+        // half open to make sure that exactly `width` pixels are covered.
+        let length = isqrt((length_squared as u64) << 8) as i64;
+        let limit = i64::from(self.stroke_width) * length;
+        let across = solve(32 * dy, -32 * v * dx, -limit, limit - 1)?;
+
+        let start_x = start.x as i64;
+        let run = along.start.max(across.start) + start_x..along.end.min(across.end) + start_x;
+
+        Some(run.start as i32..run.end as i32)
+    }
+
+    /// Returns the run of pixels in row `y` inside the disc at a join.
+    fn join_run(&self, vertex: Point, y: i32) -> Option<Range<i32>> {
+        disc_run(vertex, self.stroke_width, y)
+    }
+
+    /// Returns all runs in row `y`.
+    ///
+    /// The runs can overlap and aren't sorted.
+    fn runs(&self, y: i32) -> impl Iterator<Item = Range<i32>> + '_ {
+        let vertices = self.flattened.vertices();
+        let inner_vertices = &vertices[1..vertices.len() - 1];
+
+        vertices
+            .windows(2)
+            .filter_map(move |segment| self.segment_run(segment[0], segment[1], y))
+            .chain(
+                inner_vertices
+                    .iter()
+                    .filter_map(move |vertex| self.join_run(*vertex, y)),
+            )
+    }
+}
+
+impl Iterator for ScanlineIterator {
+    type Item = Scanline;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(run) = merge_runs(self.x, |x| first_run(x, self.runs(self.scanline_y))) {
+                self.x = run.end;
+
+                return Some(Scanline::new(self.scanline_y, run));
+            }
+
+            self.scanline_y = self.rows.next()?;
+            self.x = i32::MIN;
+        }
+    }
+}
+
+/// The solid stroke is used to align the dots of dotted curves.
+impl StrokeArea for ScanlineIterator {
+    fn contains(&self, point: Point) -> bool {
+        self.runs(point.y).any(|run| run.contains(&point.x))
+    }
+}
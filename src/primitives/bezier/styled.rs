@@ -0,0 +1,445 @@
+use crate::{
+    draw_target::DrawTarget,
+    geometry::{Dimensions, Point, Size},
+    pixelcolor::PixelColor,
+    primitives::{
+        bezier::{
+            flattened::Flattened, scanline_iterator::ScanlineIterator, CubicBezier, QuadraticBezier,
+        },
+        common::{Dashes, Dots, Scanline, SegmentsOutline, StrokePattern},
+        styled::{StyledDimensions, StyledDrawable, StyledPixels},
+        PrimitiveStyle, Rectangle,
+    },
+    Pixel,
+};
+
+/// Returns `true` if the curve is drawn using the thick curve scanline iterator.
+///
+/// Thin curves have the same bounding box as a polyline through the vertices of the flattened
+/// curve.
+fn is_thick<C: PixelColor>(style: &PrimitiveStyle<C>) -> bool {
+    style.stroke_width > 1 && style.stroke_color.is_some()
+}
+
+/// Returns the dashes or dots of the curve, if the style uses a non solid stroke.
+fn stroke_pattern<C: PixelColor>(
+    flattened: &Flattened,
+    style: &PrimitiveStyle<C>,
+) -> Option<StrokePattern<SegmentsOutline<Flattened>, ScanlineIterator>> {
+    let outline = SegmentsOutline::open(*flattened, Point::zero());
+
+    if let Some(dot_size) = style.dot_size() {
+        // The flattened curve is the center line of the solid stroke.
+        let area = ScanlineIterator::new(*flattened, style.stroke_width);
+
+        return Some(StrokePattern::Dots(Dots::new(outline, area, dot_size)));
+    }
+
+    style
+        .dash_pattern()
+        .map(|pattern| StrokePattern::Dashes(Dashes::new(outline, pattern)))
+}
+
+fn draw_flattened<D>(
+    flattened: &Flattened,
+    style: &PrimitiveStyle<D::Color>,
+    target: &mut D,
+) -> Result<(), D::Error>
+where
+    D: DrawTarget,
+{
+    let Some(stroke_color) = style.effective_stroke_color() else {
+        return Ok(());
+    };
+
+    let scanlines = ScanlineIterator::new(*flattened, style.stroke_width);
+
+    if let Some(pattern) = stroke_pattern(flattened, style) {
+        for line in scanlines {
+            line.draw_runs(target, |point| {
+                pattern.contains(point).then_some(stroke_color)
+            })?;
+        }
+    } else {
+        for line in scanlines {
+            line.draw(target, stroke_color)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn flattened_bounding_box<C: PixelColor>(
+    flattened: &Flattened,
+    style: &PrimitiveStyle<C>,
+) -> Rectangle {
+    if !is_thick(style) {
+        return flattened.polyline().styled_bounding_box(style);
+    }
+
+    ScanlineIterator::new(*flattened, style.stroke_width)
+        .map(|line| line.to_rectangle())
+        .reduce(|a, b| a.envelope(&b))
+        .unwrap_or_else(|| Rectangle::new(flattened.bounding_box().center(), Size::zero()))
+}
+
+/// Pixel iterator for each pixel of a styled curve.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub struct StyledPixelsIterator<C> {
+    scanlines: ScanlineIterator,
+    scanline: Scanline,
+    stroke_color: Option<C>,
+    pattern: Option<StrokePattern<SegmentsOutline<Flattened>, ScanlineIterator>>,
+}
+
+impl<C: PixelColor> StyledPixelsIterator<C> {
+    fn new(flattened: &Flattened, style: &PrimitiveStyle<C>) -> Self {
+        Self {
+            scanlines: ScanlineIterator::new(*flattened, style.stroke_width),
+            scanline: Scanline::new_empty(0),
+            stroke_color: style.effective_stroke_color(),
+            pattern: stroke_pattern(flattened, style),
+        }
+    }
+}
+
+impl<C: PixelColor> Iterator for StyledPixelsIterator<C> {
+    type Item = Pixel<C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let stroke_color = self.stroke_color?;
+
+        loop {
+            if let Some(point) = self.scanline.next() {
+                if self.pattern.iter().all(|pattern| pattern.contains(point)) {
+                    return Some(Pixel(point, stroke_color));
+                }
+            } else {
+                self.scanline = self.scanlines.next()?;
+            }
+        }
+    }
+}
+
+impl<C: PixelColor> StyledPixels<PrimitiveStyle<C>> for QuadraticBezier {
+    type Iter = StyledPixelsIterator<C>;
+
+    fn pixels(&self, style: &PrimitiveStyle<C>) -> Self::Iter {
+        StyledPixelsIterator::new(&self.flattened(), style)
+    }
+}
+
+impl<C: PixelColor> StyledDrawable<PrimitiveStyle<C>> for QuadraticBezier {
+    type Color = C;
+    type Output = ();
+
+    fn draw_styled<D>(
+        &self,
+        style: &PrimitiveStyle<C>,
+        target: &mut D,
+    ) -> Result<Self::Output, D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        draw_flattened(&self.flattened(), style, target)
+    }
+}
+
+impl<C: PixelColor> StyledDimensions<PrimitiveStyle<C>> for QuadraticBezier {
+    fn styled_bounding_box(&self, style: &PrimitiveStyle<C>) -> Rectangle {
+        flattened_bounding_box(&self.flattened(), style)
+    }
+}
+
+impl<C: PixelColor> StyledPixels<PrimitiveStyle<C>> for CubicBezier {
+    type Iter = StyledPixelsIterator<C>;
+
+    fn pixels(&self, style: &PrimitiveStyle<C>) -> Self::Iter {
+        StyledPixelsIterator::new(&self.flattened(), style)
+    }
+}
+
+impl<C: PixelColor> StyledDrawable<PrimitiveStyle<C>> for CubicBezier {
+    type Color = C;
+    type Output = ();
+
+    fn draw_styled<D>(
+        &self,
+        style: &PrimitiveStyle<C>,
+        target: &mut D,
+    ) -> Result<Self::Output, D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        draw_flattened(&self.flattened(), style, target)
+    }
+}
+
+impl<C: PixelColor> StyledDimensions<PrimitiveStyle<C>> for CubicBezier {
+    fn styled_bounding_box(&self, style: &PrimitiveStyle<C>) -> Rectangle {
+        flattened_bounding_box(&self.flattened(), style)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        iterator::PixelIteratorExt,
+        mock_display::MockDisplay,
+        pixelcolor::BinaryColor,
+        primitives::{
+            DashPattern, PointsIter, Primitive, PrimitiveStyleBuilder, StrokeAlignment, StrokeStyle,
+        },
+        Drawable,
+    };
+
+    const CURVE: CubicBezier = CubicBezier::new(
+        Point::new(4, 40),
+        Point::new(20, -10),
+        Point::new(30, 70),
+        Point::new(56, 20),
+    );
+
+    #[test]
+    fn one_px_stroke() {
+        let mut display = MockDisplay::new();
+
+        QuadraticBezier::new(Point::new(0, 6), Point::new(8, -6), Point::new(16, 6))
+            .into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 1))
+            .draw(&mut display)
+            .unwrap();
+
+        display.assert_pattern(&[
+            "      #####       ",
+            "     #     #      ",
+            "    #       ##    ",
+            "  ##          #   ",
+            " #             #  ",
+            " #             #  ",
+            "#               # ",
+        ]);
+    }
+
+    #[test]
+    fn thick_stroke() {
+        let mut display = MockDisplay::new();
+
+        QuadraticBezier::new(Point::new(1, 8), Point::new(9, -6), Point::new(17, 8))
+            .into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 3))
+            .draw(&mut display)
+            .unwrap();
+
+        display.assert_pattern(&[
+            "      #######      ",
+            "     #########     ",
+            "    ###########    ",
+            "   #####   #####   ",
+            "  #####     #####  ",
+            "  ####       ####  ",
+            " ####         #### ",
+            "####           ####",
+            " ##             ## ",
+        ]);
+    }
+
+    #[test]
+    fn one_px_stroke_matches_points() {
+        let mut expected = MockDisplay::new();
+        for point in CURVE.points() {
+            expected.set_pixel(point, Some(BinaryColor::On));
+        }
+
+        let mut display = MockDisplay::new();
+        CURVE
+            .into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 1))
+            .draw(&mut display)
+            .unwrap();
+
+        display.assert_eq(&expected);
+    }
+
+    #[test]
+    fn one_px_stroke_doubling_back() {
+        // The second half of the curve runs back over the first half.
+        let curve = QuadraticBezier::new(Point::new(5, 5), Point::new(60, 5), Point::new(0, 5));
+
+        let mut display = MockDisplay::new();
+        curve
+            .into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 1))
+            .draw(&mut display)
+            .unwrap();
+
+        assert_eq!(
+            display.affected_area(),
+            Rectangle::new(Point::new(0, 5), Size::new(32, 1))
+        );
+    }
+
+    #[test]
+    fn pixels_match_draw() {
+        let quadratic = QuadraticBezier::new(Point::new(5, 5), Point::new(60, 5), Point::new(0, 5));
+
+        for stroke_style in [
+            StrokeStyle::Solid,
+            StrokeStyle::Dashed(DashPattern::new(5, 3)),
+            StrokeStyle::Dotted,
+        ] {
+            for stroke_width in [0, 1, 4] {
+                let style = PrimitiveStyleBuilder::new()
+                    .stroke_color(BinaryColor::On)
+                    .stroke_width(stroke_width)
+                    .stroke_style(stroke_style)
+                    .build();
+
+                let mut expected = MockDisplay::new();
+                CURVE.draw_styled(&style, &mut expected).unwrap();
+
+                let mut pixels = MockDisplay::new();
+                CURVE.pixels(&style).draw(&mut pixels).unwrap();
+
+                pixels.assert_eq(&expected);
+
+                let mut expected = MockDisplay::new();
+                quadratic.draw_styled(&style, &mut expected).unwrap();
+
+                let mut pixels = MockDisplay::new();
+                quadratic.pixels(&style).draw(&mut pixels).unwrap();
+
+                pixels.assert_eq(&expected);
+            }
+        }
+    }
+
+    #[test]
+    fn thick_stroke_covers_thin_stroke() {
+        let mut thin = MockDisplay::new();
+        CURVE
+            .into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 1))
+            .draw(&mut thin)
+            .unwrap();
+
+        for stroke_width in 2..8 {
+            let mut thick = MockDisplay::new();
+            CURVE
+                .into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, stroke_width))
+                .draw(&mut thick)
+                .unwrap();
+
+            for point in thin.affected_area().points() {
+                if thin.get_pixel(point).is_some() {
+                    assert_eq!(
+                        thick.get_pixel(point),
+                        Some(BinaryColor::On),
+                        "{:?}, {} px",
+                        point,
+                        stroke_width
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn styled_bounding_box() {
+        let quadratic =
+            QuadraticBezier::new(Point::new(5, 50), Point::new(32, 0), Point::new(59, 50));
+
+        for stroke_width in 1..8 {
+            let style = PrimitiveStyle::with_stroke(BinaryColor::On, stroke_width);
+
+            let mut display = MockDisplay::new();
+            CURVE.into_styled(style).draw(&mut display).unwrap();
+            assert_eq!(
+                display.affected_area(),
+                CURVE.into_styled(style).bounding_box(),
+                "cubic, {} px",
+                stroke_width
+            );
+
+            let mut display = MockDisplay::new();
+            quadratic.into_styled(style).draw(&mut display).unwrap();
+            assert_eq!(
+                display.affected_area(),
+                quadratic.into_styled(style).bounding_box(),
+                "quadratic, {} px",
+                stroke_width
+            );
+        }
+    }
+
+    #[test]
+    fn stroke_alignment_is_ignored() {
+        let style = PrimitiveStyle::with_stroke(BinaryColor::On, 5);
+
+        let mut expected = MockDisplay::new();
+        CURVE.into_styled(style).draw(&mut expected).unwrap();
+
+        for stroke_alignment in [StrokeAlignment::Inside, StrokeAlignment::Outside] {
+            let style = PrimitiveStyleBuilder::from(&style)
+                .stroke_alignment(stroke_alignment)
+                .build();
+
+            let mut display = MockDisplay::new();
+            CURVE.into_styled(style).draw(&mut display).unwrap();
+
+            display.assert_eq(&expected);
+        }
+    }
+
+    #[test]
+    fn dashed_stroke_is_subset_of_solid() {
+        for stroke_width in [1, 4] {
+            let solid_style = PrimitiveStyle::with_stroke(BinaryColor::On, stroke_width);
+            let dashed_style = PrimitiveStyleBuilder::from(&solid_style)
+                .stroke_style(StrokeStyle::Dashed(DashPattern::new(5, 3)))
+                .build();
+
+            let mut solid = MockDisplay::new();
+            CURVE.into_styled(solid_style).draw(&mut solid).unwrap();
+
+            let mut dashed = MockDisplay::new();
+            CURVE.into_styled(dashed_style).draw(&mut dashed).unwrap();
+
+            let solid_count = solid
+                .affected_area()
+                .points()
+                .filter(|p| solid.get_pixel(*p).is_some())
+                .count();
+            let dashed_count = dashed
+                .affected_area()
+                .points()
+                .filter(|p| dashed.get_pixel(*p).is_some())
+                .count();
+            assert!(dashed_count > 0 && dashed_count < solid_count);
+
+            for point in dashed.affected_area().points() {
+                if dashed.get_pixel(point).is_some() {
+                    assert_eq!(solid.get_pixel(point), Some(BinaryColor::On));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn transparent_stroke() {
+        let mut display = MockDisplay::<BinaryColor>::new();
+        CURVE
+            .into_styled(PrimitiveStyle::with_fill(BinaryColor::On))
+            .draw(&mut display)
+            .unwrap();
+
+        display.assert_pattern(&[]);
+    }
+
+    #[test]
+    fn dotted_stroke() {
+        crate::primitives::common::check_dotted_stroke(CURVE);
+        crate::primitives::common::check_dotted_stroke(QuadraticBezier::new(
+            Point::new(5, 30),
+            Point::new(30, 0),
+            Point::new(55, 30),
+        ));
+    }
+}
@@ -0,0 +1,271 @@
+//! Anti-aliased circles.
+
+use core::ops::Range;
+
+use crate::{
+    geometry::{Dimensions, Point},
+    pixelcolor::PixelColor,
+    primitives::{
+        circle::Circle,
+        common::{first_run, isqrt, AntiAliasedShape, Coverage},
+        AntiAliasedStyle, Rectangle,
+    },
+};
+
+/// Anti-aliased circle.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub(in crate::primitives) struct AntiAliasedCircle {
+    /// Outside edge of the stroke.
+    outside_edge: Circle,
+
+    /// Inside edge of the stroke.
+    inside_edge: Circle,
+
+    has_fill: bool,
+}
+
+impl AntiAliasedCircle {
+    /// Returns the distance between the center of the circle and a point in 1/256 px.
+    fn radius(&self, point: Point) -> i64 {
+        let delta = point * 2 - self.outside_edge.center_2x();
+        let length_squared = i64::from(delta.x).pow(2) + i64::from(delta.y).pow(2);
+
+        isqrt((length_squared as u64) << 14) as i64
+    }
+
+    /// Returns the distances between a point and both edges of the stroke in 1/256 px.
+    pub(in crate::primitives) fn distances(&self, point: Point) -> (i64, i64) {
+        let radius = self.radius(point);
+
+        (
+            radius - i64::from(self.outside_edge.diameter) * 128,
+            radius - i64::from(self.inside_edge.diameter) * 128,
+        )
+    }
+
+    /// Returns the stroke coverage of a pixel.
+    pub(in crate::primitives) fn stroke_coverage(&self, point: Point) -> u32 {
+        let (outside, inside) = self.distances(point);
+
+        Coverage::from_edges(outside, inside, false).stroke
+    }
+
+    /// Returns the range of pixels in a row that can be covered by the stroke or fill.
+    pub(in crate::primitives) fn row(&self, y: i32) -> Option<Range<i32>> {
+        chord(&self.outside_edge, y, 1)
+    }
+
+    /// Returns the range of pixels in a row that are completely inside the inside edge.
+    pub(in crate::primitives) fn hole(&self, y: i32) -> Option<Range<i32>> {
+        chord(&self.inside_edge, y, -1)
+    }
+}
+
+/// Returns the range of pixels in a row whose center is closer than `diameter + offset` half
+/// pixels to the center of the circle.
+fn chord(circle: &Circle, y: i32, offset: i64) -> Option<Range<i32>> {
+    let center_2x = circle.center_2x();
+    let dy = i64::from(2 * y - center_2x.y);
+    let diameter = i64::from(circle.diameter) + offset;
+
+    let remainder = diameter * diameter - dy * dy;
+    if diameter <= 0 || remainder <= 0 {
+        return None;
+    }
+
+    let half_width = isqrt((remainder - 1) as u64) as i64;
+    let start = (i64::from(center_2x.x) - half_width + 1).div_euclid(2);
+    let end = (i64::from(center_2x.x) + half_width).div_euclid(2) + 1;
+
+    (start < end).then_some(start as i32..end as i32)
+}
+
+impl AntiAliasedShape for AntiAliasedCircle {
+    fn area(&self) -> Rectangle {
+        self.outside_edge.bounding_box()
+    }
+
+    fn run(&self, y: i32, x: i32) -> Option<Range<i32>> {
+        let row = self.row(y)?;
+
+        match self.hole(y).filter(|_| !self.has_fill) {
+            Some(hole) => first_run(x, [row.start..hole.start, hole.end..row.end].into_iter()),
+            None => first_run(x, core::iter::once(row)),
+        }
+    }
+
+    fn coverage(&self, point: Point) -> Coverage {
+        let (outside, inside) = self.distances(point);
+
+        Coverage::from_edges(outside, inside, self.has_fill)
+    }
+}
+
+impl Circle {
+    /// Returns the anti-aliased shape of the circle.
+    pub(in crate::primitives) fn anti_aliased<C: PixelColor>(
+        &self,
+        style: &AntiAliasedStyle<C>,
+    ) -> AntiAliasedCircle {
+        let (outside_edge, inside_edge) = style.stroke_edges(self);
+
+        AntiAliasedCircle {
+            outside_edge,
+            inside_edge,
+            has_fill: style.primitive_style.fill_color.is_some(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        geometry::PointExt,
+        mock_display::MockDisplay,
+        pixelcolor::{Gray8, GrayColor},
+        primitives::{
+            PointsIter, Primitive, PrimitiveStyle, PrimitiveStyleBuilder, StrokeAlignment,
+        },
+        Drawable,
+    };
+
+    fn draw(circle: Circle, style: PrimitiveStyle<Gray8>) -> MockDisplay<Gray8> {
+        let mut display = MockDisplay::new();
+        circle
+            .into_styled(AntiAliasedStyle::new(style, Gray8::BLACK))
+            .draw(&mut display)
+            .unwrap();
+
+        display
+    }
+
+    #[test]
+    fn filled_circle() {
+        let display = draw(
+            Circle::new(Point::new(0, 0), 5),
+            PrimitiveStyle::with_fill(Gray8::WHITE),
+        );
+
+        let a = 0x2C;
+        let b = 0xC3;
+        let f = 0xFF;
+        let expected = [
+            [a, b, f, b, a],
+            [b, f, f, f, b],
+            [f, f, f, f, f],
+            [b, f, f, f, b],
+            [a, b, f, b, a],
+        ];
+
+        for (y, row) in expected.iter().enumerate() {
+            for (x, luma) in row.iter().enumerate() {
+                let point = Point::new(x as i32, y as i32);
+
+                assert_eq!(
+                    display.get_pixel(point),
+                    Some(Gray8::new(*luma)),
+                    "{:?}",
+                    point
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn interior_is_solid() {
+        for diameter in [4, 7, 20] {
+            for stroke_width in [0, 1, 3] {
+                let circle = Circle::new(Point::new(2, 3), diameter);
+                let style = PrimitiveStyleBuilder::new()
+                    .stroke_color(Gray8::WHITE)
+                    .stroke_width(stroke_width)
+                    .fill_color(Gray8::new(100))
+                    .build();
+
+                let mut aliased = MockDisplay::new();
+                circle.into_styled(style).draw(&mut aliased).unwrap();
+
+                let display = draw(circle, style);
+
+                // Pixels that are fully covered have the same color as the aliased circle, except
+                // for the edge pixels where the aliased circle rounds differently.
+                for point in circle.bounding_box().offset(-1).points() {
+                    let color = display.get_pixel(point);
+
+                    if color == Some(Gray8::WHITE) || color == Some(Gray8::new(100)) {
+                        assert!(aliased.get_pixel(point).is_some(), "{:?}", point);
+                    }
+                }
+
+                let center = circle.center();
+                if diameter > 2 * stroke_width + 2 {
+                    assert_eq!(display.get_pixel(center), Some(Gray8::new(100)));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn stroke_alignment() {
+        let circle = Circle::with_center(Point::new(30, 30), 31);
+
+        for (alignment, outside, inside) in [
+            (StrokeAlignment::Inside, 31, 23),
+            (StrokeAlignment::Center, 35, 27),
+            (StrokeAlignment::Outside, 39, 31),
+        ] {
+            let style = PrimitiveStyleBuilder::new()
+                .stroke_color(Gray8::WHITE)
+                .stroke_width(4)
+                .stroke_alignment(alignment)
+                .build();
+
+            let shape = circle.anti_aliased(&AntiAliasedStyle::new(style, Gray8::BLACK));
+            assert_eq!(shape.outside_edge.diameter, outside, "{:?}", alignment);
+            assert_eq!(shape.inside_edge.diameter, inside, "{:?}", alignment);
+        }
+    }
+
+    #[test]
+    fn stroke_is_blended() {
+        let display = draw(
+            Circle::with_center(Point::new(30, 30), 41),
+            PrimitiveStyle::with_stroke(Gray8::WHITE, 3),
+        );
+
+        let mut blended = 0;
+        for point in display.affected_area().points() {
+            let distance = (point - Point::new(30, 30)).length_squared();
+
+            match display.get_pixel(point) {
+                Some(color) if color.luma() < 255 => blended += 1,
+                Some(_) => assert!((18 * 18..=22 * 22).contains(&distance), "{:?}", point),
+                None => {}
+            }
+        }
+
+        assert!(blended > 0);
+        assert_eq!(display.get_pixel(Point::new(30, 30)), None);
+    }
+
+    #[test]
+    fn bounding_box() {
+        for stroke_width in [0, 1, 4] {
+            let styled = Circle::new(Point::new(10, 10), 25).into_styled(AntiAliasedStyle::new(
+                PrimitiveStyleBuilder::new()
+                    .stroke_color(Gray8::WHITE)
+                    .stroke_width(stroke_width)
+                    .fill_color(Gray8::new(100))
+                    .build(),
+                Gray8::BLACK,
+            ));
+
+            let mut display = MockDisplay::new();
+            styled.draw(&mut display).unwrap();
+
+            assert_eq!(display.affected_area(), styled.bounding_box());
+        }
+    }
+}
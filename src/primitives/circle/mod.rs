@@ -5,9 +5,10 @@ use crate::{
     primitives::{
         common::DistanceIterator, ContainsPoint, OffsetOutline, PointsIter, Primitive, Rectangle,
     },
-    transform::Transform,
+    transform::{GeometricTransform, Transform, Transformation},
 };
 
+pub(in crate::primitives) mod anti_aliased;
 mod points;
 mod styled;
 
@@ -177,6 +178,25 @@ impl Transform for Circle {
     }
 }
 
+impl GeometricTransform for Circle {
+    type Output = Circle;
+
+    fn transform(&self, transformation: &Transformation) -> Self::Output {
+        if let Some(bounding_box) = transformation.transform_rectangle(&self.bounding_box()) {
+            return Self::new(bounding_box.top_left, bounding_box.size.width);
+        }
+
+        // Circles keep their shape if they are rotated by an arbitrary angle.
+        let center_2x = transformation.transform_point_2x(self.center_2x());
+        let top_left_2x = center_2x - Point::new_equal(self.diameter.saturating_sub(1) as i32);
+
+        Self::new(
+            Point::new(top_left_2x.x.div_euclid(2), top_left_2x.y.div_euclid(2)),
+            self.diameter,
+        )
+    }
+}
+
 pub(in crate::primitives) const fn diameter_to_threshold(diameter: u32) -> u32 {
     if diameter <= 4 {
         diameter.pow(2) - diameter / 2
@@ -189,7 +209,7 @@ pub(in crate::primitives) const fn diameter_to_threshold(diameter: u32) -> u32 {
 mod tests {
     use super::*;
     use crate::{
-        geometry::{Dimensions, Point, Size},
+        geometry::{AngleUnit, Dimensions, Point, Size},
         primitives::ContainsPoint,
     };
 
@@ -257,4 +277,25 @@ mod tests {
         assert_eq!(circle.offset(-2), Circle::with_center(center, 0));
         assert_eq!(circle.offset(-3), Circle::with_center(center, 0));
     }
+
+    #[test]
+    fn geometric_transform() {
+        let circle = Circle::new(Point::new(2, 3), 5);
+
+        assert_eq!(
+            circle.rotate(Point::zero(), 90.0.deg()),
+            Circle::new(Point::new(-7, 2), 5)
+        );
+        assert_eq!(
+            circle.mirror_vertical(10),
+            Circle::new(Point::new(2, 13), 5)
+        );
+        assert_eq!(
+            circle.scale(Point::new(1, 1), 2),
+            Circle::new(Point::new(3, 5), 10)
+        );
+
+        let rotated = Circle::with_center(Point::new(10, 0), 5).rotate(Point::zero(), 45.0.deg());
+        assert_eq!(rotated, Circle::with_center(Point::new(7, 7), 5));
+    }
 }
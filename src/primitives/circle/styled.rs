@@ -4,7 +4,7 @@ use crate::{
     pixelcolor::PixelColor,
     primitives::{
         circle::{points::Scanlines, Circle},
-        common::{Scanline, StyledScanline},
+        common::{Band, CircleOutline, PatternedStroke, Scanline, StyledScanline},
         rectangle::Rectangle,
         styled::{StyledDimensions, StyledDrawable, StyledPixels},
         PrimitiveStyle,
@@ -25,6 +25,8 @@ pub struct StyledPixelsIterator<C> {
 
     stroke_color: Option<C>,
     fill_color: Option<C>,
+
+    pattern: Option<PatternedStroke<CircleOutline, Band<Circle>, Circle>>,
 }
 
 impl<C: PixelColor> StyledPixelsIterator<C> {
@@ -39,17 +41,33 @@ impl<C: PixelColor> StyledPixelsIterator<C> {
             stroke_right: Scanline::new_empty(0),
             stroke_color: style.stroke_color,
             fill_color: style.fill_color,
+            pattern: patterned_stroke(primitive, style),
         }
     }
 }
 
-impl<C> Iterator for StyledPixelsIterator<C>
-where
-    C: PixelColor,
-{
+impl<C: PixelColor> Iterator for StyledPixelsIterator<C> {
     type Item = Pixel<C>;
 
     fn next(&mut self) -> Option<Self::Item> {
+        if let (Some(pattern), Some(stroke_color)) = (&self.pattern, self.stroke_color) {
+            loop {
+                if let Some(point) = self.stroke_left.next().or_else(|| self.stroke_right.next()) {
+                    if let Some(color) = pattern.color(point, stroke_color, self.fill_color) {
+                        return Some(Pixel(point, color));
+                    }
+                } else if let (Some(point), Some(fill_color)) = (self.fill.next(), self.fill_color)
+                {
+                    return Some(Pixel(point, fill_color));
+                } else {
+                    let scanline = self.styled_scanlines.next()?;
+                    self.stroke_left = scanline.stroke_left();
+                    self.fill = scanline.fill();
+                    self.stroke_right = scanline.stroke_right();
+                }
+            }
+        }
+
         match (self.stroke_color, self.fill_color) {
             (Some(stroke_color), None) => loop {
                 if let Some(pixel) = self
@@ -94,6 +112,27 @@ where
     }
 }
 
+/// Returns the dashed or dotted stroke if the style uses a non solid stroke.
+fn patterned_stroke<C: PixelColor>(
+    primitive: &Circle,
+    style: &PrimitiveStyle<C>,
+) -> Option<PatternedStroke<CircleOutline, Band<Circle>, Circle>> {
+    let outline = CircleOutline::new(&style.stroke_center_outline(primitive));
+
+    if let Some(dot_size) = style.dot_size() {
+        let area = Band::new(
+            style.stroke_area(primitive),
+            style.stroke_inner_area(primitive),
+        );
+
+        return Some(PatternedStroke::dotted(outline, area, dot_size, *primitive));
+    }
+
+    style
+        .dash_pattern()
+        .map(|pattern| PatternedStroke::dashed(outline, pattern, *primitive))
+}
+
 impl<C: PixelColor> StyledPixels<PrimitiveStyle<C>> for Circle {
     type Iter = StyledPixelsIterator<C>;
 
@@ -114,6 +153,17 @@ impl<C: PixelColor> StyledDrawable<PrimitiveStyle<C>> for Circle {
     where
         D: DrawTarget<Color = C>,
     {
+        if let (Some(stroke_color), Some(pattern)) = (
+            style.effective_stroke_color(),
+            patterned_stroke(self, style),
+        ) {
+            for scanline in StyledScanlines::new(&style.stroke_area(self), &style.fill_area(self)) {
+                scanline.draw_patterned(target, &pattern, stroke_color, style.fill_color)?;
+            }
+
+            return Ok(());
+        }
+
         match (style.effective_stroke_color(), style.fill_color) {
             (Some(stroke_color), None) => {
                 for scanline in
@@ -192,7 +242,8 @@ mod tests {
         mock_display::MockDisplay,
         pixelcolor::BinaryColor,
         primitives::{
-            OffsetOutline, PointsIter, Primitive, PrimitiveStyleBuilder, StrokeAlignment, Styled,
+            OffsetOutline, PointsIter, Primitive, PrimitiveStyleBuilder, StrokeAlignment,
+            StrokeStyle, Styled,
         },
         Drawable,
     };
@@ -492,4 +543,41 @@ mod tests {
             filled_circle.bounding_box(),
         );
     }
+
+    #[test]
+    fn dashed_stroke() {
+        crate::primitives::common::check_dashed_stroke(Circle::new(Point::new(6, 7), 30));
+    }
+
+    #[test]
+    fn dotted_stroke() {
+        crate::primitives::common::check_dotted_stroke(Circle::new(Point::new(6, 7), 30));
+    }
+
+    #[test]
+    fn dotted_small_circle() {
+        let style = PrimitiveStyleBuilder::new()
+            .stroke_color(BinaryColor::On)
+            .stroke_width(1)
+            .stroke_style(StrokeStyle::Dotted)
+            .build();
+
+        let mut display = MockDisplay::new();
+        Circle::new(Point::new(0, 0), 9)
+            .into_styled(style)
+            .draw(&mut display)
+            .unwrap();
+
+        display.assert_pattern(&[
+            "    #    ",
+            "  #   #  ",
+            " #     # ",
+            "         ",
+            "#       #",
+            "         ",
+            " #     # ",
+            "  #   #  ",
+            "    #    ",
+        ]);
+    }
 }
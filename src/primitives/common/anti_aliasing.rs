@@ -0,0 +1,287 @@
+//! Anti-aliasing.
+//!
+//! Anti-aliased shapes are described by the signed distance between a pixel center and the edges
+//! of the shape. All distances are scaled by [`DISTANCE_SCALE`] and are positive outside the
+//! shape.
+
+use core::ops::Range;
+
+use crate::{
+    draw_target::DrawTarget,
+    geometry::Point,
+    image::GetPixel,
+    pixelcolor::{PixelColor, Rgb888, RgbColor},
+    primitives::{AntiAliasedStyle, Rectangle},
+    Pixel,
+};
+
+/// Scaling factor for distances.
+pub const DISTANCE_SCALE: i64 = 256;
+
+/// Coverage of a pixel that is completely covered by a shape.
+pub const FULL_COVERAGE: u32 = DISTANCE_SCALE as u32;
+
+/// Returns the coverage of a pixel by a shape.
+///
+/// The pixel is assumed to be a square and the edge of the shape a straight line that is parallel
+/// to one side of the pixel. This approximation is good enough for anti-aliasing, because the
+/// coverage only changes the color of the pixel.
+pub fn edge_coverage(distance: i64) -> u32 {
+    (DISTANCE_SCALE / 2 - distance).clamp(0, DISTANCE_SCALE) as u32
+}
+
+/// Coverage of a pixel by the fill and the stroke of a shape.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub struct Coverage {
+    /// Fill coverage.
+    pub fill: u32,
+
+    /// Stroke coverage.
+    pub stroke: u32,
+}
+
+impl Coverage {
+    /// Creates a new stroke coverage.
+    pub const fn stroke(stroke: u32) -> Self {
+        Self { fill: 0, stroke }
+    }
+
+    /// Creates a new coverage from the distances to the outside and inside edge of the stroke.
+    ///
+    /// Shapes without a stroke should use the same distance for both edges.
+    pub fn from_edges(outside_edge: i64, inside_edge: i64, has_fill: bool) -> Self {
+        let total = edge_coverage(outside_edge);
+        let inside = edge_coverage(inside_edge).min(total);
+
+        Self {
+            fill: if has_fill { inside } else { 0 },
+            stroke: total - inside,
+        }
+    }
+
+    /// Returns `true` if the pixel isn't covered.
+    pub const fn is_empty(&self) -> bool {
+        self.fill == 0 && self.stroke == 0
+    }
+
+    /// Returns `true` if the pixel is completely covered.
+    pub const fn is_full(&self) -> bool {
+        self.fill + self.stroke >= FULL_COVERAGE
+    }
+
+    /// Returns the color of the pixel.
+    pub fn color<C>(&self, style: &AntiAliasedStyle<C>, background_color: C) -> C
+    where
+        C: PixelColor + From<Rgb888> + Into<Rgb888>,
+    {
+        let fill_color = style.primitive_style.fill_color.unwrap_or(background_color);
+        let stroke_color = style
+            .primitive_style
+            .stroke_color
+            .unwrap_or(background_color);
+
+        if self.stroke >= FULL_COVERAGE {
+            return stroke_color;
+        } else if self.fill >= FULL_COVERAGE {
+            return fill_color;
+        }
+
+        let background_coverage = FULL_COVERAGE.saturating_sub(self.fill + self.stroke);
+        let colors = [
+            (background_color.into(), background_coverage),
+            (fill_color.into(), self.fill),
+            (stroke_color.into(), self.stroke),
+        ];
+
+        let channel = |value: fn(&Rgb888) -> u8| {
+            let sum: u32 = colors
+                .iter()
+                .map(|(color, coverage)| u32::from(value(color)) * coverage)
+                .sum();
+
+            ((sum + FULL_COVERAGE / 2) / FULL_COVERAGE) as u8
+        };
+
+        Rgb888::new(channel(Rgb888::r), channel(Rgb888::g), channel(Rgb888::b)).into()
+    }
+}
+
+/// Anti-aliased shape.
+pub trait AntiAliasedShape {
+    /// Returns the area that contains all pixels which are covered by the shape.
+    fn area(&self) -> Rectangle;
+
+    /// Returns the first run of pixels in the given row that ends after `x`.
+    ///
+    /// The run must contain all covered pixels in the range, but may also contain pixels that
+    /// aren't covered.
+    fn run(&self, _y: i32, x: i32) -> Option<Range<i32>> {
+        let columns = self.area().columns();
+
+        (x < columns.end).then(|| columns.start.max(x)..columns.end)
+    }
+
+    /// Returns the coverage of a pixel.
+    fn coverage(&self, point: Point) -> Coverage;
+}
+
+/// Iterator over all covered pixels of an anti-aliased shape.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub struct CoverageIterator<S> {
+    shape: S,
+    rows: Range<i32>,
+    y: Option<i32>,
+    run: Range<i32>,
+}
+
+impl<S: AntiAliasedShape> CoverageIterator<S> {
+    /// Creates a new coverage iterator.
+    pub fn new(shape: S) -> Self {
+        let rows = shape.area().rows();
+
+        Self {
+            shape,
+            rows,
+            y: None,
+            run: 0..0,
+        }
+    }
+}
+
+impl<S: AntiAliasedShape> Iterator for CoverageIterator<S> {
+    type Item = (Point, Coverage);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let next_run = match self.y {
+                Some(y) => {
+                    if let Some(x) = self.run.next() {
+                        let point = Point::new(x, y);
+                        let coverage = self.shape.coverage(point);
+
+                        if !coverage.is_empty() {
+                            return Some((point, coverage));
+                        }
+
+                        continue;
+                    }
+
+                    self.shape.run(y, self.run.end)
+                }
+                None => None,
+            };
+
+            if let Some(run) = next_run {
+                self.run = run;
+            } else {
+                let y = self.rows.next()?;
+                self.y = Some(y);
+                self.run = self.shape.run(y, i32::MIN).unwrap_or(0..0);
+            }
+        }
+    }
+}
+
+/// Draws an anti-aliased shape by blending it with the background color of the style.
+pub fn draw_anti_aliased<S, D>(
+    shape: S,
+    style: &AntiAliasedStyle<D::Color>,
+    target: &mut D,
+) -> Result<(), D::Error>
+where
+    S: AntiAliasedShape,
+    D: DrawTarget,
+    D::Color: From<Rgb888> + Into<Rgb888>,
+{
+    target.draw_iter(
+        CoverageIterator::new(shape)
+            .map(|(point, coverage)| Pixel(point, coverage.color(style, style.background_color))),
+    )
+}
+
+/// Draws an anti-aliased shape by blending it with the current colors of the target.
+///
+/// The background color of the style is used for pixels that can't be read from the target.
+pub fn draw_blended<S, C, D>(
+    shape: S,
+    style: &AntiAliasedStyle<C>,
+    target: &mut D,
+) -> Result<(), D::Error>
+where
+    S: AntiAliasedShape,
+    C: PixelColor + From<Rgb888> + Into<Rgb888>,
+    D: DrawTarget<Color = C> + GetPixel<Color = C>,
+{
+    for (point, coverage) in CoverageIterator::new(shape) {
+        let background_color = if coverage.is_full() {
+            style.background_color
+        } else {
+            target.pixel(point).unwrap_or(style.background_color)
+        };
+
+        let color = coverage.color(style, background_color);
+        target.draw_iter(core::iter::once(Pixel(point, color)))?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        pixelcolor::{Gray8, GrayColor},
+        primitives::PrimitiveStyleBuilder,
+    };
+
+    #[test]
+    fn coverage() {
+        assert_eq!(edge_coverage(-1000), FULL_COVERAGE);
+        assert_eq!(edge_coverage(-128), FULL_COVERAGE);
+        assert_eq!(edge_coverage(0), FULL_COVERAGE / 2);
+        assert_eq!(edge_coverage(64), FULL_COVERAGE / 4);
+        assert_eq!(edge_coverage(128), 0);
+        assert_eq!(edge_coverage(1000), 0);
+    }
+
+    #[test]
+    fn coverage_from_edges() {
+        assert_eq!(
+            Coverage::from_edges(-200, 0, true),
+            Coverage {
+                fill: 128,
+                stroke: 128
+            }
+        );
+
+        assert_eq!(Coverage::from_edges(-200, 0, false), Coverage::stroke(128));
+        assert_eq!(
+            Coverage::from_edges(64, 64, true),
+            Coverage {
+                fill: 64,
+                stroke: 0
+            }
+        );
+    }
+
+    #[test]
+    fn blend_colors() {
+        let style = AntiAliasedStyle::new(
+            PrimitiveStyleBuilder::new()
+                .stroke_color(Gray8::WHITE)
+                .fill_color(Gray8::new(100))
+                .build(),
+            Gray8::new(20),
+        );
+
+        let color = |fill, stroke| Coverage { fill, stroke }.color(&style, style.background_color);
+
+        assert_eq!(color(0, 0), Gray8::new(20));
+        assert_eq!(color(256, 0), Gray8::new(100));
+        assert_eq!(color(0, 256), Gray8::WHITE);
+        assert_eq!(color(128, 0), Gray8::new(60));
+        assert_eq!(color(64, 192), Gray8::new(216));
+    }
+}
@@ -2,7 +2,10 @@
 
 use crate::{
     geometry::Point,
-    primitives::common::{LineJoin, StrokeOffset, ThickSegment},
+    primitives::{
+        common::{SegmentJoin, StrokeOffset, ThickSegment},
+        LineJoin,
+    },
 };
 
 /// Closed shape thick segments iterator.
@@ -10,19 +13,19 @@ use crate::{
 /// Iterates over all line segments in the polyline, returning a 6-sided shape as a [`ThickSegment`]
 /// for each segment. These are tessellated and are used to produce scanline intersections.
 ///
-/// Unlike [`ThickSegmentIter`], this iterator closes the shape with a final line between the
-/// start and end points.
+/// Unlike the segments of an open polyline, this iterator closes the shape with a final line
+/// between the start and end points.
 ///
 /// [`ThickSegment`]: super::thick_segment::ThickSegment
-/// [`ThickSegmentIter`]: super::thick_segment_iter::ThickSegmentIter
 #[derive(Clone, Debug)]
 #[cfg_attr(feature = "defmt", derive(::defmt::Format))]
 pub struct ClosedThickSegmentIter<'a> {
     windows: core::slice::Windows<'a, Point>,
-    first_join: LineJoin,
-    start_join: LineJoin,
+    first_join: SegmentJoin,
+    start_join: SegmentJoin,
     width: u32,
     stroke_offset: StrokeOffset,
+    line_join: LineJoin,
     points: &'a [Point],
     stop: bool,
     idx: usize,
@@ -32,16 +35,22 @@ static EMPTY: &[Point; 0] = &[];
 
 impl<'a> ClosedThickSegmentIter<'a> {
     /// Create a new thick segments iterator.
-    pub fn new(points: &'a [Point], width: u32, stroke_offset: StrokeOffset) -> Self {
+    pub fn new(
+        points: &'a [Point],
+        width: u32,
+        stroke_offset: StrokeOffset,
+        line_join: LineJoin,
+    ) -> Self {
         if let [start, end] = points {
             // Single line segment.
-            let start_join = LineJoin::start(*start, *end, width, stroke_offset);
+            let start_join = SegmentJoin::start(*start, *end, width, stroke_offset);
 
             Self {
                 windows: EMPTY.windows(3),
                 start_join,
                 width,
                 stroke_offset,
+                line_join,
                 points,
                 stop: false,
                 first_join: start_join,
@@ -52,12 +61,13 @@ impl<'a> ClosedThickSegmentIter<'a> {
         } else {
             let windows = points.windows(3);
 
-            let start_join = LineJoin::from_points(
+            let start_join = SegmentJoin::from_points(
                 *points.last().unwrap(),
                 points[0],
                 points[1],
                 width,
                 stroke_offset,
+                line_join,
             );
 
             Self {
@@ -65,6 +75,7 @@ impl<'a> ClosedThickSegmentIter<'a> {
                 start_join,
                 width,
                 stroke_offset,
+                line_join,
                 points,
                 stop: false,
                 first_join: start_join,
@@ -77,12 +88,13 @@ impl<'a> ClosedThickSegmentIter<'a> {
     fn empty() -> Self {
         Self {
             windows: EMPTY.windows(3),
-            start_join: LineJoin::empty(),
+            start_join: SegmentJoin::empty(),
             width: 0,
             stroke_offset: StrokeOffset::None,
+            line_join: LineJoin::const_default(),
             points: EMPTY,
             stop: true,
-            first_join: LineJoin::empty(),
+            first_join: SegmentJoin::empty(),
             idx: 1,
         }
     }
@@ -99,7 +111,14 @@ impl Iterator for ClosedThickSegmentIter<'_> {
         self.idx += 1;
 
         let end_join = if let Some([start, mid, end]) = self.windows.next() {
-            LineJoin::from_points(*start, *mid, *end, self.width, self.stroke_offset)
+            SegmentJoin::from_points(
+                *start,
+                *mid,
+                *end,
+                self.width,
+                self.stroke_offset,
+                self.line_join,
+            )
         } else if self.idx == self.points.len() {
             // The join at the end of the line. This will become the start join of the closing
             // segment.
@@ -107,7 +126,14 @@ impl Iterator for ClosedThickSegmentIter<'_> {
             let mid = self.points.last()?;
             let end = self.points.first()?;
 
-            LineJoin::from_points(*start, *mid, *end, self.width, self.stroke_offset)
+            SegmentJoin::from_points(
+                *start,
+                *mid,
+                *end,
+                self.width,
+                self.stroke_offset,
+                self.line_join,
+            )
         } else {
             // Final closing line between start/end.
             self.stop = true;
@@ -0,0 +1,184 @@
+//! Line joins at the outer corners of rectangular strokes.
+
+use crate::{
+    geometry::Point,
+    primitives::{common::Scanline, LineJoin, Rectangle},
+};
+
+/// Line joins at the outer corners of a rectangular stroke.
+///
+/// The stroke of a rectangle is drawn as a filled rectangle, which results in mitered corners. This
+/// type is used to cut off the pixels outside the bevelled or rounded corners.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub struct CornerJoins {
+    /// Outer edge of the stroke.
+    area: Rectangle,
+
+    stroke_width: u32,
+    line_join: LineJoin,
+
+    /// Corners the joins are applied to, in the order top left, top right, bottom right and
+    /// bottom left.
+    corners: [bool; 4],
+}
+
+impl CornerJoins {
+    /// Creates new corner joins.
+    ///
+    /// Returns `None` if the line join doesn't change the stroke. The joins are also ignored if the
+    /// stroke area is so small that the corners are covered by the opposite side of the stroke.
+    pub fn new(
+        area: &Rectangle,
+        stroke_width: u32,
+        line_join: LineJoin,
+        corners: [bool; 4],
+    ) -> Option<Self> {
+        let joins = Self {
+            area: *area,
+            stroke_width,
+            line_join,
+            corners,
+        };
+
+        let min_size = stroke_width.saturating_mul(2);
+
+        (corners.contains(&true)
+            && area.size.width >= min_size
+            && area.size.height >= min_size
+            && joins.inset(0) > 0)
+            .then_some(joins)
+    }
+
+    /// Returns the number of pixels that are cut off in a row of a corner.
+    ///
+    /// `row` is the distance between the row and the outer edge of the stroke.
+    fn inset(&self, row: u32) -> u32 {
+        // Pixel center coordinates relative to the corner of the stroke center line, in half
+        // pixels. The corner of the stroke center line is `stroke_width / 2` away from both
+        // outer edges.
+        let width = i64::from(self.stroke_width);
+        let b = width - 2 * i64::from(row) - 1;
+
+        if b <= 0 {
+            return 0;
+        }
+
+        let is_cut = |a: i64| match self.line_join {
+            // 90° corners have a miter length of `sqrt(2)` times the stroke width.
+            LineJoin::Miter { limit } if limit >= 2 => false,
+            LineJoin::Miter { .. } | LineJoin::Bevel => a + b > width,
+            LineJoin::Round => a * a + b * b >= width * width,
+        };
+
+        (0..self.stroke_width)
+            .take_while(|column| {
+                let a = width - 2 * i64::from(*column) - 1;
+
+                a > 0 && is_cut(a)
+            })
+            .count() as u32
+    }
+
+    /// Removes the pixels outside the corners from a scanline.
+    pub fn clip(&self, scanline: &Scanline) -> Scanline {
+        let Some(bottom_right) = self.area.bottom_right() else {
+            return scanline.clone();
+        };
+
+        let top_row = scanline.y - self.area.top_left.y;
+        let bottom_row = bottom_right.y - scanline.y;
+
+        let (row, left, right) = if top_row <= bottom_row {
+            (top_row, self.corners[0], self.corners[1])
+        } else {
+            (bottom_row, self.corners[3], self.corners[2])
+        };
+
+        let inset = if row >= 0 { self.inset(row as u32) } else { 0 } as i32;
+
+        let mut x = scanline.x.clone();
+        if left {
+            x.start = x.start.max(self.area.top_left.x + inset);
+        }
+        if right {
+            x.end = x.end.min(bottom_right.x + 1 - inset);
+        }
+        x.end = x.end.max(x.start);
+
+        Scanline::new(scanline.y, x)
+    }
+
+    /// Returns `true` if the point isn't cut off by a corner.
+    pub fn contains(&self, point: Point) -> bool {
+        !self
+            .clip(&Scanline::new(point.y, point.x..point.x + 1))
+            .is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geometry::Size;
+
+    fn insets(line_join: LineJoin, stroke_width: u32) -> [u32; 4] {
+        let area = Rectangle::new(Point::zero(), Size::new_equal(20));
+        let joins = CornerJoins {
+            area,
+            stroke_width,
+            line_join,
+            corners: [true; 4],
+        };
+
+        [0, 1, 2, 3].map(|row| joins.inset(row))
+    }
+
+    #[test]
+    fn inset() {
+        assert_eq!(insets(LineJoin::Bevel, 8), [3, 2, 1, 0]);
+        assert_eq!(insets(LineJoin::Round, 8), [2, 1, 0, 0]);
+        assert_eq!(insets(LineJoin::Miter { limit: 1 }, 8), [3, 2, 1, 0]);
+        assert_eq!(insets(LineJoin::Miter { limit: 2 }, 8), [0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn no_joins_for_small_areas() {
+        let area = Rectangle::new(Point::zero(), Size::new(15, 16));
+
+        assert_eq!(CornerJoins::new(&area, 8, LineJoin::Bevel, [true; 4]), None);
+        assert_eq!(
+            CornerJoins::new(&area, 7, LineJoin::Bevel, [false; 4]),
+            None
+        );
+        assert!(CornerJoins::new(&area, 7, LineJoin::Bevel, [true; 4]).is_some());
+        assert_eq!(
+            CornerJoins::new(&area, 7, LineJoin::default(), [true; 4]),
+            None
+        );
+    }
+
+    #[test]
+    fn clip() {
+        let area = Rectangle::new(Point::new(1, 2), Size::new(20, 10));
+        let joins =
+            CornerJoins::new(&area, 4, LineJoin::Bevel, [true, false, true, false]).unwrap();
+
+        assert_eq!(
+            joins.clip(&Scanline::new(2, 1..21)),
+            Scanline::new(2, 2..21)
+        );
+        assert_eq!(
+            joins.clip(&Scanline::new(11, 1..21)),
+            Scanline::new(11, 1..20)
+        );
+        assert_eq!(
+            joins.clip(&Scanline::new(5, 1..21)),
+            Scanline::new(5, 1..21)
+        );
+
+        assert!(!joins.contains(Point::new(1, 2)));
+        assert!(joins.contains(Point::new(2, 2)));
+        assert!(joins.contains(Point::new(20, 2)));
+    }
+}
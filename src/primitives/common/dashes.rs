@@ -0,0 +1,216 @@
+use crate::{
+    geometry::Point,
+    pixelcolor::PixelColor,
+    primitives::{
+        common::{Dots, Outline, StrokeArea, OUTLINE_SCALE},
+        ContainsPoint, DashPattern,
+    },
+};
+
+/// Dashes along an outline.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub struct Dashes<O> {
+    outline: O,
+    dash_length: u32,
+    period: u32,
+    offset: u32,
+}
+
+impl<O: Outline> Dashes<O> {
+    /// Creates new dashes.
+    pub fn new(outline: O, pattern: DashPattern) -> Self {
+        let period = pattern.period().saturating_mul(OUTLINE_SCALE);
+
+        Self {
+            outline,
+            dash_length: pattern.dash_length.saturating_mul(OUTLINE_SCALE),
+            period,
+            offset: pattern
+                .offset
+                .saturating_mul(OUTLINE_SCALE)
+                .checked_rem(period)
+                .unwrap_or_default(),
+        }
+    }
+
+    /// Returns `true` if the point is part of a dash.
+    pub fn contains(&self, point: Point) -> bool {
+        if self.period == 0 {
+            return false;
+        }
+
+        let position = u64::from(self.outline.position(point)) + u64::from(self.offset);
+
+        position % u64::from(self.period) < u64::from(self.dash_length)
+    }
+}
+
+/// Dashes or dots along an outline.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub enum StrokePattern<O, A> {
+    /// Dashed stroke.
+    Dashes(Dashes<O>),
+
+    /// Dotted stroke.
+    Dots(Dots<O, A>),
+}
+
+impl<O: Outline, A: StrokeArea> StrokePattern<O, A> {
+    /// Returns `true` if the point is part of a dash or a dot.
+    pub fn contains(&self, point: Point) -> bool {
+        match self {
+            Self::Dashes(dashes) => dashes.contains(point),
+            Self::Dots(dots) => dots.contains(point),
+        }
+    }
+}
+
+/// Dashed or dotted stroke of a closed shape.
+///
+/// The gaps between the dashes or dots are filled with the fill color if they are inside the
+/// shape.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub struct PatternedStroke<O, A, P> {
+    pattern: StrokePattern<O, A>,
+    primitive: P,
+}
+
+impl<O: Outline, A: StrokeArea, P: ContainsPoint> PatternedStroke<O, A, P> {
+    /// Creates a new dashed stroke.
+    pub fn dashed(outline: O, pattern: DashPattern, primitive: P) -> Self {
+        Self {
+            pattern: StrokePattern::Dashes(Dashes::new(outline, pattern)),
+            primitive,
+        }
+    }
+
+    /// Creates a new dotted stroke.
+    pub fn dotted(outline: O, area: A, dot_size: u32, primitive: P) -> Self {
+        Self {
+            pattern: StrokePattern::Dots(Dots::new(outline, area, dot_size)),
+            primitive,
+        }
+    }
+
+    /// Returns the color of a pixel inside the stroke area.
+    pub fn color<C: PixelColor>(
+        &self,
+        point: Point,
+        stroke_color: C,
+        fill_color: Option<C>,
+    ) -> Option<C> {
+        if self.pattern.contains(point) {
+            Some(stroke_color)
+        } else {
+            fill_color.filter(|_| self.primitive.contains(point))
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use crate::{
+        geometry::Point,
+        iterator::PixelIteratorExt,
+        mock_display::MockDisplay,
+        pixelcolor::BinaryColor,
+        primitives::{
+            styled::{StyledDrawable, StyledPixels},
+            Circle, ContainsPoint, DashPattern, PointsIter, Primitive, PrimitiveStyle,
+            PrimitiveStyleBuilder, StrokeAlignment, StrokeStyle,
+        },
+        Drawable, Pixel,
+    };
+
+    /// Checks the dashed stroke of a closed primitive against a solid stroke.
+    ///
+    /// Dashes must only be drawn inside the solid stroke, gaps inside the primitive must be filled
+    /// and the pixel iterator must return the same pixels that are drawn by `draw`.
+    pub fn check_dashed_stroke<P>(primitive: P)
+    where
+        P: Primitive
+            + ContainsPoint
+            + StyledDrawable<PrimitiveStyle<BinaryColor>, Color = BinaryColor, Output = ()>
+            + StyledPixels<PrimitiveStyle<BinaryColor>>
+            + Copy,
+        <P as StyledPixels<PrimitiveStyle<BinaryColor>>>::Iter: Iterator<Item = Pixel<BinaryColor>>,
+    {
+        for stroke_alignment in [
+            StrokeAlignment::Inside,
+            StrokeAlignment::Center,
+            StrokeAlignment::Outside,
+        ] {
+            for stroke_width in [1, 4] {
+                let solid_style = PrimitiveStyleBuilder::new()
+                    .stroke_color(BinaryColor::On)
+                    .stroke_width(stroke_width)
+                    .stroke_alignment(stroke_alignment)
+                    .fill_color(BinaryColor::Off)
+                    .build();
+
+                let dashed_style = PrimitiveStyleBuilder::from(&solid_style)
+                    .stroke_style(StrokeStyle::Dashed(DashPattern::new(5, 3)))
+                    .build();
+
+                let mut solid = MockDisplay::new();
+                primitive.draw_styled(&solid_style, &mut solid).unwrap();
+
+                let mut dashed = MockDisplay::new();
+                primitive.draw_styled(&dashed_style, &mut dashed).unwrap();
+
+                let mut pixels = MockDisplay::new();
+                primitive.pixels(&dashed_style).draw(&mut pixels).unwrap();
+
+                dashed.assert_eq(&pixels);
+
+                let mut gaps = 0;
+                for point in solid.affected_area().points() {
+                    let expected = match (solid.get_pixel(point), dashed.get_pixel(point)) {
+                        (Some(BinaryColor::On), Some(BinaryColor::Off)) => {
+                            gaps += 1;
+                            primitive.contains(point)
+                        }
+                        (Some(BinaryColor::On), None) => {
+                            gaps += 1;
+                            !primitive.contains(point)
+                        }
+                        (solid, dashed) => solid == dashed,
+                    };
+
+                    assert!(
+                        expected,
+                        "{:?}, {:?} px: unexpected pixel at {:?}",
+                        stroke_alignment, stroke_width, point
+                    );
+                }
+
+                assert!(
+                    gaps > 0,
+                    "{:?}, {:?} px: no gaps",
+                    stroke_alignment,
+                    stroke_width
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn zero_period() {
+        let style = PrimitiveStyleBuilder::new()
+            .stroke_color(BinaryColor::On)
+            .stroke_width(3)
+            .stroke_style(StrokeStyle::Dashed(DashPattern::new(0, 0)))
+            .build();
+
+        let mut display = MockDisplay::<BinaryColor>::new();
+        Circle::new(Point::new(1, 1), 10)
+            .into_styled(style)
+            .draw(&mut display)
+            .unwrap();
+
+        assert!(display.affected_area().is_zero_sized());
+    }
+}
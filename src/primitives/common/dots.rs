@@ -0,0 +1,509 @@
+use crate::{
+    geometry::{Point, PointExt, Size},
+    primitives::{
+        common::{Outline, Scanline, OUTLINE_SCALE},
+        Circle, ContainsPoint, Line,
+    },
+};
+
+/// Offsets which are used to move a dot to a nearby pixel, ordered by distance.
+const NEIGHBORS: [Point; 9] = [
+    Point::new(0, 0),
+    Point::new(1, 0),
+    Point::new(0, 1),
+    Point::new(-1, 0),
+    Point::new(0, -1),
+    Point::new(1, 1),
+    Point::new(-1, 1),
+    Point::new(-1, -1),
+    Point::new(1, -1),
+];
+
+/// Area of a solid stroke.
+///
+/// The stroke area is used to align the dots with the pixels of the solid stroke.
+pub trait StrokeArea {
+    /// Returns `true` if the point is part of the solid stroke.
+    fn contains(&self, point: Point) -> bool;
+}
+
+/// Dots without a stroke area aren't aligned to the solid stroke.
+impl StrokeArea for () {
+    fn contains(&self, _point: Point) -> bool {
+        true
+    }
+}
+
+/// Stroke area between the outer and inner edge of a closed shape.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub struct Band<P> {
+    outer: P,
+    inner: P,
+}
+
+impl<P> Band<P> {
+    /// Creates a new band.
+    pub const fn new(outer: P, inner: P) -> Self {
+        Self { outer, inner }
+    }
+}
+
+impl<P: ContainsPoint> StrokeArea for Band<P> {
+    fn contains(&self, point: Point) -> bool {
+        self.outer.contains(point) && !self.inner.contains(point)
+    }
+}
+
+/// Stroke area of line segments.
+///
+/// Only 1 px wide strokes are checked, because they consist of the Bresenham lines between the
+/// vertices. Dots on wider strokes always overlap the stroke and don't need to be aligned.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub struct SegmentsArea<V> {
+    vertices: V,
+    translate: Point,
+    closed: bool,
+    thin: bool,
+}
+
+impl<V: AsRef<[Point]>> SegmentsArea<V> {
+    /// Creates the stroke area of an open polyline.
+    pub fn open(vertices: V, translate: Point, stroke_width: u32) -> Self {
+        Self {
+            vertices,
+            translate,
+            closed: false,
+            thin: stroke_width <= 1,
+        }
+    }
+
+    /// Creates the stroke area of a closed polygon.
+    pub fn closed(vertices: V, stroke_width: u32) -> Self {
+        Self {
+            closed: true,
+            ..Self::open(vertices, Point::zero(), stroke_width)
+        }
+    }
+}
+
+impl<V: AsRef<[Point]>> StrokeArea for SegmentsArea<V> {
+    fn contains(&self, point: Point) -> bool {
+        if !self.thin {
+            return true;
+        }
+
+        let vertices = self.vertices.as_ref();
+        let closing = match (vertices.first(), vertices.last()) {
+            (Some(first), Some(last)) if self.closed => Some([*last, *first]),
+            _ => None,
+        };
+
+        let point = point - self.translate;
+
+        vertices
+            .windows(2)
+            .chain(closing.iter().map(|segment| &segment[..]))
+            .any(|segment| {
+                let mut scanline = Scanline::new_empty(point.y);
+                scanline.bresenham_intersection(&Line::new(segment[0], segment[1]));
+
+                scanline.x.contains(&point.x)
+            })
+    }
+}
+
+/// Dots along an outline.
+///
+/// The dots are evenly spaced along the outline and the gaps between the dots ideally have the
+/// same size as the dots. Open outlines always start and end with a dot.
+///
+/// Each dot is a circle with a diameter equal to the stroke width, which is centered on the
+/// outline. The dots are used to select the pixels of the solid stroke that are drawn, which
+/// makes sure that dots are never drawn outside the solid stroke and that no pixel is drawn twice.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub struct Dots<O, A> {
+    outline: O,
+    area: A,
+    dot_size: u32,
+
+    /// Position of the first dot.
+    start: u32,
+
+    /// Distance between the first and the last dot or the length of a closed outline.
+    span: u32,
+
+    /// Number of intervals between the dots.
+    intervals: u32,
+
+    /// Total number of dots.
+    count: u32,
+}
+
+impl<O: Outline, A: StrokeArea> Dots<O, A> {
+    /// Creates new dots.
+    pub fn new(outline: O, area: A, dot_size: u32) -> Self {
+        let dot_size = dot_size.max(1);
+        let length = outline.length();
+
+        // The distance between the centers of two adjacent dots.
+        let spacing = dot_size.saturating_mul(2 * OUTLINE_SCALE);
+
+        let (start, span, intervals, count) = if outline.is_closed() {
+            // Closed outlines without a length, like circles with a diameter of 0, have no dots.
+            let intervals = if length > 0 {
+                (length / spacing).max(1)
+            } else {
+                0
+            };
+
+            (0, length, intervals, intervals)
+        } else {
+            // The dots at the ends of open outlines are moved inwards to not extend beyond the
+            // end points.
+            let inset = (dot_size - 1).saturating_mul(OUTLINE_SCALE) / 2;
+            let span = length.saturating_sub(inset.saturating_mul(2));
+            let intervals = span.saturating_add(spacing / 2) / spacing;
+
+            if intervals == 0 {
+                (length / 2, 0, 0, 1)
+            } else {
+                (inset, span, intervals, intervals + 1)
+            }
+        };
+
+        Self {
+            outline,
+            area,
+            dot_size,
+            start,
+            span,
+            intervals,
+            count,
+        }
+    }
+
+    /// Returns the position of the dot with the given index.
+    fn dot_position(&self, index: u32) -> u32 {
+        // The offset is rounded symmetrically to the middle of the span to make sure that
+        // symmetric shapes also result in symmetric dot positions.
+        let offset = |index: u32| {
+            let intervals = u64::from(self.intervals);
+
+            (u64::from(self.span) * u64::from(index) + intervals / 2)
+                .checked_div(intervals)
+                .unwrap_or_default() as u32
+        };
+
+        if index.saturating_mul(2) <= self.intervals {
+            self.start + offset(index)
+        } else {
+            self.start + self.span - offset(self.intervals - index)
+        }
+    }
+
+    /// Returns the dot with the given index.
+    fn dot(&self, index: u32) -> Circle {
+        // Move the scaled center point to the top left corner of the dot.
+        let scale = OUTLINE_SCALE as i32;
+        let top_left_scaled = self.outline.point_at(self.dot_position(index))
+            - Size::new_equal(self.dot_size - 1) * (OUTLINE_SCALE / 2);
+        let top_left = Point::new(
+            (top_left_scaled.x + scale / 2).div_euclid(scale),
+            (top_left_scaled.y + scale / 2).div_euclid(scale),
+        );
+
+        // Rounding can move the dot away from the pixels of the solid stroke. The nearest
+        // position that is centered on the solid stroke is used instead. Dots with an even size
+        // have two center pixels, which must both be part of the stroke.
+        let center_offsets = [
+            Size::new_equal((self.dot_size - 1) / 2),
+            Size::new_equal(self.dot_size / 2),
+        ];
+        let top_left = NEIGHBORS
+            .iter()
+            .map(|offset| top_left + *offset)
+            .filter(|top_left| {
+                center_offsets
+                    .iter()
+                    .all(|offset| self.area.contains(*top_left + *offset))
+            })
+            .min_by_key(|top_left| (*top_left * scale - top_left_scaled).length_squared())
+            .unwrap_or(top_left);
+
+        Circle::new(top_left, self.dot_size)
+    }
+
+    /// Returns the index of the dot that is nearest to the given position.
+    fn nearest_index(&self, position: u32) -> u32 {
+        let relative = u64::from(position.saturating_sub(self.start).min(self.span));
+        let span = u64::from(self.span);
+
+        let index = (relative * u64::from(self.intervals) + span / 2)
+            .checked_div(span)
+            .unwrap_or_default() as u32;
+
+        // The end of a closed outline is the position of the first dot.
+        index % self.count
+    }
+
+    /// Returns `true` if the point is part of a dot.
+    ///
+    /// Only the dot that is nearest to the projection of the point onto the outline and its
+    /// neighbors are checked.
+    pub fn contains(&self, point: Point) -> bool {
+        if self.count == 0 {
+            return false;
+        }
+
+        let index = self.nearest_index(self.outline.position(point));
+
+        let (previous, next) = if self.outline.is_closed() {
+            (
+                (index + self.count - 1) % self.count,
+                (index + 1) % self.count,
+            )
+        } else {
+            (index.saturating_sub(1), (index + 1).min(self.count - 1))
+        };
+
+        [index, previous, next]
+            .iter()
+            .any(|index| self.dot(*index).contains(point))
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use crate::{
+        geometry::{AngleUnit, Point, Size},
+        iterator::PixelIteratorExt,
+        mock_display::MockDisplay,
+        pixelcolor::BinaryColor,
+        primitives::{
+            styled::{StyledDimensions, StyledDrawable, StyledPixels},
+            Arc, Circle, CornerRadii, CubicBezier, Line, PointsIter, Polygon, Polyline, Primitive,
+            PrimitiveStyle, PrimitiveStyleBuilder, Rectangle, Ring, RoundedRectangle, Sector,
+            StrokeAlignment, StrokeStyle,
+        },
+        Pixel,
+    };
+
+    /// Checks the dotted stroke of a primitive against a solid stroke.
+    ///
+    /// The dots must only be drawn inside the solid stroke, the fill must be the same as for the
+    /// solid stroke, no pixel must be drawn twice and the pixel iterator must return the same
+    /// pixels that are drawn by `draw`. All pixels must be inside the styled bounding box and the
+    /// dotted stroke must contain gaps.
+    pub fn check_dotted_stroke<P>(primitive: P)
+    where
+        P: Primitive
+            + StyledDrawable<PrimitiveStyle<BinaryColor>, Color = BinaryColor, Output = ()>
+            + StyledPixels<PrimitiveStyle<BinaryColor>>
+            + StyledDimensions<PrimitiveStyle<BinaryColor>>
+            + Copy,
+        <P as StyledPixels<PrimitiveStyle<BinaryColor>>>::Iter: Iterator<Item = Pixel<BinaryColor>>,
+    {
+        for stroke_alignment in [
+            StrokeAlignment::Inside,
+            StrokeAlignment::Center,
+            StrokeAlignment::Outside,
+        ] {
+            for stroke_width in [1, 2, 5] {
+                let solid_style = PrimitiveStyleBuilder::new()
+                    .stroke_color(BinaryColor::On)
+                    .stroke_width(stroke_width)
+                    .stroke_alignment(stroke_alignment)
+                    .fill_color(BinaryColor::Off)
+                    .build();
+
+                let dotted_style = PrimitiveStyleBuilder::from(&solid_style)
+                    .stroke_style(StrokeStyle::Dotted)
+                    .build();
+
+                let mut solid = MockDisplay::new();
+                primitive.draw_styled(&solid_style, &mut solid).unwrap();
+
+                let mut dotted = MockDisplay::new();
+                primitive.draw_styled(&dotted_style, &mut dotted).unwrap();
+
+                let mut pixels = MockDisplay::new();
+                primitive.pixels(&dotted_style).draw(&mut pixels).unwrap();
+
+                dotted.assert_eq(&pixels);
+
+                let dotted_area = dotted.affected_area();
+                assert_eq!(
+                    primitive
+                        .styled_bounding_box(&dotted_style)
+                        .intersection(&dotted_area),
+                    dotted_area,
+                    "{:?}, {:?} px: dots outside of the bounding box",
+                    stroke_alignment,
+                    stroke_width
+                );
+
+                let mut dots = 0;
+                let mut gaps = 0;
+                for point in solid.affected_area().envelope(&dotted_area).points() {
+                    let expected = match (solid.get_pixel(point), dotted.get_pixel(point)) {
+                        (Some(BinaryColor::On), Some(BinaryColor::On)) => {
+                            dots += 1;
+                            true
+                        }
+                        (Some(BinaryColor::On), _) => {
+                            gaps += 1;
+                            true
+                        }
+                        (solid, dotted) => solid == dotted,
+                    };
+
+                    assert!(
+                        expected,
+                        "{:?}, {:?} px: unexpected pixel at {:?}",
+                        stroke_alignment, stroke_width, point
+                    );
+                }
+
+                assert!(
+                    dots > 0 && gaps > 0,
+                    "{:?}, {:?} px: no gaps",
+                    stroke_alignment,
+                    stroke_width
+                );
+            }
+        }
+    }
+
+    /// Draws a primitive with a dotted stroke.
+    ///
+    /// Drawing panics if a pixel is drawn twice. The drawn pixels must match the pixel iterator
+    /// and must be inside the styled bounding box.
+    fn draw_dotted<P>(
+        primitive: P,
+        stroke_width: u32,
+        stroke_alignment: StrokeAlignment,
+    ) -> MockDisplay<BinaryColor>
+    where
+        P: StyledDrawable<PrimitiveStyle<BinaryColor>, Color = BinaryColor, Output = ()>
+            + StyledPixels<PrimitiveStyle<BinaryColor>>
+            + StyledDimensions<PrimitiveStyle<BinaryColor>>,
+        <P as StyledPixels<PrimitiveStyle<BinaryColor>>>::Iter: Iterator<Item = Pixel<BinaryColor>>,
+    {
+        let style = PrimitiveStyleBuilder::new()
+            .stroke_color(BinaryColor::On)
+            .stroke_width(stroke_width)
+            .stroke_alignment(stroke_alignment)
+            .stroke_style(StrokeStyle::Dotted)
+            .build();
+
+        let mut display = MockDisplay::new();
+        primitive.draw_styled(&style, &mut display).unwrap();
+
+        let mut pixels = MockDisplay::new();
+        primitive.pixels(&style).draw(&mut pixels).unwrap();
+        display.assert_eq(&pixels);
+
+        let area = display.affected_area();
+        assert_eq!(
+            primitive.styled_bounding_box(&style).intersection(&area),
+            area,
+            "dots outside of the bounding box"
+        );
+
+        display
+    }
+
+    #[test]
+    fn dots_are_centered_on_the_solid_stroke() {
+        let line = draw_dotted(
+            Line::new(Point::new(5, 5), Point::new(35, 5)),
+            2,
+            StrokeAlignment::Center,
+        );
+        assert_eq!(
+            line.affected_area(),
+            Rectangle::new(Point::new(5, 4), Size::new(31, 2))
+        );
+
+        // The solid stroke of the curve is one pixel lower than the solid stroke of the line.
+        let curve = draw_dotted(
+            CubicBezier::new(
+                Point::new(5, 5),
+                Point::new(5, 5),
+                Point::new(5, 5),
+                Point::new(35, 5),
+            ),
+            2,
+            StrokeAlignment::Center,
+        );
+        assert_eq!(
+            curve.affected_area(),
+            Rectangle::new(Point::new(5, 5), Size::new(31, 2))
+        );
+    }
+
+    #[test]
+    fn empty_primitives_have_no_dots() {
+        for stroke_alignment in [
+            StrokeAlignment::Inside,
+            StrokeAlignment::Center,
+            StrokeAlignment::Outside,
+        ] {
+            for display in [
+                draw_dotted(Polyline::new(&[]), 1, stroke_alignment),
+                draw_dotted(Polygon::new(&[]), 1, stroke_alignment),
+                draw_dotted(Circle::new(Point::new(3, 3), 0), 1, stroke_alignment),
+            ] {
+                assert!(display.affected_area().is_zero_sized());
+            }
+        }
+    }
+
+    #[test]
+    fn degenerate_primitives() {
+        draw_dotted(
+            RoundedRectangle::new(
+                Rectangle::new(Point::new(3, 3), Size::new(5, 6)),
+                CornerRadii::new(Size::zero()),
+            ),
+            8,
+            StrokeAlignment::Inside,
+        );
+
+        draw_dotted(
+            Polygon::new(&[Point::new(10, 10), Point::new(30, 10), Point::new(10, 10)]),
+            2,
+            StrokeAlignment::Inside,
+        );
+    }
+
+    #[test]
+    fn no_overdraw() {
+        for stroke_alignment in [
+            StrokeAlignment::Inside,
+            StrokeAlignment::Center,
+            StrokeAlignment::Outside,
+        ] {
+            for stroke_width in 1..=8 {
+                draw_dotted(
+                    Ring::new(Point::new(10, 10), 30, 14),
+                    stroke_width,
+                    stroke_alignment,
+                );
+                draw_dotted(
+                    Sector::new(Point::new(10, 10), 30, 20.0.deg(), 250.0.deg()),
+                    stroke_width,
+                    stroke_alignment,
+                );
+                draw_dotted(
+                    Arc::new(Point::new(10, 10), 30, 20.0.deg(), 250.0.deg()),
+                    stroke_width,
+                    stroke_alignment,
+                );
+            }
+        }
+    }
+}
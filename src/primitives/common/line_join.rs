@@ -1,11 +1,16 @@
 //! Thick line join.
 
+use core::ops::Range;
+
 use crate::{
-    geometry::{Point, PointExt},
+    geometry::{Point, PointExt, Size},
     primitives::{
-        common::{LineSide, LinearEquation, StrokeOffset},
+        common::{
+            disc_bounding_box, disc_run, LineSide, LinearEquation, StrokeOffset,
+            NORMAL_VECTOR_SCALE,
+        },
         line::intersection_params::{Intersection, IntersectionParams},
-        Line,
+        Line, LineJoin, Rectangle,
     },
 };
 
@@ -22,6 +27,18 @@ pub enum JoinKind {
         outer_side: LineSide,
     },
 
+    /// Rounded.
+    ///
+    /// Round joins use the same corners as bevel joins. The gap between the bevel and the rounded
+    /// corner is filled by a [`RoundJoin`].
+    Round {
+        /// Left side or right side?
+        outer_side: LineSide,
+
+        /// Rounded corner.
+        join: RoundJoin,
+    },
+
     /// Degenerate (angle between lines is too small to properly render stroke).
     ///
     /// Degenerate corners are rendered with a bevel.
@@ -42,6 +59,74 @@ pub enum JoinKind {
     End,
 }
 
+/// The rounded outer corner of a round join.
+///
+/// The corner is a disc around the vertex of the join. Its radius is the distance between the
+/// center line and the outer edge of the stroke. For strokes which aren't centered on the line the
+/// disc is limited to the sector which isn't covered by the segments themselves.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub struct RoundJoin {
+    /// Center of the disc.
+    center: Point,
+
+    /// Diameter of the disc.
+    diameter: u32,
+
+    /// Normal vectors of the half-planes which limit the disc to a sector.
+    normals: Option<[Point; 2]>,
+}
+
+impl RoundJoin {
+    /// Returns the range of pixels in the given row that are covered by the corner.
+    pub fn run(&self, y: i32) -> Option<Range<i32>> {
+        let mut x = disc_run(self.center, self.diameter, y)?;
+
+        for normal in self.normals.iter().flatten() {
+            // Only keep points with `(point - center) · normal >= 0`.
+            let limit = -normal.y * (y - self.center.y);
+
+            if normal.x > 0 {
+                x.start = x.start.max(self.center.x - (-limit).div_euclid(normal.x));
+            } else if normal.x < 0 {
+                x.end = x
+                    .end
+                    .min(self.center.x + (-limit).div_euclid(-normal.x) + 1);
+            } else if limit > 0 {
+                return None;
+            }
+        }
+
+        (!x.is_empty()).then_some(x)
+    }
+
+    /// Returns the bounding box of the corner.
+    pub fn bounding_box(&self) -> Rectangle {
+        let disc = disc_bounding_box(self.center, self.diameter);
+
+        if self.normals.is_none() {
+            return disc;
+        }
+
+        let (min, max) = disc.rows().fold(
+            (Point::new_equal(i32::MAX), Point::new_equal(i32::MIN)),
+            |(min, max), y| match self.run(y) {
+                Some(x) => (
+                    min.component_min(Point::new(x.start, y)),
+                    max.component_max(Point::new(x.end - 1, y)),
+                ),
+                None => (min, max),
+            },
+        );
+
+        if min.x <= max.x {
+            Rectangle::with_corners(min, max)
+        } else {
+            Rectangle::new(self.center, Size::zero())
+        }
+    }
+}
+
 /// The left/right corners that make up the start or end edge of a thick line.
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
 #[cfg_attr(feature = "defmt", derive(::defmt::Format))]
@@ -56,7 +141,7 @@ pub struct EdgeCorners {
 /// A join between two lines.
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
 #[cfg_attr(feature = "defmt", derive(::defmt::Format))]
-pub struct LineJoin {
+pub struct SegmentJoin {
     /// Join kind.
     pub kind: JoinKind,
 
@@ -67,7 +152,7 @@ pub struct LineJoin {
     pub second_edge_start: EdgeCorners,
 }
 
-impl LineJoin {
+impl SegmentJoin {
     /// Create a starting join.
     ///
     /// `first_edge_end` and `second_edge_start` are set to the same points.
@@ -130,6 +215,7 @@ impl LineJoin {
         end: Point,
         width: u32,
         stroke_offset: StrokeOffset,
+        line_join: LineJoin,
     ) -> Self {
         let first_line = Line::new(start, mid);
         let second_line = Line::new(mid, end);
@@ -164,14 +250,21 @@ impl LineJoin {
                     },
                 )
                 .delta()
-                .length_squared() as u32;
-
-                // Miter length limit is double the line width (but squared to avoid sqrt() costs)
-                let miter_limit = (width * 2).pow(2);
+                .length_squared() as u64;
+
+                // The miter limit is the maximum ratio between the miter length and the stroke
+                // width. Twice the distance from the midpoint to the outside end point is used as
+                // an approximation of the miter length (squared to avoid sqrt() costs).
+                let is_miter = match line_join {
+                    LineJoin::Miter { limit } => {
+                        4 * miter_length_squared <= (u64::from(limit) * u64::from(width)).pow(2)
+                    }
+                    LineJoin::Bevel | LineJoin::Round => false,
+                };
 
                 // Intersection is within limit at which it will be chopped off into a bevel, so
                 // return a miter.
-                if miter_length_squared <= miter_limit {
+                if is_miter {
                     let corners = EdgeCorners {
                         left: l_intersection,
                         right: r_intersection,
@@ -185,9 +278,41 @@ impl LineJoin {
                 }
                 // Miter is too long, chop it into bevel-style corner
                 else {
+                    // The corner of strokes that aren't centered on the line is limited to the
+                    // sector after the end of the first segment and before the start of the
+                    // second segment.
+                    let sector = [
+                        first_line.direction(NORMAL_VECTOR_SCALE as u32),
+                        -second_line.direction(NORMAL_VECTOR_SCALE as u32),
+                    ];
+
+                    let round_join = match (line_join, stroke_offset, outer_side) {
+                        (LineJoin::Round, StrokeOffset::None, _) => Some(RoundJoin {
+                            center: mid,
+                            diameter: width,
+                            normals: None,
+                        }),
+                        (LineJoin::Round, StrokeOffset::Left, LineSide::Left)
+                        | (LineJoin::Round, StrokeOffset::Right, LineSide::Right) => {
+                            Some(RoundJoin {
+                                center: mid,
+                                diameter: width.saturating_mul(2),
+                                normals: Some(sector),
+                            })
+                        }
+                        // The outer edge of strokes that are offset to the inside of the corner
+                        // passes through the vertex and doesn't need to be rounded.
+                        _ => None,
+                    };
+
+                    let kind = match round_join {
+                        Some(join) => JoinKind::Round { outer_side, join },
+                        None => JoinKind::Bevel { outer_side },
+                    };
+
                     match outer_side {
                         LineSide::Right => Self {
-                            kind: JoinKind::Bevel { outer_side },
+                            kind,
                             first_edge_end: EdgeCorners {
                                 left: l_intersection,
                                 right: first_edge_right.end,
@@ -198,7 +323,7 @@ impl LineJoin {
                             },
                         },
                         LineSide::Left => Self {
-                            kind: JoinKind::Bevel { outer_side },
+                            kind,
                             first_edge_end: EdgeCorners {
                                 left: first_edge_left.end,
                                 right: r_intersection,
@@ -245,7 +370,9 @@ impl LineJoin {
     /// The filler line (if any) for bevel and degenerate joints.
     const fn filler_line(&self) -> Option<Line> {
         match self.kind {
-            JoinKind::Bevel { outer_side, .. } | JoinKind::Degenerate { outer_side, .. } => {
+            JoinKind::Bevel { outer_side, .. }
+            | JoinKind::Round { outer_side, .. }
+            | JoinKind::Degenerate { outer_side, .. } => {
                 let line = match outer_side {
                     LineSide::Left => {
                         Line::new(self.first_edge_end.left, self.second_edge_start.left)
@@ -288,6 +415,14 @@ impl LineJoin {
         self.cap(&self.first_edge_end)
     }
 
+    /// Returns the rounded corner of round joins.
+    pub const fn round_join(&self) -> Option<RoundJoin> {
+        match self.kind {
+            JoinKind::Round { join, .. } => Some(join),
+            _ => None,
+        }
+    }
+
     /// Whether the join is degenerate (segments self-intersect) or not.
     pub const fn is_degenerate(&self) -> bool {
         matches!(self.kind, JoinKind::Degenerate { .. })
@@ -1,22 +1,39 @@
+mod anti_aliasing;
 mod closed_thick_segment_iter;
+mod corner_joins;
+mod dashes;
 mod distance_iterator;
+mod dots;
 mod line_join;
 mod linear_equation;
+mod outline;
 mod plane_sector;
 mod scanline;
 mod styled_scanline;
 mod thick_segment;
-mod thick_segment_iter;
 
+pub use anti_aliasing::{
+    draw_anti_aliased, draw_blended, edge_coverage, AntiAliasedShape, Coverage,
+};
 pub use closed_thick_segment_iter::ClosedThickSegmentIter;
+pub use corner_joins::CornerJoins;
+#[cfg(test)]
+pub use dashes::tests::check_dashed_stroke;
+pub use dashes::{Dashes, PatternedStroke, StrokePattern};
 pub use distance_iterator::DistanceIterator;
-pub use line_join::{JoinKind, LineJoin};
+#[cfg(test)]
+pub use dots::tests::check_dotted_stroke;
+pub use dots::{Band, Dots, SegmentsArea, StrokeArea};
+pub use line_join::{RoundJoin, SegmentJoin};
 pub use linear_equation::{LinearEquation, OriginLinearEquation, NORMAL_VECTOR_SCALE};
+pub use outline::{
+    isqrt, rectangle_outline, CircleOutline, EllipseOutline, Outline, RingOutline,
+    RoundedRectangleOutline, SectorOutline, SegmentsOutline, OUTLINE_SCALE,
+};
 pub use plane_sector::PlaneSector;
-pub use scanline::Scanline;
+pub use scanline::{disc_bounding_box, disc_run, first_run, merge_runs, solve, Scanline};
 pub use styled_scanline::StyledScanline;
 pub use thick_segment::ThickSegment;
-pub use thick_segment_iter::ThickSegmentIter;
 
 use crate::primitives::StrokeAlignment;
 
@@ -0,0 +1,1616 @@
+//! Outlines of primitives.
+//!
+//! Outlines are used to determine the position of a point along the edge of a primitive and the
+//! point at a given position, which is required to draw non solid strokes.
+
+use crate::{
+    geometry::{Angle, Point, PointExt, Real, Size, Trigonometry},
+    primitives::{
+        common::{SegmentJoin, StrokeOffset},
+        ellipse, Circle, Ellipse, LineJoin, Rectangle, Ring, RoundedRectangle,
+    },
+};
+#[allow(unused_imports)]
+use micromath::F32Ext;
+
+/// Scaling factor for positions and lengths along an outline.
+pub const OUTLINE_SCALE: u32 = 16;
+
+/// Number of angle units in a full turn.
+const TURN: u32 = 1 << 16;
+
+/// Number of angle units in a quarter turn.
+const QUARTER_TURN: u32 = TURN / 4;
+
+/// Outline of a primitive.
+///
+/// All positions and lengths are scaled up by [`OUTLINE_SCALE`] to increase the resolution.
+pub trait Outline {
+    /// Returns the total length of the outline.
+    fn length(&self) -> u32;
+
+    /// Returns the position of a point along the outline.
+    ///
+    /// The point is projected onto the nearest part of the outline and the returned value is the
+    /// distance from the start of the outline to the projected point.
+    fn position(&self, point: Point) -> u32;
+
+    /// Returns the point at the given position along the outline.
+    ///
+    /// The returned point is scaled by [`OUTLINE_SCALE`] to be able to represent points between
+    /// pixel centers. Positions outside the outline are clamped to the nearest end.
+    fn point_at(&self, position: u32) -> Point;
+
+    /// Returns `true` if the end of the outline is connected to the start.
+    fn is_closed(&self) -> bool;
+}
+
+/// Maximum number of vertices for which the segment data of a [`SegmentsOutline`] is precomputed.
+///
+/// Outlines with more vertices calculate the segment data on the fly.
+const CACHED_VERTICES: usize = 32;
+
+/// Precomputed vertices and positions of a [`SegmentsOutline`].
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+struct SegmentCache {
+    /// Vertices, scaled by a factor of 2 and moved to the stroke center line if required.
+    vertices: [Point; CACHED_VERTICES],
+
+    /// Position of the start of each segment along the outline.
+    ///
+    /// The last used entry contains the total length of the outline.
+    positions: [u32; CACHED_VERTICES + 1],
+
+    /// Number of cached vertices or `0` if the cache isn't used.
+    len: usize,
+}
+
+impl SegmentCache {
+    const EMPTY: Self = Self {
+        vertices: [Point::zero(); CACHED_VERTICES],
+        positions: [0; CACHED_VERTICES + 1],
+        len: 0,
+    };
+}
+
+/// Outline that consists of straight line segments.
+///
+/// The vertices are internally scaled by a factor of 2, because the center line of a stroke with
+/// an even width runs between the pixel centers.
+///
+/// The vertices and the positions of the segments are precomputed for outlines with up to
+/// [`CACHED_VERTICES`] vertices, because they are used for every pixel of a dashed or dotted
+/// stroke.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub struct SegmentsOutline<V> {
+    vertices: V,
+    translate: Point,
+    closed: bool,
+
+    /// Width and offset of the stroke whose center line is used as the outline.
+    stroke: Option<(u32, StrokeOffset)>,
+
+    cache: SegmentCache,
+}
+
+impl<V: AsRef<[Point]>> SegmentsOutline<V> {
+    /// Creates an open outline.
+    pub fn open(vertices: V, translate: Point) -> Self {
+        Self {
+            vertices,
+            translate,
+            closed: false,
+            stroke: None,
+            cache: SegmentCache::EMPTY,
+        }
+        .with_cache()
+    }
+
+    /// Creates a closed outline.
+    ///
+    /// Closed outlines include an additional segment from the last to the first vertex.
+    pub fn closed(vertices: V) -> Self {
+        Self {
+            vertices,
+            translate: Point::zero(),
+            closed: true,
+            stroke: None,
+            cache: SegmentCache::EMPTY,
+        }
+        .with_cache()
+    }
+
+    /// Moves the outline to the center line of a stroke.
+    ///
+    /// The vertices are moved to the center of the line joins of a stroke with the given width
+    /// and offset. The end points of open outlines are moved to the center of the stroke ends.
+    pub fn stroke_center(self, width: u32, offset: StrokeOffset) -> Self {
+        Self {
+            stroke: Some((width, offset)),
+            cache: SegmentCache::EMPTY,
+            ..self
+        }
+        .with_cache()
+    }
+
+    /// Precomputes the vertices and segment positions.
+    fn with_cache(mut self) -> Self {
+        let len = self.vertices.as_ref().len();
+        if len > CACHED_VERTICES {
+            return self;
+        }
+
+        let mut cache = SegmentCache::EMPTY;
+        for (index, vertex) in cache.vertices[0..len].iter_mut().enumerate() {
+            *vertex = self.vertex(index);
+        }
+        for (index, (_, _, length)) in self.segments().enumerate() {
+            cache.positions[index + 1] = cache.positions[index].saturating_add(length);
+        }
+        cache.len = len;
+
+        self.cache = cache;
+        self
+    }
+
+    /// Returns the vertex with the given index, scaled by a factor of 2.
+    fn vertex(&self, index: usize) -> Point {
+        if self.cache.len > 0 {
+            return self.cache.vertices[index];
+        }
+
+        let vertices = self.vertices.as_ref();
+        let len = vertices.len();
+        let vertex = vertices[index];
+
+        let Some((width, offset)) = self.stroke.filter(|_| len > 1) else {
+            return vertex * 2;
+        };
+
+        let corners = if self.closed && len > 2 {
+            let previous = vertices[(index + len - 1) % len];
+            let next = vertices[(index + 1) % len];
+
+            SegmentJoin::from_points(previous, vertex, next, width, offset, LineJoin::default())
+                .first_edge_end
+        } else if index == 0 {
+            SegmentJoin::start(vertex, vertices[1], width, offset).first_edge_end
+        } else if index == len - 1 {
+            SegmentJoin::end(vertices[index - 1], vertex, width, offset).first_edge_end
+        } else {
+            SegmentJoin::from_points(
+                vertices[index - 1],
+                vertex,
+                vertices[index + 1],
+                width,
+                offset,
+                LineJoin::default(),
+            )
+            .first_edge_end
+        };
+
+        corners.left + corners.right
+    }
+
+    /// Returns the number of segments.
+    fn segment_count(&self) -> usize {
+        let len = self.vertices.as_ref().len();
+
+        if self.closed && len > 1 {
+            len
+        } else {
+            len.saturating_sub(1)
+        }
+    }
+
+    /// Returns an iterator over the start point, end point and scaled length of all segments.
+    fn segments(&self) -> impl Iterator<Item = (Point, Point, u32)> + '_ {
+        let len = self.vertices.as_ref().len();
+        let mut start = if len > 0 {
+            self.vertex(0)
+        } else {
+            Point::zero()
+        };
+
+        (0..self.segment_count()).map(move |index| {
+            let end = self.vertex((index + 1) % len);
+            let length = if self.cache.len > 0 {
+                self.cache.positions[index + 1].saturating_sub(self.cache.positions[index])
+            } else {
+                scaled_length(end - start) / 2
+            };
+
+            let segment = (start, end, length);
+            start = end;
+
+            segment
+        })
+    }
+}
+
+impl<V: AsRef<[Point]>> Outline for SegmentsOutline<V> {
+    fn length(&self) -> u32 {
+        if self.cache.len > 0 {
+            return self.cache.positions[self.segment_count()];
+        }
+
+        self.segments().fold(0, |length, (_, _, segment_length)| {
+            length.saturating_add(segment_length)
+        })
+    }
+
+    fn position(&self, point: Point) -> u32 {
+        let point = (point - self.translate) * 2;
+
+        let mut segment_start = 0u32;
+        let mut nearest_distance = u64::MAX;
+
+        // Start position, squared projected length, squared length and length of the segment
+        // which is nearest to the point.
+        let mut nearest = (0, 0, 1, 0);
+
+        for (start, end, length) in self.segments() {
+            let delta = end - start;
+            let length_squared = i64::from(delta.length_squared()).max(1);
+
+            let v = point - start;
+            let dot = (i64::from(v.x) * i64::from(delta.x) + i64::from(v.y) * i64::from(delta.y))
+                .clamp(0, length_squared);
+
+            // Squared distance between the point and the nearest point on the segment.
+            let distance = if dot == 0 {
+                squared_length(v)
+            } else if dot == length_squared {
+                squared_length(point - end)
+            } else {
+                let cross =
+                    i64::from(v.x) * i64::from(delta.y) - i64::from(v.y) * i64::from(delta.x);
+                cross.unsigned_abs().saturating_mul(cross.unsigned_abs()) / length_squared as u64
+            };
+
+            if distance < nearest_distance {
+                nearest_distance = distance;
+                nearest = (segment_start, dot, length_squared, length);
+            }
+
+            segment_start = segment_start.saturating_add(length);
+        }
+
+        let (segment_start, dot, length_squared, length) = nearest;
+
+        // The projected length is divided by 2 to undo the scaling of the vertices.
+        let along =
+            (dot as u128).pow(2) * u128::from((OUTLINE_SCALE / 2).pow(2)) / length_squared as u128;
+        let along = isqrt(along.min(u128::from(u64::MAX)) as u64);
+
+        segment_start.saturating_add(along.min(u64::from(length)) as u32)
+    }
+
+    fn point_at(&self, position: u32) -> Point {
+        let mut segment_start = 0u32;
+        let mut last = None;
+
+        for (start, end, length) in self.segments() {
+            let delta = end - start;
+            let along = position.saturating_sub(segment_start);
+
+            if along <= length && length > 0 {
+                let interpolate = |value: i32| {
+                    (i64::from(value) * i64::from(OUTLINE_SCALE / 2) * i64::from(along)
+                        / i64::from(length)) as i32
+                };
+
+                return start * (OUTLINE_SCALE / 2) as i32
+                    + self.translate * OUTLINE_SCALE as i32
+                    + Point::new(interpolate(delta.x), interpolate(delta.y));
+            }
+
+            segment_start = segment_start.saturating_add(length);
+            last = Some(end);
+        }
+
+        let last = last.or_else(|| (!self.vertices.as_ref().is_empty()).then(|| self.vertex(0)));
+
+        last.unwrap_or_default() * (OUTLINE_SCALE / 2) as i32
+            + self.translate * OUTLINE_SCALE as i32
+    }
+
+    fn is_closed(&self) -> bool {
+        self.closed
+    }
+}
+
+/// Outline of a circle or a circular arc.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub struct CircleOutline {
+    center_2x: Point,
+    diameter: u32,
+    angle_start: u32,
+    angle_sweep: u32,
+    clockwise: bool,
+}
+
+impl CircleOutline {
+    /// Creates the outline of a full circle.
+    ///
+    /// The outline starts at 0° and runs clockwise.
+    pub fn new(circle: &Circle) -> Self {
+        Self {
+            center_2x: circle.center_2x(),
+            diameter: circle.diameter,
+            angle_start: 0,
+            angle_sweep: TURN,
+            clockwise: true,
+        }
+    }
+
+    /// Creates the outline of a circular arc.
+    ///
+    /// The outline starts at `angle_start` and runs in the direction of the sweep angle.
+    pub fn arc(circle: &Circle, angle_start: Angle, angle_sweep: Angle) -> Self {
+        let angle_sweep_units = angle_units(angle_sweep.abs()).min(i64::from(TURN)) as u32;
+
+        Self {
+            center_2x: circle.center_2x(),
+            diameter: circle.diameter,
+            angle_start: angle_units(angle_start).rem_euclid(i64::from(TURN)) as u32,
+            angle_sweep: angle_sweep_units,
+            clockwise: angle_sweep >= Angle::zero(),
+        }
+    }
+
+    /// Returns the length of the arc between the start angle and the given angle.
+    ///
+    /// Angles outside the sweep range are clamped to the nearest end of the arc.
+    fn arc_length(&self, angle: u32) -> u32 {
+        let relative = if self.clockwise {
+            angle.wrapping_sub(self.angle_start)
+        } else {
+            self.angle_start.wrapping_sub(angle)
+        } % TURN;
+
+        let relative = if relative <= self.angle_sweep {
+            relative
+        } else if relative - self.angle_sweep < TURN - relative {
+            self.angle_sweep
+        } else {
+            0
+        };
+
+        scale_by_circumference(relative, self.diameter)
+    }
+
+    /// Returns the end angle of the arc.
+    const fn angle_end(&self) -> u32 {
+        if self.clockwise {
+            self.angle_start.wrapping_add(self.angle_sweep)
+        } else {
+            self.angle_start.wrapping_sub(self.angle_sweep)
+        }
+    }
+
+    /// Returns the radii of the circle, scaled by a factor of 2.
+    fn radii_2x(&self) -> Size {
+        Size::new_equal(self.diameter.saturating_sub(1))
+    }
+
+    /// Returns the angle of a point relative to the center.
+    fn angle(&self, point: Point) -> u32 {
+        let delta = point * 2 - self.center_2x;
+
+        atan2(i64::from(delta.y), i64::from(delta.x))
+    }
+}
+
+impl Outline for CircleOutline {
+    fn length(&self) -> u32 {
+        scale_by_circumference(self.angle_sweep, self.diameter)
+    }
+
+    fn position(&self, point: Point) -> u32 {
+        self.arc_length(self.angle(point))
+    }
+
+    fn point_at(&self, position: u32) -> Point {
+        let circumference = u64::from(scale_by_circumference(TURN, self.diameter).max(1));
+        let relative = ((u64::from(position) * u64::from(TURN) + circumference / 2) / circumference)
+            .min(u64::from(self.angle_sweep)) as u32;
+
+        let angle = if self.clockwise {
+            self.angle_start.wrapping_add(relative)
+        } else {
+            self.angle_start.wrapping_sub(relative)
+        };
+
+        point_on_ellipse(self.center_2x, self.radii_2x(), angle)
+    }
+
+    fn is_closed(&self) -> bool {
+        self.angle_sweep >= TURN
+    }
+}
+
+/// Outline of a sector.
+///
+/// The outline starts at the center point, follows the radius at the start angle to the arc,
+/// runs along the arc and returns to the center along the radius at the end angle.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub struct SectorOutline {
+    arc: CircleOutline,
+    radius: u32,
+
+    /// Points on the arc at the start and end angle, scaled by [`OUTLINE_SCALE`].
+    start: Point,
+    end: Point,
+}
+
+impl SectorOutline {
+    /// Creates the outline of a sector.
+    pub fn new(circle: &Circle, angle_start: Angle, angle_sweep: Angle) -> Self {
+        let arc = CircleOutline::arc(circle, angle_start, angle_sweep);
+
+        Self {
+            arc,
+            radius: circle
+                .diameter
+                .saturating_sub(1)
+                .saturating_mul(OUTLINE_SCALE)
+                / 2,
+            start: point_on_ellipse(arc.center_2x, arc.radii_2x(), arc.angle_start),
+            end: point_on_ellipse(arc.center_2x, arc.radii_2x(), arc.angle_end()),
+        }
+    }
+
+    /// Returns the center point, scaled by [`OUTLINE_SCALE`].
+    fn center_scaled(&self) -> Point {
+        self.arc.center_2x * (OUTLINE_SCALE / 2) as i32
+    }
+
+    /// Returns `true` if the sector is a full circle without radial edges.
+    fn is_full_circle(&self) -> bool {
+        self.arc.angle_sweep >= TURN
+    }
+}
+
+impl Outline for SectorOutline {
+    fn length(&self) -> u32 {
+        if self.is_full_circle() {
+            self.arc.length()
+        } else {
+            self.arc
+                .length()
+                .saturating_add(self.radius.saturating_mul(2))
+        }
+    }
+
+    fn position(&self, point: Point) -> u32 {
+        if self.is_full_circle() {
+            return self.arc.position(point);
+        }
+
+        // The positions are compared with scaled coordinates.
+        let point_scaled = point * OUTLINE_SCALE as i32;
+        let center_scaled = self.center_scaled();
+
+        let delta = point_scaled - center_scaled;
+        let distance = isqrt(squared_length(delta));
+        let arc_distance = distance.abs_diff(u64::from(self.radius));
+
+        let start_radius = SegmentsOutline::open([center_scaled, self.start], Point::zero());
+        let end_radius = SegmentsOutline::open([self.end, center_scaled], Point::zero());
+
+        let start_distance = segment_distance(center_scaled, self.start, point_scaled);
+        let end_distance = segment_distance(self.end, center_scaled, point_scaled);
+
+        // Positions on the scaled radial segments need to be divided by the scaling factor.
+        let radial_length = self.radius;
+        let arc_length = self.arc.length();
+
+        if start_distance <= arc_distance.pow(2) && start_distance <= end_distance {
+            (start_radius.position(point_scaled) / OUTLINE_SCALE).min(radial_length)
+        } else if end_distance <= arc_distance.pow(2) {
+            radial_length.saturating_add(arc_length).saturating_add(
+                (end_radius.position(point_scaled) / OUTLINE_SCALE).min(radial_length),
+            )
+        } else {
+            radial_length.saturating_add(self.arc.position(point))
+        }
+    }
+
+    fn point_at(&self, position: u32) -> Point {
+        if self.is_full_circle() {
+            return self.arc.point_at(position);
+        }
+
+        let center_scaled = self.center_scaled();
+        let arc_length = self.arc.length();
+
+        // Interpolates between the center and a point on the arc.
+        let radial = |point: Point, along: u32| {
+            let along = i64::from(along.min(self.radius));
+            let radius = i64::from(self.radius.max(1));
+            let delta = point - center_scaled;
+
+            center_scaled
+                + Point::new(
+                    (i64::from(delta.x) * along / radius) as i32,
+                    (i64::from(delta.y) * along / radius) as i32,
+                )
+        };
+
+        if position < self.radius {
+            radial(self.start, position)
+        } else if position - self.radius <= arc_length {
+            self.arc.point_at(position - self.radius)
+        } else {
+            let along = position - self.radius - arc_length;
+            radial(self.end, self.radius.saturating_sub(along))
+        }
+    }
+
+    fn is_closed(&self) -> bool {
+        true
+    }
+}
+
+/// Outline of a ring or an annular sector.
+///
+/// The outline of a full ring consists of the outer circle, followed by the inner circle. The
+/// outline of an annular sector runs along the outer arc, follows the radius at the end angle to
+/// the inner arc, runs back along the inner arc and returns to the outer arc along the radius at
+/// the start angle.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub struct RingOutline {
+    outer: CircleOutline,
+    inner: CircleOutline,
+
+    /// Radii of the outer and inner arc, scaled by [`OUTLINE_SCALE`].
+    outer_radius: u32,
+    inner_radius: u32,
+
+    /// End points of the radial edges in the order of the outline, scaled by [`OUTLINE_SCALE`].
+    corners: [Point; 4],
+}
+
+impl RingOutline {
+    /// Creates the outline of a ring.
+    pub fn new(ring: &Ring) -> Self {
+        let circle = ring.outer_circle();
+
+        let outer = CircleOutline::arc(&circle, ring.angle_start, ring.angle_sweep);
+        let inner = CircleOutline {
+            diameter: ring.inner_diameter,
+            ..CircleOutline::arc(
+                &circle,
+                ring.angle_start + ring.angle_sweep,
+                -ring.angle_sweep,
+            )
+        };
+
+        let radius = |diameter: u32| diameter.saturating_sub(1).saturating_mul(OUTLINE_SCALE) / 2;
+        let point =
+            |arc: &CircleOutline, angle| point_on_ellipse(arc.center_2x, arc.radii_2x(), angle);
+
+        Self {
+            outer,
+            inner,
+            outer_radius: radius(ring.outer_diameter),
+            inner_radius: radius(ring.inner_diameter),
+            corners: [
+                point(&outer, outer.angle_end()),
+                point(&inner, inner.angle_start),
+                point(&inner, inner.angle_end()),
+                point(&outer, outer.angle_start),
+            ],
+        }
+    }
+
+    /// Returns `true` if the outline consists of two full circles without radial edges.
+    fn is_full_ring(&self) -> bool {
+        self.outer.angle_sweep >= TURN
+    }
+
+    /// Returns the length of a radial edge.
+    fn radial_length(&self) -> u32 {
+        if self.is_full_ring() {
+            0
+        } else {
+            self.outer_radius.saturating_sub(self.inner_radius)
+        }
+    }
+}
+
+impl Outline for RingOutline {
+    fn length(&self) -> u32 {
+        self.outer
+            .length()
+            .saturating_add(self.inner.length())
+            .saturating_add(self.radial_length().saturating_mul(2))
+    }
+
+    fn position(&self, point: Point) -> u32 {
+        // The positions are compared with scaled coordinates.
+        let point_scaled = point * OUTLINE_SCALE as i32;
+        let center_scaled = self.outer.center_2x * (OUTLINE_SCALE / 2) as i32;
+
+        let distance = isqrt(squared_length(point_scaled - center_scaled));
+        let outer_distance = distance.abs_diff(u64::from(self.outer_radius)).pow(2);
+        let inner_distance = distance.abs_diff(u64::from(self.inner_radius)).pow(2);
+
+        let radial_length = self.radial_length();
+        let inner_start = self.outer.length().saturating_add(radial_length);
+        let inner_end = inner_start.saturating_add(self.inner.length());
+
+        let [outer_end_point, inner_end_point, inner_start_point, outer_start_point] = self.corners;
+
+        // Distances to the radial edges.
+        let (end_distance, start_distance) = if self.is_full_ring() {
+            (u64::MAX, u64::MAX)
+        } else {
+            (
+                segment_distance(outer_end_point, inner_end_point, point_scaled),
+                segment_distance(inner_start_point, outer_start_point, point_scaled),
+            )
+        };
+
+        // Positions on the scaled radial segments need to be divided by the scaling factor.
+        let radial_position = |start: Point, end: Point| {
+            (SegmentsOutline::open([start, end], Point::zero()).position(point_scaled)
+                / OUTLINE_SCALE)
+                .min(radial_length)
+        };
+
+        let min_distance = outer_distance
+            .min(inner_distance)
+            .min(end_distance)
+            .min(start_distance);
+
+        if outer_distance == min_distance {
+            self.outer.position(point)
+        } else if end_distance == min_distance {
+            self.outer
+                .length()
+                .saturating_add(radial_position(outer_end_point, inner_end_point))
+        } else if inner_distance == min_distance {
+            inner_start.saturating_add(self.inner.position(point))
+        } else {
+            inner_end.saturating_add(radial_position(inner_start_point, outer_start_point))
+        }
+    }
+
+    fn point_at(&self, position: u32) -> Point {
+        let outer_length = self.outer.length();
+        let inner_length = self.inner.length();
+        let radial_length = self.radial_length();
+
+        // Interpolates along a radial edge.
+        let radial = |start: Point, end: Point, along: u32| {
+            let along = i64::from(along.min(radial_length));
+            let length = i64::from(radial_length.max(1));
+            let delta = end - start;
+
+            start
+                + Point::new(
+                    (i64::from(delta.x) * along / length) as i32,
+                    (i64::from(delta.y) * along / length) as i32,
+                )
+        };
+
+        let [outer_end_point, inner_end_point, inner_start_point, outer_start_point] = self.corners;
+
+        if position <= outer_length {
+            return self.outer.point_at(position);
+        }
+
+        let position = position - outer_length;
+        if position < radial_length {
+            return radial(outer_end_point, inner_end_point, position);
+        }
+
+        let position = position - radial_length;
+        if position <= inner_length || self.is_full_ring() {
+            return self.inner.point_at(position);
+        }
+
+        radial(
+            inner_start_point,
+            outer_start_point,
+            position - inner_length,
+        )
+    }
+
+    fn is_closed(&self) -> bool {
+        true
+    }
+}
+
+/// Arc lengths of an elliptical quadrant.
+///
+/// The arc length is stored for evenly spaced angles of the parametric equation of the ellipse,
+/// starting at the end of the horizontal semi-axis. Arc lengths between these angles are
+/// linearly interpolated.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+struct QuadrantArcLengths {
+    lengths: [u32; QUADRANT_STEPS + 1],
+}
+
+/// Number of steps used to approximate the arc length of an elliptical quadrant.
+const QUADRANT_STEPS: usize = 8;
+
+/// Cosine of the angles used to approximate the arc length of an elliptical quadrant.
+///
+/// The values are scaled by `1 << 16`.
+const QUADRANT_COS: [u32; QUADRANT_STEPS + 1] =
+    [65536, 64277, 60547, 54491, 46341, 36410, 25080, 12785, 0];
+
+impl QuadrantArcLengths {
+    /// Calculates the arc lengths for a quadrant with the given semi-axes.
+    ///
+    /// The semi-axes are scaled by a factor of 2.
+    fn new(radii_2x: Size) -> Self {
+        let mut lengths = [0u32; QUADRANT_STEPS + 1];
+
+        // The lengths are calculated with a higher resolution to reduce rounding errors.
+        const RESOLUTION: u64 = 16;
+
+        let point = |i: usize| {
+            let scale = |radius: u32, cos: u32| {
+                (u64::from(radius) * u64::from(cos) * u64::from(OUTLINE_SCALE) * RESOLUTION) >> 16
+            };
+
+            (
+                scale(radii_2x.width, QUADRANT_COS[i]),
+                scale(radii_2x.height, QUADRANT_COS[QUADRANT_STEPS - i]),
+            )
+        };
+
+        let mut previous = point(0);
+        let mut length = 0u64;
+        for (i, entry) in lengths.iter_mut().enumerate().skip(1) {
+            let current = point(i);
+
+            let dx = current.0.abs_diff(previous.0);
+            let dy = current.1.abs_diff(previous.1);
+            length = length.saturating_add(isqrt(
+                dx.saturating_mul(dx).saturating_add(dy.saturating_mul(dy)),
+            ));
+
+            // Divide by 2 to convert the 2x scaled coordinates back into normal coordinates.
+            *entry = (length / RESOLUTION / 2).min(u64::from(u32::MAX)) as u32;
+            previous = current;
+        }
+
+        Self { lengths }
+    }
+
+    /// Returns the length of the quadrant.
+    const fn length(&self) -> u32 {
+        self.lengths[QUADRANT_STEPS]
+    }
+
+    /// Returns the arc length between the start of the quadrant and the given angle.
+    ///
+    /// The angle must be in the range `0..=QUARTER_TURN`.
+    fn arc_length(&self, angle: u32) -> u32 {
+        let step = QUARTER_TURN / QUADRANT_STEPS as u32;
+
+        let index = (angle / step) as usize;
+        if index >= QUADRANT_STEPS {
+            return self.length();
+        }
+
+        let fraction = angle % step;
+        let start = self.lengths[index];
+        let end = self.lengths[index + 1];
+
+        start + ((u64::from(end - start) * u64::from(fraction)) / u64::from(step)) as u32
+    }
+
+    /// Returns the angle at which the arc length from the start of the quadrant equals `length`.
+    ///
+    /// This is the inverse of [`arc_length`](Self::arc_length).
+    fn angle(&self, length: u32) -> u32 {
+        let step = QUARTER_TURN / QUADRANT_STEPS as u32;
+
+        let Some(index) = self.lengths[1..].iter().position(|end| length < *end) else {
+            return QUARTER_TURN;
+        };
+
+        let start = self.lengths[index];
+        let end = self.lengths[index + 1];
+        let fraction = (u64::from(length.saturating_sub(start)) * u64::from(step)
+            / u64::from(end - start)) as u32;
+
+        index as u32 * step + fraction
+    }
+}
+
+/// Outline of an ellipse.
+///
+/// The outline starts at 0° and runs clockwise.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub struct EllipseOutline {
+    center_2x: Point,
+    radii_2x: Size,
+    quadrant: QuadrantArcLengths,
+}
+
+impl EllipseOutline {
+    /// Creates the outline of an ellipse.
+    pub fn new(ellipse: &Ellipse) -> Self {
+        let radii_2x = ellipse.size.saturating_sub(Size::new_equal(1));
+
+        Self {
+            center_2x: ellipse::center_2x(ellipse.top_left, ellipse.size),
+            radii_2x,
+            quadrant: QuadrantArcLengths::new(radii_2x),
+        }
+    }
+}
+
+impl Outline for EllipseOutline {
+    fn length(&self) -> u32 {
+        self.quadrant.length().saturating_mul(4)
+    }
+
+    fn position(&self, point: Point) -> u32 {
+        let delta = point * 2 - self.center_2x;
+
+        let angle = elliptic_angle(delta, self.radii_2x);
+
+        quadrant_position(&self.quadrant, angle)
+    }
+
+    fn point_at(&self, position: u32) -> Point {
+        point_on_ellipse(
+            self.center_2x,
+            self.radii_2x,
+            quadrant_angle(&self.quadrant, position),
+        )
+    }
+
+    fn is_closed(&self) -> bool {
+        true
+    }
+}
+
+/// Returns the position along a full ellipse for an angle of the parametric equation.
+fn quadrant_position(quadrant: &QuadrantArcLengths, angle: u32) -> u32 {
+    let length = quadrant.length();
+
+    match angle / QUARTER_TURN {
+        0 => quadrant.arc_length(angle),
+        1 => (length * 2).saturating_sub(quadrant.arc_length(2 * QUARTER_TURN - angle)),
+        2 => (length * 2).saturating_add(quadrant.arc_length(angle - 2 * QUARTER_TURN)),
+        _ => (length * 4).saturating_sub(quadrant.arc_length(TURN - angle)),
+    }
+}
+
+/// Returns the angle of the parametric equation for a position along a full ellipse.
+///
+/// This is the inverse of [`quadrant_position`].
+fn quadrant_angle(quadrant: &QuadrantArcLengths, position: u32) -> u32 {
+    let length = quadrant.length();
+    if length == 0 {
+        return 0;
+    }
+
+    match position / length {
+        0 => quadrant.angle(position),
+        1 => 2 * QUARTER_TURN - quadrant.angle(length * 2 - position),
+        2 => 2 * QUARTER_TURN + quadrant.angle(position - length * 2),
+        _ => (TURN - quadrant.angle((length * 4).saturating_sub(position))) % TURN,
+    }
+}
+
+/// Returns the angle of the parametric ellipse equation for a point.
+///
+/// The `delta` between the point and the center of the ellipse and the semi-axes need to be
+/// scaled by the same factor.
+fn elliptic_angle(delta: Point, radii: Size) -> u32 {
+    atan2(
+        i64::from(delta.y) * i64::from(radii.width.max(1)),
+        i64::from(delta.x) * i64::from(radii.height.max(1)),
+    )
+}
+
+/// Outline of a rounded rectangle.
+///
+/// The outline starts at the left end of the top edge and runs clockwise.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub struct RoundedRectangleOutline {
+    /// Corner points of the straight edges, in 2x scaled coordinates.
+    ///
+    /// The points are stored in clockwise order, starting with the top left corner.
+    inner_2x: [Point; 4],
+
+    /// Corner radii, scaled by a factor of 2.
+    ///
+    /// The radii are stored in clockwise order, starting with the top left corner.
+    radii_2x: [Size; 4],
+
+    /// Arc lengths of the corners.
+    corners: [QuadrantArcLengths; 4],
+}
+
+impl RoundedRectangleOutline {
+    /// Creates the outline of a rounded rectangle.
+    pub fn new(rounded_rectangle: &RoundedRectangle) -> Self {
+        let rounded_rectangle = rounded_rectangle.confine_radii();
+        let rectangle = &rounded_rectangle.rectangle;
+
+        let top_left_2x = rectangle.top_left * 2;
+        let bottom_right_2x = top_left_2x + rectangle.size.saturating_sub(Size::new_equal(1)) * 2;
+
+        let radii = &rounded_rectangle.corners;
+        let radii_2x = [
+            radii.top_left,
+            radii.top_right,
+            radii.bottom_right,
+            radii.bottom_left,
+        ]
+        .map(|radius| (radius * 2).saturating_sub(Size::new_equal(1)));
+
+        let inner_2x = [
+            top_left_2x + radii_2x[0],
+            Point::new(
+                bottom_right_2x.x - radii_2x[1].width as i32,
+                top_left_2x.y + radii_2x[1].height as i32,
+            ),
+            bottom_right_2x - radii_2x[2],
+            Point::new(
+                top_left_2x.x + radii_2x[3].width as i32,
+                bottom_right_2x.y - radii_2x[3].height as i32,
+            ),
+        ];
+
+        Self {
+            inner_2x,
+            radii_2x,
+            corners: radii_2x.map(QuadrantArcLengths::new),
+        }
+    }
+
+    /// Returns the lengths of the straight edges, in clockwise order starting with the top edge.
+    fn edge_lengths(&self) -> [u32; 4] {
+        let [tl, tr, br, bl] = self.inner_2x;
+        let scale = |value: i32| value.unsigned_abs().saturating_mul(OUTLINE_SCALE) / 2;
+
+        [
+            scale(tr.x - tl.x),
+            scale(br.y - tr.y),
+            scale(br.x - bl.x),
+            scale(bl.y - tl.y),
+        ]
+    }
+}
+
+impl Outline for RoundedRectangleOutline {
+    fn length(&self) -> u32 {
+        self.edge_lengths()
+            .into_iter()
+            .chain(self.corners.iter().map(QuadrantArcLengths::length))
+            .fold(0, u32::saturating_add)
+    }
+
+    fn position(&self, point: Point) -> u32 {
+        let p = point * 2;
+        let [tl, tr, br, bl] = self.inner_2x;
+        let [top, right, bottom, left] = self.edge_lengths();
+        let corner = |i: usize| self.corners[i].length();
+
+        // Start positions of the edges and corners in clockwise order.
+        let top_start = 0u32;
+        let tr_start = top_start.saturating_add(top);
+        let right_start = tr_start.saturating_add(corner(1));
+        let br_start = right_start.saturating_add(right);
+        let bottom_start = br_start.saturating_add(corner(2));
+        let bl_start = bottom_start.saturating_add(bottom);
+        let left_start = bl_start.saturating_add(corner(3));
+        let tl_start = left_start.saturating_add(left);
+
+        let along = |value: i32| value.unsigned_abs().saturating_mul(OUTLINE_SCALE) / 2;
+
+        // The top left and bottom right corner arcs start at the end of the horizontal semi-axis
+        // and the other corners start at the end of the vertical semi-axis.
+        let corner_position = |i: usize, center: Point, start: u32| {
+            let angle = elliptic_angle(p - center, self.radii_2x[i]);
+            let relative =
+                (angle.wrapping_sub(QUARTER_TURN * (i as u32 + 2)) % TURN).min(QUARTER_TURN);
+
+            let corner = &self.corners[i];
+            let length = match i {
+                0 | 2 => corner.arc_length(relative),
+                _ => corner.length() - corner.arc_length(QUARTER_TURN - relative),
+            };
+
+            start.saturating_add(length)
+        };
+
+        let in_x = p.x >= tl.x.min(bl.x) && p.x <= tr.x.max(br.x);
+        let in_y = p.y >= tl.y.min(tr.y) && p.y <= bl.y.max(br.y);
+
+        if p.x < tl.x && p.y < tl.y {
+            corner_position(0, tl, tl_start)
+        } else if p.x > tr.x && p.y < tr.y {
+            corner_position(1, tr, tr_start)
+        } else if p.x > br.x && p.y > br.y {
+            corner_position(2, br, br_start)
+        } else if p.x < bl.x && p.y > bl.y {
+            corner_position(3, bl, bl_start)
+        } else {
+            // Distances to the straight edges, which are used to find the nearest edge.
+            let distances = [
+                (p.y - (tl.y - self.radii_2x[0].height as i32)).abs(),
+                ((tr.x + self.radii_2x[1].width as i32) - p.x).abs(),
+                ((br.y + self.radii_2x[2].height as i32) - p.y).abs(),
+                (p.x - (bl.x - self.radii_2x[3].width as i32)).abs(),
+            ];
+
+            let nearest = if in_x && !in_y {
+                if p.y < tl.y {
+                    0
+                } else {
+                    2
+                }
+            } else if in_y && !in_x {
+                if p.x > tr.x {
+                    1
+                } else {
+                    3
+                }
+            } else {
+                (0..4).min_by_key(|i| distances[*i]).unwrap_or(0)
+            };
+
+            match nearest {
+                0 => top_start.saturating_add(along(p.x - tl.x).min(top)),
+                1 => right_start.saturating_add(along(p.y - tr.y).min(right)),
+                2 => bottom_start.saturating_add(along(br.x - p.x).min(bottom)),
+                _ => left_start.saturating_add(along(bl.y - p.y).min(left)),
+            }
+        }
+    }
+
+    fn point_at(&self, position: u32) -> Point {
+        let [tl, tr, br, bl] = self.inner_2x;
+        let [top, right, bottom, left] = self.edge_lengths();
+        let r = &self.radii_2x;
+
+        // Converts 2x scaled coordinates into scaled coordinates.
+        let scale = |value: i32| value * (OUTLINE_SCALE / 2) as i32;
+
+        let corner = |i: usize, along: u32| {
+            let corner = &self.corners[i];
+            let relative = match i {
+                0 | 2 => corner.angle(along),
+                _ => QUARTER_TURN - corner.angle(corner.length().saturating_sub(along)),
+            };
+
+            point_on_ellipse(
+                self.inner_2x[i],
+                r[i],
+                QUARTER_TURN
+                    .wrapping_mul(i as u32 + 2)
+                    .wrapping_add(relative),
+            )
+        };
+
+        let mut position = position;
+        for (i, edge) in [top, right, bottom, left].into_iter().enumerate() {
+            if position <= edge {
+                let along = position as i32;
+
+                return match i {
+                    0 => Point::new(scale(tl.x) + along, scale(tl.y - r[0].height as i32)),
+                    1 => Point::new(scale(tr.x + r[1].width as i32), scale(tr.y) + along),
+                    2 => Point::new(scale(br.x) - along, scale(br.y + r[2].height as i32)),
+                    _ => Point::new(scale(bl.x - r[3].width as i32), scale(bl.y) - along),
+                };
+            }
+            position -= edge;
+
+            let corner_index = (i + 1) % 4;
+            let corner_length = self.corners[corner_index].length();
+            if position <= corner_length || i == 3 {
+                return corner(corner_index, position.min(corner_length));
+            }
+            position -= corner_length;
+        }
+
+        unreachable!()
+    }
+
+    fn is_closed(&self) -> bool {
+        true
+    }
+}
+
+/// Creates the outline of a rectangle.
+///
+/// The outline runs through the center of the border pixels and starts at the top left corner.
+pub fn rectangle_outline(rectangle: &Rectangle) -> SegmentsOutline<[Point; 4]> {
+    let top_left = rectangle.top_left;
+    let bottom_right = rectangle.bottom_right().unwrap_or(top_left);
+
+    SegmentsOutline::closed([
+        top_left,
+        Point::new(bottom_right.x, top_left.y),
+        bottom_right,
+        Point::new(top_left.x, bottom_right.y),
+    ])
+}
+
+/// Returns the squared length of a vector.
+fn squared_length(delta: Point) -> u64 {
+    let x = u64::from(delta.x.unsigned_abs());
+    let y = u64::from(delta.y.unsigned_abs());
+
+    x.saturating_mul(x).saturating_add(y.saturating_mul(y))
+}
+
+/// Returns the scaled length of a vector.
+fn scaled_length(delta: Point) -> u32 {
+    let length = isqrt(squared_length(delta).saturating_mul(u64::from(OUTLINE_SCALE.pow(2))));
+
+    length.min(u64::from(u32::MAX)) as u32
+}
+
+/// Returns the squared distance between a point and a line segment.
+fn segment_distance(start: Point, end: Point, point: Point) -> u64 {
+    let delta = end - start;
+    let length_squared = i64::from(delta.length_squared());
+    let v = point - start;
+
+    let dot = i64::from(v.x) * i64::from(delta.x) + i64::from(v.y) * i64::from(delta.y);
+
+    if dot <= 0 || length_squared == 0 {
+        squared_length(v)
+    } else if dot >= length_squared {
+        squared_length(point - end)
+    } else {
+        let cross = i64::from(v.x) * i64::from(delta.y) - i64::from(v.y) * i64::from(delta.x);
+        cross.unsigned_abs().saturating_mul(cross.unsigned_abs()) / length_squared as u64
+    }
+}
+
+/// Returns the point on an ellipse at the given angle of the parametric equation.
+///
+/// The center point and the semi-axes are scaled by a factor of 2 and the returned point is
+/// scaled by [`OUTLINE_SCALE`].
+fn point_on_ellipse(center_2x: Point, radii_2x: Size, angle: u32) -> Point {
+    // The angle is folded into the first quadrant to make sure that the points are exactly
+    // symmetric to both axes. This also keeps the angle in the range of fixed point numbers.
+    let mut angle = angle % TURN;
+    let sign_y = if angle > TURN / 2 {
+        angle = TURN - angle;
+        -1
+    } else {
+        1
+    };
+    let sign_x = if angle > QUARTER_TURN {
+        angle = TURN / 2 - angle;
+        -1
+    } else {
+        1
+    };
+
+    let angle = Angle::from_radians(angle as f32 * (core::f32::consts::TAU / TURN as f32));
+
+    let scale = |center: i32, radius: u32, value: Real, sign: i32| {
+        let offset = radius as f32 * f32::from(value) * (OUTLINE_SCALE / 2) as f32;
+
+        center * (OUTLINE_SCALE / 2) as i32 + offset.round() as i32 * sign
+    };
+
+    Point::new(
+        scale(center_2x.x, radii_2x.width, angle.cos(), sign_x),
+        scale(center_2x.y, radii_2x.height, angle.sin(), sign_y),
+    )
+}
+
+/// Converts an angle to angle units.
+fn angle_units(angle: Angle) -> i64 {
+    let units = angle.to_radians() * (TURN as f32 / core::f32::consts::TAU);
+
+    if units >= 0.0 {
+        (units + 0.5) as i64
+    } else {
+        (units - 0.5) as i64
+    }
+}
+
+/// Returns the length of a circular arc with the given angle and diameter.
+///
+/// The diameter is measured between the outermost pixels and the arc is placed through the
+/// center of the pixels.
+fn scale_by_circumference(angle: u32, diameter: u32) -> u32 {
+    // PI scaled by `1 << 16`.
+    const PI: u64 = 205887;
+
+    let length =
+        u64::from(angle) * u64::from(diameter.saturating_sub(1)) * u64::from(OUTLINE_SCALE) * PI
+            / (u64::from(TURN) << 16);
+
+    length.min(u64::from(u32::MAX)) as u32
+}
+
+/// Calculates the integer square root.
+pub fn isqrt(value: u64) -> u64 {
+    if value < 2 {
+        return value;
+    }
+
+    let mut result = 0u64;
+    let mut remainder = value;
+    let mut bit = 1u64 << ((63 - value.leading_zeros()) & !1);
+
+    while bit != 0 {
+        if remainder >= result + bit {
+            remainder -= result + bit;
+            result = (result >> 1) + bit;
+        } else {
+            result >>= 1;
+        }
+        bit >>= 2;
+    }
+
+    result
+}
+
+/// Approximates the angle of a vector.
+///
+/// The returned angle is in the range `0..TURN` and increases in clockwise direction, starting
+/// at the positive X axis. The maximum error of the approximation is about 0.1°.
+pub fn atan2(y: i64, x: i64) -> u32 {
+    if x == 0 && y == 0 {
+        return 0;
+    }
+
+    let ax = x.unsigned_abs();
+    let ay = y.unsigned_abs();
+
+    // Reduce the angle to the first octant.
+    let (num, den) = if ay <= ax { (ay, ax) } else { (ax, ay) };
+    let t = ((u128::from(num) << 16) / u128::from(den)) as u64;
+
+    // atan(t) ≈ π/4·t + t·(1 - t)·(0.2447 + 0.0663·t), converted to angle units
+    let octant = (u64::from(TURN / 8) * t) >> 16;
+    let correction = (((t * ((1 << 16) - t)) >> 16) * (2552 + ((692 * t) >> 16))) >> 16;
+    let angle = (octant + correction) as u32;
+
+    let angle = if ay > ax { QUARTER_TURN - angle } else { angle };
+
+    match (x >= 0, y >= 0) {
+        (true, true) => angle % TURN,
+        (false, true) => TURN / 2 - angle,
+        (false, false) => TURN / 2 + angle,
+        (true, false) => (TURN - angle) % TURN,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{geometry::AngleUnit, primitives::PointsIter};
+
+    #[test]
+    fn integer_square_root() {
+        for value in 0..10_000u64 {
+            let root = isqrt(value);
+            assert!(root * root <= value && (root + 1) * (root + 1) > value);
+        }
+
+        assert_eq!(isqrt(u64::MAX), u64::from(u32::MAX));
+    }
+
+    #[test]
+    fn atan2_quadrants() {
+        assert_eq!(atan2(0, 10), 0);
+        assert_eq!(atan2(10, 0), TURN / 4);
+        assert_eq!(atan2(0, -10), TURN / 2);
+        assert_eq!(atan2(-10, 0), TURN * 3 / 4);
+
+        assert_eq!(atan2(10, 10), TURN / 8);
+        assert_eq!(atan2(10, -10), TURN * 3 / 8);
+        assert_eq!(atan2(-10, -10), TURN * 5 / 8);
+        assert_eq!(atan2(-10, 10), TURN * 7 / 8);
+    }
+
+    #[test]
+    fn atan2_accuracy() {
+        for degrees in 0..360 {
+            let angle = (degrees as f32).deg();
+            let (x, y) = (
+                (angle.to_radians().cos() * 10000.0) as i64,
+                (angle.to_radians().sin() * 10000.0) as i64,
+            );
+
+            let expected = angle_units(angle);
+            let error = (i64::from(atan2(y, x)) - expected).rem_euclid(i64::from(TURN));
+            let error = error.min(i64::from(TURN) - error);
+
+            // 0.1° is approximately 18 angle units
+            assert!(error <= 18, "{}°: error {}", degrees, error);
+        }
+    }
+
+    #[test]
+    fn segments_outline() {
+        let outline = SegmentsOutline::open(
+            [Point::new(0, 0), Point::new(10, 0), Point::new(10, 5)],
+            Point::zero(),
+        );
+
+        assert_eq!(outline.length(), 15 * OUTLINE_SCALE);
+        assert_eq!(outline.position(Point::new(-3, 0)), 0);
+        assert_eq!(outline.position(Point::new(4, 1)), 4 * OUTLINE_SCALE);
+        assert_eq!(outline.position(Point::new(11, 3)), 13 * OUTLINE_SCALE);
+        assert_eq!(outline.position(Point::new(10, 8)), 15 * OUTLINE_SCALE);
+
+        let closed =
+            SegmentsOutline::closed([Point::new(0, 0), Point::new(10, 0), Point::new(10, 5)]);
+        assert_eq!(closed.position(Point::new(2, 0)), 2 * OUTLINE_SCALE);
+        assert!(closed.length() > outline.length());
+    }
+
+    #[test]
+    fn segments_outline_cache() {
+        let vertices = [
+            Point::new(2, 1),
+            Point::new(20, 4),
+            Point::new(14, 12),
+            Point::new(22, 20),
+            Point::new(3, 18),
+        ];
+
+        for outline in [
+            SegmentsOutline::open(vertices, Point::new(1, 2)),
+            SegmentsOutline::closed(vertices).stroke_center(5, StrokeOffset::Left),
+        ] {
+            assert_eq!(outline.cache.len, vertices.len());
+
+            let uncached = SegmentsOutline {
+                cache: SegmentCache::EMPTY,
+                ..outline
+            };
+
+            assert_eq!(outline.length(), uncached.length());
+            for point in Rectangle::new(Point::zero(), Size::new(25, 25)).points() {
+                assert_eq!(outline.position(point), uncached.position(point));
+            }
+            for position in (0..outline.length()).step_by(7) {
+                assert_eq!(outline.point_at(position), uncached.point_at(position));
+            }
+        }
+
+        // Outlines with too many vertices aren't cached.
+        let outline = SegmentsOutline::closed([Point::zero(); CACHED_VERTICES + 1]);
+        assert_eq!(outline.cache.len, 0);
+    }
+
+    #[test]
+    fn circle_outline() {
+        let circle = Circle::with_center(Point::new(20, 20), 21);
+        let outline = CircleOutline::new(&circle);
+
+        // The circumference of a circle with a diameter of 20px (measured between pixel centers).
+        let length = outline.length();
+        assert!(
+            length.abs_diff((62.83 * OUTLINE_SCALE as f32) as u32) <= 1,
+            "{}",
+            length
+        );
+
+        assert_eq!(outline.position(Point::new(30, 20)), 0);
+        assert_eq!(outline.position(Point::new(20, 30)), length / 4);
+        assert_eq!(outline.position(Point::new(10, 20)), length / 2);
+        assert_eq!(outline.position(Point::new(20, 10)), length * 3 / 4);
+    }
+
+    #[test]
+    fn arc_outline() {
+        let circle = Circle::with_center(Point::new(20, 20), 21);
+        let quarter = CircleOutline::new(&circle).length() / 4;
+
+        let outline = CircleOutline::arc(&circle, 90.0.deg(), 90.0.deg());
+        assert_eq!(outline.length(), quarter);
+        assert_eq!(outline.position(Point::new(20, 30)), 0);
+        assert_eq!(outline.position(Point::new(10, 20)), quarter);
+        // points outside the arc are clamped to the nearest end point
+        assert_eq!(outline.position(Point::new(30, 21)), 0);
+        assert_eq!(outline.position(Point::new(19, 10)), quarter);
+
+        let outline = CircleOutline::arc(&circle, 90.0.deg(), -90.0.deg());
+        assert_eq!(outline.position(Point::new(20, 30)), 0);
+        assert_eq!(outline.position(Point::new(30, 20)), quarter);
+    }
+
+    #[test]
+    fn ellipse_outline() {
+        // An ellipse with equal axes is a circle.
+        let ellipse = Ellipse::with_center(Point::new(20, 20), Size::new_equal(21));
+        let circle = Circle::with_center(Point::new(20, 20), 21);
+
+        let ellipse_length = EllipseOutline::new(&ellipse).length();
+        let circle_length = CircleOutline::new(&circle).length();
+        assert!(ellipse_length.abs_diff(circle_length) <= circle_length / 200);
+
+        let outline =
+            EllipseOutline::new(&Ellipse::with_center(Point::new(20, 20), Size::new(41, 21)));
+        let length = outline.length();
+        assert_eq!(outline.position(Point::new(40, 20)), 0);
+        assert_eq!(outline.position(Point::new(20, 30)), length / 4);
+        assert_eq!(outline.position(Point::new(0, 20)), length / 2);
+        assert_eq!(outline.position(Point::new(20, 10)), length * 3 / 4);
+    }
+
+    #[test]
+    fn rounded_rectangle_outline() {
+        let rectangle = Rectangle::new(Point::new(0, 0), Size::new(21, 11));
+
+        // Without corner radii the outline is equal to the rectangle outline.
+        let outline = RoundedRectangleOutline::new(&RoundedRectangle::with_equal_corners(
+            rectangle,
+            Size::zero(),
+        ));
+        assert_eq!(outline.length(), rectangle_outline(&rectangle).length());
+        for point in [
+            Point::new(5, 0),
+            Point::new(20, 4),
+            Point::new(7, 10),
+            Point::new(0, 3),
+        ] {
+            assert_eq!(
+                outline.position(point),
+                rectangle_outline(&rectangle).position(point),
+                "{:?}",
+                point
+            );
+        }
+
+        let outline = RoundedRectangleOutline::new(&RoundedRectangle::with_equal_corners(
+            rectangle,
+            Size::new_equal(5),
+        ));
+        let corner = CircleOutline::new(&Circle::new(Point::zero(), 10)).length() / 4;
+        let top = 11 * OUTLINE_SCALE;
+        assert_eq!(outline.position(Point::new(5, 0)), OUTLINE_SCALE / 2);
+        assert_eq!(outline.position(Point::new(15, 0)), 21 * OUTLINE_SCALE / 2);
+        assert!(
+            outline
+                .position(Point::new(20, 5))
+                .abs_diff(top + corner + OUTLINE_SCALE / 2)
+                <= 2
+        );
+        assert!(
+            outline
+                .length()
+                .abs_diff(2 * (top + OUTLINE_SCALE) + 4 * corner)
+                <= 8
+        );
+    }
+
+    #[test]
+    fn sector_outline() {
+        let circle = Circle::with_center(Point::new(20, 20), 21);
+        let outline = SectorOutline::new(&circle, 0.0.deg(), 90.0.deg());
+        let quarter = CircleOutline::new(&circle).length() / 4;
+        let radius = 10 * OUTLINE_SCALE;
+
+        assert_eq!(outline.length(), 2 * radius + quarter);
+        assert_eq!(outline.position(Point::new(20, 20)), 0);
+        assert_eq!(outline.position(Point::new(25, 20)), radius / 2);
+        assert!(
+            outline
+                .position(Point::new(27, 27))
+                .abs_diff(radius + quarter / 2)
+                <= 2
+        );
+        assert_eq!(
+            outline.position(Point::new(20, 25)),
+            radius + quarter + radius / 2
+        );
+    }
+
+    #[test]
+    fn ring_outline() {
+        let ring = Ring::with_center(Point::new(20, 20), 21, 11);
+        let outer = CircleOutline::new(&ring.outer_circle()).length();
+        let inner = CircleOutline::new(&Circle::with_center(Point::new(20, 20), 11)).length();
+
+        let outline = RingOutline::new(&ring);
+        assert_eq!(outline.length(), outer + inner);
+        assert_eq!(outline.position(Point::new(30, 20)), 0);
+        assert_eq!(outline.position(Point::new(25, 20)), outer);
+        assert_eq!(outline.position(Point::new(20, 15)), outer + inner / 4);
+
+        let outline = RingOutline::new(&ring.with_angles(0.0.deg(), 90.0.deg()));
+        let radial = 5 * OUTLINE_SCALE;
+
+        assert_eq!(outline.length(), (outer + inner) / 4 + 2 * radial);
+        assert_eq!(outline.position(Point::new(30, 20)), 0);
+        assert_eq!(outline.position(Point::new(20, 30)), outer / 4);
+        assert_eq!(
+            outline.position(Point::new(20, 27)),
+            outer / 4 + 3 * OUTLINE_SCALE
+        );
+        assert_eq!(outline.position(Point::new(20, 25)), outer / 4 + radial);
+        assert_eq!(
+            outline.position(Point::new(27, 20)),
+            (outer + inner) / 4 + radial + 2 * OUTLINE_SCALE
+        );
+    }
+
+    /// Checks that the positions of the points returned by `point_at` match the input.
+    fn check_point_at<O: Outline>(outline: &O) {
+        let length = outline.length();
+
+        for position in (0..=length).step_by(OUTLINE_SCALE as usize / 2) {
+            let scaled = outline.point_at(position);
+            let half = OUTLINE_SCALE as i32 / 2;
+            let point = Point::new(
+                (scaled.x + half).div_euclid(OUTLINE_SCALE as i32),
+                (scaled.y + half).div_euclid(OUTLINE_SCALE as i32),
+            );
+
+            // Rounding to the pixel grid can move a point by up to 1px along the outline.
+            let error = outline.position(point).abs_diff(position);
+            let error = if outline.is_closed() {
+                error.min(length - error)
+            } else {
+                error
+            };
+
+            assert!(
+                error <= OUTLINE_SCALE * 3 / 2,
+                "position {}: {:?} has position {}",
+                position,
+                point,
+                outline.position(point)
+            );
+        }
+    }
+
+    #[test]
+    fn point_at() {
+        let circle = Circle::with_center(Point::new(20, 20), 21);
+        let rectangle = Rectangle::new(Point::new(3, 4), Size::new(21, 11));
+
+        check_point_at(&SegmentsOutline::open(
+            [Point::new(0, 0), Point::new(10, 0), Point::new(13, 5)],
+            Point::new(2, 3),
+        ));
+        check_point_at(&rectangle_outline(&rectangle));
+        check_point_at(&CircleOutline::new(&circle));
+        check_point_at(&CircleOutline::arc(&circle, 30.0.deg(), -200.0.deg()));
+        check_point_at(&SectorOutline::new(&circle, 10.0.deg(), 100.0.deg()));
+        check_point_at(&RingOutline::new(
+            &Ring::with_center(Point::new(20, 20), 21, 9).with_angles(10.0.deg(), 250.0.deg()),
+        ));
+        check_point_at(&EllipseOutline::new(&Ellipse::new(
+            Point::new(2, 5),
+            Size::new(41, 16),
+        )));
+        check_point_at(&RoundedRectangleOutline::new(
+            &RoundedRectangle::with_equal_corners(rectangle, Size::new(6, 4)),
+        ));
+
+        assert_eq!(
+            CircleOutline::new(&circle).point_at(0),
+            Point::new(30, 20) * OUTLINE_SCALE as i32
+        );
+        assert_eq!(
+            rectangle_outline(&rectangle).point_at(20 * OUTLINE_SCALE),
+            Point::new(23, 4) * OUTLINE_SCALE as i32
+        );
+    }
+}
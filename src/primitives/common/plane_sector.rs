@@ -82,6 +82,24 @@ impl PlaneSector {
         self.operation.execute(correct_side_1, correct_side_2)
     }
 
+    /// Returns the signed distance between a point and the edges of the plane sector.
+    ///
+    /// The distance is scaled by [`NORMAL_VECTOR_SCALE`] and is positive outside the sector. The
+    /// distance to the union of the half planes is an approximation, which is only accurate for
+    /// points that are close to one of the edges.
+    ///
+    /// [`NORMAL_VECTOR_SCALE`]: super::NORMAL_VECTOR_SCALE
+    pub fn distance(&self, point: Point) -> i32 {
+        let distance_right = -self.half_plane_right.distance(point);
+        let distance_left = self.half_plane_left.distance(point);
+
+        match self.operation {
+            Operation::Intersection => distance_right.max(distance_left),
+            Operation::Union => distance_right.min(distance_left),
+            Operation::EntirePlane => i32::MIN,
+        }
+    }
+
     /// Checks if a point is inside the stroke or fill area.
     pub fn point_type(
         &self,
@@ -159,6 +177,21 @@ mod tests {
         );
     }
 
+    #[test]
+    fn distance() {
+        let plane_sector = PlaneSector::new(0.0.deg(), 90.0.deg());
+        assert_eq!(plane_sector.distance(Point::new(10, 5)), -5 * 1024);
+        assert_eq!(plane_sector.distance(Point::new(-3, 10)), 3 * 1024);
+        assert_eq!(plane_sector.distance(Point::new(10, -2)), 2 * 1024);
+
+        let plane_sector = PlaneSector::new(0.0.deg(), 270.0.deg());
+        assert_eq!(plane_sector.distance(Point::new(-10, 10)), -10 * 1024);
+        assert_eq!(plane_sector.distance(Point::new(10, -2)), 2 * 1024);
+
+        let plane_sector = PlaneSector::new(0.0.deg(), 360.0.deg());
+        assert_eq!(plane_sector.distance(Point::new(10, -2)), i32::MIN);
+    }
+
     #[test]
     fn plane_sector_quadrants_negative_sweep() {
         let plane_sector = PlaneSector::new(0.0.deg(), -90.0.deg());
@@ -1,7 +1,7 @@
 use crate::{
     draw_target::DrawTarget,
     geometry::{Point, Size},
-    primitives::{Line, PointsIter, Rectangle},
+    primitives::{common::isqrt, Line, PointsIter, Rectangle},
 };
 use core::ops::Range;
 
@@ -154,6 +154,40 @@ impl Scanline {
     }
 }
 
+impl Scanline {
+    /// Draws the scanline with a color that can vary along the scanline.
+    ///
+    /// Pixels with the same color are combined into runs that are drawn with a single call to
+    /// `fill_solid`. Pixels for which `color` returns `None` aren't drawn.
+    pub fn draw_runs<T, F>(&self, target: &mut T, mut color: F) -> Result<(), T::Error>
+    where
+        T: DrawTarget,
+        F: FnMut(Point) -> Option<T::Color>,
+    {
+        let mut run_start = self.x.start;
+        let mut run_color = None;
+
+        for x in self.x.clone() {
+            let pixel_color = color(Point::new(x, self.y));
+
+            if pixel_color != run_color {
+                if let Some(run_color) = run_color {
+                    Self::new(self.y, run_start..x).draw(target, run_color)?;
+                }
+
+                run_start = x;
+                run_color = pixel_color;
+            }
+        }
+
+        if let Some(run_color) = run_color {
+            Self::new(self.y, run_start..self.x.end).draw(target, run_color)?;
+        }
+
+        Ok(())
+    }
+}
+
 impl Iterator for Scanline {
     type Item = Point;
 
@@ -162,6 +196,80 @@ impl Iterator for Scanline {
     }
 }
 
+/// Returns the run with the lowest start point that ends after the given X coordinate.
+///
+/// The returned run is clipped to start at or after `x`.
+pub fn first_run(x: i32, runs: impl Iterator<Item = Range<i32>>) -> Option<Range<i32>> {
+    runs.filter(|run| !run.is_empty() && run.end > x)
+        .map(|run| run.start.max(x)..run.end)
+        .min_by_key(|run| run.start)
+}
+
+/// Merges adjacent and overlapping runs.
+///
+/// The `next_run` function returns the first run that ends after the given X coordinate.
+pub fn merge_runs<F>(x: i32, next_run: F) -> Option<Range<i32>>
+where
+    F: Fn(i32) -> Option<Range<i32>>,
+{
+    let mut run = next_run(x)?;
+
+    while let Some(next) = next_run(run.end).filter(|next| next.start <= run.end) {
+        run.end = next.end;
+    }
+
+    Some(run)
+}
+
+/// Returns the run of pixels in a row that are inside a disc.
+///
+/// The disc is centered on the pixel `center` and a pixel is inside the disc if the distance
+/// between the pixel centers is less than half the diameter.
+pub fn disc_run(center: Point, diameter: u32, y: i32) -> Option<Range<i32>> {
+    let diameter = i64::from(diameter);
+    let v = i64::from(y - center.y);
+
+    // Points are inside the disc if `4 * (u² + v²) < diameter²`.
+    let remainder = diameter * diameter - 4 * v * v;
+    if remainder <= 0 {
+        return None;
+    }
+
+    let radius = isqrt(((remainder - 1) / 4) as u64) as i32;
+
+    Some(center.x - radius..center.x + radius + 1)
+}
+
+/// Returns the bounding box of the pixels that are inside a disc.
+///
+/// See [`disc_run`] for the definition of the disc.
+pub fn disc_bounding_box(center: Point, diameter: u32) -> Rectangle {
+    Rectangle::with_center(
+        center,
+        Size::new_equal(diameter.saturating_sub(1) / 2 * 2 + 1),
+    )
+}
+
+/// Solves `lower <= a * u + b <= upper` for `u`.
+///
+/// Returns the range of all integer solutions or `None` if there are no solutions.
+pub fn solve(a: i64, b: i64, lower: i64, upper: i64) -> Option<Range<i64>> {
+    let (a, lower, upper) = match a {
+        0 => {
+            return (lower..=upper)
+                .contains(&b)
+                .then_some(i64::from(i32::MIN)..i64::from(i32::MAX))
+        }
+        a if a < 0 => (-a, b - upper, b - lower),
+        a => (a, lower - b, upper - b),
+    };
+
+    let start = -((-lower).div_euclid(a));
+    let end = upper.div_euclid(a) + 1;
+
+    (start < end).then_some(start..end)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,6 +1,12 @@
 use core::ops::Range;
 
-use crate::{draw_target::DrawTarget, primitives::common::Scanline};
+use crate::{
+    draw_target::DrawTarget,
+    primitives::{
+        common::{Outline, PatternedStroke, Scanline, StrokeArea},
+        ContainsPoint,
+    },
+};
 
 /// Scanline with stroke and fill regions.
 #[derive(Clone, Eq, PartialEq, Hash, Debug)]
@@ -63,4 +69,27 @@ impl StyledScanline {
         self.fill().draw(target, fill_color)?;
         self.stroke_right().draw(target, stroke_color)
     }
+
+    /// Draws the stroke regions with a dashed or dotted stroke and the fill region.
+    pub fn draw_patterned<T, O, A, P>(
+        &self,
+        target: &mut T,
+        stroke: &PatternedStroke<O, A, P>,
+        stroke_color: T::Color,
+        fill_color: Option<T::Color>,
+    ) -> Result<(), T::Error>
+    where
+        T: DrawTarget,
+        O: Outline,
+        A: StrokeArea,
+        P: ContainsPoint,
+    {
+        let color = |point| stroke.color(point, stroke_color, fill_color);
+
+        self.stroke_left().draw_runs(target, color)?;
+        if let Some(fill_color) = fill_color {
+            self.fill().draw(target, fill_color)?;
+        }
+        self.stroke_right().draw_runs(target, color)
+    }
 }
@@ -3,26 +3,32 @@
 use crate::{
     geometry::Dimensions,
     primitives::{
-        common::{LineJoin, Scanline},
+        common::{Scanline, SegmentJoin},
         Line, Rectangle,
     },
 };
 
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
 pub struct ThickSegment {
-    start_join: LineJoin,
-    end_join: LineJoin,
+    start_join: SegmentJoin,
+    end_join: SegmentJoin,
 }
 
 impl ThickSegment {
     /// Create a new thick segment from two joints.
-    pub const fn new(start_join: LineJoin, end_join: LineJoin) -> Self {
+    pub const fn new(start_join: SegmentJoin, end_join: SegmentJoin) -> Self {
         Self {
             start_join,
             end_join,
         }
     }
 
+    /// Returns the join at the end of this segment.
+    pub const fn end_join(&self) -> SegmentJoin {
+        self.end_join
+    }
+
     /// Check whether the thick segment is thick or not.
     pub fn is_skeleton(&self) -> bool {
         self.start_join.first_edge_end.left == self.start_join.first_edge_end.right
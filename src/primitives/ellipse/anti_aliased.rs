@@ -0,0 +1,197 @@
+//! Anti-aliased ellipses.
+
+use crate::{
+    geometry::{Dimensions, Point, Size},
+    pixelcolor::PixelColor,
+    primitives::{
+        common::{isqrt, AntiAliasedShape, Coverage},
+        ellipse::{center_2x, Ellipse},
+        AntiAliasedStyle, Rectangle,
+    },
+};
+
+/// Anti-aliased ellipse.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub(in crate::primitives) struct AntiAliasedEllipse {
+    /// Outside edge of the stroke.
+    outside_edge: Ellipse,
+
+    /// Size of the inside edge of the stroke.
+    inside_size: Size,
+
+    has_fill: bool,
+}
+
+impl AntiAliasedEllipse {
+    /// Returns the distance between a point and an ellipse in 1/256 px.
+    ///
+    /// `delta` is the vector between the center of the ellipse and the point in half pixels. The
+    /// distance is approximated by dividing the implicit ellipse equation by the length of its
+    /// gradient, which is accurate close to the edge of the ellipse.
+    fn distance(delta: Point, size: Size) -> i64 {
+        if size.width == 0 || size.height == 0 {
+            return i64::MAX / 2;
+        }
+
+        let x = i128::from(delta.x).pow(2);
+        let y = i128::from(delta.y).pow(2);
+        let a = i128::from(size.width).pow(2);
+        let b = i128::from(size.height).pow(2);
+
+        let value = x * b + y * a - a * b;
+        let gradient = sqrt_u128((x * b * b + y * a * a) as u128) as i128;
+
+        if gradient == 0 {
+            return i64::MIN / 2;
+        }
+
+        (value * 64 / gradient).clamp(i128::from(i64::MIN / 2), i128::from(i64::MAX / 2)) as i64
+    }
+}
+
+/// Returns the integer square root of a 128 bit number.
+///
+/// Small values are calculated exactly and the precision of large values is reduced to 64 bits.
+fn sqrt_u128(value: u128) -> u128 {
+    let shift = ((128 - value.leading_zeros()).saturating_sub(64) + 1) & !1;
+
+    u128::from(isqrt((value >> shift) as u64)) << (shift / 2)
+}
+
+impl AntiAliasedShape for AntiAliasedEllipse {
+    fn area(&self) -> Rectangle {
+        self.outside_edge.bounding_box()
+    }
+
+    fn coverage(&self, point: Point) -> Coverage {
+        let delta = point * 2 - center_2x(self.outside_edge.top_left, self.outside_edge.size);
+
+        Coverage::from_edges(
+            Self::distance(delta, self.outside_edge.size),
+            Self::distance(delta, self.inside_size),
+            self.has_fill,
+        )
+    }
+}
+
+impl Ellipse {
+    /// Returns the anti-aliased shape of the ellipse.
+    pub(in crate::primitives) fn anti_aliased<C: PixelColor>(
+        &self,
+        style: &AntiAliasedStyle<C>,
+    ) -> AntiAliasedEllipse {
+        let (outside_edge, inside_edge) = style.stroke_edges(self);
+
+        AntiAliasedEllipse {
+            outside_edge,
+            inside_size: inside_edge.size,
+            has_fill: style.primitive_style.fill_color.is_some(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        mock_display::MockDisplay,
+        pixelcolor::{Gray8, GrayColor},
+        primitives::{Circle, PointsIter, Primitive, PrimitiveStyle, PrimitiveStyleBuilder},
+        Drawable,
+    };
+
+    fn style(stroke_width: u32) -> AntiAliasedStyle<Gray8> {
+        AntiAliasedStyle::new(
+            PrimitiveStyleBuilder::new()
+                .stroke_color(Gray8::WHITE)
+                .stroke_width(stroke_width)
+                .fill_color(Gray8::new(100))
+                .build(),
+            Gray8::BLACK,
+        )
+    }
+
+    #[test]
+    fn square_root() {
+        assert_eq!(sqrt_u128(0), 0);
+        assert_eq!(sqrt_u128(99), 9);
+        assert_eq!(sqrt_u128(1 << 100), 1 << 50);
+    }
+
+    #[test]
+    fn distance_on_axes() {
+        let size = Size::new(20, 10);
+
+        assert_eq!(AntiAliasedEllipse::distance(Point::new(20, 0), size), 0);
+        assert_eq!(AntiAliasedEllipse::distance(Point::new(0, -10), size), 0);
+        assert_eq!(AntiAliasedEllipse::distance(Point::new(0, 8), size), -288);
+    }
+
+    #[test]
+    fn circular_ellipse_matches_circle() {
+        for stroke_width in [0, 1, 4] {
+            let mut expected = MockDisplay::new();
+            Circle::new(Point::new(5, 6), 31)
+                .into_styled(style(stroke_width))
+                .draw(&mut expected)
+                .unwrap();
+
+            let mut display = MockDisplay::new();
+            Ellipse::new(Point::new(5, 6), Size::new(31, 31))
+                .into_styled(style(stroke_width))
+                .draw(&mut display)
+                .unwrap();
+
+            for point in expected.affected_area().points() {
+                let expected = expected.get_pixel(point).map_or(0, |c| c.luma());
+                let actual = display.get_pixel(point).map_or(0, |c| c.luma());
+
+                assert!(
+                    expected.abs_diff(actual) <= 2,
+                    "{:?}: {} != {}",
+                    point,
+                    expected,
+                    actual
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn stroke_only() {
+        let mut display = MockDisplay::new();
+        Ellipse::new(Point::new(2, 10), Size::new(60, 30))
+            .into_styled(AntiAliasedStyle::new(
+                PrimitiveStyle::with_stroke(Gray8::WHITE, 2),
+                Gray8::BLACK,
+            ))
+            .draw(&mut display)
+            .unwrap();
+
+        let luma = |point| display.get_pixel(point).map_or(0, |c: Gray8| c.luma());
+
+        assert_eq!(luma(Point::new(32, 25)), 0);
+        assert!(luma(Point::new(2, 25)) > 250);
+        assert!(luma(Point::new(32, 10)) > 250);
+    }
+
+    #[test]
+    fn bounding_box_contains_drawn_pixels() {
+        for stroke_width in [0, 1, 5] {
+            let styled =
+                Ellipse::new(Point::new(4, 8), Size::new(50, 21)).into_styled(style(stroke_width));
+
+            let mut display = MockDisplay::new();
+            styled.draw(&mut display).unwrap();
+
+            let area = display.affected_area();
+            assert_eq!(
+                styled.bounding_box().intersection(&area),
+                area,
+                "{} px",
+                stroke_width
+            );
+        }
+    }
+}
@@ -3,9 +3,10 @@
 use crate::{
     geometry::{Dimensions, Point, Size},
     primitives::{circle, ContainsPoint, OffsetOutline, PointsIter, Primitive, Rectangle},
-    transform::Transform,
+    transform::{GeometricTransform, Transform, Transformation},
 };
 
+mod anti_aliased;
 mod points;
 mod styled;
 
@@ -171,6 +172,16 @@ impl Transform for Ellipse {
     }
 }
 
+impl GeometricTransform for Ellipse {
+    type Output = Option<Ellipse>;
+
+    fn transform(&self, transformation: &Transformation) -> Self::Output {
+        transformation
+            .transform_rectangle(&self.bounding_box())
+            .map(|bounding_box| Self::new(bounding_box.top_left, bounding_box.size))
+    }
+}
+
 /// Determines if a point is inside an ellipse.
 // TODO: Make this available to the user as part of #343
 #[derive(Clone, Copy, Eq, PartialEq, PartialOrd, Ord, Hash, Debug)]
@@ -219,7 +230,7 @@ impl EllipseContains {
 mod tests {
     use super::*;
     use crate::{
-        geometry::{Point, Size},
+        geometry::{AngleUnit, Point, Size},
         mock_display::MockDisplay,
         pixelcolor::BinaryColor,
         primitives::ContainsPoint,
@@ -278,4 +289,19 @@ mod tests {
             Ellipse::with_center(center, Size::new(0, 0))
         );
     }
+
+    #[test]
+    fn geometric_transform() {
+        let ellipse = Ellipse::new(Point::new(2, 3), Size::new(10, 5));
+
+        assert_eq!(
+            ellipse.rotate(Point::new(2, 3), 90.0.deg()),
+            Some(Ellipse::new(Point::new(-2, 3), Size::new(5, 10)))
+        );
+        assert_eq!(
+            ellipse.scale_ratio(Point::zero(), 1, 2),
+            Some(Ellipse::new(Point::new(1, 2), Size::new(5, 3)))
+        );
+        assert_eq!(ellipse.rotate(Point::new(2, 3), 30.0.deg()), None);
+    }
 }
@@ -3,7 +3,7 @@ use crate::{
     geometry::{Dimensions, Point},
     pixelcolor::PixelColor,
     primitives::{
-        common::{Scanline, StyledScanline},
+        common::{Band, EllipseOutline, PatternedStroke, Scanline, StyledScanline},
         ellipse::{points::Scanlines, Ellipse, EllipseContains},
         styled::{StyledDimensions, StyledDrawable, StyledPixels},
         PrimitiveStyle, Rectangle,
@@ -24,6 +24,8 @@ pub struct StyledPixelsIterator<C> {
 
     stroke_color: Option<C>,
     fill_color: Option<C>,
+
+    pattern: Option<PatternedStroke<EllipseOutline, Band<Ellipse>, Ellipse>>,
 }
 
 impl<C: PixelColor> StyledPixelsIterator<C> {
@@ -38,6 +40,7 @@ impl<C: PixelColor> StyledPixelsIterator<C> {
             stroke_right: Scanline::new_empty(0),
             stroke_color: style.stroke_color,
             fill_color: style.fill_color,
+            pattern: patterned_stroke(primitive, style),
         }
     }
 }
@@ -46,6 +49,24 @@ impl<C: PixelColor> Iterator for StyledPixelsIterator<C> {
     type Item = Pixel<C>;
 
     fn next(&mut self) -> Option<Self::Item> {
+        if let (Some(pattern), Some(stroke_color)) = (&self.pattern, self.stroke_color) {
+            loop {
+                if let Some(point) = self.stroke_left.next().or_else(|| self.stroke_right.next()) {
+                    if let Some(color) = pattern.color(point, stroke_color, self.fill_color) {
+                        return Some(Pixel(point, color));
+                    }
+                } else if let (Some(point), Some(fill_color)) = (self.fill.next(), self.fill_color)
+                {
+                    return Some(Pixel(point, fill_color));
+                } else {
+                    let scanline = self.styled_scanlines.next()?;
+                    self.stroke_left = scanline.stroke_left();
+                    self.fill = scanline.fill();
+                    self.stroke_right = scanline.stroke_right();
+                }
+            }
+        }
+
         match (self.stroke_color, self.fill_color) {
             (Some(stroke_color), None) => loop {
                 if let Some(pixel) = self
@@ -90,6 +111,27 @@ impl<C: PixelColor> Iterator for StyledPixelsIterator<C> {
     }
 }
 
+/// Returns the dashed or dotted stroke if the style uses a non solid stroke.
+fn patterned_stroke<C: PixelColor>(
+    primitive: &Ellipse,
+    style: &PrimitiveStyle<C>,
+) -> Option<PatternedStroke<EllipseOutline, Band<Ellipse>, Ellipse>> {
+    let outline = EllipseOutline::new(&style.stroke_center_outline(primitive));
+
+    if let Some(dot_size) = style.dot_size() {
+        let area = Band::new(
+            style.stroke_area(primitive),
+            style.stroke_inner_area(primitive),
+        );
+
+        return Some(PatternedStroke::dotted(outline, area, dot_size, *primitive));
+    }
+
+    style
+        .dash_pattern()
+        .map(|pattern| PatternedStroke::dashed(outline, pattern, *primitive))
+}
+
 impl<C: PixelColor> StyledPixels<PrimitiveStyle<C>> for Ellipse {
     type Iter = StyledPixelsIterator<C>;
 
@@ -110,6 +152,17 @@ impl<C: PixelColor> StyledDrawable<PrimitiveStyle<C>> for Ellipse {
     where
         D: DrawTarget<Color = C>,
     {
+        if let (Some(stroke_color), Some(pattern)) = (
+            style.effective_stroke_color(),
+            patterned_stroke(self, style),
+        ) {
+            for scanline in StyledScanlines::new(&style.stroke_area(self), &style.fill_area(self)) {
+                scanline.draw_patterned(target, &pattern, stroke_color, style.fill_color)?;
+            }
+
+            return Ok(());
+        }
+
         match (style.effective_stroke_color(), style.fill_color) {
             (Some(stroke_color), None) => {
                 for scanline in
@@ -417,4 +470,20 @@ mod tests {
             filled_ellipse.bounding_box(),
         );
     }
+
+    #[test]
+    fn dashed_stroke() {
+        crate::primitives::common::check_dashed_stroke(Ellipse::new(
+            Point::new(6, 7),
+            Size::new(40, 25),
+        ));
+    }
+
+    #[test]
+    fn dotted_stroke() {
+        crate::primitives::common::check_dotted_stroke(Ellipse::new(
+            Point::new(6, 7),
+            Size::new(40, 25),
+        ));
+    }
 }
@@ -0,0 +1,272 @@
+use crate::{
+    draw_target::DrawTarget,
+    geometry::Point,
+    pixelcolor::{BinaryColor, PixelColor},
+    primitives::{
+        styled::{StyledDimensions, StyledDrawable, StyledPixels},
+        Circle, Ellipse, Path, Polygon, PrimitiveStyle, Rectangle, Ring, RoundedRectangle, Sector,
+        Triangle,
+    },
+    Pixel,
+};
+
+/// Source of the fill colors of a [`FillStyle`].
+///
+/// Implemented by [`Gradient`] and [`Pattern`].
+///
+/// [`Gradient`]: crate::primitives::Gradient
+/// [`Pattern`]: crate::primitives::Pattern
+pub trait FillColorSource<C: PixelColor> {
+    /// Returns the fill color at the given point.
+    ///
+    /// The point is an absolute coordinate on the draw target. Pixels for which `None` is
+    /// returned aren't drawn.
+    fn fill_color(&self, point: Point) -> Option<C>;
+
+    /// Returns `true` if this source doesn't return a color for any point.
+    fn is_empty(&self) -> bool;
+}
+
+/// Fill style with a color per pixel.
+///
+/// `FillStyle` can be applied to a closed primitive ([`Rectangle`], [`RoundedRectangle`],
+/// [`Circle`], [`Ellipse`], [`Sector`], [`Ring`], [`Triangle`], [`Polygon`] or closed [`Path`]) to
+/// fill the primitive with colors from a [`FillColorSource`] instead of a single color. The stroke
+/// is drawn as defined by the `primitive_style`, and the `fill_color` of the `primitive_style` is
+/// ignored.
+///
+/// See [`GradientStyle`] and [`PatternStyle`] for examples.
+///
+/// [`Rectangle`]: crate::primitives::Rectangle
+/// [`RoundedRectangle`]: crate::primitives::RoundedRectangle
+/// [`Circle`]: crate::primitives::Circle
+/// [`Ellipse`]: crate::primitives::Ellipse
+/// [`Sector`]: crate::primitives::Sector
+/// [`Ring`]: crate::primitives::Ring
+/// [`Triangle`]: crate::primitives::Triangle
+/// [`Polygon`]: crate::primitives::Polygon
+/// [`Path`]: crate::primitives::Path
+/// [`GradientStyle`]: crate::primitives::GradientStyle
+/// [`PatternStyle`]: crate::primitives::PatternStyle
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub struct FillStyle<S, C>
+where
+    C: PixelColor,
+{
+    /// Primitive style.
+    ///
+    /// The `fill_color` of the primitive style is replaced by the fill color source.
+    pub primitive_style: PrimitiveStyle<C>,
+
+    /// Fill color source.
+    pub fill: S,
+}
+
+impl<S, C> FillStyle<S, C>
+where
+    C: PixelColor,
+{
+    /// Creates a new fill style.
+    pub const fn new(primitive_style: PrimitiveStyle<C>, fill: S) -> Self {
+        Self {
+            primitive_style,
+            fill,
+        }
+    }
+}
+
+impl<S, C> FillStyle<S, C>
+where
+    S: FillColorSource<C>,
+    C: PixelColor,
+{
+    /// Returns a binary style which marks the stroke pixels as `On` and the fill pixels as `Off`.
+    fn marker_style(&self) -> PrimitiveStyle<BinaryColor> {
+        let stroke_color = self.primitive_style.stroke_color.map(|_| BinaryColor::On);
+        let fill_color = (!self.fill.is_empty()).then_some(BinaryColor::Off);
+
+        self.primitive_style.with_colors(fill_color, stroke_color)
+    }
+
+    /// Returns the primitive style without the fill color.
+    fn stroke_style(&self) -> PrimitiveStyle<C> {
+        self.primitive_style
+            .with_colors(None, self.primitive_style.stroke_color)
+    }
+}
+
+/// Pixel iterator for primitives with a fill style.
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub struct FillPixels<I, S, C> {
+    iter: I,
+    fill: S,
+    stroke_color: Option<C>,
+}
+
+impl<I, S, C> Iterator for FillPixels<I, S, C>
+where
+    I: Iterator<Item = Pixel<BinaryColor>>,
+    S: FillColorSource<C>,
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for Pixel(point, marker) in &mut self.iter {
+            let color = match marker {
+                BinaryColor::On => self.stroke_color,
+                BinaryColor::Off => self.fill.fill_color(point),
+            };
+
+            if let Some(color) = color {
+                return Some(Pixel(point, color));
+            }
+        }
+
+        None
+    }
+}
+
+macro_rules! impl_fill_style {
+    ($($primitive:ty),*) => {
+        $(
+            impl<S, C> StyledPixels<FillStyle<S, C>> for $primitive
+            where
+                S: FillColorSource<C> + Clone,
+                C: PixelColor,
+            {
+                type Iter = FillPixels<
+                    <Self as StyledPixels<PrimitiveStyle<BinaryColor>>>::Iter,
+                    S,
+                    C,
+                >;
+
+                fn pixels(&self, style: &FillStyle<S, C>) -> Self::Iter {
+                    FillPixels {
+                        iter: self.pixels(&style.marker_style()),
+                        fill: style.fill.clone(),
+                        stroke_color: style.primitive_style.stroke_color,
+                    }
+                }
+            }
+
+            impl<S, C> StyledDrawable<FillStyle<S, C>> for $primitive
+            where
+                S: FillColorSource<C> + Clone,
+                C: PixelColor,
+            {
+                type Color = C;
+                type Output = ();
+
+                fn draw_styled<D>(
+                    &self,
+                    style: &FillStyle<S, C>,
+                    target: &mut D,
+                ) -> Result<Self::Output, D::Error>
+                where
+                    D: DrawTarget<Color = C>,
+                {
+                    // Only the fill is drawn pixel by pixel. The solid stroke is drawn with the
+                    // primitive style to keep its optimized draw calls.
+                    target.draw_iter(FillPixels {
+                        iter: self.pixels(&style.marker_style()),
+                        fill: style.fill.clone(),
+                        stroke_color: None,
+                    })?;
+
+                    self.draw_styled(&style.stroke_style(), target)
+                }
+            }
+
+            impl<S, C> StyledDimensions<FillStyle<S, C>> for $primitive
+            where
+                S: FillColorSource<C>,
+                C: PixelColor,
+            {
+                fn styled_bounding_box(&self, style: &FillStyle<S, C>) -> Rectangle {
+                    self.styled_bounding_box(&style.marker_style())
+                }
+            }
+        )*
+    };
+}
+
+impl_fill_style!(
+    Rectangle,
+    RoundedRectangle,
+    Circle,
+    Ellipse,
+    Sector,
+    Ring,
+    Triangle,
+    Polygon<'_>,
+    Path<'_>
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        geometry::{Dimensions, Size},
+        iterator::PixelIteratorExt,
+        mock_display::MockDisplay,
+        primitives::{BinaryPattern, Pattern, PatternStyle, Primitive},
+        Drawable,
+    };
+
+    /// Draw target which counts the pixels that are drawn by `draw_iter`.
+    struct PixelCounter {
+        display: MockDisplay<BinaryColor>,
+        pixels: usize,
+    }
+
+    impl DrawTarget for PixelCounter {
+        type Color = BinaryColor;
+        type Error = core::convert::Infallible;
+
+        fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+        where
+            I: IntoIterator<Item = Pixel<Self::Color>>,
+        {
+            let count = &mut self.pixels;
+
+            self.display
+                .draw_iter(pixels.into_iter().inspect(|_| *count += 1))
+        }
+
+        fn fill_solid(&mut self, area: &Rectangle, color: Self::Color) -> Result<(), Self::Error> {
+            self.display.fill_solid(area, color)
+        }
+    }
+
+    impl Dimensions for PixelCounter {
+        fn bounding_box(&self) -> Rectangle {
+            self.display.bounding_box()
+        }
+    }
+
+    #[test]
+    fn solid_stroke_is_not_drawn_per_pixel() {
+        let pattern = BinaryPattern::checkerboard(BinaryColor::On, BinaryColor::Off);
+        let rect =
+            Rectangle::new(Point::new(1, 1), Size::new(6, 4)).into_styled(PatternStyle::new(
+                PrimitiveStyle::with_stroke(BinaryColor::On, 1),
+                Pattern::new(&pattern, Point::zero()),
+            ));
+
+        let mut counter = PixelCounter {
+            display: MockDisplay::new(),
+            pixels: 0,
+        };
+        rect.draw(&mut counter).unwrap();
+
+        // Only the 4x2 fill area is drawn pixel by pixel.
+        assert_eq!(counter.pixels, 8);
+
+        let mut expected = MockDisplay::new();
+        rect.pixels().draw(&mut expected).unwrap();
+        counter.display.assert_eq(&expected);
+    }
+}
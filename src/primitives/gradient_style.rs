@@ -0,0 +1,431 @@
+use crate::{
+    geometry::Point,
+    pixelcolor::{PixelColor, Rgb888, RgbColor},
+    primitives::{
+        common::isqrt,
+        fill_style::{FillColorSource, FillStyle},
+    },
+};
+
+/// Gradient fill style.
+///
+/// `GradientStyle` is a [`FillStyle`] which fills a closed primitive ([`Rectangle`],
+/// [`RoundedRectangle`], [`Circle`], [`Ellipse`], [`Sector`], [`Ring`], [`Triangle`], [`Polygon`]
+/// or closed [`Path`]) with a [`Gradient`] instead of a single color. The stroke is drawn as defined by the
+/// `primitive_style`, and the `fill_color` of the `primitive_style` is ignored.
+///
+/// The colors of the gradient are interpolated in the [`Rgb888`] color space and converted to the
+/// color type of the draw target.
+///
+/// # Examples
+///
+/// ## Draw a button with a vertical gradient
+///
+/// ```
+/// use embedded_graphics::{
+///     pixelcolor::{Rgb565, Rgb888},
+///     prelude::*,
+///     primitives::{
+///         ColorStop, Gradient, GradientStyle, PrimitiveStyle, Rectangle, RoundedRectangle,
+///     },
+/// };
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display = MockDisplay::<Rgb565>::new();
+///
+/// const STOPS: [ColorStop; 3] = [
+///     ColorStop::new(0, Rgb888::CSS_LIGHT_BLUE),
+///     ColorStop::new(128, Rgb888::CSS_DODGER_BLUE),
+///     ColorStop::new(255, Rgb888::CSS_NAVY),
+/// ];
+///
+/// let button = Rectangle::new(Point::new(2, 10), Size::new(60, 20));
+///
+/// let style = GradientStyle::new(
+///     PrimitiveStyle::with_stroke(Rgb565::WHITE, 1),
+///     Gradient::linear(button.top_left, button.bottom_right().unwrap(), &STOPS),
+/// );
+///
+/// RoundedRectangle::with_equal_corners(button, Size::new(6, 6))
+///     .into_styled(style)
+///     .draw(&mut display)?;
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+///
+/// [`Rectangle`]: crate::primitives::Rectangle
+/// [`RoundedRectangle`]: crate::primitives::RoundedRectangle
+/// [`Circle`]: crate::primitives::Circle
+/// [`Ellipse`]: crate::primitives::Ellipse
+/// [`Sector`]: crate::primitives::Sector
+/// [`Ring`]: crate::primitives::Ring
+/// [`Triangle`]: crate::primitives::Triangle
+/// [`Polygon`]: crate::primitives::Polygon
+/// [`Path`]: crate::primitives::Path
+/// [`Rgb888`]: crate::pixelcolor::Rgb888
+/// [`FillStyle`]: crate::primitives::FillStyle
+pub type GradientStyle<'a, C> = FillStyle<Gradient<'a>, C>;
+
+/// Color gradient.
+///
+/// The color of a gradient is defined by a list of [`ColorStop`]s, which must be sorted by their
+/// position. Pixels before the first stop use the color of the first stop and pixels after the
+/// last stop use the color of the last stop.
+///
+/// The coordinates of a gradient are absolute coordinates on the draw target and aren't changed
+/// if the styled primitive is translated.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub enum Gradient<'a> {
+    /// Linear gradient.
+    ///
+    /// The color changes along the line from `start` to `end` and is constant perpendicular to
+    /// this line. Position `0` of the color stops is at `start` and position `255` is at `end`.
+    Linear {
+        /// Start point.
+        start: Point,
+        /// End point.
+        end: Point,
+        /// Color stops.
+        stops: &'a [ColorStop],
+    },
+
+    /// Radial gradient.
+    ///
+    /// The color changes with the distance from the `center` point. Position `0` of the color
+    /// stops is at the center and position `255` is `radius` pixels away from the center.
+    Radial {
+        /// Center point.
+        center: Point,
+        /// Radius in pixels.
+        radius: u32,
+        /// Color stops.
+        stops: &'a [ColorStop],
+    },
+}
+
+/// Scaling factor of a color stop position.
+///
+/// Positions on the gradient are calculated with 8 additional bits of precision to get smooth
+/// transitions between color stops which are close to each other.
+const POSITION_SCALE: u32 = 256;
+
+/// Position of the end of a gradient.
+const END_POSITION: u32 = 255 * POSITION_SCALE;
+
+impl<'a> Gradient<'a> {
+    /// Creates a new linear gradient.
+    pub const fn linear(start: Point, end: Point, stops: &'a [ColorStop]) -> Self {
+        Self::Linear { start, end, stops }
+    }
+
+    /// Creates a new radial gradient.
+    pub const fn radial(center: Point, radius: u32, stops: &'a [ColorStop]) -> Self {
+        Self::Radial {
+            center,
+            radius,
+            stops,
+        }
+    }
+
+    /// Returns the color stops.
+    pub const fn stops(&self) -> &'a [ColorStop] {
+        match self {
+            Self::Linear { stops, .. } | Self::Radial { stops, .. } => stops,
+        }
+    }
+
+    /// Returns the color of the gradient at the given point.
+    ///
+    /// Returns `None` if the gradient has no color stops.
+    pub fn color_at(&self, point: Point) -> Option<Rgb888> {
+        let position = self.position(point);
+
+        let (first, rest) = self.stops().split_first()?;
+
+        let mut previous = first;
+        if position <= previous.scaled_position() {
+            return Some(previous.color);
+        }
+
+        for stop in rest {
+            let start = previous.scaled_position();
+            let end = stop.scaled_position();
+
+            if position <= end {
+                return Some(if end > start {
+                    interpolate(previous.color, stop.color, position - start, end - start)
+                } else {
+                    stop.color
+                });
+            }
+
+            previous = stop;
+        }
+
+        Some(previous.color)
+    }
+
+    /// Returns the scaled position of a point on the gradient.
+    fn position(&self, point: Point) -> u32 {
+        match *self {
+            Self::Linear { start, end, .. } => {
+                let direction = end - start;
+                let delta = point - start;
+
+                let length_squared =
+                    i128::from(direction.x).pow(2) + i128::from(direction.y).pow(2);
+                if length_squared == 0 {
+                    return 0;
+                }
+
+                let projection = i128::from(delta.x) * i128::from(direction.x)
+                    + i128::from(delta.y) * i128::from(direction.y);
+
+                (projection.clamp(0, length_squared) * i128::from(END_POSITION) / length_squared)
+                    as u32
+            }
+            Self::Radial { center, radius, .. } => {
+                if radius == 0 {
+                    return END_POSITION;
+                }
+
+                let delta = point - center;
+                let distance_squared = u128::from(delta.x.unsigned_abs()).pow(2)
+                    + u128::from(delta.y.unsigned_abs()).pow(2);
+
+                // Distance in 1/256 pixels.
+                let distance = isqrt((distance_squared << 16).min(u128::from(u64::MAX)) as u64);
+
+                (distance * 255 / u64::from(radius)).min(u64::from(END_POSITION)) as u32
+            }
+        }
+    }
+}
+
+impl<C> FillColorSource<C> for Gradient<'_>
+where
+    C: PixelColor + From<Rgb888>,
+{
+    fn fill_color(&self, point: Point) -> Option<C> {
+        self.color_at(point).map(C::from)
+    }
+
+    fn is_empty(&self) -> bool {
+        self.stops().is_empty()
+    }
+}
+
+/// Interpolates between two colors.
+fn interpolate(start: Rgb888, end: Rgb888, numerator: u32, denominator: u32) -> Rgb888 {
+    let channel = |value: fn(&Rgb888) -> u8| {
+        let sum = u32::from(value(&start)) * (denominator - numerator)
+            + u32::from(value(&end)) * numerator;
+
+        ((sum + denominator / 2) / denominator) as u8
+    };
+
+    Rgb888::new(channel(Rgb888::r), channel(Rgb888::g), channel(Rgb888::b))
+}
+
+/// Color stop of a gradient.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub struct ColorStop {
+    /// Position of the color stop.
+    ///
+    /// Position `0` is the start and position `255` is the end of the gradient.
+    pub position: u8,
+
+    /// Color at the position of the stop.
+    pub color: Rgb888,
+}
+
+impl ColorStop {
+    /// Creates a new color stop.
+    pub const fn new(position: u8, color: Rgb888) -> Self {
+        Self { position, color }
+    }
+
+    const fn scaled_position(&self) -> u32 {
+        self.position as u32 * POSITION_SCALE
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        geometry::{Angle, Dimensions, Size},
+        iterator::PixelIteratorExt,
+        mock_display::MockDisplay,
+        pixelcolor::{Gray8, GrayColor},
+        primitives::{
+            Circle, PointsIter, Primitive, PrimitiveStyle, PrimitiveStyleBuilder, Rectangle, Ring,
+            StrokeAlignment,
+        },
+        Drawable,
+    };
+
+    const BLACK_TO_WHITE: [ColorStop; 2] = [
+        ColorStop::new(0, Rgb888::BLACK),
+        ColorStop::new(255, Rgb888::WHITE),
+    ];
+
+    #[test]
+    fn linear_gradient() {
+        let stops = [
+            ColorStop::new(64, Rgb888::RED),
+            ColorStop::new(128, Rgb888::GREEN),
+            ColorStop::new(128, Rgb888::BLUE),
+            ColorStop::new(192, Rgb888::WHITE),
+        ];
+        let gradient = Gradient::linear(Point::new(10, 0), Point::new(10, 255), &stops);
+
+        assert_eq!(gradient.color_at(Point::new(0, -10)), Some(Rgb888::RED));
+        assert_eq!(gradient.color_at(Point::new(0, 64)), Some(Rgb888::RED));
+        assert_eq!(
+            gradient.color_at(Point::new(0, 96)),
+            Some(Rgb888::new(128, 128, 0))
+        );
+        assert_eq!(gradient.color_at(Point::new(0, 128)), Some(Rgb888::GREEN));
+        assert_eq!(
+            gradient.color_at(Point::new(0, 129)),
+            Some(Rgb888::new(4, 4, 255))
+        );
+        assert_eq!(gradient.color_at(Point::new(0, 200)), Some(Rgb888::WHITE));
+        assert_eq!(gradient.color_at(Point::new(0, 1000)), Some(Rgb888::WHITE));
+    }
+
+    #[test]
+    fn radial_gradient() {
+        let gradient = Gradient::radial(Point::new(10, 10), 5, &BLACK_TO_WHITE);
+
+        assert_eq!(gradient.color_at(Point::new(10, 10)), Some(Rgb888::BLACK));
+        assert_eq!(
+            gradient.color_at(Point::new(12, 10)),
+            Some(Rgb888::new(102, 102, 102))
+        );
+        assert_eq!(gradient.color_at(Point::new(13, 14)), Some(Rgb888::WHITE));
+        assert_eq!(gradient.color_at(Point::new(0, 0)), Some(Rgb888::WHITE));
+    }
+
+    #[test]
+    fn empty_gradient() {
+        let gradient = Gradient::linear(Point::zero(), Point::new(10, 10), &[]);
+
+        assert_eq!(gradient.color_at(Point::new(5, 5)), None);
+    }
+
+    #[test]
+    fn degenerate_gradients() {
+        let linear = Gradient::linear(Point::new(5, 5), Point::new(5, 5), &BLACK_TO_WHITE);
+        assert_eq!(linear.color_at(Point::new(10, 10)), Some(Rgb888::BLACK));
+
+        let radial = Gradient::radial(Point::new(5, 5), 0, &BLACK_TO_WHITE);
+        assert_eq!(radial.color_at(Point::new(5, 5)), Some(Rgb888::WHITE));
+    }
+
+    #[test]
+    fn draw_rectangle() {
+        let style = GradientStyle::new(
+            PrimitiveStyle::new(),
+            Gradient::linear(Point::new(1, 0), Point::new(16, 0), &BLACK_TO_WHITE),
+        );
+
+        let mut display = MockDisplay::<Gray8>::new();
+        Rectangle::new(Point::new(1, 1), Size::new(16, 2))
+            .into_styled(style)
+            .draw(&mut display)
+            .unwrap();
+
+        display.assert_pattern(&[
+            "                 ",
+            " 0123456789ABCDEF",
+            " 0123456789ABCDEF",
+        ]);
+    }
+
+    #[test]
+    fn stroke_and_fill() {
+        let circle = Circle::new(Point::new(5, 5), 20);
+
+        let primitive_style = PrimitiveStyleBuilder::new()
+            .stroke_color(Gray8::WHITE)
+            .stroke_width(3)
+            .stroke_alignment(StrokeAlignment::Outside)
+            .build();
+        let style = GradientStyle::new(
+            primitive_style,
+            Gradient::radial(circle.center(), 10, &BLACK_TO_WHITE),
+        );
+
+        let mut display = MockDisplay::<Gray8>::new();
+        circle.into_styled(style).draw(&mut display).unwrap();
+
+        let mut pixels = MockDisplay::<Gray8>::new();
+        circle
+            .into_styled(style)
+            .pixels()
+            .draw(&mut pixels)
+            .unwrap();
+        pixels.assert_eq(&display);
+
+        // The gradient is drawn in the fill area of the primitive style.
+        let mut expected = MockDisplay::<Gray8>::new();
+        circle
+            .into_styled(PrimitiveStyle::with_fill(Gray8::BLACK))
+            .draw(&mut expected)
+            .unwrap();
+        circle
+            .into_styled(primitive_style)
+            .draw(&mut expected)
+            .unwrap();
+
+        for point in expected.bounding_box().points() {
+            let expected_color = expected.get_pixel(point).map(|color| {
+                if color == Gray8::BLACK {
+                    style.fill.color_at(point).unwrap().into()
+                } else {
+                    color
+                }
+            });
+
+            assert_eq!(display.get_pixel(point), expected_color, "{:?}", point);
+        }
+
+        assert_eq!(
+            display.affected_area(),
+            circle.into_styled(style).bounding_box()
+        );
+    }
+
+    #[test]
+    fn progress_ring() {
+        let ring = Ring::with_center(Point::new(10, 10), 19, 11)
+            .with_angles(Angle::from_degrees(-90.0), Angle::from_degrees(270.0));
+        let style = GradientStyle::new(
+            PrimitiveStyle::new(),
+            Gradient::linear(Point::new(1, 0), Point::new(19, 0), &BLACK_TO_WHITE),
+        );
+
+        let mut display = MockDisplay::<Gray8>::new();
+        ring.into_styled(style).draw(&mut display).unwrap();
+
+        let mut expected = MockDisplay::<Gray8>::new();
+        ring.into_styled(PrimitiveStyle::with_fill(Gray8::BLACK))
+            .draw(&mut expected)
+            .unwrap();
+
+        for point in expected.bounding_box().points() {
+            let expected_color = expected
+                .get_pixel(point)
+                .map(|_| style.fill.color_at(point).unwrap().into());
+
+            assert_eq!(display.get_pixel(point), expected_color, "{:?}", point);
+        }
+
+        assert_eq!(
+            display.affected_area(),
+            ring.into_styled(style).bounding_box()
+        );
+    }
+}
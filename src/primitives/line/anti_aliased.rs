@@ -0,0 +1,271 @@
+//! Anti-aliased lines.
+
+use core::ops::Range;
+
+use crate::{
+    geometry::{Point, Size},
+    pixelcolor::PixelColor,
+    primitives::{
+        common::{edge_coverage, first_run, isqrt, solve, AntiAliasedShape, Coverage},
+        line::Line,
+        AntiAliasedStyle, Rectangle,
+    },
+};
+
+/// Anti-aliased line.
+///
+/// The stroke of the line is a rectangle, which is centered on the line and extends half a pixel
+/// past the start and end point of the line. This makes sure that a 1px wide horizontal or
+/// vertical line covers the same pixels as the aliased line.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub(in crate::primitives) struct AntiAliasedLine {
+    start: Point,
+    delta: Point,
+
+    /// Length of the line in 1/256 px.
+    length: i64,
+
+    /// Stroke width in pixels.
+    stroke_width: u32,
+}
+
+impl AntiAliasedLine {
+    /// Creates a new anti-aliased line.
+    pub fn new(line: &Line, stroke_width: u32) -> Self {
+        let delta = line.delta();
+        let length_squared = i64::from(delta.x).pow(2) + i64::from(delta.y).pow(2);
+
+        Self {
+            start: line.start,
+            delta,
+            length: isqrt((length_squared as u64) << 16) as i64,
+            stroke_width,
+        }
+    }
+
+    /// Returns the distances between a point and the edges of the stroke.
+    ///
+    /// The first distance is measured perpendicular to the line and the second distance along the
+    /// line. Both distances are in 1/256 px and are positive outside the stroke.
+    fn distances(&self, point: Point) -> (i64, i64) {
+        let u = i64::from(point.x - self.start.x);
+        let v = i64::from(point.y - self.start.y);
+        let half_width = i64::from(self.stroke_width) * 128;
+
+        if self.length == 0 {
+            return (v.abs() * 256 - half_width, u.abs() * 256 - 128);
+        }
+
+        let dx = i64::from(self.delta.x);
+        let dy = i64::from(self.delta.y);
+
+        let scale = |value: i64| ((i128::from(value) << 16) / i128::from(self.length)) as i64;
+        let across = scale(u * dy - v * dx).abs();
+
+        // The distances to both ends are calculated separately to get symmetric results.
+        let after_start = scale(u * dx + v * dy);
+        let before_end = scale((dx - u) * dx + (dy - v) * dy);
+
+        (across - half_width, -after_start.min(before_end) - 128)
+    }
+
+    /// Returns the stroke coverage of a pixel.
+    pub fn stroke_coverage(&self, point: Point) -> u32 {
+        let (across, along) = self.distances(point);
+
+        edge_coverage(across) * edge_coverage(along) / 256
+    }
+
+    /// Returns the range of pixels in a row that can be covered by the line.
+    pub fn row(&self, y: i32) -> Option<Range<i32>> {
+        let columns = self.area().columns();
+        let dx = i64::from(self.delta.x);
+        let dy = i64::from(self.delta.y);
+        let v = i64::from(y - self.start.y);
+
+        // Upper limit of the line length in pixels.
+        let length = (self.length + 255) / 256;
+
+        let along = solve(dx, v * dy, -length, length * length + length)?;
+        let across = solve(
+            dy,
+            -v * dx,
+            -length * (i64::from(self.stroke_width) + 2) / 2,
+            length * (i64::from(self.stroke_width) + 2) / 2,
+        )?;
+
+        let start_x = i64::from(self.start.x);
+        let start = (along.start.max(across.start) + start_x).max(i64::from(columns.start));
+        let end = (along.end.min(across.end) + start_x).min(i64::from(columns.end));
+
+        (start < end).then_some(start as i32..end as i32)
+    }
+}
+
+impl AntiAliasedShape for AntiAliasedLine {
+    fn area(&self) -> Rectangle {
+        if self.stroke_width == 0 {
+            return Rectangle::new(self.start, Size::zero());
+        }
+
+        let offset = (self.stroke_width / 2 + 1) as i32;
+
+        Rectangle::with_corners(self.start, self.start + self.delta).offset(offset)
+    }
+
+    fn run(&self, y: i32, x: i32) -> Option<Range<i32>> {
+        first_run(x, self.row(y).into_iter())
+    }
+
+    fn coverage(&self, point: Point) -> Coverage {
+        Coverage::stroke(self.stroke_coverage(point))
+    }
+}
+
+impl Line {
+    /// Returns the anti-aliased shape of the line.
+    pub(in crate::primitives) fn anti_aliased<C: PixelColor>(
+        &self,
+        style: &AntiAliasedStyle<C>,
+    ) -> AntiAliasedLine {
+        let stroke_width = style
+            .primitive_style
+            .effective_stroke_color()
+            .map_or(0, |_| style.primitive_style.stroke_width);
+
+        AntiAliasedLine::new(self, stroke_width)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        draw_target::DrawTarget,
+        framebuffer::{buffer_size, Framebuffer},
+        geometry::Dimensions,
+        image::GetPixel,
+        mock_display::MockDisplay,
+        pixelcolor::{raw::LittleEndianMsb0, Gray8, GrayColor, Rgb888, RgbColor},
+        primitives::{PointsIter, Primitive, PrimitiveStyle},
+        Drawable,
+    };
+
+    fn style(stroke_width: u32) -> AntiAliasedStyle<Gray8> {
+        AntiAliasedStyle::new(
+            PrimitiveStyle::with_stroke(Gray8::WHITE, stroke_width),
+            Gray8::BLACK,
+        )
+    }
+
+    #[test]
+    fn axis_aligned_line_matches_aliased_line() {
+        for line in [
+            Line::new(Point::new(2, 3), Point::new(20, 3)),
+            Line::new(Point::new(5, 20), Point::new(5, 1)),
+        ] {
+            let mut expected = MockDisplay::new();
+            line.into_styled(PrimitiveStyle::with_stroke(Gray8::WHITE, 1))
+                .draw(&mut expected)
+                .unwrap();
+
+            let mut display = MockDisplay::new();
+            line.into_styled(style(1)).draw(&mut display).unwrap();
+
+            display.assert_eq(&expected);
+        }
+    }
+
+    #[test]
+    fn diagonal_line() {
+        let mut display = MockDisplay::new();
+        Line::new(Point::new(1, 1), Point::new(5, 5))
+            .into_styled(style(1))
+            .draw(&mut display)
+            .unwrap();
+
+        // Pixels on the line are drawn in the stroke color and the neighboring pixels are
+        // partially covered.
+        for i in 1..=5 {
+            assert_eq!(display.get_pixel(Point::new(i, i)), Some(Gray8::WHITE));
+        }
+
+        let edge = display.get_pixel(Point::new(3, 2)).unwrap();
+        assert!(edge.luma() > 0 && edge.luma() < 255, "{:?}", edge);
+        assert_eq!(
+            display.get_pixel(Point::new(3, 2)),
+            display.get_pixel(Point::new(2, 3))
+        );
+
+        assert_eq!(display.get_pixel(Point::new(5, 1)), None);
+    }
+
+    #[test]
+    fn thick_line_is_symmetric() {
+        let mut display = MockDisplay::new();
+        Line::new(Point::new(10, 40), Point::new(50, 20))
+            .into_styled(style(5))
+            .draw(&mut display)
+            .unwrap();
+
+        for point in display.affected_area().points() {
+            let mirrored = Point::new(60, 60) - point;
+
+            assert_eq!(
+                display.get_pixel(point),
+                display.get_pixel(mirrored),
+                "{:?}",
+                point
+            );
+        }
+    }
+
+    #[test]
+    fn bounding_box_contains_drawn_pixels() {
+        for stroke_width in 0..6 {
+            let styled =
+                Line::new(Point::new(20, 30), Point::new(45, 12)).into_styled(style(stroke_width));
+
+            let mut display = MockDisplay::new();
+            styled.draw(&mut display).unwrap();
+
+            let area = display.affected_area();
+            assert_eq!(
+                styled.bounding_box().intersection(&area),
+                area,
+                "{} px",
+                stroke_width
+            );
+        }
+    }
+
+    #[test]
+    fn draw_blended() {
+        let mut fb = Framebuffer::<
+            Rgb888,
+            _,
+            LittleEndianMsb0,
+            16,
+            16,
+            { buffer_size::<Rgb888>(16, 16) },
+        >::new();
+        fb.clear(Rgb888::BLUE).unwrap();
+
+        let style =
+            AntiAliasedStyle::new(PrimitiveStyle::with_stroke(Rgb888::RED, 1), Rgb888::BLACK);
+        Line::new(Point::new(0, 0), Point::new(15, 5))
+            .into_styled(style)
+            .draw_blended(&mut fb)
+            .unwrap();
+
+        assert_eq!(fb.pixel(Point::new(0, 0)), Some(Rgb888::RED));
+        assert_eq!(fb.pixel(Point::new(15, 0)), Some(Rgb888::BLUE));
+
+        // Partially covered pixels are blended with the framebuffer contents and not with the
+        // background color.
+        let edge = fb.pixel(Point::new(2, 0)).unwrap();
+        assert!(edge.r() > 0 && edge.b() > 0 && edge.g() == 0, "{:?}", edge);
+        assert!((254..=256).contains(&(u16::from(edge.r()) + u16::from(edge.b()))));
+    }
+}
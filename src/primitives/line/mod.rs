@@ -3,17 +3,19 @@
 use crate::{
     geometry::{Dimensions, Point},
     primitives::{
-        common::StrokeOffset,
+        common::{isqrt, StrokeOffset},
         line::thick_points::{ParallelLineType, ParallelsIterator},
         PointsIter, Primitive, Rectangle,
     },
-    transform::Transform,
+    transform::{GeometricTransform, Transform, Transformation},
 };
 use az::SaturatingAs;
 
+pub(in crate::primitives) mod anti_aliased;
 mod bresenham;
 pub(in crate::primitives) mod intersection_params;
 mod points;
+mod round_cap_points;
 mod styled;
 mod thick_points;
 
@@ -171,6 +173,36 @@ impl Line {
         (left_line, right_line)
     }
 
+    /// Returns a vector with the direction of the line and the given length.
+    ///
+    /// The components of the returned vector are rounded to the nearest integer. A zero vector is
+    /// returned for lines with zero length.
+    pub(in crate::primitives) fn direction(&self, length: u32) -> Point {
+        let delta = self.delta();
+        let length_squared = i64::from(delta.x).pow(2) + i64::from(delta.y).pow(2);
+        if length_squared == 0 {
+            return Point::zero();
+        }
+
+        // Line length in 1/256 px.
+        let line_length = isqrt((length_squared as u64) << 16) as i64;
+
+        let scale = |value: i32| {
+            let value = i64::from(value) * i64::from(length) * 256;
+
+            ((2 * value + value.signum() * line_length) / (2 * line_length)) as i32
+        };
+
+        Point::new(scale(delta.x), scale(delta.y))
+    }
+
+    /// Returns a line that is extended by the given length past both end points.
+    pub(in crate::primitives) fn extend(&self, length: u32) -> Self {
+        let direction = self.direction(length);
+
+        Self::new(self.start - direction, self.end + direction)
+    }
+
     /// Compute the midpoint of the line.
     pub fn midpoint(&self) -> Point {
         self.start + (self.end - self.start) / 2
@@ -221,6 +253,17 @@ impl Transform for Line {
     }
 }
 
+impl GeometricTransform for Line {
+    type Output = Line;
+
+    fn transform(&self, transformation: &Transformation) -> Self::Output {
+        Self::new(
+            transformation.transform_point(self.start),
+            transformation.transform_point(self.end),
+        )
+    }
+}
+
 /// Pixel iterator for each pixel in the line
 #[cfg(test)]
 mod tests {
@@ -463,6 +506,29 @@ mod tests {
         assert_eq!(r, line.translate(Point::new(5, 0)));
     }
 
+    #[test]
+    fn direction() {
+        let line = Line::new(Point::new(10, 20), Point::new(10, 0));
+        assert_eq!(line.direction(3), Point::new(0, -3));
+
+        let line = Line::new(Point::new(0, 0), Point::new(30, 40));
+        assert_eq!(line.direction(5), Point::new(3, 4));
+        assert_eq!(line.direction(2), Point::new(1, 2));
+
+        let line = Line::new(Point::new(5, 5), Point::new(5, 5));
+        assert_eq!(line.direction(5), Point::zero());
+    }
+
+    #[test]
+    fn extend() {
+        let line = Line::new(Point::new(10, 20), Point::new(30, 20));
+
+        assert_eq!(
+            line.extend(2),
+            Line::new(Point::new(8, 20), Point::new(32, 20))
+        );
+    }
+
     #[test]
     fn extents_zero_thickness() {
         let line = Line::new(Point::new(10, 20), Point::new(20, 10));
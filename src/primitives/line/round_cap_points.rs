@@ -0,0 +1,108 @@
+//! Points of a thick line with round caps.
+
+use core::ops::Range;
+
+use crate::{
+    geometry::Point,
+    primitives::{
+        common::{
+            disc_bounding_box, disc_run, first_run, merge_runs, Scanline, SegmentJoin,
+            StrokeOffset, ThickSegment,
+        },
+        Line, Rectangle,
+    },
+};
+
+/// Iterator over all points of a thick line with round caps.
+///
+/// The stroke is the union of the thick line with butt caps and a disc at each end point. The
+/// runs of each scanline are merged before they are returned to prevent overdraw.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub(in crate::primitives::line) struct RoundCapPoints {
+    line: Line,
+    segment: ThickSegment,
+    stroke_width: u32,
+    rows: Range<i32>,
+    scanline: Scanline,
+}
+
+impl RoundCapPoints {
+    /// Creates a new round cap points iterator.
+    pub fn new(line: &Line, stroke_width: u32) -> Self {
+        let rows = Self::bounding_box(line, stroke_width).rows();
+
+        Self {
+            line: *line,
+            segment: Self::segment(line, stroke_width),
+            stroke_width,
+            scanline: Scanline::new(rows.start, i32::MIN..i32::MIN),
+            rows,
+        }
+    }
+
+    /// Returns the thick line without caps.
+    fn segment(line: &Line, stroke_width: u32) -> ThickSegment {
+        ThickSegment::new(
+            SegmentJoin::start(line.start, line.end, stroke_width, StrokeOffset::None),
+            SegmentJoin::end(line.start, line.end, stroke_width, StrokeOffset::None),
+        )
+    }
+
+    /// Returns the bounding box of a thick line with round caps.
+    pub fn bounding_box(line: &Line, stroke_width: u32) -> Rectangle {
+        let start_disc = disc_bounding_box(line.start, stroke_width);
+        let end_disc = disc_bounding_box(line.end, stroke_width);
+        let body = Self::segment(line, stroke_width).edges_bounding_box();
+
+        let top_left = body
+            .top_left
+            .component_min(start_disc.top_left)
+            .component_min(end_disc.top_left);
+        let bottom_right = [start_disc, end_disc]
+            .iter()
+            .filter_map(|rect| rect.bottom_right())
+            .fold(
+                body.bottom_right().unwrap_or(body.top_left),
+                |max, point| max.component_max(point),
+            );
+
+        Rectangle::with_corners(top_left, bottom_right)
+    }
+
+    /// Returns the first merged run in a row that ends after the given X coordinate.
+    fn run(&self, y: i32, x: i32) -> Option<Range<i32>> {
+        merge_runs(x, |x| {
+            let body = self.segment.intersection(y).x;
+            let start = disc_run(self.line.start, self.stroke_width, y);
+            let end = disc_run(self.line.end, self.stroke_width, y);
+
+            first_run(
+                x,
+                start.into_iter().chain(end).chain(core::iter::once(body)),
+            )
+        })
+    }
+}
+
+impl Iterator for RoundCapPoints {
+    type Item = Point;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(point) = self.scanline.next() {
+                return Some(point);
+            }
+
+            let y = self.scanline.y;
+            if !self.rows.contains(&y) {
+                return None;
+            }
+
+            match self.run(y, self.scanline.x.end) {
+                Some(run) => self.scanline.x = run,
+                None => self.scanline = Scanline::new(y + 1, i32::MIN..i32::MIN),
+            }
+        }
+    }
+}
@@ -1,21 +1,45 @@
 use crate::{
     draw_target::DrawTarget,
+    geometry::Point,
     pixelcolor::PixelColor,
     primitives::{
-        line::{thick_points::ThickPoints, Line, StrokeOffset},
+        common::{Dashes, Dots, SegmentsArea, SegmentsOutline, StrokePattern},
+        line::{round_cap_points::RoundCapPoints, thick_points::ThickPoints, Line, StrokeOffset},
         styled::{StyledDimensions, StyledDrawable, StyledPixels},
-        PrimitiveStyle, Rectangle,
+        LineCap, PrimitiveStyle, Rectangle, StrokeStyle,
     },
     Pixel,
 };
 use az::SaturatingAs;
 
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+enum StyledIter {
+    Thick(ThickPoints),
+    RoundCaps(RoundCapPoints),
+}
+
+impl Iterator for StyledIter {
+    type Item = Point;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            StyledIter::Thick(it) => it.next(),
+            StyledIter::RoundCaps(it) => it.next(),
+        }
+    }
+}
+
+/// Dashes or dots of a line.
+type LinePattern = StrokePattern<SegmentsOutline<[Point; 2]>, SegmentsArea<[Point; 2]>>;
+
 /// Styled line iterator.
 #[derive(Clone, Debug)]
 #[cfg_attr(feature = "defmt", derive(::defmt::Format))]
 pub struct StyledPixelsIterator<C> {
     stroke_color: Option<C>,
-    line_iter: ThickPoints,
+    line_iter: StyledIter,
+    pattern: Option<LinePattern>,
 }
 
 impl<C: PixelColor> StyledPixelsIterator<C> {
@@ -24,9 +48,36 @@ impl<C: PixelColor> StyledPixelsIterator<C> {
         let stroke_color = style.effective_stroke_color();
         let stroke_width = style.stroke_width.saturating_as();
 
+        let vertices = [primitive.start, primitive.end];
+        let outline = SegmentsOutline::open(vertices, Point::zero());
+
+        let pattern = if let Some(dot_size) = style.dot_size() {
+            // The dots are placed on the center line of the solid stroke.
+            let outline = outline.stroke_center(style.stroke_width, StrokeOffset::None);
+            let area = SegmentsArea::open(vertices, Point::zero(), style.stroke_width);
+
+            Some(StrokePattern::Dots(Dots::new(outline, area, dot_size)))
+        } else {
+            style
+                .dash_pattern()
+                .map(|pattern| StrokePattern::Dashes(Dashes::new(outline, pattern)))
+        };
+
+        let line_iter = match style.effective_line_cap() {
+            LineCap::Butt => StyledIter::Thick(ThickPoints::new(primitive, stroke_width)),
+            LineCap::Square => StyledIter::Thick(ThickPoints::new(
+                &primitive.extend(style.stroke_width / 2),
+                stroke_width,
+            )),
+            LineCap::Round => {
+                StyledIter::RoundCaps(RoundCapPoints::new(primitive, style.stroke_width))
+            }
+        };
+
         Self {
             stroke_color,
-            line_iter: ThickPoints::new(primitive, stroke_width),
+            line_iter,
+            pattern,
         }
     }
 }
@@ -38,8 +89,10 @@ impl<C: PixelColor> Iterator for StyledPixelsIterator<C> {
         // Return none if stroke color is none
         let stroke_color = self.stroke_color?;
 
+        let pattern = &self.pattern;
+
         self.line_iter
-            .next()
+            .find(|point| pattern.iter().all(|pattern| pattern.contains(*point)))
             .map(|point| Pixel(point, stroke_color))
     }
 }
@@ -70,7 +123,20 @@ impl<C: PixelColor> StyledDrawable<PrimitiveStyle<C>> for Line {
 
 impl<C: PixelColor> StyledDimensions<PrimitiveStyle<C>> for Line {
     fn styled_bounding_box(&self, style: &PrimitiveStyle<C>) -> Rectangle {
-        let (l, r) = self.extents(style.stroke_width, StrokeOffset::None);
+        // The bounding box is calculated independent of the stroke color, which is why
+        // `effective_line_cap` can't be used here.
+        let line = match style.stroke_style {
+            StrokeStyle::Solid => match style.line_cap {
+                LineCap::Butt => *self,
+                LineCap::Square => self.extend(style.stroke_width / 2),
+                LineCap::Round => {
+                    return RoundCapPoints::bounding_box(self, style.stroke_width);
+                }
+            },
+            _ => *self,
+        };
+
+        let (l, r) = line.extents(style.stroke_width, StrokeOffset::None);
 
         let min = l
             .start
@@ -91,10 +157,13 @@ impl<C: PixelColor> StyledDimensions<PrimitiveStyle<C>> for Line {
 mod tests {
     use super::*;
     use crate::{
-        geometry::{Dimensions, Point},
+        geometry::Dimensions,
         mock_display::MockDisplay,
-        pixelcolor::{Rgb888, RgbColor},
-        primitives::{Primitive, PrimitiveStyleBuilder},
+        pixelcolor::{BinaryColor, Rgb888, RgbColor},
+        primitives::{
+            common::check_dotted_stroke, DashPattern, PointsIter, Primitive, PrimitiveStyleBuilder,
+            StrokeStyle,
+        },
         Drawable,
     };
 
@@ -155,4 +224,204 @@ mod tests {
 
         assert_eq!(transparent_line.bounding_box(), stroked_line.bounding_box(),);
     }
+
+    fn dashed_style(width: u32, pattern: DashPattern) -> PrimitiveStyle<BinaryColor> {
+        PrimitiveStyleBuilder::new()
+            .stroke_color(BinaryColor::On)
+            .stroke_width(width)
+            .stroke_style(StrokeStyle::Dashed(pattern))
+            .build()
+    }
+
+    #[test]
+    fn dashed() {
+        let mut display = MockDisplay::new();
+        Line::new(Point::new(0, 1), Point::new(12, 1))
+            .into_styled(dashed_style(1, DashPattern::new(3, 2)))
+            .draw(&mut display)
+            .unwrap();
+
+        display.assert_pattern(&[
+            "             ", //
+            "###  ###  ###", //
+        ]);
+    }
+
+    #[test]
+    fn dashed_with_offset() {
+        let mut display = MockDisplay::new();
+        Line::new(Point::new(0, 0), Point::new(12, 0))
+            .into_styled(dashed_style(1, DashPattern::new(3, 2).with_offset(4)))
+            .draw(&mut display)
+            .unwrap();
+
+        display.assert_pattern(&[
+            " ###  ###  ##", //
+        ]);
+    }
+
+    #[test]
+    fn dashed_thick() {
+        let mut display = MockDisplay::new();
+        Line::new(Point::new(0, 1), Point::new(9, 1))
+            .into_styled(dashed_style(3, DashPattern::new(2, 2)))
+            .draw(&mut display)
+            .unwrap();
+
+        display.assert_pattern(&[
+            "##  ##  ##", //
+            "##  ##  ##", //
+            "##  ##  ##", //
+        ]);
+    }
+
+    #[test]
+    fn dashed_diagonal_is_subset_of_solid() {
+        let line = Line::new(Point::new(3, 4), Point::new(25, 17));
+
+        for width in 1..6 {
+            let mut solid = MockDisplay::new();
+            line.into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, width))
+                .draw(&mut solid)
+                .unwrap();
+
+            let mut dashed = MockDisplay::new();
+            line.into_styled(dashed_style(width, DashPattern::new(4, 3)))
+                .draw(&mut dashed)
+                .unwrap();
+
+            assert!(!dashed.affected_area().is_zero_sized());
+
+            for point in dashed.affected_area().points() {
+                if dashed.get_pixel(point).is_some() {
+                    assert_eq!(solid.get_pixel(point), Some(BinaryColor::On));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn dashed_zero_period() {
+        let mut display = MockDisplay::<BinaryColor>::new();
+        Line::new(Point::new(0, 0), Point::new(12, 0))
+            .into_styled(dashed_style(1, DashPattern::new(0, 0)))
+            .draw(&mut display)
+            .unwrap();
+
+        assert!(display.affected_area().is_zero_sized());
+    }
+
+    #[test]
+    fn dotted() {
+        let mut display = MockDisplay::new();
+        Line::new(Point::new(0, 0), Point::new(8, 0))
+            .into_styled(
+                PrimitiveStyleBuilder::new()
+                    .stroke_color(BinaryColor::On)
+                    .stroke_width(1)
+                    .stroke_style(StrokeStyle::Dotted)
+                    .build(),
+            )
+            .draw(&mut display)
+            .unwrap();
+
+        display.assert_pattern(&[
+            "# # # # #", //
+        ]);
+    }
+
+    #[test]
+    fn dotted_stroke() {
+        check_dotted_stroke(Line::new(Point::new(3, 4), Point::new(25, 17)));
+    }
+
+    fn cap_style(width: u32, line_cap: LineCap) -> PrimitiveStyle<BinaryColor> {
+        PrimitiveStyleBuilder::new()
+            .stroke_color(BinaryColor::On)
+            .stroke_width(width)
+            .line_cap(line_cap)
+            .build()
+    }
+
+    #[test]
+    fn square_caps() {
+        let mut display = MockDisplay::new();
+        Line::new(Point::new(2, 2), Point::new(7, 2))
+            .into_styled(cap_style(3, LineCap::Square))
+            .draw(&mut display)
+            .unwrap();
+
+        display.assert_pattern(&[
+            "          ", //
+            " ######## ", //
+            " ######## ", //
+            " ######## ", //
+        ]);
+    }
+
+    #[test]
+    fn round_caps() {
+        let mut display = MockDisplay::new();
+        Line::new(Point::new(3, 3), Point::new(8, 3))
+            .into_styled(cap_style(5, LineCap::Round))
+            .draw(&mut display)
+            .unwrap();
+
+        display.assert_pattern(&[
+            "            ", //
+            "  ########  ", //
+            " ########## ", //
+            " ########## ", //
+            " ########## ", //
+            "  ########  ", //
+        ]);
+    }
+
+    #[test]
+    fn caps_are_ignored_for_dashed_lines() {
+        let line = Line::new(Point::new(5, 5), Point::new(30, 20));
+        let style = dashed_style(5, DashPattern::new(4, 3));
+
+        let mut expected = MockDisplay::new();
+        line.into_styled(style).draw(&mut expected).unwrap();
+
+        for line_cap in [LineCap::Square, LineCap::Round] {
+            let mut display = MockDisplay::new();
+            line.into_styled(PrimitiveStyle { line_cap, ..style })
+                .draw(&mut display)
+                .unwrap();
+
+            display.assert_eq(&expected);
+        }
+    }
+
+    #[test]
+    fn caps_bounding_box() {
+        let lines = [
+            Line::new(Point::new(20, 20), Point::new(40, 20)),
+            Line::new(Point::new(20, 20), Point::new(20, 40)),
+            Line::new(Point::new(20, 20), Point::new(40, 40)),
+            Line::new(Point::new(40, 20), Point::new(18, 31)),
+            Line::new(Point::new(30, 30), Point::new(30, 30)),
+        ];
+
+        for line in lines {
+            for line_cap in [LineCap::Square, LineCap::Round] {
+                for width in 1..12 {
+                    let styled = line.into_styled(cap_style(width, line_cap));
+
+                    let mut display = MockDisplay::new();
+                    styled.draw(&mut display).unwrap();
+                    assert_eq!(
+                        display.affected_area(),
+                        styled.bounding_box(),
+                        "{:?}, {:?}, {} px",
+                        line,
+                        line_cap,
+                        width
+                    );
+                }
+            }
+        }
+    }
 }
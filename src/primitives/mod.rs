@@ -1,13 +1,21 @@
 //! Graphics primitives
 
+mod anti_aliased_style;
 pub mod arc;
+pub mod bezier;
 pub mod circle;
 mod common;
 pub mod ellipse;
+mod fill_style;
+mod gradient_style;
 pub mod line;
+pub mod path;
+mod pattern_style;
+pub mod polygon;
 pub mod polyline;
 mod primitive_style;
 pub mod rectangle;
+pub mod ring;
 pub mod rounded_rectangle;
 pub mod sector;
 mod styled;
@@ -16,12 +24,23 @@ pub mod triangle;
 #[doc(no_inline)]
 pub use self::rectangle::Rectangle;
 pub use self::{
+    anti_aliased_style::AntiAliasedStyle,
     arc::Arc,
+    bezier::{CubicBezier, QuadraticBezier},
     circle::Circle,
     ellipse::Ellipse,
+    fill_style::{FillColorSource, FillPixels, FillStyle},
+    gradient_style::{ColorStop, Gradient, GradientStyle},
     line::Line,
+    path::{Path, PathBuilder, PathError},
+    pattern_style::{BinaryPattern, Pattern, PatternStyle},
+    polygon::{FillRule, Polygon},
     polyline::Polyline,
-    primitive_style::{PrimitiveStyle, PrimitiveStyleBuilder, StrokeAlignment, StrokeStyle},
+    primitive_style::{
+        DashPattern, LineCap, LineJoin, PrimitiveStyle, PrimitiveStyleBuilder, StrokeAlignment,
+        StrokeStyle,
+    },
+    ring::Ring,
     rounded_rectangle::{CornerRadii, CornerRadiiBuilder, RoundedRectangle},
     sector::Sector,
     triangle::Triangle,
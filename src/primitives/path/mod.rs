@@ -0,0 +1,634 @@
+//! The path primitive
+
+use crate::{
+    geometry::{Dimensions, Point},
+    primitives::{
+        polygon, Arc, ContainsPoint, CubicBezier, FillRule, PointsIter, Polygon, Polyline,
+        Primitive, QuadraticBezier, Rectangle,
+    },
+    transform::{Transform, Transformation},
+};
+
+mod styled;
+
+pub use styled::StyledPixelsIterator;
+
+/// Path primitive
+///
+/// A path is a single outline which is assembled from straight lines, [`Arc`]s and Bézier
+/// curves. Paths are created with a [`PathBuilder`], which flattens all segments into a sequence
+/// of vertices that are stored in a buffer provided by the caller. Drawing a path doesn't
+/// allocate any memory. The number of vertices of an arc grows with its radius, see [`arc_to`]
+/// for details.
+///
+/// Closed paths are drawn like a [`Polygon`] and support fill and stroke. Open paths are drawn
+/// like a [`Polyline`] and the fill color is ignored. The points returned by [`points`] and
+/// [`contains`] are based on the area enclosed by the path, with open paths being implicitly
+/// closed.
+///
+/// A path consists of a single contour. Shapes with several separate parts can be drawn by using
+/// multiple paths.
+///
+/// [`points`]: PointsIter::points
+/// [`contains`]: ContainsPoint::contains
+/// [`arc_to`]: PathBuilder::arc_to
+///
+/// # Examples
+///
+/// ## Draw a tab with rounded top corners
+///
+/// ```rust
+/// use embedded_graphics::{
+///     pixelcolor::Rgb565,
+///     prelude::*,
+///     primitives::{Arc, PathBuilder, PrimitiveStyleBuilder},
+/// };
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display = MockDisplay::default();
+///
+/// let mut buffer = [Point::zero(); 64];
+///
+/// let path = PathBuilder::new(&mut buffer)
+///     .move_to(Point::new(2, 30))
+///     .arc_to(Arc::new(Point::new(2, 2), 17, 180.0.deg(), 90.0.deg()))
+///     .arc_to(Arc::new(Point::new(44, 2), 17, 270.0.deg(), 90.0.deg()))
+///     .line_to(Point::new(60, 30))
+///     .close()
+///     .unwrap();
+///
+/// let style = PrimitiveStyleBuilder::new()
+///     .stroke_color(Rgb565::WHITE)
+///     .stroke_width(1)
+///     .fill_color(Rgb565::BLUE)
+///     .build();
+///
+/// path.into_styled(style).draw(&mut display)?;
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+///
+/// ## Draw an open path with a curve
+///
+/// ```rust
+/// use embedded_graphics::{
+///     pixelcolor::Rgb565,
+///     prelude::*,
+///     primitives::{PathBuilder, PrimitiveStyle},
+/// };
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display = MockDisplay::default();
+///
+/// let mut buffer = [Point::zero(); 64];
+///
+/// let path = PathBuilder::new(&mut buffer)
+///     .move_to(Point::new(5, 40))
+///     .line_to(Point::new(20, 40))
+///     .cubic_to(Point::new(30, 10), Point::new(40, 60), Point::new(55, 20))
+///     .build()
+///     .unwrap();
+///
+/// path.into_styled(PrimitiveStyle::with_stroke(Rgb565::GREEN, 3))
+///     .draw(&mut display)?;
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub struct Path<'a> {
+    /// An offset to apply to the path as a whole
+    pub translate: Point,
+
+    /// All vertices of the flattened path
+    pub vertices: &'a [Point],
+
+    /// Whether the last vertex is connected to the first vertex
+    pub closed: bool,
+
+    /// Fill rule
+    pub fill_rule: FillRule,
+}
+
+impl<'a> Path<'a> {
+    /// Sets the fill rule.
+    pub const fn with_fill_rule(self, fill_rule: FillRule) -> Self {
+        Self { fill_rule, ..self }
+    }
+
+    /// Applies a transformation to this path.
+    ///
+    /// The transformed vertices are stored in `buffer` and the returned path isn't translated.
+    /// Returns `None` if `buffer` is smaller than the number of vertices.
+    pub fn transform_into<'b>(
+        &self,
+        transformation: &Transformation,
+        buffer: &'b mut [Point],
+    ) -> Option<Path<'b>> {
+        transformation
+            .transform_vertices(self.vertices, self.translate, buffer)
+            .map(|vertices| Path {
+                translate: Point::zero(),
+                vertices,
+                closed: self.closed,
+                fill_rule: self.fill_rule,
+            })
+    }
+
+    /// Returns a polygon with the same vertices as this path.
+    pub(in crate::primitives) const fn to_polygon(self) -> Polygon<'a> {
+        Polygon {
+            translate: self.translate,
+            vertices: self.vertices,
+            fill_rule: self.fill_rule,
+        }
+    }
+
+    /// Returns a polyline with the same vertices as this path.
+    pub(in crate::primitives) const fn to_polyline(self) -> Polyline<'a> {
+        Polyline {
+            translate: self.translate,
+            vertices: self.vertices,
+        }
+    }
+}
+
+impl Primitive for Path<'_> {}
+
+impl<'a> PointsIter for Path<'a> {
+    type Iter = polygon::Points<'a>;
+
+    fn points(&self) -> Self::Iter {
+        self.to_polygon().points()
+    }
+}
+
+impl ContainsPoint for Path<'_> {
+    fn contains(&self, point: Point) -> bool {
+        self.to_polygon().contains(point)
+    }
+}
+
+impl Dimensions for Path<'_> {
+    fn bounding_box(&self) -> Rectangle {
+        self.to_polygon().bounding_box()
+    }
+}
+
+impl Transform for Path<'_> {
+    /// Translate the path from its current position to a new position by (x, y) pixels,
+    /// returning a new `Path`. For a mutating transform, see `translate_mut`.
+    ///
+    /// ```
+    /// # use embedded_graphics::primitives::PathBuilder;
+    /// # use embedded_graphics::prelude::*;
+    /// let mut buffer = [Point::zero(); 4];
+    /// let path = PathBuilder::new(&mut buffer)
+    ///     .move_to(Point::new(5, 10))
+    ///     .line_to(Point::new(7, 7))
+    ///     .line_to(Point::new(10, 10))
+    ///     .close()
+    ///     .unwrap();
+    ///
+    /// let moved = path.translate(Point::new(10, 12));
+    ///
+    /// assert_eq!(path.bounding_box().top_left, Point::new(5, 7));
+    /// assert_eq!(moved.bounding_box().top_left, Point::new(15, 19));
+    /// ```
+    fn translate(&self, by: Point) -> Self {
+        Self {
+            translate: self.translate + by,
+            ..*self
+        }
+    }
+
+    /// Translate the path from its current position to a new position by (x, y) pixels.
+    ///
+    /// ```
+    /// # use embedded_graphics::primitives::PathBuilder;
+    /// # use embedded_graphics::prelude::*;
+    /// let mut buffer = [Point::zero(); 4];
+    /// let mut path = PathBuilder::new(&mut buffer)
+    ///     .move_to(Point::new(5, 10))
+    ///     .line_to(Point::new(7, 7))
+    ///     .line_to(Point::new(10, 10))
+    ///     .close()
+    ///     .unwrap();
+    ///
+    /// path.translate_mut(Point::new(10, 12));
+    ///
+    /// assert_eq!(path.bounding_box().top_left, Point::new(15, 19));
+    /// ```
+    fn translate_mut(&mut self, by: Point) -> &mut Self {
+        self.translate += by;
+
+        self
+    }
+}
+
+/// Error returned by [`PathBuilder::build`] and [`PathBuilder::close`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub enum PathError {
+    /// The buffer is too small to store all vertices of the path.
+    BufferTooSmall,
+
+    /// A segment was added before the start point was set with [`move_to`].
+    ///
+    /// [`move_to`]: PathBuilder::move_to
+    MissingStart,
+
+    /// [`move_to`] was called after segments were added to the path.
+    ///
+    /// Paths consist of a single contour and can't be split into multiple parts.
+    ///
+    /// [`move_to`]: PathBuilder::move_to
+    MultipleContours,
+}
+
+/// Path builder.
+///
+/// The builder flattens all added segments into vertices, which are stored in the buffer that
+/// is passed to [`new`]. Straight lines require a single vertex and Bézier curves are split into
+/// up to 32 line segments. The number of vertices of an arc grows with the square root of its
+/// radius, see [`arc_to`] for details.
+///
+/// Errors, like running out of space in the buffer, are returned by [`build`] or [`close`] to
+/// make it possible to chain all builder methods.
+///
+/// See the [`Path`] documentation for usage examples.
+///
+/// [`new`]: PathBuilder::new
+/// [`arc_to`]: PathBuilder::arc_to
+/// [`build`]: PathBuilder::build
+/// [`close`]: PathBuilder::close
+#[derive(PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub struct PathBuilder<'a> {
+    buffer: &'a mut [Point],
+    len: usize,
+    error: Option<PathError>,
+}
+
+impl<'a> PathBuilder<'a> {
+    /// Creates a new path builder which stores the vertices in the given buffer.
+    pub fn new(buffer: &'a mut [Point]) -> Self {
+        Self {
+            buffer,
+            len: 0,
+            error: None,
+        }
+    }
+
+    /// Sets the start point of the path.
+    ///
+    /// This method must be called before any segments are added, except for [`arc_to`], which
+    /// starts the path at the start point of the arc if no start point was set.
+    ///
+    /// [`arc_to`]: PathBuilder::arc_to
+    pub fn move_to(mut self, point: Point) -> Self {
+        if self.len > 1 {
+            self.set_error(PathError::MultipleContours);
+        } else {
+            self.len = 0;
+            self.push(point);
+        }
+
+        self
+    }
+
+    /// Adds a straight line from the current point to the given point.
+    pub fn line_to(mut self, point: Point) -> Self {
+        if self.current_point().is_some() {
+            self.push(point);
+        }
+
+        self
+    }
+
+    /// Adds an arc.
+    ///
+    /// If the current point isn't the start point of the arc, a straight line is added to
+    /// connect the current point with the start of the arc.
+    ///
+    /// The arc is flattened into enough straight segments to match the pixels of the [`Arc`]
+    /// primitive. The number of vertices grows with the square root of the radius: a quarter
+    /// circle with a radius of 100 pixels uses up to 17 vertices and a full circle with the same
+    /// radius up to 64 vertices.
+    pub fn arc_to(mut self, arc: Arc) -> Self {
+        self.push_vertices(arc.flattened_vertices());
+
+        self
+    }
+
+    /// Adds a quadratic Bézier curve from the current point to `end`.
+    pub fn quadratic_to(mut self, control: Point, end: Point) -> Self {
+        if let Some(start) = self.current_point() {
+            self.push_vertices(
+                QuadraticBezier::new(start, control, end)
+                    .flattened()
+                    .vertices()
+                    .iter()
+                    .copied(),
+            );
+        }
+
+        self
+    }
+
+    /// Adds a cubic Bézier curve from the current point to `end`.
+    pub fn cubic_to(mut self, control1: Point, control2: Point, end: Point) -> Self {
+        if let Some(start) = self.current_point() {
+            self.push_vertices(
+                CubicBezier::new(start, control1, control2, end)
+                    .flattened()
+                    .vertices()
+                    .iter()
+                    .copied(),
+            );
+        }
+
+        self
+    }
+
+    /// Builds an open path.
+    pub fn build(self) -> Result<Path<'a>, PathError> {
+        self.finish(false)
+    }
+
+    /// Closes the path by connecting the current point with the start point and builds the
+    /// path.
+    pub fn close(self) -> Result<Path<'a>, PathError> {
+        self.finish(true)
+    }
+
+    fn finish(self, closed: bool) -> Result<Path<'a>, PathError> {
+        if let Some(error) = self.error {
+            return Err(error);
+        }
+
+        let buffer: &'a [Point] = self.buffer;
+        let mut vertices = &buffer[0..self.len];
+
+        // The closing edge is added automatically and doesn't need a duplicate start point.
+        if closed {
+            if let [first, .., last] = vertices {
+                if first == last {
+                    vertices = &vertices[0..vertices.len() - 1];
+                }
+            }
+        }
+
+        Ok(Path {
+            translate: Point::zero(),
+            vertices,
+            closed,
+            fill_rule: FillRule::NonZero,
+        })
+    }
+
+    /// Returns the current point.
+    ///
+    /// Sets the missing start error if the path is empty.
+    fn current_point(&mut self) -> Option<Point> {
+        let point = self.len.checked_sub(1).map(|index| self.buffer[index]);
+
+        if point.is_none() {
+            self.set_error(PathError::MissingStart);
+        }
+
+        point
+    }
+
+    /// Adds all vertices of a flattened segment.
+    ///
+    /// Stops at the first error to not flatten the remaining part of large segments.
+    fn push_vertices(&mut self, vertices: impl Iterator<Item = Point>) {
+        for vertex in vertices {
+            if self.error.is_some() {
+                break;
+            }
+
+            self.push(vertex);
+        }
+    }
+
+    /// Adds a vertex, unless it is equal to the current point.
+    fn push(&mut self, point: Point) {
+        if self.error.is_some() || self.len > 0 && self.buffer[self.len - 1] == point {
+            return;
+        }
+
+        if let Some(vertex) = self.buffer.get_mut(self.len) {
+            *vertex = point;
+            self.len += 1;
+        } else {
+            self.set_error(PathError::BufferTooSmall);
+        }
+    }
+
+    /// Stores the first error that occurred.
+    fn set_error(&mut self, error: PathError) {
+        self.error.get_or_insert(error);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geometry::{AngleUnit, Size};
+
+    #[test]
+    fn lines() {
+        let mut buffer = [Point::zero(); 8];
+        let path = PathBuilder::new(&mut buffer)
+            .move_to(Point::new(1, 2))
+            .line_to(Point::new(10, 2))
+            .line_to(Point::new(10, 2))
+            .line_to(Point::new(5, 8))
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            path.vertices,
+            &[Point::new(1, 2), Point::new(10, 2), Point::new(5, 8)]
+        );
+        assert!(!path.closed);
+        assert_eq!(
+            path.bounding_box(),
+            Rectangle::with_corners(Point::new(1, 2), Point::new(10, 8))
+        );
+    }
+
+    #[test]
+    fn close_removes_duplicate_start_point() {
+        let mut buffer = [Point::zero(); 8];
+        let path = PathBuilder::new(&mut buffer)
+            .move_to(Point::new(1, 2))
+            .line_to(Point::new(10, 2))
+            .line_to(Point::new(5, 8))
+            .line_to(Point::new(1, 2))
+            .close()
+            .unwrap();
+
+        assert_eq!(
+            path.vertices,
+            &[Point::new(1, 2), Point::new(10, 2), Point::new(5, 8)]
+        );
+        assert!(path.closed);
+    }
+
+    #[test]
+    fn move_to_replaces_start_point() {
+        let mut buffer = [Point::zero(); 8];
+        let path = PathBuilder::new(&mut buffer)
+            .move_to(Point::new(1, 2))
+            .move_to(Point::new(3, 4))
+            .line_to(Point::new(5, 6))
+            .build()
+            .unwrap();
+
+        assert_eq!(path.vertices, &[Point::new(3, 4), Point::new(5, 6)]);
+    }
+
+    #[test]
+    fn arcs() {
+        let arc = Arc::with_center(Point::new(10, 10), 11, 0.0.deg(), 90.0.deg());
+
+        let mut buffer = [Point::zero(); 32];
+        let path = PathBuilder::new(&mut buffer).arc_to(arc).build().unwrap();
+
+        assert_eq!(path.vertices.first(), Some(&Point::new(15, 10)));
+        assert_eq!(path.vertices.last(), Some(&Point::new(10, 15)));
+
+        // All vertices are located on the arc, apart from rounding errors.
+        for vertex in path.vertices {
+            let delta = *vertex - Point::new(10, 10);
+            let distance_squared = delta.x.pow(2) + delta.y.pow(2);
+
+            assert!((18..=33).contains(&distance_squared), "{:?}", vertex);
+        }
+
+        // A line is inserted between the current point and the start of the arc.
+        let mut buffer = [Point::zero(); 32];
+        let path = PathBuilder::new(&mut buffer)
+            .move_to(Point::new(15, 20))
+            .arc_to(arc)
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            path.vertices[0..2],
+            [Point::new(15, 20), Point::new(15, 10)]
+        );
+    }
+
+    #[test]
+    fn large_arcs() {
+        let arc = Arc::with_center(Point::new(0, 0), 201, 0.0.deg(), 360.0.deg());
+
+        let mut buffer = [Point::zero(); 128];
+        let path = PathBuilder::new(&mut buffer).arc_to(arc).close().unwrap();
+
+        // Large arcs aren't limited to the maximum number of segments of a Bézier curve.
+        assert!(path.vertices.len() > 60, "{}", path.vertices.len());
+
+        for vertex in path.vertices {
+            let distance_squared = vertex.x.pow(2) + vertex.y.pow(2);
+
+            assert!((9850..=10150).contains(&distance_squared), "{:?}", vertex);
+        }
+
+        let mut buffer = [Point::zero(); 32];
+        assert_eq!(
+            PathBuilder::new(&mut buffer).arc_to(arc).close(),
+            Err(PathError::BufferTooSmall)
+        );
+    }
+
+    #[test]
+    fn curves() {
+        let start = Point::new(0, 10);
+        let control1 = Point::new(10, 0);
+        let control2 = Point::new(20, 20);
+        let end = Point::new(30, 10);
+
+        let mut buffer = [Point::zero(); 64];
+        let path = PathBuilder::new(&mut buffer)
+            .move_to(start)
+            .quadratic_to(control1, end)
+            .cubic_to(control2, control1, start)
+            .build()
+            .unwrap();
+
+        let quadratic = QuadraticBezier::new(start, control1, end).flattened();
+        let cubic = CubicBezier::new(end, control2, control1, start).flattened();
+        let expected = quadratic
+            .vertices()
+            .iter()
+            .chain(cubic.vertices().iter().skip(1));
+
+        assert!(path.vertices.iter().eq(expected));
+    }
+
+    #[test]
+    fn errors() {
+        let mut buffer = [Point::zero(); 2];
+        assert_eq!(
+            PathBuilder::new(&mut buffer)
+                .move_to(Point::new(1, 2))
+                .line_to(Point::new(3, 4))
+                .line_to(Point::new(5, 6))
+                .close(),
+            Err(PathError::BufferTooSmall)
+        );
+
+        let mut buffer = [Point::zero(); 8];
+        assert_eq!(
+            PathBuilder::new(&mut buffer)
+                .line_to(Point::new(3, 4))
+                .move_to(Point::new(1, 2))
+                .build(),
+            Err(PathError::MissingStart)
+        );
+
+        let mut buffer = [Point::zero(); 8];
+        assert_eq!(
+            PathBuilder::new(&mut buffer)
+                .move_to(Point::new(1, 2))
+                .line_to(Point::new(3, 4))
+                .move_to(Point::new(5, 6))
+                .build(),
+            Err(PathError::MultipleContours)
+        );
+    }
+
+    #[test]
+    fn empty_path() {
+        let path = PathBuilder::new(&mut []).close().unwrap();
+
+        assert_eq!(path.vertices, &[]);
+        assert_eq!(path.bounding_box(), Rectangle::zero());
+        assert_eq!(path.points().count(), 0);
+    }
+
+    #[test]
+    fn contains_matches_points() {
+        let mut buffer = [Point::zero(); 64];
+        let path = PathBuilder::new(&mut buffer)
+            .move_to(Point::new(2, 20))
+            .arc_to(Arc::new(Point::new(2, 2), 11, 180.0.deg(), 90.0.deg()))
+            .line_to(Point::new(20, 2))
+            .quadratic_to(Point::new(30, 10), Point::new(20, 20))
+            .close()
+            .unwrap()
+            .translate(Point::new(3, -1));
+
+        let expected = path
+            .bounding_box()
+            .points()
+            .filter(|point| path.contains(*point));
+
+        assert!(path.points().eq(expected));
+        assert_eq!(
+            path.bounding_box(),
+            Rectangle::new(Point::new(5, 1), Size::new(24, 19))
+        );
+    }
+}
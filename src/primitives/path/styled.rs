@@ -0,0 +1,250 @@
+use crate::{
+    draw_target::DrawTarget,
+    pixelcolor::PixelColor,
+    primitives::{
+        path::Path,
+        polygon, polyline,
+        styled::{StyledDimensions, StyledDrawable, StyledPixels},
+        PrimitiveStyle, Rectangle,
+    },
+    Pixel,
+};
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+enum StyledIter<'a, C> {
+    Closed(polygon::StyledPixelsIterator<'a, C>),
+    Open(polyline::StyledPixelsIterator<'a, C>),
+}
+
+/// Pixel iterator for each pixel in the path border and fill
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub struct StyledPixelsIterator<'a, C> {
+    iter: StyledIter<'a, C>,
+}
+
+impl<'a, C: PixelColor> StyledPixelsIterator<'a, C> {
+    fn new(primitive: &Path<'a>, style: &PrimitiveStyle<C>) -> Self {
+        let iter = if primitive.closed {
+            StyledIter::Closed(primitive.to_polygon().pixels(style))
+        } else {
+            StyledIter::Open(primitive.to_polyline().pixels(style))
+        };
+
+        Self { iter }
+    }
+}
+
+impl<C: PixelColor> Iterator for StyledPixelsIterator<'_, C> {
+    type Item = Pixel<C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match &mut self.iter {
+            StyledIter::Closed(iter) => iter.next(),
+            StyledIter::Open(iter) => iter.next(),
+        }
+    }
+}
+
+impl<'a, C: PixelColor> StyledPixels<PrimitiveStyle<C>> for Path<'a> {
+    type Iter = StyledPixelsIterator<'a, C>;
+
+    fn pixels(&self, style: &PrimitiveStyle<C>) -> Self::Iter {
+        StyledPixelsIterator::new(self, style)
+    }
+}
+
+impl<C: PixelColor> StyledDrawable<PrimitiveStyle<C>> for Path<'_> {
+    type Color = C;
+    type Output = ();
+
+    fn draw_styled<D>(
+        &self,
+        style: &PrimitiveStyle<C>,
+        target: &mut D,
+    ) -> Result<Self::Output, D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        if self.closed {
+            self.to_polygon().draw_styled(style, target)
+        } else {
+            self.to_polyline().draw_styled(style, target)
+        }
+    }
+}
+
+impl<C: PixelColor> StyledDimensions<PrimitiveStyle<C>> for Path<'_> {
+    fn styled_bounding_box(&self, style: &PrimitiveStyle<C>) -> Rectangle {
+        if self.closed {
+            self.to_polygon().styled_bounding_box(style)
+        } else {
+            self.to_polyline().styled_bounding_box(style)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        geometry::{AngleUnit, Dimensions, Point},
+        iterator::PixelIteratorExt,
+        mock_display::MockDisplay,
+        pixelcolor::BinaryColor,
+        primitives::{
+            common::{check_dashed_stroke, check_dotted_stroke},
+            Arc, PathBuilder, Polygon, Polyline, Primitive, PrimitiveStyleBuilder, StrokeAlignment,
+        },
+        transform::Transform,
+        Drawable,
+    };
+
+    fn styles() -> impl Iterator<Item = PrimitiveStyle<BinaryColor>> {
+        [1, 3].into_iter().flat_map(|stroke_width| {
+            [
+                StrokeAlignment::Inside,
+                StrokeAlignment::Center,
+                StrokeAlignment::Outside,
+            ]
+            .into_iter()
+            .map(move |stroke_alignment| {
+                PrimitiveStyleBuilder::new()
+                    .stroke_color(BinaryColor::On)
+                    .stroke_width(stroke_width)
+                    .stroke_alignment(stroke_alignment)
+                    .fill_color(BinaryColor::Off)
+                    .build()
+            })
+        })
+    }
+
+    #[test]
+    fn rounded_tab() {
+        let mut buffer = [Point::zero(); 32];
+        let path = PathBuilder::new(&mut buffer)
+            .move_to(Point::new(0, 11))
+            .arc_to(Arc::new(Point::new(0, 0), 11, 180.0.deg(), 90.0.deg()))
+            .arc_to(Arc::new(Point::new(11, 0), 11, 270.0.deg(), 90.0.deg()))
+            .line_to(Point::new(21, 11))
+            .close()
+            .unwrap();
+
+        let mut display = MockDisplay::new();
+        path.into_styled(
+            PrimitiveStyleBuilder::new()
+                .stroke_color(BinaryColor::On)
+                .stroke_width(1)
+                .fill_color(BinaryColor::Off)
+                .build(),
+        )
+        .draw(&mut display)
+        .unwrap();
+
+        display.assert_pattern(&[
+            "   #################    ", //
+            " ##.................#   ", //
+            "#...................#   ", //
+            "#....................#  ", //
+            "#....................#  ", //
+            "#....................#  ", //
+            "#....................#  ", //
+            "#....................#  ", //
+            "#....................#  ", //
+            "#....................#  ", //
+            "#....................#  ", //
+            "######################  ", //
+        ]);
+    }
+
+    #[test]
+    fn closed_path_matches_polygon() {
+        let mut buffer = [Point::zero(); 64];
+        let path = PathBuilder::new(&mut buffer)
+            .move_to(Point::new(5, 30))
+            .line_to(Point::new(5, 10))
+            .quadratic_to(Point::new(20, 0), Point::new(35, 10))
+            .line_to(Point::new(35, 30))
+            .close()
+            .unwrap()
+            .translate(Point::new(2, 3));
+
+        let polygon = Polygon::new(path.vertices).translate(Point::new(2, 3));
+
+        for style in styles() {
+            let mut expected = MockDisplay::new();
+            polygon.into_styled(style).draw(&mut expected).unwrap();
+
+            let mut display = MockDisplay::new();
+            path.into_styled(style).draw(&mut display).unwrap();
+            display.assert_eq(&expected);
+
+            let mut pixels = MockDisplay::new();
+            path.into_styled(style).pixels().draw(&mut pixels).unwrap();
+            pixels.assert_eq(&expected);
+
+            assert_eq!(
+                path.into_styled(style).bounding_box(),
+                polygon.into_styled(style).bounding_box()
+            );
+        }
+    }
+
+    #[test]
+    fn open_path_matches_polyline() {
+        let mut buffer = [Point::zero(); 64];
+        let path = PathBuilder::new(&mut buffer)
+            .move_to(Point::new(5, 30))
+            .line_to(Point::new(5, 10))
+            .cubic_to(Point::new(15, 0), Point::new(25, 20), Point::new(35, 10))
+            .build()
+            .unwrap();
+
+        let polyline = Polyline::new(path.vertices);
+
+        for style in styles() {
+            let mut expected = MockDisplay::new();
+            polyline.into_styled(style).draw(&mut expected).unwrap();
+
+            let mut display = MockDisplay::new();
+            path.into_styled(style).draw(&mut display).unwrap();
+            display.assert_eq(&expected);
+
+            let mut pixels = MockDisplay::new();
+            path.into_styled(style).pixels().draw(&mut pixels).unwrap();
+            pixels.assert_eq(&expected);
+
+            assert_eq!(
+                path.into_styled(style).bounding_box(),
+                polyline.into_styled(style).bounding_box()
+            );
+        }
+    }
+
+    #[test]
+    fn dashed_stroke() {
+        let mut buffer = [Point::zero(); 64];
+        let path = PathBuilder::new(&mut buffer)
+            .move_to(Point::new(10, 40))
+            .arc_to(Arc::new(Point::new(10, 10), 20, 180.0.deg(), 180.0.deg()))
+            .line_to(Point::new(40, 40))
+            .close()
+            .unwrap();
+
+        check_dashed_stroke(path);
+    }
+
+    #[test]
+    fn dotted_stroke() {
+        let mut buffer = [Point::zero(); 64];
+        let path = PathBuilder::new(&mut buffer)
+            .move_to(Point::new(10, 40))
+            .arc_to(Arc::new(Point::new(10, 10), 20, 180.0.deg(), 180.0.deg()))
+            .line_to(Point::new(40, 40))
+            .close()
+            .unwrap();
+
+        check_dotted_stroke(path);
+    }
+}
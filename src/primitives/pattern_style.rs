@@ -0,0 +1,434 @@
+use crate::{
+    geometry::{OriginDimensions, Point, Size},
+    image::GetPixel,
+    pixelcolor::PixelColor,
+    primitives::fill_style::{FillColorSource, FillStyle},
+};
+
+/// Pattern fill style.
+///
+/// `PatternStyle` is a [`FillStyle`] which fills a closed primitive ([`Rectangle`],
+/// [`RoundedRectangle`], [`Circle`], [`Ellipse`], [`Sector`], [`Ring`], [`Triangle`], [`Polygon`]
+/// or closed [`Path`]) with a repeating [`Pattern`] instead of a single color. The stroke is drawn as defined by the
+/// `primitive_style`, and the `fill_color` of the `primitive_style` is ignored.
+///
+/// Any type that implements [`GetPixel`] and [`OriginDimensions`], like an [`ImageRaw`] or a
+/// [`Framebuffer`], can be used as the pattern tile. Hatched and checkered fills can be created
+/// with a compact 8x8 [`BinaryPattern`].
+///
+/// The tile is repeated in both directions, starting at the `origin` of the pattern. Using the
+/// same origin for multiple primitives makes sure that the patterns of adjacent primitives line
+/// up.
+///
+/// # Examples
+///
+/// ## Draw a hatched rectangle
+///
+/// ```
+/// use embedded_graphics::{
+///     pixelcolor::BinaryColor,
+///     prelude::*,
+///     primitives::{BinaryPattern, Pattern, PatternStyle, PrimitiveStyle, Rectangle},
+/// };
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display = MockDisplay::<BinaryColor>::new();
+///
+/// let hatch = BinaryPattern::diagonal_hatch(BinaryColor::On, BinaryColor::Off);
+/// let style = PatternStyle::new(
+///     PrimitiveStyle::with_stroke(BinaryColor::On, 1),
+///     Pattern::new(&hatch, Point::zero()),
+/// );
+///
+/// Rectangle::new(Point::new(4, 4), Size::new(40, 20))
+///     .into_styled(style)
+///     .draw(&mut display)?;
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+///
+/// [`Rectangle`]: crate::primitives::Rectangle
+/// [`RoundedRectangle`]: crate::primitives::RoundedRectangle
+/// [`Circle`]: crate::primitives::Circle
+/// [`Ellipse`]: crate::primitives::Ellipse
+/// [`Sector`]: crate::primitives::Sector
+/// [`Ring`]: crate::primitives::Ring
+/// [`Triangle`]: crate::primitives::Triangle
+/// [`Polygon`]: crate::primitives::Polygon
+/// [`Path`]: crate::primitives::Path
+/// [`GetPixel`]: crate::image::GetPixel
+/// [`OriginDimensions`]: crate::geometry::OriginDimensions
+/// [`ImageRaw`]: crate::image::ImageRaw
+/// [`Framebuffer`]: crate::framebuffer::Framebuffer
+/// [`FillStyle`]: crate::primitives::FillStyle
+pub type PatternStyle<'a, P, C> = FillStyle<Pattern<'a, P>, C>;
+
+/// Repeated pattern tile.
+///
+/// The tile is repeated in both directions, starting at `origin`.
+#[derive(Debug)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub struct Pattern<'a, P> {
+    /// Pattern tile.
+    pub tile: &'a P,
+
+    /// Position of the top left corner of one of the pattern tiles.
+    pub origin: Point,
+}
+
+impl<'a, P> Pattern<'a, P> {
+    /// Creates a new pattern.
+    pub const fn new(tile: &'a P, origin: Point) -> Self {
+        Self { tile, origin }
+    }
+}
+
+impl<P, C> FillColorSource<C> for Pattern<'_, P>
+where
+    P: GetPixel<Color = C> + OriginDimensions,
+    C: PixelColor,
+{
+    fn fill_color(&self, point: Point) -> Option<C> {
+        let size = self.tile.size();
+        if is_empty(size) {
+            return None;
+        }
+
+        let point = point - self.origin;
+        let tile_point = Point::new(
+            point.x.rem_euclid(size.width as i32),
+            point.y.rem_euclid(size.height as i32),
+        );
+
+        self.tile.pixel(tile_point)
+    }
+
+    fn is_empty(&self) -> bool {
+        is_empty(self.tile.size())
+    }
+}
+
+// The trait impls are implemented manually to not require the tile type to implement them.
+
+impl<P> Clone for Pattern<'_, P> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<P> Copy for Pattern<'_, P> {}
+
+impl<P> PartialEq for Pattern<'_, P>
+where
+    P: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.tile == other.tile && self.origin == other.origin
+    }
+}
+
+/// 8x8 pixel binary pattern.
+///
+/// Each row of the pattern is stored in one byte. The most significant bit is the leftmost pixel
+/// of the row. Set bits are drawn in the `foreground` color and cleared bits in the `background`
+/// color.
+///
+/// # Examples
+///
+/// ```
+/// use embedded_graphics::{pixelcolor::BinaryColor, primitives::BinaryPattern};
+///
+/// // Vertical stripes.
+/// let pattern = BinaryPattern::new([0b1000_1000; 8], BinaryColor::On, BinaryColor::Off);
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub struct BinaryPattern<C> {
+    /// Pattern rows.
+    pub rows: [u8; 8],
+
+    /// Foreground color.
+    pub foreground: C,
+
+    /// Background color.
+    pub background: C,
+}
+
+impl<C> BinaryPattern<C> {
+    /// Creates a new binary pattern.
+    pub const fn new(rows: [u8; 8], foreground: C, background: C) -> Self {
+        Self {
+            rows,
+            foreground,
+            background,
+        }
+    }
+
+    /// Creates a checkerboard pattern with 1 pixel squares.
+    pub const fn checkerboard(foreground: C, background: C) -> Self {
+        Self::new(
+            [
+                0b1010_1010,
+                0b0101_0101,
+                0b1010_1010,
+                0b0101_0101,
+                0b1010_1010,
+                0b0101_0101,
+                0b1010_1010,
+                0b0101_0101,
+            ],
+            foreground,
+            background,
+        )
+    }
+
+    /// Creates a pattern with diagonal lines from the bottom left to the top right.
+    pub const fn diagonal_hatch(foreground: C, background: C) -> Self {
+        Self::new(
+            [
+                0b0001_0001,
+                0b0010_0010,
+                0b0100_0100,
+                0b1000_1000,
+                0b0001_0001,
+                0b0010_0010,
+                0b0100_0100,
+                0b1000_1000,
+            ],
+            foreground,
+            background,
+        )
+    }
+
+    /// Creates a pattern with horizontal and vertical lines.
+    pub const fn cross_hatch(foreground: C, background: C) -> Self {
+        Self::new(
+            [
+                0b1111_1111,
+                0b1000_1000,
+                0b1000_1000,
+                0b1000_1000,
+                0b1111_1111,
+                0b1000_1000,
+                0b1000_1000,
+                0b1000_1000,
+            ],
+            foreground,
+            background,
+        )
+    }
+}
+
+impl<C> OriginDimensions for BinaryPattern<C> {
+    fn size(&self) -> Size {
+        Size::new(8, 8)
+    }
+}
+
+impl<C> GetPixel for BinaryPattern<C>
+where
+    C: PixelColor,
+{
+    type Color = C;
+
+    fn pixel(&self, p: Point) -> Option<Self::Color> {
+        let row = self.rows.get(usize::try_from(p.y).ok()?)?;
+        let x = u32::try_from(p.x).ok().filter(|x| *x < 8)?;
+
+        Some(if row & (0x80 >> x) != 0 {
+            self.foreground
+        } else {
+            self.background
+        })
+    }
+}
+
+/// Returns `true` if a pattern tile contains no pixels.
+const fn is_empty(size: Size) -> bool {
+    size.width == 0 || size.height == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        draw_target::DrawTargetExt,
+        geometry::Dimensions,
+        image::ImageRaw,
+        iterator::PixelIteratorExt,
+        mock_display::MockDisplay,
+        pixelcolor::{BinaryColor, Gray8, GrayColor},
+        primitives::{
+            Circle, PathBuilder, PointsIter, Polygon, Primitive, PrimitiveStyle, Rectangle, Ring,
+            Triangle,
+        },
+        transform::Transform,
+        Drawable,
+    };
+
+    #[test]
+    fn binary_pattern_pixels() {
+        let pattern = BinaryPattern::new(
+            [0b1000_0001, 0, 0, 0, 0, 0, 0, 0b0100_0000],
+            Gray8::WHITE,
+            Gray8::BLACK,
+        );
+
+        assert_eq!(pattern.pixel(Point::new(0, 0)), Some(Gray8::WHITE));
+        assert_eq!(pattern.pixel(Point::new(1, 0)), Some(Gray8::BLACK));
+        assert_eq!(pattern.pixel(Point::new(7, 0)), Some(Gray8::WHITE));
+        assert_eq!(pattern.pixel(Point::new(1, 7)), Some(Gray8::WHITE));
+        assert_eq!(pattern.pixel(Point::new(8, 0)), None);
+        assert_eq!(pattern.pixel(Point::new(0, 8)), None);
+        assert_eq!(pattern.pixel(Point::new(-1, 0)), None);
+    }
+
+    #[test]
+    fn checkerboard_with_stroke() {
+        let pattern = BinaryPattern::checkerboard(BinaryColor::On, BinaryColor::Off);
+        let style = PatternStyle::new(
+            PrimitiveStyle::with_stroke(BinaryColor::On, 1),
+            Pattern::new(&pattern, Point::zero()),
+        );
+
+        let rect = Rectangle::new(Point::new(1, 1), Size::new(7, 5)).into_styled(style);
+
+        let mut display = MockDisplay::new();
+        rect.draw(&mut display).unwrap();
+
+        display.assert_pattern(&[
+            "        ", //
+            " #######", //
+            " ##.#.##", //
+            " #.#.#.#", //
+            " ##.#.##", //
+            " #######", //
+        ]);
+
+        let mut pixels = MockDisplay::new();
+        rect.pixels().draw(&mut pixels).unwrap();
+        pixels.assert_eq(&display);
+
+        assert_eq!(display.affected_area(), rect.bounding_box());
+    }
+
+    #[test]
+    fn origin() {
+        let pattern = BinaryPattern::diagonal_hatch(BinaryColor::On, BinaryColor::Off);
+        let circle = Circle::new(Point::new(3, 5), 20);
+
+        // The pattern is repeated every 8 pixels.
+        let mut expected = MockDisplay::new();
+        circle
+            .into_styled(PatternStyle::new(
+                PrimitiveStyle::new(),
+                Pattern::new(&pattern, Point::new(-8, 16)),
+            ))
+            .draw(&mut expected.translated(Point::new(5, 7)))
+            .unwrap();
+
+        let mut display = MockDisplay::new();
+        circle
+            .translate(Point::new(5, 7))
+            .into_styled(PatternStyle::new(
+                PrimitiveStyle::new(),
+                Pattern::new(&pattern, Point::new(5, 7)),
+            ))
+            .draw(&mut display)
+            .unwrap();
+
+        display.assert_eq(&expected);
+    }
+
+    #[test]
+    fn image_tile() {
+        let data = [0x00, 0x55, 0xAA, 0xFF];
+        let image = ImageRaw::<Gray8>::new(&data, Size::new(2, 2)).unwrap();
+
+        let style = PatternStyle::new(
+            PrimitiveStyle::new(),
+            Pattern::new(&image, Point::new(1, 0)),
+        );
+
+        let mut display = MockDisplay::new();
+        Triangle::new(Point::new(0, 0), Point::new(5, 0), Point::new(0, 3))
+            .into_styled(style)
+            .draw(&mut display)
+            .unwrap();
+
+        display.assert_pattern(&[
+            "505050", //
+            "FAFAF ", //
+            "505   ", //
+            "F     ", //
+        ]);
+    }
+
+    #[test]
+    fn empty_pattern() {
+        let image = ImageRaw::<Gray8>::new(&[], Size::zero()).unwrap();
+        let style = PatternStyle::new(PrimitiveStyle::new(), Pattern::new(&image, Point::zero()));
+
+        let rect = Rectangle::new(Point::new(1, 1), Size::new(7, 5));
+
+        let mut display = MockDisplay::new();
+        rect.into_styled(style).draw(&mut display).unwrap();
+
+        display.assert_pattern(&[]);
+    }
+
+    #[test]
+    fn ring() {
+        let pattern = BinaryPattern::checkerboard(BinaryColor::On, BinaryColor::Off);
+        let ring = Ring::new(Point::new(2, 1), 15, 7);
+        let style = PatternStyle::new(PrimitiveStyle::new(), Pattern::new(&pattern, Point::zero()));
+
+        let mut display = MockDisplay::new();
+        ring.into_styled(style).draw(&mut display).unwrap();
+
+        let mut expected = MockDisplay::new();
+        ring.into_styled(PrimitiveStyle::with_fill(BinaryColor::On))
+            .draw(&mut expected)
+            .unwrap();
+
+        for point in expected.bounding_box().points() {
+            let expected_color = expected
+                .get_pixel(point)
+                .and_then(|_| style.fill.fill_color(point));
+
+            assert_eq!(display.get_pixel(point), expected_color, "{:?}", point);
+        }
+
+        assert_eq!(
+            display.affected_area(),
+            ring.into_styled(style).bounding_box()
+        );
+    }
+
+    #[test]
+    fn closed_path() {
+        let pattern = BinaryPattern::diagonal_hatch(BinaryColor::On, BinaryColor::Off);
+        let style = PatternStyle::new(
+            PrimitiveStyle::with_stroke(BinaryColor::On, 1),
+            Pattern::new(&pattern, Point::zero()),
+        );
+
+        let mut buffer = [Point::zero(); 32];
+        let path = PathBuilder::new(&mut buffer)
+            .move_to(Point::new(2, 20))
+            .line_to(Point::new(2, 8))
+            .quadratic_to(Point::new(12, 0), Point::new(22, 8))
+            .line_to(Point::new(22, 20))
+            .close()
+            .unwrap();
+
+        let mut expected = MockDisplay::new();
+        Polygon::new(path.vertices)
+            .into_styled(style)
+            .draw(&mut expected)
+            .unwrap();
+
+        let mut display = MockDisplay::new();
+        path.into_styled(style).draw(&mut display).unwrap();
+
+        display.assert_eq(&expected);
+    }
+}
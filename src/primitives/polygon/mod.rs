@@ -0,0 +1,385 @@
+//! The polygon primitive
+
+use crate::{
+    geometry::{Dimensions, Point, Size},
+    primitives::{
+        common::StrokeOffset, polygon::scanline_intersections::ScanlineIntersections,
+        ContainsPoint, LineJoin, PointsIter, Primitive, Rectangle,
+    },
+    transform::{Transform, Transformation},
+};
+
+mod points;
+mod scanline_intersections;
+mod scanline_iterator;
+mod styled;
+
+pub use points::Points;
+pub use styled::StyledPixelsIterator;
+
+/// Polygon primitive
+///
+/// Creates a closed shape from a list of vertices. The last vertex is automatically connected
+/// to the first vertex. Polygons can be filled and stroked and the edges are allowed to
+/// intersect each other. The [`FillRule`] determines which parts of a self intersecting polygon
+/// are filled.
+///
+/// # Examples
+///
+/// ## Draw a filled star
+///
+/// This example draws a star with a red fill and a 1px white stroke. The center of the star is
+/// only filled because the default [`FillRule::NonZero`] fill rule is used.
+///
+/// ```rust
+/// use embedded_graphics::{
+///     pixelcolor::Rgb565,
+///     prelude::*,
+///     primitives::{Polygon, PrimitiveStyleBuilder},
+/// };
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display = MockDisplay::default();
+///
+/// let vertices = [
+///     Point::new(32, 2),
+///     Point::new(50, 60),
+///     Point::new(3, 24),
+///     Point::new(61, 24),
+///     Point::new(14, 60),
+/// ];
+///
+/// let style = PrimitiveStyleBuilder::new()
+///     .stroke_color(Rgb565::WHITE)
+///     .stroke_width(1)
+///     .fill_color(Rgb565::RED)
+///     .build();
+///
+/// Polygon::new(&vertices)
+///     .into_styled(style)
+///     .draw(&mut display)?;
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+///
+/// ## Use the even-odd fill rule
+///
+/// ```rust
+/// use embedded_graphics::{
+///     pixelcolor::Rgb565,
+///     prelude::*,
+///     primitives::{FillRule, Polygon, PrimitiveStyle},
+/// };
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display = MockDisplay::default();
+///
+/// let vertices = [
+///     Point::new(32, 2),
+///     Point::new(50, 60),
+///     Point::new(3, 24),
+///     Point::new(61, 24),
+///     Point::new(14, 60),
+/// ];
+///
+/// // The center of the star isn't filled when the even-odd rule is used.
+/// Polygon::new(&vertices)
+///     .with_fill_rule(FillRule::EvenOdd)
+///     .into_styled(PrimitiveStyle::with_fill(Rgb565::BLUE))
+///     .draw(&mut display)?;
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub struct Polygon<'a> {
+    /// An offset to apply to the polygon as a whole
+    pub translate: Point,
+
+    /// All vertices in the polygon
+    pub vertices: &'a [Point],
+
+    /// Fill rule
+    pub fill_rule: FillRule,
+}
+
+impl<'a> Polygon<'a> {
+    /// Create a new polygon from a list of vertices
+    ///
+    /// The polygon uses the [`FillRule::NonZero`] fill rule. If fewer than two vertices are
+    /// provided, the polygon will not render anything when drawn.
+    pub const fn new(vertices: &'a [Point]) -> Self {
+        Self {
+            vertices,
+            translate: Point::zero(),
+            fill_rule: FillRule::NonZero,
+        }
+    }
+
+    /// Sets the fill rule.
+    pub const fn with_fill_rule(self, fill_rule: FillRule) -> Self {
+        Self { fill_rule, ..self }
+    }
+
+    /// Applies a transformation to this polygon.
+    ///
+    /// The transformed vertices are stored in `buffer` and the returned polygon isn't
+    /// translated. Returns `None` if `buffer` is smaller than the number of vertices.
+    pub fn transform_into<'b>(
+        &self,
+        transformation: &Transformation,
+        buffer: &'b mut [Point],
+    ) -> Option<Polygon<'b>> {
+        transformation
+            .transform_vertices(self.vertices, self.translate, buffer)
+            .map(|vertices| Polygon::new(vertices).with_fill_rule(self.fill_rule))
+    }
+
+    /// Returns twice the signed area of the polygon.
+    ///
+    /// The area is positive if the vertices are sorted clockwise.
+    pub(in crate::primitives) fn area_doubled(&self) -> i64 {
+        let vertices = self.vertices;
+
+        vertices
+            .iter()
+            .zip(vertices.iter().cycle().skip(1))
+            .map(|(a, b)| i64::from(a.x) * i64::from(b.y) - i64::from(b.x) * i64::from(a.y))
+            .sum()
+    }
+}
+
+impl Primitive for Polygon<'_> {}
+
+impl<'a> PointsIter for Polygon<'a> {
+    type Iter = Points<'a>;
+
+    fn points(&self) -> Self::Iter {
+        Points::new(self)
+    }
+}
+
+impl ContainsPoint for Polygon<'_> {
+    fn contains(&self, point: Point) -> bool {
+        if !self.bounding_box().contains(point) {
+            return false;
+        }
+
+        let point = point - self.translate;
+
+        ScanlineIntersections::new(
+            self,
+            0,
+            StrokeOffset::None,
+            LineJoin::default(),
+            true,
+            point.y,
+        )
+        .fill_run(point.x)
+        .is_some_and(|run| run.start == point.x)
+    }
+}
+
+impl Dimensions for Polygon<'_> {
+    fn bounding_box(&self) -> Rectangle {
+        match self.vertices {
+            [] => Rectangle::zero(),
+            [v] => Rectangle::new(*v + self.translate, Size::zero()),
+            vertices => {
+                let (min, max) = vertices.iter().fold(
+                    (Point::new_equal(i32::MAX), Point::new_equal(i32::MIN)),
+                    |(min, max), v| (min.component_min(*v), max.component_max(*v)),
+                );
+
+                Rectangle::with_corners(min + self.translate, max + self.translate)
+            }
+        }
+    }
+}
+
+impl Transform for Polygon<'_> {
+    /// Translate the polygon from its current position to a new position by (x, y) pixels,
+    /// returning a new `Polygon`. For a mutating transform, see `translate_mut`.
+    ///
+    /// ```
+    /// # use embedded_graphics::primitives::Polygon;
+    /// # use embedded_graphics::prelude::*;
+    /// let points = [Point::new(5, 10), Point::new(7, 7), Point::new(10, 10)];
+    ///
+    /// let polygon = Polygon::new(&points);
+    /// let moved = polygon.translate(Point::new(10, 12));
+    ///
+    /// assert_eq!(polygon.bounding_box().top_left, Point::new(5, 7));
+    /// assert_eq!(moved.bounding_box().top_left, Point::new(15, 19));
+    /// ```
+    fn translate(&self, by: Point) -> Self {
+        Self {
+            translate: self.translate + by,
+            ..*self
+        }
+    }
+
+    /// Translate the polygon from its current position to a new position by (x, y) pixels.
+    ///
+    /// ```
+    /// # use embedded_graphics::primitives::Polygon;
+    /// # use embedded_graphics::prelude::*;
+    /// let points = [Point::new(5, 10), Point::new(7, 7), Point::new(10, 10)];
+    ///
+    /// let mut polygon = Polygon::new(&points);
+    ///
+    /// polygon.translate_mut(Point::new(10, 12));
+    ///
+    /// assert_eq!(polygon.bounding_box().top_left, Point::new(15, 19));
+    /// ```
+    fn translate_mut(&mut self, by: Point) -> &mut Self {
+        self.translate += by;
+
+        self
+    }
+}
+
+/// Fill rule.
+///
+/// The fill rule determines which points are inside a polygon. Both rules count how often a ray
+/// from the point crosses the edges of the polygon, but they only differ for self intersecting
+/// polygons or polygons with overlapping parts.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub enum FillRule {
+    /// Non-zero winding rule.
+    ///
+    /// A point is inside the polygon if the winding number, which counts how often the edges
+    /// wind around the point, isn't zero. Edges which cross the ray in opposite directions
+    /// cancel each other out.
+    #[default]
+    NonZero,
+
+    /// Even-odd rule.
+    ///
+    /// A point is inside the polygon if a ray from the point crosses an odd number of edges.
+    EvenOdd,
+}
+
+impl FillRule {
+    /// Returns `true` if a point with the given winding number is inside the polygon.
+    pub(in crate::primitives) const fn is_inside(self, winding_number: i32) -> bool {
+        match self {
+            Self::NonZero => winding_number != 0,
+            Self::EvenOdd => winding_number % 2 != 0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geometry::{Point, Size};
+
+    // A five pointed star with overlapping parts.
+    pub(in crate::primitives::polygon) const STAR: [Point; 5] = [
+        Point::new(10, 1),
+        Point::new(16, 19),
+        Point::new(1, 7),
+        Point::new(19, 7),
+        Point::new(4, 19),
+    ];
+
+    #[test]
+    fn special_case_dimensions() {
+        assert_eq!(Polygon::new(&[]).bounding_box(), Rectangle::zero());
+
+        assert_eq!(
+            Polygon::new(&[Point::new(15, 17)]).bounding_box(),
+            Rectangle::new(Point::new(15, 17), Size::zero())
+        );
+    }
+
+    #[test]
+    fn dimensions() {
+        let polygon = Polygon::new(&STAR);
+
+        assert_eq!(
+            polygon.bounding_box(),
+            Rectangle::with_corners(Point::new(1, 1), Point::new(19, 19))
+        );
+
+        assert_eq!(
+            polygon.translate(Point::new(-5, 3)).bounding_box(),
+            Rectangle::with_corners(Point::new(-4, 4), Point::new(14, 22))
+        );
+    }
+
+    #[test]
+    fn area_doubled() {
+        let clockwise = [Point::new(0, 0), Point::new(4, 0), Point::new(4, 3)];
+        let counterclockwise = [Point::new(0, 0), Point::new(4, 3), Point::new(4, 0)];
+
+        assert_eq!(Polygon::new(&clockwise).area_doubled(), 12);
+        assert_eq!(Polygon::new(&counterclockwise).area_doubled(), -12);
+    }
+
+    #[test]
+    fn fill_rule() {
+        let polygon = Polygon::new(&STAR);
+        let center = Point::new(10, 11);
+
+        assert!(polygon.contains(center));
+        assert!(!polygon.with_fill_rule(FillRule::EvenOdd).contains(center));
+
+        for fill_rule in [FillRule::NonZero, FillRule::EvenOdd] {
+            let polygon = polygon.with_fill_rule(fill_rule);
+
+            // Tip of the top point.
+            assert!(polygon.contains(Point::new(10, 2)));
+            // Point in the left arm.
+            assert!(polygon.contains(Point::new(4, 8)));
+            // Points outside the star.
+            assert!(!polygon.contains(Point::new(3, 3)));
+            assert!(!polygon.contains(Point::new(10, 18)));
+        }
+    }
+
+    #[test]
+    fn contains_matches_points() {
+        for fill_rule in [FillRule::NonZero, FillRule::EvenOdd] {
+            let polygon = Polygon::new(&STAR)
+                .with_fill_rule(fill_rule)
+                .translate(Point::new(3, -2));
+
+            let expected = polygon
+                .bounding_box()
+                .points()
+                .filter(|point| polygon.contains(*point));
+
+            assert!(polygon.points().eq(expected), "{:?}", fill_rule);
+        }
+    }
+
+    #[test]
+    fn transform_into() {
+        let polygon = Polygon::new(&STAR)
+            .with_fill_rule(FillRule::EvenOdd)
+            .translate(Point::new(-1, -1));
+
+        let mut buffer = [Point::zero(); 5];
+        let mirrored = polygon
+            .transform_into(&Transformation::mirror_horizontal(10), &mut buffer)
+            .unwrap();
+
+        assert_eq!(
+            mirrored.vertices,
+            &[
+                Point::new(11, 0),
+                Point::new(5, 18),
+                Point::new(20, 6),
+                Point::new(2, 6),
+                Point::new(17, 18),
+            ]
+        );
+        assert_eq!(mirrored.translate, Point::zero());
+        assert_eq!(mirrored.fill_rule, FillRule::EvenOdd);
+
+        let mut buffer = [Point::zero(); 4];
+        assert_eq!(
+            polygon.transform_into(&Transformation::mirror_horizontal(10), &mut buffer),
+            None
+        );
+    }
+}
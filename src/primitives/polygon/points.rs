@@ -0,0 +1,52 @@
+use crate::{
+    geometry::{Dimensions, Point},
+    primitives::{
+        common::{Scanline, StrokeOffset},
+        polygon::{scanline_iterator::ScanlineIterator, Polygon},
+        LineJoin, Rectangle,
+    },
+};
+
+/// Iterator over all points inside the polygon.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub struct Points<'a> {
+    scanline_iter: ScanlineIterator<'a>,
+    current_line: Scanline,
+    translate: Point,
+}
+
+impl<'a> Points<'a> {
+    pub(in crate::primitives) fn new(polygon: &Polygon<'a>) -> Self {
+        let bounding_box = polygon.bounding_box();
+        let bounding_box =
+            Rectangle::new(bounding_box.top_left - polygon.translate, bounding_box.size);
+
+        Self {
+            scanline_iter: ScanlineIterator::new(
+                polygon,
+                0,
+                StrokeOffset::None,
+                LineJoin::default(),
+                true,
+                &bounding_box,
+            ),
+            current_line: Scanline::new_empty(0),
+            translate: polygon.translate,
+        }
+    }
+}
+
+impl Iterator for Points<'_> {
+    type Item = Point;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(point) = self.current_line.next() {
+                return Some(point + self.translate);
+            }
+
+            self.current_line = self.scanline_iter.next()?.0;
+        }
+    }
+}
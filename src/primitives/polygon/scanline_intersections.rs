@@ -0,0 +1,456 @@
+//! Polygon scanline intersections iterator.
+
+use core::ops::Range;
+
+use crate::{
+    geometry::Point,
+    primitives::{
+        common::{
+            first_run, merge_runs, ClosedThickSegmentIter, PointType, Scanline, StrokeOffset,
+        },
+        polygon::{FillRule, Polygon},
+        Line, LineJoin,
+    },
+};
+
+/// Polygon scanline intersections iterator.
+///
+/// Returns the stroke and fill runs of a single scanline, sorted by their X coordinate. Fill runs
+/// never overlap the stroke.
+///
+/// The fill runs are calculated once per scanline by sorting the edge crossings and are stored in
+/// a fixed size [`FillRuns`] cache. Scanlines with more crossings or runs than fit into the cache
+/// fall back to repeatedly searching for the next run after the end of the previous run.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub struct ScanlineIntersections<'a> {
+    vertices: &'a [Point],
+    fill_rule: FillRule,
+    stroke_width: u32,
+    stroke_offset: StrokeOffset,
+    line_join: LineJoin,
+    has_fill: bool,
+    scanline_y: i32,
+
+    /// Fill runs of the current scanline or `None` if they couldn't be cached.
+    fill_runs: Option<FillRuns>,
+
+    /// Start of the next run.
+    x: i32,
+}
+
+impl<'a> ScanlineIntersections<'a> {
+    /// Creates a new scanline intersections iterator.
+    ///
+    /// The translation of the polygon is ignored.
+    pub fn new(
+        polygon: &Polygon<'a>,
+        stroke_width: u32,
+        stroke_offset: StrokeOffset,
+        line_join: LineJoin,
+        has_fill: bool,
+        scanline_y: i32,
+    ) -> Self {
+        let mut intersections = Self {
+            vertices: polygon.vertices,
+            fill_rule: polygon.fill_rule,
+            stroke_width,
+            stroke_offset,
+            line_join,
+            has_fill,
+            scanline_y,
+            fill_runs: None,
+            x: i32::MIN,
+        };
+        intersections.reset_with_new_scanline(scanline_y);
+
+        intersections
+    }
+
+    /// Empty.
+    pub const fn empty() -> Self {
+        Self {
+            vertices: &[],
+            fill_rule: FillRule::NonZero,
+            stroke_width: 0,
+            stroke_offset: StrokeOffset::None,
+            line_join: LineJoin::const_default(),
+            has_fill: false,
+            scanline_y: 0,
+            fill_runs: None,
+            x: i32::MIN,
+        }
+    }
+
+    /// Reset with a new scanline.
+    pub fn reset_with_new_scanline(&mut self, scanline_y: i32) {
+        self.scanline_y = scanline_y;
+        self.x = i32::MIN;
+        self.fill_runs = if self.has_fill {
+            self.calculate_fill_runs()
+        } else {
+            None
+        };
+    }
+
+    /// Calculates the fill runs of the current scanline.
+    ///
+    /// Returns `None` if the scanline contains more crossings or runs than can be cached.
+    fn calculate_fill_runs(&self) -> Option<FillRuns> {
+        let mut crossings = [(0, 0); CACHED_CROSSINGS];
+        let mut len = 0;
+        for crossing in self.crossings() {
+            *crossings.get_mut(len)? = crossing;
+            len += 1;
+        }
+
+        let crossings = &mut crossings[0..len];
+        crossings.sort_unstable_by_key(|(x, _)| *x);
+
+        let mut runs = FillRuns::EMPTY;
+
+        let mut winding_number = 0;
+        let mut start = None;
+        for (x, direction) in crossings.iter().copied() {
+            winding_number += direction;
+
+            match (start, self.fill_rule.is_inside(winding_number)) {
+                (None, true) => start = Some(x),
+                (Some(start_x), false) => {
+                    runs.insert(start_x..x)?;
+                    start = None;
+                }
+                _ => {}
+            }
+        }
+
+        let y = self.scanline_y;
+        for (start, end) in self.edges() {
+            let mut scanline = Scanline::new_empty(y);
+            scanline.bresenham_intersection(&Line::new(start, end));
+
+            runs.insert(scanline.x)?;
+        }
+
+        Some(runs)
+    }
+
+    /// Returns an iterator over all edges of the polygon.
+    fn edges(&self) -> impl Iterator<Item = (Point, Point)> + 'a {
+        let vertices = self.vertices;
+        let count = if vertices.len() > 1 {
+            vertices.len()
+        } else {
+            0
+        };
+
+        vertices
+            .iter()
+            .copied()
+            .zip(vertices.iter().copied().cycle().skip(1))
+            .take(count)
+    }
+
+    /// Returns the crossings of the edges with the scanline.
+    ///
+    /// Each crossing consists of the X coordinate of the first pixel to the right of the edge and
+    /// the direction of the edge. Pixels whose center is exactly on an edge are considered to be
+    /// to the left of the edge. The bottom end point of each edge is excluded to make sure that
+    /// vertices aren't counted twice.
+    fn crossings(&self) -> impl Iterator<Item = (i32, i32)> + 'a {
+        let y = self.scanline_y;
+
+        self.edges().filter_map(move |(start, end)| {
+            if y < start.y.min(end.y) || y >= start.y.max(end.y) {
+                return None;
+            }
+
+            let mut numerator = i64::from(y - start.y) * i64::from(end.x - start.x);
+            let mut denominator = i64::from(end.y - start.y);
+            if denominator < 0 {
+                numerator = -numerator;
+                denominator = -denominator;
+            }
+
+            let x = i64::from(start.x) + numerator.div_euclid(denominator) + 1;
+            let direction = if end.y > start.y { 1 } else { -1 };
+
+            Some((x as i32, direction))
+        })
+    }
+
+    /// Returns the winding number of the pixel at the given X coordinate.
+    fn winding_number(&self, x: i32) -> i32 {
+        self.crossings()
+            .filter(|(crossing_x, _)| *crossing_x <= x)
+            .map(|(_, direction)| direction)
+            .sum()
+    }
+
+    /// Returns the first crossing after the given X coordinate.
+    ///
+    /// The returned direction is the sum of all crossings at the same X coordinate.
+    fn next_crossing(&self, x: i32) -> Option<(i32, i32)> {
+        let next_x = self
+            .crossings()
+            .map(|(crossing_x, _)| crossing_x)
+            .filter(|crossing_x| *crossing_x > x)
+            .min()?;
+
+        let direction = self
+            .crossings()
+            .filter(|(crossing_x, _)| *crossing_x == next_x)
+            .map(|(_, direction)| direction)
+            .sum();
+
+        Some((next_x, direction))
+    }
+
+    /// Returns the first run of interior pixels that ends after the given X coordinate.
+    fn interior_run(&self, x: i32) -> Option<Range<i32>> {
+        let mut winding_number = self.winding_number(x);
+
+        let mut start = x;
+        while !self.fill_rule.is_inside(winding_number) {
+            let (crossing_x, direction) = self.next_crossing(start)?;
+            winding_number += direction;
+            start = crossing_x;
+        }
+
+        let mut end = start;
+        while self.fill_rule.is_inside(winding_number) {
+            let (crossing_x, direction) = self.next_crossing(end)?;
+            winding_number += direction;
+            end = crossing_x;
+        }
+
+        Some(start..end)
+    }
+
+    /// Returns the first run of edge pixels that ends after the given X coordinate.
+    ///
+    /// The edges are drawn using the same algorithm as 1px wide lines to make sure that the fill
+    /// covers the same pixels as a 1px stroke.
+    fn edge_run(&self, x: i32) -> Option<Range<i32>> {
+        let y = self.scanline_y;
+
+        first_run(
+            x,
+            self.edges().map(move |(start, end)| {
+                let mut scanline = Scanline::new_empty(y);
+                scanline.bresenham_intersection(&Line::new(start, end));
+
+                scanline.x
+            }),
+        )
+    }
+
+    /// Returns the first run of fill pixels that ends after the given X coordinate.
+    ///
+    /// The fill area includes all interior pixels and all pixels on the edges of the polygon,
+    /// regardless of the stroke.
+    pub fn fill_run(&self, x: i32) -> Option<Range<i32>> {
+        if let Some(fill_runs) = &self.fill_runs {
+            return first_run(x, fill_runs.runs().iter().cloned());
+        }
+
+        merge_runs(x, |x| {
+            first_run(x, self.interior_run(x).into_iter().chain(self.edge_run(x)))
+        })
+    }
+
+    /// Returns the first run of stroke pixels that ends after the given X coordinate.
+    fn stroke_run(&self, x: i32) -> Option<Range<i32>> {
+        if self.stroke_width == 0 || self.vertices.len() < 2 {
+            return None;
+        }
+
+        let y = self.scanline_y;
+
+        merge_runs(x, |x| {
+            first_run(
+                x,
+                ClosedThickSegmentIter::new(
+                    self.vertices,
+                    self.stroke_width,
+                    self.stroke_offset,
+                    self.line_join,
+                )
+                .flat_map(move |segment| {
+                    // Round joins are filled by a disc at the end of each segment.
+                    let join = segment.end_join().round_join().and_then(|join| join.run(y));
+
+                    core::iter::once(segment.intersection(y).x).chain(join)
+                }),
+            )
+        })
+    }
+}
+
+/// Maximum number of edge crossings per scanline for which the fill runs are cached.
+const CACHED_CROSSINGS: usize = 32;
+
+/// Maximum number of cached fill runs per scanline.
+const CACHED_RUNS: usize = 16;
+
+/// Cached fill runs of a single scanline.
+///
+/// The runs are sorted by their X coordinate and adjacent or overlapping runs are merged.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+struct FillRuns {
+    runs: [Range<i32>; CACHED_RUNS],
+    len: usize,
+}
+
+impl FillRuns {
+    const EMPTY_RUN: Range<i32> = 0..0;
+
+    const EMPTY: Self = Self {
+        runs: [Self::EMPTY_RUN; CACHED_RUNS],
+        len: 0,
+    };
+
+    /// Returns the cached runs.
+    fn runs(&self) -> &[Range<i32>] {
+        &self.runs[0..self.len]
+    }
+
+    /// Inserts a run and merges it with all adjacent or overlapping runs.
+    ///
+    /// Returns `None` if the cache is full.
+    fn insert(&mut self, run: Range<i32>) -> Option<()> {
+        if run.is_empty() {
+            return Some(());
+        }
+
+        let runs = self.runs();
+        let first = runs
+            .iter()
+            .position(|r| r.end >= run.start)
+            .unwrap_or(runs.len());
+        let last = runs
+            .iter()
+            .position(|r| r.start > run.end)
+            .unwrap_or(runs.len());
+
+        if first < last {
+            let start = runs[first].start.min(run.start);
+            let end = runs[last - 1].end.max(run.end);
+
+            self.runs[first] = start..end;
+            self.runs[first + 1..self.len].rotate_left(last - first - 1);
+            self.len -= last - first - 1;
+        } else {
+            if self.len == CACHED_RUNS {
+                return None;
+            }
+
+            self.runs[first..=self.len].rotate_right(1);
+            self.runs[first] = run;
+            self.len += 1;
+        }
+
+        Some(())
+    }
+}
+
+impl Iterator for ScanlineIntersections<'_> {
+    type Item = (Scanline, PointType);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let stroke = self.stroke_run(self.x);
+        let fill = if self.has_fill {
+            self.fill_run(self.x)
+        } else {
+            None
+        };
+
+        let (x, point_type) = match (stroke, fill) {
+            (Some(stroke), Some(fill)) if fill.start < stroke.start => {
+                (fill.start..fill.end.min(stroke.start), PointType::Fill)
+            }
+            (Some(stroke), _) => (stroke, PointType::Stroke),
+            (None, Some(fill)) => (fill, PointType::Fill),
+            (None, None) => return None,
+        };
+
+        self.x = x.end;
+
+        Some((Scanline::new(self.scanline_y, x), point_type))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{geometry::Dimensions, primitives::polygon::tests::STAR};
+
+    /// Returns the fill runs of a scanline, with or without using the cache.
+    fn fill_runs<'a>(
+        polygon: &Polygon<'a>,
+        scanline_y: i32,
+        cached: bool,
+    ) -> impl Iterator<Item = Range<i32>> + 'a {
+        let mut intersections = ScanlineIntersections::new(
+            polygon,
+            0,
+            StrokeOffset::None,
+            LineJoin::default(),
+            true,
+            scanline_y,
+        );
+        if !cached {
+            intersections.fill_runs = None;
+        }
+
+        let mut x = i32::MIN;
+        core::iter::from_fn(move || {
+            let run = intersections.fill_run(x)?;
+            x = run.end;
+
+            Some(run)
+        })
+    }
+
+    #[test]
+    fn cached_fill_runs() {
+        // A comb with 20 teeth, which exceeds the cache size.
+        let mut comb = [Point::zero(); 82];
+        for (i, vertex) in comb[0..80].iter_mut().enumerate() {
+            let i = i as i32;
+            *vertex = Point::new(i / 2 * 4 + (i + 1) % 4 / 2 * 2, (i + 1) % 4 / 2 * 10);
+        }
+        comb[80] = Point::new(160, 15);
+        comb[81] = Point::new(0, 15);
+
+        for vertices in [&STAR[..], &comb[..]] {
+            for fill_rule in [FillRule::NonZero, FillRule::EvenOdd] {
+                let polygon = Polygon::new(vertices).with_fill_rule(fill_rule);
+
+                for y in polygon.bounding_box().rows() {
+                    assert!(
+                        fill_runs(&polygon, y, true).eq(fill_runs(&polygon, y, false)),
+                        "{:?} {}",
+                        fill_rule,
+                        y
+                    );
+                }
+            }
+        }
+
+        let polygon = Polygon::new(&comb);
+        let intersections = |y| {
+            ScanlineIntersections::new(
+                &polygon,
+                0,
+                StrokeOffset::None,
+                LineJoin::default(),
+                true,
+                y,
+            )
+        };
+        assert!(intersections(12).fill_runs.is_some());
+        assert!(intersections(5).fill_runs.is_none());
+    }
+}
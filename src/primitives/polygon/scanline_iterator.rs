@@ -0,0 +1,75 @@
+//! Scanline iterator.
+
+use core::ops::Range;
+
+use crate::primitives::{
+    common::{PointType, Scanline, StrokeOffset},
+    polygon::{scanline_intersections::ScanlineIntersections, Polygon},
+    LineJoin, Rectangle,
+};
+
+/// Iterate over every scanline in the polygon's bounding box.
+///
+/// Each scanline produces multiple stroke and fill lines. The translation of the polygon is
+/// ignored.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub struct ScanlineIterator<'a> {
+    rows: Range<i32>,
+    intersections: ScanlineIntersections<'a>,
+}
+
+impl<'a> ScanlineIterator<'a> {
+    /// New.
+    pub fn new(
+        polygon: &Polygon<'a>,
+        stroke_width: u32,
+        stroke_offset: StrokeOffset,
+        line_join: LineJoin,
+        has_fill: bool,
+        bounding_box: &Rectangle,
+    ) -> Self {
+        let mut rows = bounding_box.rows();
+
+        if let Some(scanline_y) = rows.next() {
+            let intersections = ScanlineIntersections::new(
+                polygon,
+                stroke_width,
+                stroke_offset,
+                line_join,
+                has_fill,
+                scanline_y,
+            );
+
+            Self {
+                rows,
+                intersections,
+            }
+        } else {
+            Self::empty()
+        }
+    }
+
+    /// Empty.
+    pub const fn empty() -> Self {
+        Self {
+            rows: 0i32..0,
+            intersections: ScanlineIntersections::empty(),
+        }
+    }
+}
+
+impl Iterator for ScanlineIterator<'_> {
+    type Item = (Scanline, PointType);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(next) = self.intersections.next() {
+                break Some(next);
+            }
+
+            let scanline_y = self.rows.next()?;
+            self.intersections.reset_with_new_scanline(scanline_y);
+        }
+    }
+}
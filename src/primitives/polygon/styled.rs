@@ -0,0 +1,627 @@
+use crate::{
+    draw_target::{DrawTarget, DrawTargetExt},
+    geometry::{Dimensions, Point},
+    pixelcolor::PixelColor,
+    primitives::{
+        common::{
+            ClosedThickSegmentIter, PatternedStroke, PointType, Scanline, SegmentsArea,
+            SegmentsOutline, StrokeOffset,
+        },
+        polygon::{scanline_iterator::ScanlineIterator, Polygon},
+        styled::{StyledDimensions, StyledDrawable, StyledPixels},
+        PrimitiveStyle, Rectangle,
+    },
+    transform::Transform,
+    Pixel,
+};
+
+/// Returns the stroke offset for the polygon.
+///
+/// The offset is swapped for counterclockwise polygons to make sure that inside and outside
+/// strokes are drawn on the correct side of the edges.
+fn stroke_offset<C: PixelColor>(primitive: &Polygon, style: &PrimitiveStyle<C>) -> StrokeOffset {
+    let offset = StrokeOffset::from(style.stroke_alignment);
+
+    if primitive.area_doubled() >= 0 {
+        return offset;
+    }
+
+    match offset {
+        StrokeOffset::Left => StrokeOffset::Right,
+        StrokeOffset::Right => StrokeOffset::Left,
+        StrokeOffset::None => StrokeOffset::None,
+    }
+}
+
+/// Compute the bounding box of the non-translated polygon.
+fn untranslated_bounding_box<C: PixelColor>(
+    primitive: &Polygon,
+    style: &PrimitiveStyle<C>,
+) -> Rectangle {
+    let bounding_box = Polygon {
+        translate: Point::zero(),
+        ..*primitive
+    }
+    .bounding_box();
+
+    // Short circuit special cases. Inside strokes aren't included, because the inside of a
+    // self-intersecting polygon can be on the outer side of an edge.
+    if style.stroke_width < 2 || primitive.vertices.len() < 2 {
+        return bounding_box;
+    }
+
+    let (min, max) = ClosedThickSegmentIter::new(
+        primitive.vertices,
+        style.stroke_width,
+        stroke_offset(primitive, style),
+        style.effective_line_join(),
+    )
+    .fold(
+        (
+            bounding_box.top_left,
+            bounding_box.bottom_right().unwrap_or(bounding_box.top_left),
+        ),
+        |(min, max), segment| {
+            let join = segment
+                .end_join()
+                .round_join()
+                .map(|join| join.bounding_box());
+
+            core::iter::once(segment.edges_bounding_box())
+                .chain(join)
+                .fold((min, max), |(min, max), bb| {
+                    (
+                        min.component_min(bb.top_left),
+                        max.component_max(bb.bottom_right().unwrap_or(bb.top_left)),
+                    )
+                })
+        },
+    );
+
+    Rectangle::with_corners(min, max)
+}
+
+/// Dashed or dotted polygon stroke.
+type PolygonStroke<'a> =
+    PatternedStroke<SegmentsOutline<&'a [Point]>, SegmentsArea<&'a [Point]>, Polygon<'a>>;
+
+/// Returns the dashed or dotted stroke if the style uses a non solid stroke.
+fn patterned_stroke<'a, C: PixelColor>(
+    primitive: &Polygon<'a>,
+    style: &PrimitiveStyle<C>,
+) -> Option<PolygonStroke<'a>> {
+    let outline = SegmentsOutline::closed(primitive.vertices);
+    let untranslated = Polygon {
+        translate: Point::zero(),
+        ..*primitive
+    };
+
+    if let Some(dot_size) = style.dot_size() {
+        // The dots are placed on the center line of the solid stroke.
+        let outline = outline.stroke_center(style.stroke_width, stroke_offset(primitive, style));
+        let area = SegmentsArea::closed(primitive.vertices, style.stroke_width);
+
+        return Some(PatternedStroke::dotted(
+            outline,
+            area,
+            dot_size,
+            untranslated,
+        ));
+    }
+
+    style
+        .dash_pattern()
+        .map(|pattern| PatternedStroke::dashed(outline, pattern, untranslated))
+}
+
+/// Pixel iterator for each pixel in the polygon border and fill
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub struct StyledPixelsIterator<'a, C> {
+    lines_iter: ScanlineIterator<'a>,
+    current_line: Scanline,
+    current_color: Option<C>,
+    current_type: PointType,
+    fill_color: Option<C>,
+    stroke_color: Option<C>,
+    translate: Point,
+    pattern: Option<PolygonStroke<'a>>,
+}
+
+impl<'a, C: PixelColor> StyledPixelsIterator<'a, C> {
+    pub(in crate::primitives) fn new(primitive: &Polygon<'a>, style: &PrimitiveStyle<C>) -> Self {
+        let lines_iter = if style.is_transparent() {
+            ScanlineIterator::empty()
+        } else {
+            ScanlineIterator::new(
+                primitive,
+                style.stroke_width,
+                stroke_offset(primitive, style),
+                style.effective_line_join(),
+                style.fill_color.is_some(),
+                &untranslated_bounding_box(primitive, style),
+            )
+        };
+
+        Self {
+            lines_iter,
+            current_line: Scanline::new_empty(0),
+            current_color: None,
+            current_type: PointType::Fill,
+            fill_color: style.fill_color,
+            stroke_color: style.effective_stroke_color(),
+            translate: primitive.translate,
+            pattern: patterned_stroke(primitive, style),
+        }
+    }
+}
+
+impl<C: PixelColor> Iterator for StyledPixelsIterator<'_, C> {
+    type Item = Pixel<C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(p) = self.current_line.next() {
+                let color = match (&self.pattern, self.current_type, self.current_color) {
+                    (Some(pattern), PointType::Stroke, Some(stroke_color)) => {
+                        pattern.color(p, stroke_color, self.fill_color)
+                    }
+                    (_, _, color) => color,
+                };
+
+                if let Some(color) = color {
+                    return Some(Pixel(p + self.translate, color));
+                }
+            } else {
+                let (next_line, next_type) = self.lines_iter.next()?;
+
+                self.current_line = next_line;
+                self.current_type = next_type;
+
+                self.current_color = match next_type {
+                    PointType::Stroke => self.stroke_color,
+                    PointType::Fill => self.fill_color,
+                };
+
+                // Skip invisible lines.
+                if self.current_color.is_none() {
+                    self.current_line = Scanline::new_empty(0);
+                }
+            }
+        }
+    }
+}
+
+impl<'a, C: PixelColor> StyledPixels<PrimitiveStyle<C>> for Polygon<'a> {
+    type Iter = StyledPixelsIterator<'a, C>;
+
+    fn pixels(&self, style: &PrimitiveStyle<C>) -> Self::Iter {
+        StyledPixelsIterator::new(self, style)
+    }
+}
+
+fn draw_untranslated<D>(
+    polygon: &Polygon,
+    style: &PrimitiveStyle<D::Color>,
+    target: &mut D,
+) -> Result<(), D::Error>
+where
+    D: DrawTarget,
+{
+    let pattern = patterned_stroke(polygon, style);
+    let stroke_color = style.effective_stroke_color();
+
+    for (line, kind) in ScanlineIterator::new(
+        polygon,
+        style.stroke_width,
+        stroke_offset(polygon, style),
+        style.effective_line_join(),
+        style.fill_color.is_some(),
+        &untranslated_bounding_box(polygon, style),
+    ) {
+        let color = match kind {
+            PointType::Stroke => stroke_color,
+            PointType::Fill => style.fill_color,
+        };
+
+        if let (Some(pattern), Some(stroke_color), PointType::Stroke) = (&pattern, color, kind) {
+            line.draw_runs(target, |point| {
+                pattern.color(point, stroke_color, style.fill_color)
+            })?;
+        } else if let Some(color) = color {
+            line.draw(target, color)?;
+        }
+    }
+
+    Ok(())
+}
+
+impl<C: PixelColor> StyledDrawable<PrimitiveStyle<C>> for Polygon<'_> {
+    type Color = C;
+    type Output = ();
+
+    fn draw_styled<D>(
+        &self,
+        style: &PrimitiveStyle<C>,
+        target: &mut D,
+    ) -> Result<Self::Output, D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        if style.is_transparent() {
+            return Ok(());
+        }
+
+        if self.translate != Point::zero() {
+            draw_untranslated(self, style, &mut target.translated(self.translate))
+        } else {
+            draw_untranslated(self, style, target)
+        }
+    }
+}
+
+impl<C: PixelColor> StyledDimensions<PrimitiveStyle<C>> for Polygon<'_> {
+    fn styled_bounding_box(&self, style: &PrimitiveStyle<C>) -> Rectangle {
+        untranslated_bounding_box(self, style).translate(self.translate)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        iterator::PixelIteratorExt,
+        mock_display::MockDisplay,
+        pixelcolor::BinaryColor,
+        primitives::{
+            common::{check_dashed_stroke, check_dotted_stroke},
+            polygon::{tests::STAR, FillRule},
+            LineJoin, PointsIter, Polyline, Primitive, PrimitiveStyleBuilder, StrokeAlignment,
+            Triangle,
+        },
+        Drawable,
+    };
+
+    const ARROW: [Point; 7] = [
+        Point::new(12, 22),
+        Point::new(30, 22),
+        Point::new(30, 12),
+        Point::new(46, 28),
+        Point::new(30, 44),
+        Point::new(30, 34),
+        Point::new(12, 34),
+    ];
+
+    fn styles() -> impl Iterator<Item = PrimitiveStyle<BinaryColor>> {
+        [
+            StrokeAlignment::Inside,
+            StrokeAlignment::Center,
+            StrokeAlignment::Outside,
+        ]
+        .into_iter()
+        .flat_map(|stroke_alignment| {
+            [0, 1, 2, 5].into_iter().flat_map(move |stroke_width| {
+                [None, Some(BinaryColor::Off)]
+                    .into_iter()
+                    .map(move |fill_color| {
+                        let mut style = PrimitiveStyleBuilder::new()
+                            .stroke_color(BinaryColor::On)
+                            .stroke_width(stroke_width)
+                            .stroke_alignment(stroke_alignment)
+                            .build();
+                        style.fill_color = fill_color;
+
+                        style
+                    })
+            })
+        })
+    }
+
+    #[test]
+    fn fill_square() {
+        let vertices = [
+            Point::new(1, 1),
+            Point::new(5, 1),
+            Point::new(5, 4),
+            Point::new(1, 4),
+        ];
+
+        let mut display = MockDisplay::new();
+        Polygon::new(&vertices)
+            .into_styled(PrimitiveStyle::with_fill(BinaryColor::On))
+            .draw(&mut display)
+            .unwrap();
+
+        display.assert_pattern(&[
+            "      ", //
+            " #####", //
+            " #####", //
+            " #####", //
+            " #####", //
+        ]);
+    }
+
+    #[test]
+    fn fill_rules() {
+        let mut display = MockDisplay::new();
+        Polygon::new(&STAR)
+            .with_fill_rule(FillRule::EvenOdd)
+            .into_styled(PrimitiveStyle::with_fill(BinaryColor::On))
+            .draw(&mut display)
+            .unwrap();
+
+        let mut expected = MockDisplay::new();
+        Polygon::new(&STAR)
+            .into_styled(PrimitiveStyle::with_fill(BinaryColor::On))
+            .draw(&mut expected)
+            .unwrap();
+
+        // The even-odd rule leaves a hole in the center of the star.
+        assert_eq!(
+            expected.get_pixel(Point::new(10, 11)),
+            Some(BinaryColor::On)
+        );
+        assert_eq!(display.get_pixel(Point::new(10, 11)), None);
+
+        for point in display.affected_area().points() {
+            if display.get_pixel(point).is_some() {
+                assert_eq!(expected.get_pixel(point), Some(BinaryColor::On));
+            }
+        }
+    }
+
+    #[test]
+    fn draw_matches_pixels() {
+        for polygon in [
+            Polygon::new(&ARROW),
+            Polygon::new(&STAR).translate(Point::new(20, 20)),
+            Polygon::new(&STAR)
+                .with_fill_rule(FillRule::EvenOdd)
+                .translate(Point::new(20, 20)),
+        ] {
+            for style in styles() {
+                let mut drawn = MockDisplay::new();
+                polygon.draw_styled(&style, &mut drawn).unwrap();
+
+                let mut pixels = MockDisplay::new();
+                polygon.pixels(&style).draw(&mut pixels).unwrap();
+
+                drawn.assert_eq(&pixels);
+
+                assert_eq!(
+                    polygon
+                        .styled_bounding_box(&style)
+                        .intersection(&drawn.affected_area()),
+                    drawn.affected_area(),
+                    "{:?}",
+                    style
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn stroke_same_as_closed_polyline() {
+        let mut closed = [Point::zero(); 8];
+        closed[..7].copy_from_slice(&ARROW);
+        closed[7] = ARROW[0];
+
+        for stroke_width in [1, 3, 4] {
+            let style = PrimitiveStyle::with_stroke(BinaryColor::On, stroke_width);
+
+            let mut expected = MockDisplay::new();
+            expected.set_allow_overdraw(true);
+            Polyline::new(&closed)
+                .into_styled(style)
+                .draw(&mut expected)
+                .unwrap();
+
+            let mut display = MockDisplay::new();
+            Polygon::new(&ARROW)
+                .into_styled(style)
+                .draw(&mut display)
+                .unwrap();
+
+            // The closing joint of the polyline isn't mitered, so only the pixels of the
+            // polyline are checked.
+            for point in expected.affected_area().points() {
+                if expected.get_pixel(point).is_some() {
+                    assert_eq!(
+                        display.get_pixel(point),
+                        Some(BinaryColor::On),
+                        "{} px, {:?}",
+                        stroke_width,
+                        point
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn line_joins_match_triangles() {
+        let vertices = [Point::new(14, 10), Point::new(36, 14), Point::new(8, 26)];
+        let triangle = Triangle::from_slice(&vertices);
+
+        for line_join in [
+            LineJoin::default(),
+            LineJoin::Miter { limit: 1 },
+            LineJoin::Bevel,
+            LineJoin::Round,
+        ] {
+            for stroke_alignment in [StrokeAlignment::Center, StrokeAlignment::Outside] {
+                let style = PrimitiveStyleBuilder::new()
+                    .stroke_color(BinaryColor::On)
+                    .stroke_width(5)
+                    .stroke_alignment(stroke_alignment)
+                    .fill_color(BinaryColor::Off)
+                    .line_join(line_join)
+                    .build();
+
+                let mut expected = MockDisplay::new();
+                triangle.draw_styled(&style, &mut expected).unwrap();
+
+                let polygon = Polygon::new(&vertices);
+
+                let mut drawn = MockDisplay::new();
+                polygon.draw_styled(&style, &mut drawn).unwrap();
+
+                let mut pixels = MockDisplay::new();
+                polygon.pixels(&style).draw(&mut pixels).unwrap();
+
+                drawn.assert_eq_with_message(&expected, |f| {
+                    write!(f, "{:?}, {:?}", line_join, stroke_alignment)
+                });
+                drawn.assert_eq(&pixels);
+                assert_eq!(
+                    drawn.affected_area(),
+                    polygon.styled_bounding_box(&style),
+                    "{:?}, {:?}",
+                    line_join,
+                    stroke_alignment
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn winding_direction_is_ignored() {
+        let mut reversed = ARROW;
+        reversed.reverse();
+
+        // Thicker strokes can differ by single pixels at the corners, because the edges of the
+        // joints are rasterized in the direction of the outline. Center aligned strokes with an
+        // even width can't be split evenly between both sides of an edge and the extra pixel is
+        // always placed on the same side relative to the edge direction, like it is for
+        // polylines.
+        for style in styles().filter(|style| {
+            style.stroke_width <= 2
+                && (style.stroke_alignment != StrokeAlignment::Center
+                    || style.stroke_width % 2 == 1)
+        }) {
+            let mut expected = MockDisplay::new();
+            Polygon::new(&ARROW)
+                .into_styled(style)
+                .draw(&mut expected)
+                .unwrap();
+
+            let mut display = MockDisplay::new();
+            Polygon::new(&reversed)
+                .into_styled(style)
+                .draw(&mut display)
+                .unwrap();
+
+            assert_eq!(display, expected, "{:?}", style);
+        }
+    }
+
+    #[test]
+    fn inside_stroke_stays_inside() {
+        let polygon = Polygon::new(&ARROW);
+
+        let mut filled = MockDisplay::new();
+        polygon
+            .into_styled(PrimitiveStyle::with_fill(BinaryColor::On))
+            .draw(&mut filled)
+            .unwrap();
+
+        let style = PrimitiveStyleBuilder::new()
+            .stroke_color(BinaryColor::On)
+            .stroke_width(3)
+            .stroke_alignment(StrokeAlignment::Inside)
+            .fill_color(BinaryColor::Off)
+            .build();
+
+        let mut display = MockDisplay::new();
+        polygon.into_styled(style).draw(&mut display).unwrap();
+
+        for point in polygon.bounding_box().points() {
+            assert_eq!(
+                display.get_pixel(point).is_some(),
+                filled.get_pixel(point).is_some(),
+                "{:?}",
+                point
+            );
+        }
+    }
+
+    #[test]
+    fn self_intersecting_bounding_box() {
+        // The inside stroke of a self-intersecting polygon extends beyond the vertices.
+        let vertices = [
+            Point::new(10, 10),
+            Point::new(40, 12),
+            Point::new(20, 40),
+            Point::new(45, 45),
+            Point::new(5, 30),
+        ];
+        let polygon = Polygon::new(&vertices);
+
+        for stroke_alignment in [
+            StrokeAlignment::Inside,
+            StrokeAlignment::Center,
+            StrokeAlignment::Outside,
+        ] {
+            let style = PrimitiveStyleBuilder::new()
+                .stroke_color(BinaryColor::On)
+                .stroke_width(5)
+                .stroke_alignment(stroke_alignment)
+                .build();
+
+            let mut display = MockDisplay::new();
+            polygon.draw_styled(&style, &mut display).unwrap();
+
+            assert_eq!(
+                polygon
+                    .styled_bounding_box(&style)
+                    .intersection(&display.affected_area()),
+                display.affected_area(),
+                "{:?}",
+                stroke_alignment
+            );
+        }
+    }
+
+    #[test]
+    fn degenerate_polygons() {
+        let style = PrimitiveStyleBuilder::new()
+            .stroke_color(BinaryColor::On)
+            .stroke_width(3)
+            .fill_color(BinaryColor::Off)
+            .build();
+
+        for vertices in [&[][..], &[Point::new(5, 5)][..]] {
+            let polygon = Polygon::new(vertices);
+
+            let mut display = MockDisplay::new();
+            polygon.into_styled(style).draw(&mut display).unwrap();
+
+            assert!(display.affected_area().is_zero_sized());
+            assert_eq!(polygon.pixels(&style).count(), 0);
+        }
+
+        // A polygon with two vertices is drawn as a line.
+        let vertices = [Point::new(1, 1), Point::new(6, 1)];
+
+        let mut display = MockDisplay::new();
+        Polygon::new(&vertices)
+            .into_styled(PrimitiveStyle::with_fill(BinaryColor::On))
+            .draw(&mut display)
+            .unwrap();
+
+        display.assert_pattern(&[
+            "       ", //
+            " ######", //
+        ]);
+    }
+
+    #[test]
+    fn dashed_stroke() {
+        check_dashed_stroke(Polygon::new(&ARROW));
+    }
+
+    #[test]
+    fn dotted_stroke() {
+        check_dotted_stroke(Polygon::new(&ARROW));
+    }
+}
@@ -0,0 +1,240 @@
+//! Anti-aliased polylines.
+
+use core::ops::Range;
+
+use crate::{
+    geometry::{Dimensions, Point, Size},
+    pixelcolor::PixelColor,
+    primitives::{
+        common::{edge_coverage, first_run, isqrt, AntiAliasedShape, Coverage},
+        line::anti_aliased::AntiAliasedLine,
+        polyline::Polyline,
+        AntiAliasedStyle, Line, Rectangle,
+    },
+    transform::Transform,
+};
+
+/// Anti-aliased polyline.
+///
+/// The stroke is the union of the anti-aliased strokes of all line segments and a disc at each
+/// vertex between two segments, which results in round joins.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub(in crate::primitives) struct AntiAliasedPolyline<'a> {
+    polyline: Polyline<'a>,
+    stroke_width: u32,
+}
+
+impl<'a> AntiAliasedPolyline<'a> {
+    fn segments(&self) -> impl Iterator<Item = AntiAliasedLine> + '_ {
+        self.polyline.vertices.windows(2).map(move |segment| {
+            let line = Line::new(segment[0], segment[1]).translate(self.polyline.translate);
+
+            AntiAliasedLine::new(&line, self.stroke_width)
+        })
+    }
+
+    fn joins(&self) -> impl Iterator<Item = Point> + '_ {
+        let vertices = self.polyline.vertices;
+        let inner_vertices = vertices.get(1..vertices.len().saturating_sub(1));
+
+        inner_vertices
+            .unwrap_or_default()
+            .iter()
+            .map(move |vertex| *vertex + self.polyline.translate)
+    }
+
+    /// Returns the coverage of the disc at a join.
+    fn join_coverage(&self, vertex: Point, point: Point) -> u32 {
+        let delta = point - vertex;
+        let distance_squared = i64::from(delta.x).pow(2) + i64::from(delta.y).pow(2);
+        let distance = isqrt((distance_squared as u64) << 16) as i64;
+
+        edge_coverage(distance - i64::from(self.stroke_width) * 128)
+    }
+
+    /// Returns the range of pixels in a row that can be covered by the disc at a join.
+    fn join_row(&self, vertex: Point, y: i32) -> Option<Range<i32>> {
+        let radius = (self.stroke_width / 2 + 1) as i32;
+
+        ((y - vertex.y).abs() <= radius).then(|| vertex.x - radius..vertex.x + radius + 1)
+    }
+}
+
+impl AntiAliasedShape for AntiAliasedPolyline<'_> {
+    fn area(&self) -> Rectangle {
+        if self.stroke_width == 0 || self.polyline.vertices.len() < 2 {
+            return Rectangle::new(self.polyline.bounding_box().top_left, Size::zero());
+        }
+
+        let offset = (self.stroke_width / 2 + 1) as i32;
+
+        self.polyline.bounding_box().offset(offset)
+    }
+
+    fn run(&self, y: i32, x: i32) -> Option<Range<i32>> {
+        let segment_rows = self.segments().filter_map(|segment| segment.row(y));
+        let join_rows = self.joins().filter_map(|vertex| self.join_row(vertex, y));
+
+        first_run(x, segment_rows.chain(join_rows))
+    }
+
+    fn coverage(&self, point: Point) -> Coverage {
+        let segments = self
+            .segments()
+            .map(|segment| segment.stroke_coverage(point));
+        let joins = self.joins().map(|vertex| self.join_coverage(vertex, point));
+
+        Coverage::stroke(segments.chain(joins).max().unwrap_or_default())
+    }
+}
+
+impl<'a> Polyline<'a> {
+    /// Returns the anti-aliased shape of the polyline.
+    pub(in crate::primitives) fn anti_aliased<C: PixelColor>(
+        &self,
+        style: &AntiAliasedStyle<C>,
+    ) -> AntiAliasedPolyline<'a> {
+        let stroke_width = style
+            .primitive_style
+            .effective_stroke_color()
+            .map_or(0, |_| style.primitive_style.stroke_width);
+
+        AntiAliasedPolyline {
+            polyline: *self,
+            stroke_width,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        mock_display::MockDisplay,
+        pixelcolor::{Gray8, GrayColor},
+        primitives::{PointsIter, Primitive, PrimitiveStyle},
+        Drawable,
+    };
+
+    const VERTICES: [Point; 4] = [
+        Point::new(5, 40),
+        Point::new(20, 10),
+        Point::new(35, 50),
+        Point::new(55, 30),
+    ];
+
+    fn style(stroke_width: u32) -> AntiAliasedStyle<Gray8> {
+        AntiAliasedStyle::new(
+            PrimitiveStyle::with_stroke(Gray8::WHITE, stroke_width),
+            Gray8::BLACK,
+        )
+    }
+
+    #[test]
+    fn segments_match_lines() {
+        for stroke_width in [1, 4] {
+            let mut display = MockDisplay::new();
+            Polyline::new(&VERTICES)
+                .into_styled(style(stroke_width))
+                .draw(&mut display)
+                .unwrap();
+
+            // Away from the joins the polyline is drawn like the individual lines.
+            for segment in VERTICES.windows(2) {
+                let line = Line::new(segment[0], segment[1]);
+
+                let mut expected = MockDisplay::new();
+                line.into_styled(style(stroke_width))
+                    .draw(&mut expected)
+                    .unwrap();
+
+                let midpoint = line.midpoint();
+                for point in Rectangle::with_center(midpoint, Size::new(5, 5)).points() {
+                    assert_eq!(
+                        display.get_pixel(point),
+                        expected.get_pixel(point),
+                        "{:?}, {} px",
+                        point,
+                        stroke_width
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn joins_are_covered() {
+        let mut display = MockDisplay::new();
+        Polyline::new(&VERTICES)
+            .into_styled(style(5))
+            .draw(&mut display)
+            .unwrap();
+
+        for vertex in &VERTICES[1..3] {
+            for point in Rectangle::with_center(*vertex, Size::new(3, 3)).points() {
+                assert_eq!(display.get_pixel(point), Some(Gray8::WHITE), "{:?}", point);
+            }
+        }
+    }
+
+    #[test]
+    fn translated() {
+        let polyline = Polyline::new(&VERTICES);
+
+        let mut expected = MockDisplay::new();
+        polyline.into_styled(style(3)).draw(&mut expected).unwrap();
+
+        let mut display = MockDisplay::new();
+        polyline
+            .translate(Point::new(2, -3))
+            .into_styled(style(3))
+            .draw(&mut display)
+            .unwrap();
+
+        for point in expected.affected_area().points() {
+            assert_eq!(
+                display.get_pixel(point + Point::new(2, -3)),
+                expected.get_pixel(point),
+                "{:?}",
+                point
+            );
+        }
+    }
+
+    #[test]
+    fn bounding_box_contains_drawn_pixels() {
+        for stroke_width in 0..6 {
+            let styled = Polyline::new(&VERTICES).into_styled(style(stroke_width));
+
+            let mut display = MockDisplay::new();
+            styled.draw(&mut display).unwrap();
+
+            let area = display.affected_area();
+            assert_eq!(
+                styled.bounding_box().intersection(&area),
+                area,
+                "{} px",
+                stroke_width
+            );
+        }
+    }
+
+    #[test]
+    fn edge_pixels_are_blended() {
+        let mut display = MockDisplay::new();
+        Polyline::new(&VERTICES)
+            .into_styled(style(1))
+            .draw(&mut display)
+            .unwrap();
+
+        let blended = display
+            .affected_area()
+            .points()
+            .filter_map(|point| display.get_pixel(point))
+            .filter(|color| color.luma() > 0 && color.luma() < 255)
+            .count();
+
+        assert!(blended > 0);
+    }
+}
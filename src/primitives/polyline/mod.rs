@@ -3,9 +3,10 @@
 use crate::{
     geometry::{Dimensions, Point, Size},
     primitives::{PointsIter, Primitive, Rectangle},
-    transform::Transform,
+    transform::{Transform, Transformation},
 };
 
+mod anti_aliased;
 mod points;
 pub(in crate::primitives) mod scanline_intersections;
 mod scanline_iterator;
@@ -73,6 +74,20 @@ impl<'a> Polyline<'a> {
             translate: Point::zero(),
         }
     }
+
+    /// Applies a transformation to this polyline.
+    ///
+    /// The transformed vertices are stored in `buffer` and the returned polyline isn't
+    /// translated. Returns `None` if `buffer` is smaller than the number of vertices.
+    pub fn transform_into<'b>(
+        &self,
+        transformation: &Transformation,
+        buffer: &'b mut [Point],
+    ) -> Option<Polyline<'b>> {
+        transformation
+            .transform_vertices(self.vertices, self.translate, buffer)
+            .map(Polyline::new)
+    }
 }
 
 impl Primitive for Polyline<'_> {}
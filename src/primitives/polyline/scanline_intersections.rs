@@ -2,7 +2,10 @@
 
 use crate::{
     geometry::Point,
-    primitives::common::{LineJoin, Scanline, StrokeOffset, ThickSegment},
+    primitives::{
+        common::{disc_run, RoundJoin, Scanline, SegmentJoin, StrokeOffset, ThickSegment},
+        Line, LineCap, LineJoin,
+    },
 };
 
 /// Scanline intersections iterator.
@@ -16,8 +19,12 @@ use crate::{
 pub struct ScanlineIntersections<'a> {
     points: &'a [Point],
     remaining_points: &'a [Point],
-    next_start_join: Option<LineJoin>,
+    next_start_join: Option<SegmentJoin>,
     width: u32,
+    line_cap: LineCap,
+    line_join: LineJoin,
+    end_cap: Option<Point>,
+    round_join: Option<RoundJoin>,
     scanline: Scanline,
 }
 
@@ -25,20 +32,54 @@ const EMPTY: &[Point; 3] = &[Point::zero(); 3];
 
 impl<'a> ScanlineIntersections<'a> {
     /// New
-    pub fn new(points: &'a [Point], width: u32, scanline_y: i32) -> Self {
+    pub fn new(
+        points: &'a [Point],
+        width: u32,
+        line_cap: LineCap,
+        line_join: LineJoin,
+        scanline_y: i32,
+    ) -> Self {
         let next_start_join = match points {
             [first, second, ..] => {
-                Some(LineJoin::start(*first, *second, width, StrokeOffset::None))
+                let first = match line_cap {
+                    LineCap::Square => *first - Line::new(*first, *second).direction(width / 2),
+                    _ => *first,
+                };
+
+                Some(SegmentJoin::start(
+                    first,
+                    *second,
+                    width,
+                    StrokeOffset::None,
+                ))
             }
             _ => None,
         };
 
+        // Round caps are drawn by adding a disc at both ends. The disc at the start is used as
+        // the initial value of the accumulator and the disc at the end is added after the last
+        // segment.
+        let (scanline, end_cap) = match (line_cap, points) {
+            (LineCap::Round, [first, .., last]) => (
+                Scanline::new(
+                    scanline_y,
+                    disc_run(*first, width, scanline_y).unwrap_or(0..0),
+                ),
+                Some(*last),
+            ),
+            _ => (Scanline::new_empty(scanline_y), None),
+        };
+
         Self {
             next_start_join,
             width,
+            line_cap,
+            line_join,
+            end_cap,
+            round_join: None,
             points,
             remaining_points: points,
-            scanline: Scanline::new_empty(scanline_y),
+            scanline,
         }
     }
 
@@ -47,6 +88,10 @@ impl<'a> ScanlineIntersections<'a> {
         Self {
             next_start_join: None,
             width: 0,
+            line_cap: LineCap::Butt,
+            line_join: LineJoin::const_default(),
+            end_cap: None,
+            round_join: None,
             points: EMPTY,
             remaining_points: EMPTY,
             scanline: Scanline::new_empty(0),
@@ -55,17 +100,44 @@ impl<'a> ScanlineIntersections<'a> {
 
     /// Reset scanline iterator with a new scanline.
     pub(in crate::primitives) fn reset_with_new_scanline(&mut self, scanline_y: i32) {
-        *self = Self::new(self.points, self.width, scanline_y);
+        *self = Self::new(
+            self.points,
+            self.width,
+            self.line_cap,
+            self.line_join,
+            scanline_y,
+        );
+    }
+
+    /// Returns an iterator over all segments of the polyline.
+    ///
+    /// Square caps are included in the first and last segment.
+    pub(in crate::primitives::polyline) fn segments(
+        mut self,
+    ) -> impl Iterator<Item = ThickSegment> + 'a {
+        core::iter::from_fn(move || self.next_segment())
     }
 
     fn next_segment(&mut self) -> Option<ThickSegment> {
         let start_join = self.next_start_join?;
 
         let end_join = match self.remaining_points {
-            [start, mid, end, ..] => {
-                LineJoin::from_points(*start, *mid, *end, self.width, StrokeOffset::None)
+            [start, mid, end, ..] => SegmentJoin::from_points(
+                *start,
+                *mid,
+                *end,
+                self.width,
+                StrokeOffset::None,
+                self.line_join,
+            ),
+            [start, end] => {
+                let end = match self.line_cap {
+                    LineCap::Square => *end + Line::new(*start, *end).direction(self.width / 2),
+                    _ => *end,
+                };
+
+                SegmentJoin::end(*start, end, self.width, StrokeOffset::None)
             }
-            [start, end] => LineJoin::end(*start, *end, self.width, StrokeOffset::None),
             _ => return None,
         };
 
@@ -107,8 +179,25 @@ impl Iterator for ScanlineIntersections<'_> {
     type Item = Scanline;
 
     fn next(&mut self) -> Option<Self::Item> {
-        while let Some(segment) = self.next_segment() {
-            let next_scanline = segment.intersection(self.scanline.y);
+        let y = self.scanline.y;
+
+        loop {
+            // Round joins are drawn after the segment that ends at the join.
+            let next_scanline = if let Some(round_join) = self.round_join.take() {
+                Scanline::new(y, round_join.run(y).unwrap_or(0..0))
+            } else if let Some(segment) = self.next_segment() {
+                self.round_join = segment.end_join().round_join();
+
+                segment.intersection(y)
+            } else if let Some(end_cap) = self.end_cap.take() {
+                Scanline::new(y, disc_run(end_cap, self.width, y).unwrap_or(0..0))
+            } else {
+                break;
+            };
+
+            if next_scanline.is_empty() {
+                continue;
+            }
 
             if !self.scanline.try_extend(&next_scanline) {
                 let ret = self.scanline.clone();
@@ -35,8 +35,13 @@ impl<'a> ScanlineIterator<'a> {
         let mut rows = untranslated_bounding_box(primitive, style).rows();
 
         if let Some(scanline_y) = rows.next() {
-            let intersections =
-                ScanlineIntersections::new(primitive.vertices, style.stroke_width, scanline_y);
+            let intersections = ScanlineIntersections::new(
+                primitive.vertices,
+                style.stroke_width,
+                style.effective_line_cap(),
+                style.effective_line_join(),
+                scanline_y,
+            );
 
             Self {
                 rows,
@@ -3,10 +3,16 @@ use crate::{
     geometry::{Dimensions, Point, Size},
     pixelcolor::PixelColor,
     primitives::{
-        common::{Scanline, StrokeOffset, ThickSegmentIter},
-        polyline::{self, scanline_iterator::ScanlineIterator, Polyline},
+        common::{
+            disc_bounding_box, Dashes, Dots, Scanline, SegmentsArea, SegmentsOutline, StrokeOffset,
+            StrokePattern,
+        },
+        polyline::{
+            self, scanline_intersections::ScanlineIntersections,
+            scanline_iterator::ScanlineIterator, Polyline,
+        },
         styled::{StyledDimensions, StyledDrawable, StyledPixels},
-        PointsIter, PrimitiveStyle, Rectangle,
+        LineCap, PointsIter, PrimitiveStyle, Rectangle,
     },
     transform::Transform,
     Pixel,
@@ -18,18 +24,43 @@ pub(in crate::primitives::polyline) fn untranslated_bounding_box<C: PixelColor>(
     style: &PrimitiveStyle<C>,
 ) -> Rectangle {
     if style.effective_stroke_color().is_some() && primitive.vertices.len() > 1 {
-        let (min, max) =
-            ThickSegmentIter::new(primitive.vertices, style.stroke_width, StrokeOffset::None).fold(
-                (Point::new_equal(i32::MAX), Point::new_equal(i32::MIN)),
-                |(min, max), segment| {
-                    let bb = segment.edges_bounding_box();
-
-                    (
-                        min.component_min(bb.top_left),
-                        max.component_max(bb.bottom_right().unwrap_or(bb.top_left)),
-                    )
-                },
-            );
+        let vertices = primitive.vertices;
+        let line_cap = style.effective_line_cap();
+
+        let segments = ScanlineIntersections::new(
+            vertices,
+            style.stroke_width,
+            line_cap,
+            style.effective_line_join(),
+            0,
+        )
+        .segments()
+        .flat_map(|segment| {
+            let join = segment
+                .end_join()
+                .round_join()
+                .map(|join| join.bounding_box());
+
+            core::iter::once(segment.edges_bounding_box()).chain(join)
+        });
+
+        let round_caps = (line_cap == LineCap::Round)
+            .then(|| {
+                [vertices[0], vertices[vertices.len() - 1]]
+                    .map(|vertex| disc_bounding_box(vertex, style.stroke_width))
+            })
+            .into_iter()
+            .flatten();
+
+        let (min, max) = segments.chain(round_caps).fold(
+            (Point::new_equal(i32::MAX), Point::new_equal(i32::MIN)),
+            |(min, max), bb| {
+                (
+                    min.component_min(bb.top_left),
+                    max.component_max(bb.bottom_right().unwrap_or(bb.top_left)),
+                )
+            },
+        );
 
         Rectangle::with_corners(min, max)
     } else {
@@ -37,6 +68,30 @@ pub(in crate::primitives::polyline) fn untranslated_bounding_box<C: PixelColor>(
     }
 }
 
+/// Dashes or dots of a polyline.
+type PolylinePattern<'a> = StrokePattern<SegmentsOutline<&'a [Point]>, SegmentsArea<&'a [Point]>>;
+
+/// Returns the dashes or dots of the polyline, if the style uses a non solid stroke.
+fn stroke_pattern<'a, C: PixelColor>(
+    primitive: &Polyline<'a>,
+    translate: Point,
+    style: &PrimitiveStyle<C>,
+) -> Option<PolylinePattern<'a>> {
+    let outline = SegmentsOutline::open(primitive.vertices, translate);
+
+    if let Some(dot_size) = style.dot_size() {
+        // The dots are placed on the center line of the solid stroke.
+        let outline = outline.stroke_center(style.stroke_width, StrokeOffset::None);
+        let area = SegmentsArea::open(primitive.vertices, translate, style.stroke_width);
+
+        return Some(StrokePattern::Dots(Dots::new(outline, area, dot_size)));
+    }
+
+    style
+        .dash_pattern()
+        .map(|pattern| StrokePattern::Dashes(Dashes::new(outline, pattern)))
+}
+
 fn draw_thick<D>(
     polyline: &Polyline,
     style: &PrimitiveStyle<D::Color>,
@@ -46,6 +101,16 @@ fn draw_thick<D>(
 where
     D: DrawTarget,
 {
+    if let Some(pattern) = stroke_pattern(polyline, Point::zero(), style) {
+        for line in ScanlineIterator::new(polyline, style) {
+            line.draw_runs(target, |point| {
+                pattern.contains(point).then_some(stroke_color)
+            })?;
+        }
+
+        return Ok(());
+    }
+
     for line in ScanlineIterator::new(polyline, style) {
         let rect = line.to_rectangle();
 
@@ -68,12 +133,40 @@ enum StyledIter<'a> {
     },
 }
 
+impl Iterator for StyledIter<'_> {
+    type Item = Point;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            StyledIter::Thin(it) => it.next(),
+            StyledIter::Thick {
+                scanline_iter,
+                line_iter,
+                translate,
+            } => {
+                // We've got a line to iterate over, so get it's next pixel.
+                if let Some(p) = line_iter.next() {
+                    Some(p)
+                }
+                // Finished this line. Get the next one from the scanline iterator.
+                else {
+                    *line_iter = scanline_iter.next()?;
+
+                    line_iter.next()
+                }
+                .map(|p| p + *translate)
+            }
+        }
+    }
+}
+
 /// Pixel iterator for each pixel in the line
 #[derive(Clone, Debug)]
 #[cfg_attr(feature = "defmt", derive(::defmt::Format))]
 pub struct StyledPixelsIterator<'a, C> {
     stroke_color: Option<C>,
     line_iter: StyledIter<'a>,
+    pattern: Option<PolylinePattern<'a>>,
 }
 
 impl<'a, C: PixelColor> StyledPixelsIterator<'a, C> {
@@ -96,6 +189,7 @@ impl<'a, C: PixelColor> StyledPixelsIterator<'a, C> {
         StyledPixelsIterator {
             stroke_color: style.effective_stroke_color(),
             line_iter,
+            pattern: stroke_pattern(primitive, primitive.translate, style),
         }
     }
 }
@@ -106,28 +200,11 @@ impl<C: PixelColor> Iterator for StyledPixelsIterator<'_, C> {
     fn next(&mut self) -> Option<Self::Item> {
         // Return none if stroke color is none
         let stroke_color = self.stroke_color?;
+        let pattern = &self.pattern;
 
-        match self.line_iter {
-            StyledIter::Thin(ref mut it) => it.next(),
-            StyledIter::Thick {
-                ref mut scanline_iter,
-                ref mut line_iter,
-                translate,
-            } => {
-                // We've got a line to iterate over, so get it's next pixel.
-                if let Some(p) = line_iter.next() {
-                    Some(p)
-                }
-                // Finished this line. Get the next one from the scanline iterator.
-                else {
-                    *line_iter = scanline_iter.next()?;
-
-                    line_iter.next()
-                }
-                .map(|p| p + translate)
-            }
-        }
-        .map(|point| Pixel(point, stroke_color))
+        self.line_iter
+            .find(|point| pattern.iter().all(|pattern| pattern.contains(*point)))
+            .map(|point| Pixel(point, stroke_color))
     }
 }
 
@@ -154,7 +231,7 @@ impl<C: PixelColor> StyledDrawable<PrimitiveStyle<C>> for Polyline<'_> {
         if let Some(stroke_color) = style.stroke_color {
             match style.stroke_width {
                 0 => Ok(()),
-                1 => target.draw_iter(self.points().map(|point| Pixel(point, stroke_color))),
+                1 => target.draw_iter(StyledPixelsIterator::new(self, style)),
                 _ => {
                     if self.translate != Point::zero() {
                         draw_thick(
@@ -188,7 +265,10 @@ mod tests {
         iterator::PixelIteratorExt,
         mock_display::MockDisplay,
         pixelcolor::{BinaryColor, Rgb565, RgbColor},
-        primitives::{Primitive, PrimitiveStyle, PrimitiveStyleBuilder, StrokeAlignment},
+        primitives::{
+            common::check_dotted_stroke, DashPattern, Line, LineJoin, Primitive, PrimitiveStyle,
+            PrimitiveStyleBuilder, StrokeAlignment, StrokeStyle,
+        },
         Drawable,
     };
 
@@ -630,4 +710,242 @@ mod tests {
         // Check no pixels are drawn outside bounding box
         assert_eq!(display.affected_area(), bb);
     }
+
+    #[test]
+    fn dashed() {
+        let points = [Point::new(0, 0), Point::new(9, 0), Point::new(9, 5)];
+
+        let mut display = MockDisplay::new();
+        Polyline::new(&points)
+            .into_styled(
+                PrimitiveStyleBuilder::new()
+                    .stroke_color(BinaryColor::On)
+                    .stroke_width(1)
+                    .stroke_style(StrokeStyle::Dashed(DashPattern::new(3, 2)))
+                    .build(),
+            )
+            .draw(&mut display)
+            .unwrap();
+
+        display.assert_pattern(&[
+            "###  ###  ", //
+            "         #", //
+            "         #", //
+            "         #", //
+        ]);
+    }
+
+    #[test]
+    fn dashed_thick_draw_matches_pixels() {
+        for stroke_width in 2..8 {
+            let style = PrimitiveStyleBuilder::new()
+                .stroke_color(BinaryColor::On)
+                .stroke_width(stroke_width)
+                .stroke_style(StrokeStyle::Dashed(DashPattern::new(5, 3).with_offset(2)))
+                .build();
+
+            let polyline = Polyline::new(&PATTERN).translate(Point::new(3, 4));
+
+            let mut expected = MockDisplay::new();
+            polyline
+                .into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, stroke_width))
+                .draw(&mut expected)
+                .unwrap();
+
+            let mut drawn = MockDisplay::new();
+            polyline.into_styled(style).draw(&mut drawn).unwrap();
+
+            let mut pixels = MockDisplay::new();
+            polyline
+                .into_styled(style)
+                .pixels()
+                .draw(&mut pixels)
+                .unwrap();
+
+            drawn.assert_eq(&pixels);
+            assert!(drawn.affected_area().size.width > 0);
+            assert!(expected
+                .affected_area()
+                .contains(drawn.affected_area().top_left));
+        }
+    }
+
+    #[test]
+    fn dotted_stroke() {
+        check_dotted_stroke(Polyline::new(&PATTERN).translate(Point::new(-2, 3)));
+    }
+
+    #[test]
+    fn line_caps_match_lines() {
+        let vertices = [Point::new(10, 10), Point::new(30, 10)];
+
+        for line_cap in [LineCap::Butt, LineCap::Square, LineCap::Round] {
+            for stroke_width in 2..8 {
+                let style = PrimitiveStyleBuilder::new()
+                    .stroke_color(BinaryColor::On)
+                    .stroke_width(stroke_width)
+                    .line_cap(line_cap)
+                    .build();
+
+                let mut expected = MockDisplay::new();
+                Line::new(vertices[0], vertices[1])
+                    .into_styled(style)
+                    .draw(&mut expected)
+                    .unwrap();
+
+                let mut display = MockDisplay::new();
+                Polyline::new(&vertices)
+                    .into_styled(style)
+                    .draw(&mut display)
+                    .unwrap();
+
+                display.assert_eq_with_message(&expected, |f| {
+                    write!(f, "{:?}, {} px", line_cap, stroke_width)
+                });
+            }
+        }
+    }
+
+    #[test]
+    fn line_caps() {
+        for line_cap in [LineCap::Square, LineCap::Round] {
+            for stroke_width in 2..10 {
+                let style = PrimitiveStyleBuilder::new()
+                    .stroke_color(BinaryColor::On)
+                    .stroke_width(stroke_width)
+                    .line_cap(line_cap)
+                    .build();
+
+                let styled = Polyline::new(&PATTERN)
+                    .translate(Point::new(10, 10))
+                    .into_styled(style);
+
+                let mut display = MockDisplay::new();
+                styled.draw(&mut display).unwrap();
+
+                let mut pixels = MockDisplay::new();
+                styled.pixels().draw(&mut pixels).unwrap();
+
+                display.assert_eq(&pixels);
+                assert_eq!(
+                    display.affected_area(),
+                    styled.bounding_box(),
+                    "{:?}, {} px",
+                    line_cap,
+                    stroke_width
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn line_joins() {
+        let vertices = [Point::new(1, 12), Point::new(16, 6), Point::new(2, 2)];
+
+        let draw = |line_join| {
+            let style = PrimitiveStyleBuilder::new()
+                .stroke_color(BinaryColor::On)
+                .stroke_width(4)
+                .line_join(line_join)
+                .build();
+
+            let mut display = MockDisplay::new();
+            Polyline::new(&vertices)
+                .into_styled(style)
+                .draw(&mut display)
+                .unwrap();
+
+            display
+        };
+
+        draw(LineJoin::default()).assert_pattern(&[
+            "                    ",
+            "  ##                ",
+            "  ######            ",
+            " ##########         ",
+            " #############      ",
+            "    ##############  ",
+            "        ############",
+            "      ############  ",
+            "    ############    ",
+            "  ###########       ",
+            "###########         ",
+            "########            ",
+            " ####               ",
+            " ##                 ",
+        ]);
+
+        draw(LineJoin::Bevel).assert_pattern(&[
+            "                 ",
+            "  ##             ",
+            "  ######         ",
+            " ##########      ",
+            " ##############  ",
+            "    #############",
+            "        #########",
+            "      ###########",
+            "    ###########  ",
+            "  ###########    ",
+            "##########       ",
+            "########         ",
+            " ####            ",
+            " ##              ",
+        ]);
+
+        draw(LineJoin::Round).assert_pattern(&[
+            "                  ",
+            "  ##              ",
+            "  ######          ",
+            " ##########       ",
+            " ##############   ",
+            "    ##############",
+            "        ##########",
+            "      ############",
+            "    ###########   ",
+            "  ###########     ",
+            "##########        ",
+            "########          ",
+            " ####             ",
+            " ##               ",
+        ]);
+
+        // Miters that exceed the miter limit are drawn as bevel joins.
+        draw(LineJoin::Miter { limit: 1 }).assert_eq(&draw(LineJoin::Bevel));
+    }
+
+    #[test]
+    fn line_joins_bounding_box() {
+        for line_join in [
+            LineJoin::Miter { limit: 1 },
+            LineJoin::Bevel,
+            LineJoin::Round,
+        ] {
+            for stroke_width in 2..10 {
+                let style = PrimitiveStyleBuilder::new()
+                    .stroke_color(BinaryColor::On)
+                    .stroke_width(stroke_width)
+                    .line_join(line_join)
+                    .build();
+
+                let styled = Polyline::new(&PATTERN)
+                    .translate(Point::new(10, 10))
+                    .into_styled(style);
+
+                let mut display = MockDisplay::new();
+                styled.draw(&mut display).unwrap();
+
+                let mut pixels = MockDisplay::new();
+                styled.pixels().draw(&mut pixels).unwrap();
+
+                display.assert_eq(&pixels);
+                assert_eq!(
+                    display.affected_area(),
+                    styled.bounding_box(),
+                    "{:?}, {} px",
+                    line_join,
+                    stroke_width
+                );
+            }
+        }
+    }
 }
@@ -47,9 +47,38 @@ where
     ///
     /// The stroke style sets the border style (default is [`StrokeStyle::Solid`]).
     ///
-    /// [`StrokeStyle::Dotted`] is only implemented for the [`Rectangle`](crate::primitives::Rectangle)
-    /// primitive, the default will be used for all other primitives.
+    /// [`StrokeStyle::Dashed`] and [`StrokeStyle::Dotted`] are supported by all built-in
+    /// primitives.
     pub stroke_style: StrokeStyle,
+
+    /// Line cap.
+    ///
+    /// The line cap sets the shape of the start and end of the stroke (default is
+    /// [`LineCap::Butt`]).
+    ///
+    /// This property only applies to [`Line`]s, [`Polyline`]s and [`Arc`]s and is ignored for
+    /// other primitives. Dashed and dotted strokes always use butt caps.
+    ///
+    /// [`Line`]: crate::primitives::Line
+    /// [`Polyline`]: crate::primitives::Polyline
+    /// [`Arc`]: crate::primitives::Arc
+    pub line_cap: LineCap,
+
+    /// Line join.
+    ///
+    /// The line join sets the shape of the corners between two segments of the stroke (default
+    /// is [`LineJoin::Miter`] with a miter limit of `4`).
+    ///
+    /// This property only applies to [`Polyline`]s, [`Polygon`]s, [`Triangle`]s, [`Rectangle`]s
+    /// and the corners of [`RoundedRectangle`]s without a corner radius. Dashed and dotted strokes
+    /// always use the default line join.
+    ///
+    /// [`Polyline`]: crate::primitives::Polyline
+    /// [`Polygon`]: crate::primitives::Polygon
+    /// [`Triangle`]: crate::primitives::Triangle
+    /// [`Rectangle`]: crate::primitives::Rectangle
+    /// [`RoundedRectangle`]: crate::primitives::RoundedRectangle
+    pub line_join: LineJoin,
 }
 
 impl<C> PrimitiveStyle<C>
@@ -123,20 +152,92 @@ where
         primitive.offset(offset)
     }
 
-    /// Returns the fill area.
-    pub(in crate::primitives) fn fill_area<P: OffsetOutline>(&self, primitive: &P) -> P {
+    /// Returns the area enclosed by the inner edge of the stroke.
+    ///
+    /// Unlike the fill area, the returned area doesn't depend on the stroke style.
+    pub(in crate::primitives) fn stroke_inner_area<P: OffsetOutline>(&self, primitive: &P) -> P {
         // saturate offset at i32::min_value() if stroke width is to large
-        let offset = if self.stroke_style == StrokeStyle::Solid {
-            -self.inside_stroke_width().saturating_as::<i32>()
-        } else {
-            // do not shrink the fill area for non solid borders, because the entire fill
-            // area is visible through the gaps in the border
-            0
-        };
+        let offset = -self.inside_stroke_width().saturating_as::<i32>();
+
+        primitive.offset(offset)
+    }
+
+    /// Returns the outline that runs through the center of the stroke.
+    pub(in crate::primitives) fn stroke_center_outline<P: OffsetOutline>(
+        &self,
+        primitive: &P,
+    ) -> P {
+        let offset = (self.outside_stroke_width().saturating_as::<i32>()
+            - self.inside_stroke_width().saturating_as::<i32>())
+            / 2;
 
         primitive.offset(offset)
     }
 
+    /// Returns the fill area.
+    ///
+    /// The gaps in dashed and dotted strokes are filled separately.
+    pub(in crate::primitives) fn fill_area<P: OffsetOutline>(&self, primitive: &P) -> P {
+        self.stroke_inner_area(primitive)
+    }
+
+    /// Returns the dash pattern if the stroke is dashed and visible.
+    pub(in crate::primitives) fn dash_pattern(&self) -> Option<DashPattern> {
+        match self.stroke_style {
+            StrokeStyle::Dashed(pattern) if self.effective_stroke_color().is_some() => {
+                Some(pattern)
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the dot size if the stroke is dotted and visible.
+    pub(in crate::primitives) fn dot_size(&self) -> Option<u32> {
+        match self.stroke_style {
+            StrokeStyle::Dotted if self.effective_stroke_color().is_some() => {
+                Some(self.stroke_width)
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the line cap if the stroke is solid and visible.
+    ///
+    /// Dashed and dotted strokes always use butt caps.
+    pub(in crate::primitives) fn effective_line_cap(&self) -> LineCap {
+        match self.stroke_style {
+            StrokeStyle::Solid if self.effective_stroke_color().is_some() => self.line_cap,
+            _ => LineCap::Butt,
+        }
+    }
+
+    /// Returns the line join if the stroke is solid.
+    ///
+    /// Dashed and dotted strokes always use the default line join.
+    pub(in crate::primitives) fn effective_line_join(&self) -> LineJoin {
+        match self.stroke_style {
+            StrokeStyle::Solid => self.line_join,
+            _ => LineJoin::const_default(),
+        }
+    }
+
+    /// Returns a copy of this style with different fill and stroke colors.
+    pub(in crate::primitives) const fn with_colors<T: PixelColor>(
+        &self,
+        fill_color: Option<T>,
+        stroke_color: Option<T>,
+    ) -> PrimitiveStyle<T> {
+        PrimitiveStyle {
+            fill_color,
+            stroke_color,
+            stroke_width: self.stroke_width,
+            stroke_alignment: self.stroke_alignment,
+            stroke_style: self.stroke_style,
+            line_cap: self.line_cap,
+            line_join: self.line_join,
+        }
+    }
+
     /// A helper function to allow `const` default.
     // MSRV: Move into `Default` impl when we have consts in traits
     const fn const_default() -> Self {
@@ -146,6 +247,8 @@ where
             stroke_width: 0,
             stroke_alignment: StrokeAlignment::Center,
             stroke_style: StrokeStyle::const_default(),
+            line_cap: LineCap::const_default(),
+            line_join: LineJoin::const_default(),
         }
     }
 }
@@ -279,6 +382,26 @@ where
         self
     }
 
+    /// Sets the line cap.
+    ///
+    /// This feature is not supported for all primitives, see [PrimitiveStyle::line_cap]
+    /// for the complete list.
+    pub const fn line_cap(mut self, line_cap: LineCap) -> Self {
+        self.style.line_cap = line_cap;
+
+        self
+    }
+
+    /// Sets the line join.
+    ///
+    /// This feature is not supported for all primitives, see [PrimitiveStyle::line_join]
+    /// for the complete list.
+    pub const fn line_join(mut self, line_join: LineJoin) -> Self {
+        self.style.line_join = line_join;
+
+        self
+    }
+
     /// Builds the primitive style.
     pub const fn build(self) -> PrimitiveStyle<C> {
         self.style
@@ -321,7 +444,16 @@ pub enum StrokeStyle {
     #[default]
     Solid,
     /// Dotted.
+    ///
+    /// The stroke is drawn as a series of dots with a diameter equal to the stroke width. The
+    /// dots are evenly spaced along the outline of the primitive, with gaps that are roughly the
+    /// same size as the dots. Open shapes always start and end with a dot.
     Dotted,
+    /// Dashed.
+    ///
+    /// The stroke is split into dashes and gaps along the outline of the primitive. The ends of
+    /// the dashes are perpendicular to the outline.
+    Dashed(DashPattern),
 }
 
 impl StrokeStyle {
@@ -330,6 +462,146 @@ impl StrokeStyle {
     }
 }
 
+/// Line cap.
+///
+/// The line cap sets the shape of the ends of open primitives.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub enum LineCap {
+    /// Butt.
+    ///
+    /// The stroke ends exactly at the end points of the primitive.
+    #[default]
+    Butt,
+    /// Square.
+    ///
+    /// The stroke is extended past the end points by half the stroke width.
+    Square,
+    /// Round.
+    ///
+    /// The stroke ends with a half circle with a diameter equal to the stroke width.
+    Round,
+}
+
+impl LineCap {
+    const fn const_default() -> Self {
+        Self::Butt
+    }
+}
+
+/// Line join.
+///
+/// The line join sets the shape of the outer corner between two segments of a stroke.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub enum LineJoin {
+    /// Miter.
+    ///
+    /// The outer edges of the segments are extended until they meet in a sharp point. If the
+    /// ratio between the length of the miter and the stroke width exceeds `limit` the corner is
+    /// drawn as a bevel instead. This prevents long spikes at acute angles.
+    Miter {
+        /// Miter limit.
+        limit: u32,
+    },
+    /// Bevel.
+    ///
+    /// The outer corner is cut off by a straight line between the outer edges of the segments.
+    Bevel,
+    /// Round.
+    ///
+    /// The outer corner is rounded by a circular arc with a diameter equal to the stroke width.
+    Round,
+}
+
+impl LineJoin {
+    pub(in crate::primitives) const fn const_default() -> Self {
+        Self::Miter { limit: 4 }
+    }
+}
+
+impl Default for LineJoin {
+    fn default() -> Self {
+        Self::const_default()
+    }
+}
+
+/// Dash pattern.
+///
+/// The dash pattern defines the lengths of the dashes and gaps of a
+/// [`StrokeStyle::Dashed`] stroke. The pattern starts at the beginning of the outline of a
+/// primitive and is repeated until the end of the outline is reached. The starting point
+/// of the pattern can be moved along the outline by setting an offset.
+///
+/// Open primitives, like lines and arcs, start their outline at the start point. The outlines
+/// of closed primitives run clockwise:
+///
+/// - circles and ellipses start on the right side (at 0°),
+/// - rectangles start at the top left corner and rounded rectangles at the left end of the top
+///   edge,
+/// - triangles start at the first vertex,
+/// - sectors start at the center point.
+///
+/// # Examples
+///
+/// ```rust
+/// use embedded_graphics::{
+///     pixelcolor::Rgb565,
+///     prelude::*,
+///     primitives::{Circle, DashPattern, PrimitiveStyleBuilder, StrokeStyle},
+/// };
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display = MockDisplay::default();
+///
+/// // 6px long dashes, separated by 3px gaps
+/// let style = PrimitiveStyleBuilder::new()
+///     .stroke_color(Rgb565::RED)
+///     .stroke_width(3)
+///     .stroke_style(StrokeStyle::Dashed(DashPattern::new(6, 3)))
+///     .build();
+///
+/// Circle::new(Point::new(10, 10), 40)
+///     .into_styled(style)
+///     .draw(&mut display)?;
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub struct DashPattern {
+    /// Length of the dashes in pixels.
+    pub dash_length: u32,
+
+    /// Length of the gaps in pixels.
+    pub gap_length: u32,
+
+    /// Offset in pixels.
+    ///
+    /// The offset moves the pattern backwards along the outline. An offset of `0` starts the
+    /// outline with a dash and an offset equal to the dash length starts it with a gap.
+    pub offset: u32,
+}
+
+impl DashPattern {
+    /// Creates a new dash pattern.
+    pub const fn new(dash_length: u32, gap_length: u32) -> Self {
+        Self {
+            dash_length,
+            gap_length,
+            offset: 0,
+        }
+    }
+
+    /// Returns a copy of this dash pattern with the given offset.
+    pub const fn with_offset(self, offset: u32) -> Self {
+        Self { offset, ..self }
+    }
+
+    /// Returns the length of one dash and one gap.
+    pub(in crate::primitives) const fn period(&self) -> u32 {
+        self.dash_length.saturating_add(self.gap_length)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -345,6 +617,8 @@ mod tests {
                 stroke_width: 0,
                 stroke_alignment: StrokeAlignment::Center,
                 stroke_style: StrokeStyle::Solid,
+                line_cap: LineCap::Butt,
+                line_join: LineJoin::Miter { limit: 4 },
             }
         );
 
@@ -472,6 +746,44 @@ mod tests {
         );
     }
 
+    #[test]
+    fn effective_line_cap() {
+        let style = PrimitiveStyleBuilder::new()
+            .stroke_color(BinaryColor::On)
+            .stroke_width(3)
+            .line_cap(LineCap::Round)
+            .build();
+        assert_eq!(style.effective_line_cap(), LineCap::Round);
+
+        let dotted = PrimitiveStyle {
+            stroke_style: StrokeStyle::Dotted,
+            ..style
+        };
+        assert_eq!(dotted.effective_line_cap(), LineCap::Butt);
+
+        let no_stroke = PrimitiveStyle {
+            stroke_width: 0,
+            ..style
+        };
+        assert_eq!(no_stroke.effective_line_cap(), LineCap::Butt);
+    }
+
+    #[test]
+    fn effective_line_join() {
+        let style = PrimitiveStyleBuilder::new()
+            .stroke_color(BinaryColor::On)
+            .stroke_width(3)
+            .line_join(LineJoin::Bevel)
+            .build();
+        assert_eq!(style.effective_line_join(), LineJoin::Bevel);
+
+        let dotted = PrimitiveStyle {
+            stroke_style: StrokeStyle::Dotted,
+            ..style
+        };
+        assert_eq!(dotted.effective_line_join(), LineJoin::default());
+    }
+
     #[test]
     fn stroke_width_max_value() {
         assert_eq!(
@@ -1,12 +1,14 @@
 use crate::{
     draw_target::DrawTarget,
-    geometry::{Dimensions, Point, Real, Size},
+    geometry::{Dimensions, Point, Size},
     pixelcolor::PixelColor,
     primitives::{
-        primitive_style::StrokeStyle,
+        common::{
+            rectangle_outline, Band, CornerJoins, PatternedStroke, Scanline, SegmentsOutline,
+        },
         rectangle::{Points, Rectangle},
         styled::{StyledDimensions, StyledDrawable, StyledPixels},
-        Circle, PointsIter, PrimitiveStyle,
+        PointsIter, PrimitiveStyle,
     },
     transform::Transform,
     Pixel,
@@ -23,6 +25,9 @@ pub struct StyledPixelsIterator<C> {
 
     fill_area: Rectangle,
     fill_color: Option<C>,
+
+    pattern: Option<PatternedStroke<SegmentsOutline<[Point; 4]>, Band<Rectangle>, Rectangle>>,
+    joins: Option<CornerJoins>,
 }
 
 impl<C: PixelColor> StyledPixelsIterator<C> {
@@ -38,6 +43,8 @@ impl<C: PixelColor> StyledPixelsIterator<C> {
             fill_area: style.fill_area(primitive),
             stroke_color: style.stroke_color,
             fill_color: style.fill_color,
+            pattern: patterned_stroke(primitive, style),
+            joins: corner_joins(primitive, style),
         }
     }
 }
@@ -49,6 +56,12 @@ impl<C: PixelColor> Iterator for StyledPixelsIterator<C> {
         for point in &mut self.iter {
             let color = if self.fill_area.contains(point) {
                 self.fill_color
+            } else if self.joins.is_some_and(|joins| !joins.contains(point)) {
+                // Skip points outside the corners of the stroke.
+                continue;
+            } else if let Some(pattern) = &self.pattern {
+                self.stroke_color
+                    .and_then(|stroke_color| pattern.color(point, stroke_color, self.fill_color))
             } else {
                 self.stroke_color
             };
@@ -62,6 +75,42 @@ impl<C: PixelColor> Iterator for StyledPixelsIterator<C> {
     }
 }
 
+/// Returns the line joins at the corners of the stroke.
+fn corner_joins<C: PixelColor>(
+    primitive: &Rectangle,
+    style: &PrimitiveStyle<C>,
+) -> Option<CornerJoins> {
+    style.effective_stroke_color()?;
+
+    CornerJoins::new(
+        &style.stroke_area(primitive),
+        style.stroke_width,
+        style.effective_line_join(),
+        [true; 4],
+    )
+}
+
+/// Returns the dashed or dotted stroke if the style uses a non solid stroke.
+fn patterned_stroke<C: PixelColor>(
+    primitive: &Rectangle,
+    style: &PrimitiveStyle<C>,
+) -> Option<PatternedStroke<SegmentsOutline<[Point; 4]>, Band<Rectangle>, Rectangle>> {
+    let outline = rectangle_outline(&style.stroke_center_outline(primitive));
+
+    if let Some(dot_size) = style.dot_size() {
+        let area = Band::new(
+            style.stroke_area(primitive),
+            style.stroke_inner_area(primitive),
+        );
+
+        return Some(PatternedStroke::dotted(outline, area, dot_size, *primitive));
+    }
+
+    style
+        .dash_pattern()
+        .map(|pattern| PatternedStroke::dashed(outline, pattern, *primitive))
+}
+
 impl<C: PixelColor> StyledPixels<PrimitiveStyle<C>> for Rectangle {
     type Iter = StyledPixelsIterator<C>;
 
@@ -70,108 +119,37 @@ impl<C: PixelColor> StyledPixels<PrimitiveStyle<C>> for Rectangle {
     }
 }
 
-fn dot_positions_with_dotted_corners(
-    length: u32,
-    dot_size: u32,
-    include_corners: bool,
-) -> impl Iterator<Item = i32> {
-    let nb_dots = (length + dot_size) / (2 * dot_size);
-    let dot_offset = Real::from(length) / Real::from(nb_dots);
-
-    let idx_iter = if include_corners {
-        0..=nb_dots
-    } else {
-        1..=nb_dots - 1
-    };
-
-    idx_iter.map(move |idx| (dot_offset * Real::from(idx)).round().into())
-}
-
-/// Draw a dotted rectangular border with dots in the 4 corners.
+/// Draw a dashed or dotted rectangular border.
 ///
-/// The gaps between dots ideally have the same size as the dots.
-/// The gaps can be smaller or larger than ideal.
-/// Opposite borders are identical (horizontal and vertical sides are independent).
-fn draw_dotted_rectangle_border_with_dotted_corners<D>(
-    top_left: &Point,
-    border_size: &Size,
-    dot_size: u32,
-    style: &PrimitiveStyle<D::Color>,
+/// Gaps between the dashes or dots inside the rectangle are filled with the fill color.
+fn draw_patterned_rectangle_border<D>(
+    stroke_area: &Rectangle,
+    fill_area: &Rectangle,
+    pattern: &PatternedStroke<SegmentsOutline<[Point; 4]>, Band<Rectangle>, Rectangle>,
+    stroke_color: D::Color,
+    fill_color: Option<D::Color>,
     target: &mut D,
 ) -> Result<(), D::Error>
 where
     D: DrawTarget,
 {
-    let top_left_dot = Circle::new(*top_left, dot_size);
-
-    // Draw horizontal sides (including corner dots)
-    for x in dot_positions_with_dotted_corners(border_size.width, dot_size, true) {
-        top_left_dot
-            .translate(Point::new(x, 0))
-            .draw_styled(style, target)?;
-        top_left_dot
-            .translate(Point::new(x, 0) + border_size.y_axis())
-            .draw_styled(style, target)?;
-    }
-
-    // Draw vertical sides (without corner dots)
-    for y in dot_positions_with_dotted_corners(border_size.height, dot_size, false) {
-        top_left_dot
-            .translate(Point::new(0, y))
-            .draw_styled(style, target)?;
-        top_left_dot
-            .translate(Point::new(0, y) + border_size.x_axis())
-            .draw_styled(style, target)?;
-    }
-
-    Ok(())
-}
-
-fn dot_positions_in_clockwise_order(length: u32, dot_size: u32) -> impl Iterator<Item = i32> {
-    let nb_dots = length / (2 * dot_size);
-    let dot_offset = if nb_dots != 0 {
-        Real::from(length) / Real::from(nb_dots)
-    } else {
-        Real::from(0) // this value won't be used
+    let Some(bottom_right) = stroke_area.bottom_right() else {
+        return Ok(());
     };
 
-    let idx_iter = 0..nb_dots;
+    let x = stroke_area.top_left.x..bottom_right.x + 1;
+    let fill_x = fill_area.top_left.x..fill_area.top_left.x + fill_area.size.width as i32;
+    let fill_y = fill_area.top_left.y..fill_area.top_left.y + fill_area.size.height as i32;
 
-    idx_iter.map(move |idx| (dot_offset * Real::from(idx)).round().into())
-}
+    let color = |point| pattern.color(point, stroke_color, fill_color);
 
-/// Draw a dotted rectangular border.
-///
-/// The dot type is [`Rectangle`] (this method is meant to be used with smaller values of `dot_size`).
-/// The gaps between dots ideally have the same size as the dots.
-/// The gaps can be larger than ideal, but not smaller.
-/// A corner can be filled either by a dot or a gap (sides are drawn in clockwise order).
-fn draw_dotted_rectangle_border_in_clockwise_order<D>(
-    top_left: &Point,
-    border_sides: &[Point],
-    dot_size: u32,
-    style: &PrimitiveStyle<D::Color>,
-    target: &mut D,
-) -> Result<(), D::Error>
-where
-    D: DrawTarget,
-{
-    let mut corner_dot = Rectangle::new(*top_left, Size::new_equal(dot_size));
-    let mut unit_is_dot = true;
-
-    for (side_idx, side) in border_sides.iter().enumerate() {
-        let length = side[side_idx % 2].unsigned_abs();
-
-        for offset in dot_positions_in_clockwise_order(length, dot_size) {
-            if unit_is_dot {
-                let translation = *side / length as i32 * offset;
-                corner_dot
-                    .translate(translation)
-                    .draw_styled(style, target)?;
-            };
-            unit_is_dot = !unit_is_dot; // alternating dots and gaps
+    for y in stroke_area.top_left.y..=bottom_right.y {
+        if fill_y.contains(&y) && !fill_x.is_empty() {
+            Scanline::new(y, x.start..fill_x.start).draw_runs(target, color)?;
+            Scanline::new(y, fill_x.end..x.end).draw_runs(target, color)?;
+        } else {
+            Scanline::new(y, x.clone()).draw_runs(target, color)?;
         }
-        corner_dot.translate_mut(*side);
     }
 
     Ok(())
@@ -203,40 +181,15 @@ impl<C: PixelColor> StyledDrawable<PrimitiveStyle<C>> for Rectangle {
         let stroke_width = style.stroke_width;
         let stroke_area = style.stroke_area(self);
 
-        if style.stroke_style == StrokeStyle::Dotted {
-            let dot_size = stroke_width
-                .min(stroke_area.size.height / 2)
-                .min(stroke_area.size.width / 2);
-            if dot_size == 0 {
-                return Ok(());
-            }
-
-            let border_size = stroke_area.size - Size::new_equal(dot_size);
-            let dot_style = PrimitiveStyle::with_fill(stroke_color);
-
-            if dot_size < 4 {
-                let mut border_sides: [Point; 4] = [Point::zero(); 4];
-                border_sides[0] += border_size.x_axis();
-                border_sides[1] += border_size.y_axis();
-                border_sides[2] -= border_size.x_axis();
-                border_sides[3] -= border_size.y_axis();
-
-                draw_dotted_rectangle_border_in_clockwise_order(
-                    &stroke_area.top_left,
-                    &border_sides,
-                    dot_size,
-                    &dot_style,
-                    target,
-                )?
-            } else {
-                draw_dotted_rectangle_border_with_dotted_corners(
-                    &stroke_area.top_left,
-                    &border_size,
-                    dot_size,
-                    &dot_style,
-                    target,
-                )?
-            }
+        if let Some(pattern) = patterned_stroke(self, style) {
+            draw_patterned_rectangle_border(
+                &stroke_area,
+                &fill_area,
+                &pattern,
+                stroke_color,
+                style.fill_color,
+                target,
+            )?
         } else {
             let top_border = Rectangle::new(
                 stroke_area.top_left,
@@ -258,8 +211,17 @@ impl<C: PixelColor> StyledDrawable<PrimitiveStyle<C>> for Rectangle {
                 Size::new(stroke_area.size.width, bottom_stroke_width),
             );
 
-            target.fill_solid(&top_border, stroke_color)?;
-            target.fill_solid(&bottom_border, stroke_color)?;
+            if let Some(joins) = corner_joins(self, style) {
+                for border in [top_border, bottom_border] {
+                    for y in border.rows() {
+                        let row = Scanline::new(y, border.columns());
+                        target.fill_solid(&joins.clip(&row).to_rectangle(), stroke_color)?;
+                    }
+                }
+            } else {
+                target.fill_solid(&top_border, stroke_color)?;
+                target.fill_solid(&bottom_border, stroke_color)?;
+            }
 
             if fill_area.size.height > 0 {
                 let left_border = Rectangle::new(
@@ -303,7 +265,10 @@ mod tests {
         iterator::PixelIteratorExt,
         mock_display::MockDisplay,
         pixelcolor::{BinaryColor, Rgb565, RgbColor},
-        primitives::{Primitive, PrimitiveStyle, PrimitiveStyleBuilder, StrokeAlignment},
+        primitives::{
+            Circle, DashPattern, LineJoin, Primitive, PrimitiveStyle, PrimitiveStyleBuilder,
+            StrokeAlignment, StrokeStyle,
+        },
         Drawable,
     };
 
@@ -573,74 +538,169 @@ mod tests {
     }
 
     #[test]
-    fn dotted_border_dots_have_correct_shape_and_size() {
-        // The diameter of the dots should be the border width, except in the case
-        // where this causes corner dots to overlap.
-        // Rectangles are used for dots with size <= 3.
-        let base = PrimitiveStyleBuilder::new()
-            .stroke_color(BinaryColor::On)
-            .stroke_width(5)
-            .stroke_alignment(StrokeAlignment::Inside);
-
-        let rectangle1 = Rectangle::new(Point::new(5, 6), Size::new(40, 7));
-        let rectangle2 = Rectangle::new(Point::new(4, 5), Size::new(40, 39));
+    fn dotted_border_dots_are_circles() {
+        // The diameter of the dots is equal to the stroke width.
+        let rectangle = Rectangle::new(Point::new(4, 5), Size::new(40, 39));
 
-        let top_left_dot1 = Rectangle::new(Point::new(5, 6), Size::new_equal(3)); // because the height is 7
-        let top_left_dot2 = Circle::new(Point::new(4, 5), 5);
+        for stroke_width in [3, 5] {
+            let top_left_dot = Circle::new(rectangle.top_left, stroke_width);
 
-        let mut dot = MockDisplay::new();
-        let mut dotted_border = MockDisplay::new();
+            let mut dot = MockDisplay::new();
+            top_left_dot
+                .into_styled(PrimitiveStyle::with_fill(BinaryColor::On))
+                .draw(&mut dot)
+                .unwrap();
 
-        top_left_dot1
-            .into_styled(PrimitiveStyle::with_fill(BinaryColor::On))
-            .draw(&mut dot)
-            .unwrap();
-        rectangle1
-            .into_styled(base.stroke_style(StrokeStyle::Dotted).build())
-            .draw(&mut dotted_border)
-            .unwrap();
+            let mut dotted_border = MockDisplay::new();
+            rectangle
+                .into_styled(
+                    PrimitiveStyleBuilder::new()
+                        .stroke_color(BinaryColor::On)
+                        .stroke_width(stroke_width)
+                        .stroke_alignment(StrokeAlignment::Inside)
+                        .stroke_style(StrokeStyle::Dotted)
+                        .build(),
+                )
+                .draw(&mut dotted_border)
+                .unwrap();
 
-        for p in top_left_dot1.bounding_box().points() {
-            assert_eq!(dot.get_pixel(p), dotted_border.get_pixel(p));
+            for p in top_left_dot.bounding_box().points() {
+                assert_eq!(dot.get_pixel(p), dotted_border.get_pixel(p), "{:?}", p);
+            }
         }
+    }
 
-        let mut dot = MockDisplay::new();
-        let mut dotted_border = MockDisplay::new();
+    #[test]
+    fn dotted_border_gaps_are_filled() {
+        let rect = Rectangle::new(Point::new(6, 7), Size::new(9, 13));
+        let base = PrimitiveStyleBuilder::new()
+            .stroke_color(BinaryColor::On)
+            .fill_color(BinaryColor::Off)
+            .stroke_width(5)
+            .stroke_style(StrokeStyle::Dotted);
 
-        top_left_dot2
-            .into_styled(PrimitiveStyle::with_fill(BinaryColor::On))
-            .draw(&mut dot)
-            .unwrap();
-        rectangle2
-            .into_styled(base.stroke_style(StrokeStyle::Dotted).build())
-            .draw(&mut dotted_border)
-            .unwrap();
+        for stroke_alignment in [
+            StrokeAlignment::Inside,
+            StrokeAlignment::Center,
+            StrokeAlignment::Outside,
+        ] {
+            let mut display = MockDisplay::new();
+            rect.into_styled(base.stroke_alignment(stroke_alignment).build())
+                .draw(&mut display)
+                .unwrap();
 
-        for p in top_left_dot2.bounding_box().points() {
-            assert_eq!(dot.get_pixel(p), dotted_border.get_pixel(p));
+            for p in rect.points() {
+                assert!(display.get_pixel(p).is_some(), "{:?}", p);
+            }
         }
     }
 
     #[test]
-    fn dotted_border_fill_is_independent_of_stroke_alignment() {
-        let rect = Rectangle::new(Point::new(3, 4), Size::new(9, 13));
-        let base = PrimitiveStyleBuilder::new()
-            .fill_color(BinaryColor::On)
-            .stroke_width(5)
-            .stroke_style(StrokeStyle::Dotted);
+    fn dotted_stroke() {
+        crate::primitives::common::check_dotted_stroke(Rectangle::new(
+            Point::new(6, 7),
+            Size::new(40, 25),
+        ));
+    }
 
-        let mut inside = MockDisplay::new();
-        let mut outside = MockDisplay::new();
+    #[test]
+    fn dashed_stroke() {
+        crate::primitives::common::check_dashed_stroke(Rectangle::new(
+            Point::new(6, 7),
+            Size::new(40, 25),
+        ));
+    }
 
-        rect.into_styled(base.stroke_alignment(StrokeAlignment::Inside).build())
-            .draw(&mut inside)
-            .unwrap();
-        rect.into_styled(base.stroke_alignment(StrokeAlignment::Outside).build())
-            .draw(&mut outside)
+    #[test]
+    fn dashed_stroke_pattern() {
+        let mut display = MockDisplay::new();
+        Rectangle::new(Point::new(0, 0), Size::new(10, 5))
+            .into_styled(
+                PrimitiveStyleBuilder::new()
+                    .stroke_color(BinaryColor::On)
+                    .stroke_width(1)
+                    .stroke_style(StrokeStyle::Dashed(DashPattern::new(3, 2)))
+                    .build(),
+            )
+            .draw(&mut display)
             .unwrap();
 
-        for p in rect.bounding_box().points() {
-            assert_eq!(inside.get_pixel(p), outside.get_pixel(p));
-        }
+        display.assert_pattern(&[
+            "###  ###  ", //
+            "#        #", //
+            "         #", //
+            "         #", //
+            "###  ###  ", //
+        ]);
+    }
+
+    fn draw_with_line_join(line_join: LineJoin) -> MockDisplay<BinaryColor> {
+        let style = PrimitiveStyleBuilder::new()
+            .stroke_color(BinaryColor::On)
+            .stroke_width(6)
+            .fill_color(BinaryColor::Off)
+            .line_join(line_join)
+            .build();
+
+        let rect = Rectangle::new(Point::new(3, 3), Size::new(14, 12)).into_styled(style);
+
+        let mut display = MockDisplay::new();
+        rect.draw(&mut display).unwrap();
+
+        let mut pixels = MockDisplay::new();
+        rect.pixels().draw(&mut pixels).unwrap();
+        pixels.assert_eq(&display);
+
+        assert_eq!(display.affected_area(), rect.bounding_box());
+
+        display
+    }
+
+    #[test]
+    fn line_joins() {
+        draw_with_line_join(LineJoin::Bevel).assert_pattern(&[
+            "  ################  ", //
+            " ################## ", //
+            "####################", //
+            "####################", //
+            "####################", //
+            "####################", //
+            "######........######", //
+            "######........######", //
+            "######........######", //
+            "######........######", //
+            "######........######", //
+            "######........######", //
+            "####################", //
+            "####################", //
+            "####################", //
+            "####################", //
+            " ################## ", //
+            "  ################  ", //
+        ]);
+
+        draw_with_line_join(LineJoin::Round).assert_pattern(&[
+            " ################## ", //
+            "####################", //
+            "####################", //
+            "####################", //
+            "####################", //
+            "####################", //
+            "######........######", //
+            "######........######", //
+            "######........######", //
+            "######........######", //
+            "######........######", //
+            "######........######", //
+            "####################", //
+            "####################", //
+            "####################", //
+            "####################", //
+            "####################", //
+            " ################## ", //
+        ]);
+
+        draw_with_line_join(LineJoin::Miter { limit: 1 })
+            .assert_eq(&draw_with_line_join(LineJoin::Bevel));
     }
 }
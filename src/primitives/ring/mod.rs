@@ -0,0 +1,331 @@
+//! The ring primitive
+
+use crate::{
+    geometry::{angle_consts::ANGLE_360DEG, Angle, Dimensions, Point, PointExt, Size},
+    primitives::{
+        circle::diameter_to_threshold,
+        common::{DistanceIterator, PlaneSector},
+        Circle, ContainsPoint, OffsetOutline, PointsIter, Primitive, Rectangle,
+    },
+    transform::{GeometricTransform, Transform, Transformation},
+};
+
+mod points;
+mod styled;
+
+pub use points::Points;
+pub use styled::StyledPixelsIterator;
+
+/// Ring primitive
+///
+/// A ring is the area between two concentric circles. The ring can optionally be limited to a
+/// range of angles, which turns it into an annular sector. Unlike drawing two circles on top of
+/// each other, the hole of the ring isn't drawn and stays transparent.
+///
+/// # Examples
+///
+/// ## Draw a progress ring
+///
+/// ```rust
+/// use embedded_graphics::{
+///     pixelcolor::Rgb565,
+///     prelude::*,
+///     primitives::{PrimitiveStyle, PrimitiveStyleBuilder, Ring},
+/// };
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display = MockDisplay::default();
+///
+/// let track = Ring::with_center(Point::new(32, 32), 50, 36);
+///
+/// // Background of the progress ring
+/// track
+///     .into_styled(PrimitiveStyle::with_fill(Rgb565::CSS_DARK_GRAY))
+///     .draw(&mut display)?;
+///
+/// // Progress indicator for 30% with a white outline
+/// let style = PrimitiveStyleBuilder::new()
+///     .stroke_color(Rgb565::WHITE)
+///     .stroke_width(1)
+///     .fill_color(Rgb565::GREEN)
+///     .build();
+///
+/// # display.set_allow_overdraw(true);
+/// track
+///     .with_angles(-90.0.deg(), 108.0.deg())
+///     .into_styled(style)
+///     .draw(&mut display)?;
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub struct Ring {
+    /// Top-left point of the bounding-box of the outer circle
+    pub top_left: Point,
+
+    /// Diameter of the outer circle
+    pub outer_diameter: u32,
+
+    /// Diameter of the inner circle
+    pub inner_diameter: u32,
+
+    /// Angle at which the ring starts
+    pub angle_start: Angle,
+
+    /// Angle defining the ring sweep starting at angle_start
+    pub angle_sweep: Angle,
+}
+
+impl Ring {
+    /// Create a new full ring delimited with a top-left point of the outer circle and the outer
+    /// and inner diameter
+    pub fn new(top_left: Point, outer_diameter: u32, inner_diameter: u32) -> Self {
+        Ring {
+            top_left,
+            outer_diameter,
+            inner_diameter,
+            angle_start: Angle::zero(),
+            angle_sweep: ANGLE_360DEG,
+        }
+    }
+
+    /// Create a new full ring centered around a given point with the outer and inner diameter
+    pub fn with_center(center: Point, outer_diameter: u32, inner_diameter: u32) -> Self {
+        let top_left = Rectangle::with_center(center, Size::new_equal(outer_diameter)).top_left;
+
+        Self::new(top_left, outer_diameter, inner_diameter)
+    }
+
+    /// Returns a copy of this ring which is limited to the given start and sweep angles.
+    ///
+    /// The resulting ring is an annular sector.
+    pub const fn with_angles(self, angle_start: Angle, angle_sweep: Angle) -> Self {
+        Self {
+            angle_start,
+            angle_sweep,
+            ..self
+        }
+    }
+
+    /// Returns the outer circle of the ring.
+    pub const fn outer_circle(&self) -> Circle {
+        Circle::new(self.top_left, self.outer_diameter)
+    }
+
+    /// Return the center point of the ring
+    pub fn center(&self) -> Point {
+        self.bounding_box().center()
+    }
+
+    /// Returns `true` if the ring covers a full turn.
+    pub fn is_full_ring(&self) -> bool {
+        self.angle_sweep.abs() >= ANGLE_360DEG
+    }
+
+    /// Returns the center point of the ring scaled by a factor of 2.
+    ///
+    /// The inner and outer edge of the ring share this center point, even if the difference
+    /// between both diameters is odd.
+    pub(in crate::primitives) fn center_2x(&self) -> Point {
+        self.outer_circle().center_2x()
+    }
+
+    /// Returns the threshold of the outer edge.
+    ///
+    /// Points with a squared distance to the center which is less than this threshold are inside
+    /// the outer circle.
+    pub(in crate::primitives) const fn outer_threshold(&self) -> u32 {
+        diameter_to_threshold(self.outer_diameter)
+    }
+
+    /// Returns the threshold of the inner edge.
+    ///
+    /// Points with a squared distance to the center which is less than this threshold are inside
+    /// the hole of the ring.
+    pub(in crate::primitives) const fn inner_threshold(&self) -> u32 {
+        diameter_to_threshold(self.inner_diameter)
+    }
+
+    /// Returns the squared distance for every point in the bounding box.
+    pub(in crate::primitives) fn distances(&self) -> DistanceIterator {
+        DistanceIterator::new(self.center_2x(), &self.bounding_box())
+    }
+}
+
+impl OffsetOutline for Ring {
+    fn offset(&self, offset: i32) -> Self {
+        let outer = self.outer_circle().offset(offset);
+
+        let inner_offset = offset.unsigned_abs().saturating_mul(2);
+        let inner_diameter = if offset >= 0 {
+            self.inner_diameter.saturating_sub(inner_offset)
+        } else {
+            self.inner_diameter.saturating_add(inner_offset)
+        };
+
+        Self {
+            top_left: outer.top_left,
+            outer_diameter: outer.diameter,
+            inner_diameter,
+            ..*self
+        }
+    }
+}
+
+impl Primitive for Ring {}
+
+impl PointsIter for Ring {
+    type Iter = Points;
+
+    fn points(&self) -> Self::Iter {
+        Points::new(self)
+    }
+}
+
+impl ContainsPoint for Ring {
+    fn contains(&self, point: Point) -> bool {
+        let delta = point * 2 - self.center_2x();
+        let distance = delta.length_squared() as u32;
+
+        distance < self.outer_threshold()
+            && distance >= self.inner_threshold()
+            && PlaneSector::new(self.angle_start, self.angle_sweep).contains(delta)
+    }
+}
+
+impl Dimensions for Ring {
+    fn bounding_box(&self) -> Rectangle {
+        Rectangle::new(self.top_left, Size::new_equal(self.outer_diameter))
+    }
+}
+
+impl Transform for Ring {
+    /// Translate the ring from its current position to a new position by (x, y) pixels,
+    /// returning a new `Ring`. For a mutating transform, see `translate_mut`.
+    ///
+    /// ```
+    /// # use embedded_graphics::primitives::Ring;
+    /// # use embedded_graphics::prelude::*;
+    /// let ring = Ring::new(Point::new(5, 10), 10, 6);
+    /// let moved = ring.translate(Point::new(10, 10));
+    ///
+    /// assert_eq!(moved.top_left, Point::new(15, 20));
+    /// ```
+    fn translate(&self, by: Point) -> Self {
+        Self {
+            top_left: self.top_left + by,
+            ..*self
+        }
+    }
+
+    /// Translate the ring from its current position to a new position by (x, y) pixels.
+    ///
+    /// ```
+    /// # use embedded_graphics::primitives::Ring;
+    /// # use embedded_graphics::prelude::*;
+    /// let mut ring = Ring::new(Point::new(5, 10), 10, 6);
+    /// ring.translate_mut(Point::new(10, 10));
+    ///
+    /// assert_eq!(ring.top_left, Point::new(15, 20));
+    /// ```
+    fn translate_mut(&mut self, by: Point) -> &mut Self {
+        self.top_left += by;
+
+        self
+    }
+}
+
+impl GeometricTransform for Ring {
+    type Output = Ring;
+
+    fn transform(&self, transformation: &Transformation) -> Self::Output {
+        let outer_circle = self.outer_circle().transform(transformation);
+        let (angle_start, angle_sweep) =
+            transformation.transform_angles(self.angle_start, self.angle_sweep);
+
+        Self {
+            top_left: outer_circle.top_left,
+            outer_diameter: outer_circle.diameter,
+            inner_diameter: transformation.transform_length(self.inner_diameter),
+            angle_start,
+            angle_sweep,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geometry::AngleUnit;
+
+    #[test]
+    fn dimensions() {
+        let ring = Ring::new(Point::new(5, 15), 10, 4);
+
+        assert_eq!(
+            ring.bounding_box(),
+            Rectangle::new(Point::new(5, 15), Size::new(10, 10))
+        );
+    }
+
+    #[test]
+    fn center_is_correct() {
+        let ring = Ring::new(Point::new(10, 10), 5, 3);
+        assert_eq!(ring.center(), Point::new(12, 12));
+
+        let ring = Ring::with_center(Point::new(10, 10), 6, 2);
+        assert_eq!(ring.center(), Point::new(10, 10));
+    }
+
+    #[test]
+    fn full_ring() {
+        assert!(Ring::new(Point::zero(), 10, 4).is_full_ring());
+        assert!(Ring::new(Point::zero(), 10, 4)
+            .with_angles(30.0.deg(), -360.0.deg())
+            .is_full_ring());
+        assert!(!Ring::new(Point::zero(), 10, 4)
+            .with_angles(0.0.deg(), 359.0.deg())
+            .is_full_ring());
+    }
+
+    #[test]
+    fn contains() {
+        for ring in [
+            Ring::new(Point::zero(), 10, 4),
+            Ring::new(Point::zero(), 11, 4),
+            Ring::new(Point::zero(), 10, 0),
+            Ring::new(Point::zero(), 15, 7).with_angles(30.0.deg(), 200.0.deg()),
+        ] {
+            let contained_points = Rectangle::new(Point::new(-10, -10), Size::new(40, 40))
+                .points()
+                .filter(|p| ring.contains(*p));
+
+            assert!(contained_points.eq(ring.points()), "{:?}", ring);
+        }
+    }
+
+    #[test]
+    fn ring_without_hole_is_circle() {
+        let ring = Ring::new(Point::new(2, 3), 11, 0);
+        let circle = Circle::new(Point::new(2, 3), 11);
+
+        assert!(ring.points().eq(circle.points()));
+    }
+
+    #[test]
+    fn offset() {
+        let center = Point::new(5, 7);
+        let ring = Ring::with_center(center, 9, 5).with_angles(10.0.deg(), 20.0.deg());
+
+        assert_eq!(ring.offset(0), ring);
+
+        assert_eq!(
+            ring.offset(1),
+            Ring::with_center(center, 11, 3).with_angles(10.0.deg(), 20.0.deg())
+        );
+        assert_eq!(
+            ring.offset(-2),
+            Ring::with_center(center, 5, 9).with_angles(10.0.deg(), 20.0.deg())
+        );
+        assert_eq!(ring.offset(3).inner_diameter, 0);
+    }
+}
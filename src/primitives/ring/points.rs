@@ -0,0 +1,72 @@
+use crate::{
+    geometry::Point,
+    primitives::{
+        common::{DistanceIterator, PlaneSector},
+        ring::Ring,
+    },
+};
+
+/// Iterator over all points inside the ring.
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub struct Points {
+    iter: DistanceIterator,
+
+    plane_sector: PlaneSector,
+
+    outer_threshold: u32,
+    inner_threshold: u32,
+}
+
+impl Points {
+    pub(in crate::primitives) fn new(ring: &Ring) -> Self {
+        Self {
+            iter: ring.distances(),
+            plane_sector: PlaneSector::new(ring.angle_start, ring.angle_sweep),
+            outer_threshold: ring.outer_threshold(),
+            inner_threshold: ring.inner_threshold(),
+        }
+    }
+}
+
+impl Iterator for Points {
+    type Item = Point;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let outer_threshold = self.outer_threshold;
+        let inner_threshold = self.inner_threshold;
+        let plane_sector = self.plane_sector;
+
+        self.iter
+            .find(|(_, delta, distance)| {
+                *distance < outer_threshold
+                    && *distance >= inner_threshold
+                    && plane_sector.contains(*delta)
+            })
+            .map(|(point, ..)| point)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        geometry::AngleUnit,
+        pixelcolor::BinaryColor,
+        primitives::{PointsIter, Primitive, PrimitiveStyle},
+        Pixel,
+    };
+
+    #[test]
+    fn points_equals_filled() {
+        let ring =
+            Ring::with_center(Point::new(10, 10), 15, 6).with_angles(90.0.deg(), 180.0.deg());
+
+        let styled_points = ring
+            .into_styled(PrimitiveStyle::with_fill(BinaryColor::On))
+            .pixels()
+            .map(|Pixel(p, _)| p);
+
+        assert!(ring.points().eq(styled_points));
+    }
+}
@@ -0,0 +1,347 @@
+use crate::{
+    draw_target::DrawTarget,
+    geometry::Dimensions,
+    pixelcolor::PixelColor,
+    primitives::{
+        common::{
+            Band, DistanceIterator, PatternedStroke, PlaneSector, PointType, RingOutline,
+            NORMAL_VECTOR_SCALE,
+        },
+        styled::{StyledDimensions, StyledDrawable, StyledPixels},
+        PrimitiveStyle, Rectangle, Ring,
+    },
+    Pixel,
+};
+use az::SaturatingAs;
+
+/// Pixel iterator for each pixel in the ring border and fill
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub struct StyledPixelsIterator<C> {
+    iter: DistanceIterator,
+
+    plane_sector: PlaneSector,
+
+    /// Thresholds of the outer and inner edge of the stroke.
+    outer_threshold: u32,
+    inner_threshold: u32,
+
+    /// Thresholds of the outer and inner edge of the fill.
+    fill_outer_threshold: u32,
+    fill_inner_threshold: u32,
+
+    stroke_threshold_inside: i32,
+    stroke_threshold_outside: i32,
+
+    stroke_color: Option<C>,
+    fill_color: Option<C>,
+
+    pattern: Option<PatternedStroke<RingOutline, Band<Ring>, Ring>>,
+}
+
+impl<C: PixelColor> StyledPixelsIterator<C> {
+    fn new(primitive: &Ring, style: &PrimitiveStyle<C>) -> Self {
+        let stroke_area = style.stroke_area(primitive);
+        let fill_area = style.fill_area(primitive);
+
+        let iter = if !style.is_transparent() {
+            // PERF: The distance iterator should use the smaller annular sector bounding box
+            stroke_area.distances()
+        } else {
+            DistanceIterator::empty()
+        };
+
+        let plane_sector = PlaneSector::new(primitive.angle_start, primitive.angle_sweep);
+
+        let inside_stroke_width: i32 = style.inside_stroke_width().saturating_as();
+        let outside_stroke_width: i32 = style.outside_stroke_width().saturating_as();
+
+        let stroke_threshold_inside =
+            inside_stroke_width * NORMAL_VECTOR_SCALE * 2 - NORMAL_VECTOR_SCALE;
+        let stroke_threshold_outside =
+            outside_stroke_width * NORMAL_VECTOR_SCALE * 2 + NORMAL_VECTOR_SCALE;
+
+        Self {
+            iter,
+            plane_sector,
+            outer_threshold: stroke_area.outer_threshold(),
+            inner_threshold: stroke_area.inner_threshold(),
+            fill_outer_threshold: fill_area.outer_threshold(),
+            fill_inner_threshold: fill_area.inner_threshold(),
+            stroke_threshold_inside,
+            stroke_threshold_outside,
+            stroke_color: style.stroke_color,
+            fill_color: style.fill_color,
+            pattern: patterned_stroke(primitive, style),
+        }
+    }
+}
+
+impl<C: PixelColor> Iterator for StyledPixelsIterator<C> {
+    type Item = Pixel<C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let outer_threshold = self.outer_threshold;
+        let inner_threshold = self.inner_threshold;
+
+        loop {
+            let (point, delta, distance) = self.iter.find(|(_, _, distance)| {
+                *distance < outer_threshold && *distance >= inner_threshold
+            })?;
+
+            // Check if point is inside the radial stroke lines or the fill.
+            let mut point_type = match self.plane_sector.point_type(
+                delta,
+                self.stroke_threshold_inside,
+                self.stroke_threshold_outside,
+            ) {
+                Some(point_type) => point_type,
+                None => continue,
+            };
+
+            // Add the outer and inner circular stroke.
+            if point_type == PointType::Fill
+                && (distance >= self.fill_outer_threshold || distance < self.fill_inner_threshold)
+            {
+                point_type = PointType::Stroke;
+            }
+
+            let color = match (point_type, &self.pattern) {
+                (PointType::Stroke, Some(pattern)) => self
+                    .stroke_color
+                    .and_then(|stroke_color| pattern.color(point, stroke_color, self.fill_color)),
+                (PointType::Stroke, None) => self.stroke_color,
+                (PointType::Fill, _) => self.fill_color,
+            };
+
+            if let Some(color) = color {
+                return Some(Pixel(point, color));
+            }
+        }
+    }
+}
+
+/// Returns the dashed or dotted stroke if the style uses a non solid stroke.
+fn patterned_stroke<C: PixelColor>(
+    primitive: &Ring,
+    style: &PrimitiveStyle<C>,
+) -> Option<PatternedStroke<RingOutline, Band<Ring>, Ring>> {
+    let outline = RingOutline::new(&style.stroke_center_outline(primitive));
+
+    if let Some(dot_size) = style.dot_size() {
+        let area = Band::new(
+            style.stroke_area(primitive),
+            style.stroke_inner_area(primitive),
+        );
+
+        return Some(PatternedStroke::dotted(outline, area, dot_size, *primitive));
+    }
+
+    style
+        .dash_pattern()
+        .map(|pattern| PatternedStroke::dashed(outline, pattern, *primitive))
+}
+
+impl<C: PixelColor> StyledPixels<PrimitiveStyle<C>> for Ring {
+    type Iter = StyledPixelsIterator<C>;
+
+    fn pixels(&self, style: &PrimitiveStyle<C>) -> Self::Iter {
+        StyledPixelsIterator::new(self, style)
+    }
+}
+
+impl<C: PixelColor> StyledDrawable<PrimitiveStyle<C>> for Ring {
+    type Color = C;
+    type Output = ();
+
+    fn draw_styled<D>(
+        &self,
+        style: &PrimitiveStyle<C>,
+        target: &mut D,
+    ) -> Result<Self::Output, D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        target.draw_iter(StyledPixelsIterator::new(self, style))
+    }
+}
+
+impl<C: PixelColor> StyledDimensions<PrimitiveStyle<C>> for Ring {
+    // FIXME: This doesn't take into account start/end angles, like the bounding box of `Sector`.
+    fn styled_bounding_box(&self, style: &PrimitiveStyle<C>) -> Rectangle {
+        let offset = style.outside_stroke_width().saturating_as();
+
+        self.bounding_box().offset(offset)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        geometry::{AngleUnit, Point},
+        iterator::PixelIteratorExt,
+        mock_display::MockDisplay,
+        pixelcolor::BinaryColor,
+        primitives::{Circle, PointsIter, Primitive, PrimitiveStyleBuilder, StrokeAlignment},
+        Drawable,
+    };
+
+    #[test]
+    fn filled_ring() {
+        let mut display = MockDisplay::new();
+
+        Ring::new(Point::zero(), 9, 5)
+            .into_styled(PrimitiveStyle::with_fill(BinaryColor::On))
+            .draw(&mut display)
+            .unwrap();
+
+        display.assert_pattern(&[
+            "  #####  ", //
+            " ####### ", //
+            "###   ###", //
+            "##     ##", //
+            "##     ##", //
+            "##     ##", //
+            "###   ###", //
+            " ####### ", //
+            "  #####  ", //
+        ]);
+    }
+
+    #[test]
+    fn annular_sector() {
+        let style = PrimitiveStyleBuilder::new()
+            .stroke_color(BinaryColor::On)
+            .stroke_width(1)
+            .fill_color(BinaryColor::Off)
+            .build();
+
+        let mut display = MockDisplay::new();
+
+        Ring::new(Point::zero(), 15, 7)
+            .with_angles(0.0.deg(), 90.0.deg())
+            .into_styled(style)
+            .draw(&mut display)
+            .unwrap();
+
+        display.assert_pattern(&[
+            "               ", //
+            "               ", //
+            "               ", //
+            "               ", //
+            "               ", //
+            "               ", //
+            "               ", //
+            "           ####", //
+            "           #..#", //
+            "          ##..#", //
+            "         ##..# ", //
+            "       ###...# ", //
+            "       #....#  ", //
+            "       #..##   ", //
+            "       ###     ", //
+        ]);
+    }
+
+    #[test]
+    fn ring_equals_circles() {
+        for stroke_alignment in [
+            StrokeAlignment::Inside,
+            StrokeAlignment::Center,
+            StrokeAlignment::Outside,
+        ] {
+            let style = PrimitiveStyleBuilder::new()
+                .stroke_color(BinaryColor::On)
+                .stroke_width(4)
+                .stroke_alignment(stroke_alignment)
+                .fill_color(BinaryColor::Off)
+                .build();
+
+            let ring = Ring::with_center(Point::new(20, 20), 31, 15);
+
+            let mut display = MockDisplay::new();
+            ring.into_styled(style).draw(&mut display).unwrap();
+
+            // The outer edge of the ring matches a circle and the inner edge matches the
+            // outline of a circle which is drawn with the opposite stroke alignment.
+            let mut expected = MockDisplay::new();
+            expected.set_allow_overdraw(true);
+            Circle::with_center(Point::new(20, 20), 31)
+                .into_styled(style)
+                .draw(&mut expected)
+                .unwrap();
+
+            let inner_alignment = match stroke_alignment {
+                StrokeAlignment::Inside => StrokeAlignment::Outside,
+                StrokeAlignment::Center => StrokeAlignment::Center,
+                StrokeAlignment::Outside => StrokeAlignment::Inside,
+            };
+            let inner_style = PrimitiveStyleBuilder::from(&style)
+                .stroke_alignment(inner_alignment)
+                .build();
+            let mut hole = MockDisplay::new();
+            Circle::with_center(Point::new(20, 20), 15)
+                .into_styled(inner_style)
+                .draw(&mut hole)
+                .unwrap();
+
+            for point in hole.affected_area().points() {
+                match hole.get_pixel(point) {
+                    Some(BinaryColor::On) => expected.set_pixel(point, Some(BinaryColor::On)),
+                    Some(BinaryColor::Off) => expected.set_pixel(point, None),
+                    None => {}
+                }
+            }
+
+            display.assert_eq_with_message(&expected, |f| write!(f, "{:?}", stroke_alignment));
+
+            let mut pixels = MockDisplay::new();
+            ring.into_styled(style).pixels().draw(&mut pixels).unwrap();
+            pixels.assert_eq(&display);
+
+            assert_eq!(
+                display.affected_area(),
+                ring.into_styled(style).bounding_box()
+            );
+        }
+    }
+
+    #[test]
+    fn transparent_hole() {
+        let mut display = MockDisplay::new();
+
+        Ring::with_center(Point::new(10, 10), 15, 7)
+            .into_styled(PrimitiveStyle::with_fill(BinaryColor::On))
+            .draw(&mut display)
+            .unwrap();
+
+        assert_eq!(display.get_pixel(Point::new(10, 10)), None);
+        assert_eq!(display.get_pixel(Point::new(13, 10)), None);
+        assert_eq!(display.get_pixel(Point::new(14, 10)), Some(BinaryColor::On));
+    }
+
+    #[test]
+    fn dashed_stroke() {
+        crate::primitives::common::check_dashed_stroke(
+            Ring::with_center(Point::new(25, 25), 40, 16).with_angles(10.0.deg(), 280.0.deg()),
+        );
+        crate::primitives::common::check_dashed_stroke(Ring::with_center(
+            Point::new(25, 25),
+            40,
+            16,
+        ));
+    }
+
+    #[test]
+    fn dotted_stroke() {
+        crate::primitives::common::check_dotted_stroke(
+            Ring::with_center(Point::new(25, 25), 40, 16).with_angles(10.0.deg(), 280.0.deg()),
+        );
+        crate::primitives::common::check_dotted_stroke(Ring::with_center(
+            Point::new(25, 25),
+            40,
+            16,
+        ));
+    }
+}
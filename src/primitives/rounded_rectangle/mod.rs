@@ -5,7 +5,7 @@ use core::ops::Range;
 use crate::{
     geometry::{Dimensions, Point, Size},
     primitives::{rectangle::Rectangle, ContainsPoint, OffsetOutline, PointsIter, Primitive},
-    transform::Transform,
+    transform::{GeometricTransform, Transform, Transformation},
 };
 
 mod corner_radii;
@@ -337,6 +337,44 @@ impl Transform for RoundedRectangle {
     }
 }
 
+impl GeometricTransform for RoundedRectangle {
+    type Output = Option<RoundedRectangle>;
+
+    fn transform(&self, transformation: &Transformation) -> Self::Output {
+        let rectangle = transformation.transform_rectangle(&self.rectangle)?;
+
+        let CornerRadii {
+            top_left,
+            top_right,
+            bottom_right,
+            bottom_left,
+        } = self.corners;
+
+        // Radii in clockwise order, starting at the top left corner.
+        let mut radii = match transformation.mirroring() {
+            (true, _) => [top_right, top_left, bottom_left, bottom_right],
+            (_, true) => [bottom_left, bottom_right, top_right, top_left],
+            _ => [top_left, top_right, bottom_right, bottom_left],
+        };
+        if let Some(turns) = transformation.quarter_turns() {
+            radii.rotate_right(usize::from(turns));
+        }
+
+        let [top_left, top_right, bottom_right, bottom_left] =
+            radii.map(|radius| transformation.transform_size(radius));
+
+        Some(Self::new(
+            rectangle,
+            CornerRadii {
+                top_left,
+                top_right,
+                bottom_right,
+                bottom_left,
+            },
+        ))
+    }
+}
+
 #[derive(Clone, PartialEq, Eq, Hash, Debug)]
 #[cfg_attr(feature = "defmt", derive(::defmt::Format))]
 pub(in crate::primitives) struct RoundedRectangleContains {
@@ -426,10 +464,12 @@ impl RoundedRectangleContains {
 mod tests {
     use super::*;
     use crate::{
-        geometry::{Point, Size},
+        geometry::{AngleUnit, Point, Size},
+        iterator::PixelIteratorExt,
         mock_display::MockDisplay,
         pixelcolor::BinaryColor,
         primitives::CornerRadiiBuilder,
+        Pixel,
     };
 
     #[test]
@@ -564,4 +604,42 @@ mod tests {
         );
         display.assert_eq(&expected);
     }
+
+    #[test]
+    fn geometric_transform() {
+        let rounded_rectangle = RoundedRectangle::new(
+            Rectangle::new(Point::new(10, 5), Size::new(20, 15)),
+            CornerRadiiBuilder::new()
+                .top_left(Size::new(2, 2))
+                .top_right(Size::new(3, 4))
+                .bottom_right(Size::new(5, 6))
+                .build(),
+        );
+
+        for transformation in [
+            Transformation::rotation(Point::new(20, 20), 90.0.deg()),
+            Transformation::rotation(Point::new(20, 20), 180.0.deg()),
+            Transformation::rotation(Point::new(20, 20), 270.0.deg()),
+            Transformation::mirror_horizontal(25),
+            Transformation::mirror_vertical(15),
+        ] {
+            let mut expected = MockDisplay::new();
+            rounded_rectangle
+                .points()
+                .map(|point| Pixel(transformation.transform_point(point), BinaryColor::On))
+                .draw(&mut expected)
+                .unwrap();
+
+            let mut display = MockDisplay::new();
+            rounded_rectangle
+                .transform(&transformation)
+                .unwrap()
+                .points()
+                .map(|point| Pixel(point, BinaryColor::On))
+                .draw(&mut display)
+                .unwrap();
+
+            display.assert_eq_with_message(&expected, |f| write!(f, "{:?}", transformation));
+        }
+    }
 }
@@ -1,9 +1,11 @@
 use crate::{
     draw_target::DrawTarget,
-    geometry::{Dimensions, Point},
+    geometry::{Dimensions, Point, Size},
     pixelcolor::PixelColor,
     primitives::{
-        common::{Scanline, StyledScanline},
+        common::{
+            Band, CornerJoins, PatternedStroke, RoundedRectangleOutline, Scanline, StyledScanline,
+        },
         rounded_rectangle::{points::Scanlines, RoundedRectangle},
         styled::{StyledDimensions, StyledDrawable, StyledPixels},
         PrimitiveStyle, Rectangle,
@@ -26,6 +28,9 @@ pub struct StyledPixelsIterator<C> {
 
     stroke_color: Option<C>,
     fill_color: Option<C>,
+
+    pattern:
+        Option<PatternedStroke<RoundedRectangleOutline, Band<RoundedRectangle>, RoundedRectangle>>,
 }
 
 impl<C: PixelColor> StyledPixelsIterator<C> {
@@ -33,16 +38,14 @@ impl<C: PixelColor> StyledPixelsIterator<C> {
         primitive: &RoundedRectangle,
         style: &PrimitiveStyle<C>,
     ) -> Self {
-        let stroke_area = style.stroke_area(primitive);
-        let fill_area = style.fill_area(primitive);
-
         Self {
-            styled_scanlines: StyledScanlines::new(&stroke_area, &fill_area),
+            styled_scanlines: StyledScanlines::new(primitive, style),
             stroke_left: Scanline::new_empty(0),
             fill: Scanline::new_empty(0),
             stroke_right: Scanline::new_empty(0),
             stroke_color: style.stroke_color,
             fill_color: style.fill_color,
+            pattern: patterned_stroke(primitive, style),
         }
     }
 }
@@ -51,6 +54,24 @@ impl<C: PixelColor> Iterator for StyledPixelsIterator<C> {
     type Item = Pixel<C>;
 
     fn next(&mut self) -> Option<Self::Item> {
+        if let (Some(pattern), Some(stroke_color)) = (&self.pattern, self.stroke_color) {
+            loop {
+                if let Some(point) = self.stroke_left.next().or_else(|| self.stroke_right.next()) {
+                    if let Some(color) = pattern.color(point, stroke_color, self.fill_color) {
+                        return Some(Pixel(point, color));
+                    }
+                } else if let (Some(point), Some(fill_color)) = (self.fill.next(), self.fill_color)
+                {
+                    return Some(Pixel(point, fill_color));
+                } else {
+                    let scanline = self.styled_scanlines.next()?;
+                    self.stroke_left = scanline.stroke_left();
+                    self.fill = scanline.fill();
+                    self.stroke_right = scanline.stroke_right();
+                }
+            }
+        }
+
         match (self.stroke_color, self.fill_color) {
             (Some(stroke_color), None) => loop {
                 if let Some(pixel) = self
@@ -95,6 +116,71 @@ impl<C: PixelColor> Iterator for StyledPixelsIterator<C> {
     }
 }
 
+/// Returns the dashed or dotted stroke if the style uses a non solid stroke.
+fn patterned_stroke<C: PixelColor>(
+    primitive: &RoundedRectangle,
+    style: &PrimitiveStyle<C>,
+) -> Option<PatternedStroke<RoundedRectangleOutline, Band<RoundedRectangle>, RoundedRectangle>> {
+    let outline = RoundedRectangleOutline::new(&style.stroke_center_outline(primitive));
+
+    if let Some(dot_size) = style.dot_size() {
+        let area = Band::new(
+            style.stroke_area(primitive),
+            style.stroke_inner_area(primitive),
+        );
+
+        return Some(PatternedStroke::dotted(outline, area, dot_size, *primitive));
+    }
+
+    style
+        .dash_pattern()
+        .map(|pattern| PatternedStroke::dashed(outline, pattern, *primitive))
+}
+
+/// Returns the stroke area and the line joins at the corners without a corner radius.
+///
+/// Corners without a radius are sharp corners of the outline. Their outer edge isn't rounded by
+/// the stroke and is shaped by the line join instead.
+fn stroke_area<C: PixelColor>(
+    primitive: &RoundedRectangle,
+    style: &PrimitiveStyle<C>,
+) -> (RoundedRectangle, Option<CornerJoins>) {
+    let mut area = style.stroke_area(primitive);
+
+    let radii = &primitive.corners;
+    let corners = [
+        radii.top_left,
+        radii.top_right,
+        radii.bottom_right,
+        radii.bottom_left,
+    ]
+    .map(|radius| radius == Size::zero());
+
+    let area_radii = &mut area.corners;
+    for (radius, is_sharp) in [
+        &mut area_radii.top_left,
+        &mut area_radii.top_right,
+        &mut area_radii.bottom_right,
+        &mut area_radii.bottom_left,
+    ]
+    .into_iter()
+    .zip(corners)
+    {
+        if is_sharp {
+            *radius = Size::zero();
+        }
+    }
+
+    let joins = CornerJoins::new(
+        &area.rectangle,
+        style.stroke_width,
+        style.effective_line_join(),
+        corners,
+    );
+
+    (area, joins)
+}
+
 impl<C: PixelColor> StyledPixels<PrimitiveStyle<C>> for RoundedRectangle {
     type Iter = StyledPixelsIterator<C>;
 
@@ -115,18 +201,25 @@ impl<C: PixelColor> StyledDrawable<PrimitiveStyle<C>> for RoundedRectangle {
     where
         D: DrawTarget<Color = C>,
     {
+        if let (Some(stroke_color), Some(pattern)) = (
+            style.effective_stroke_color(),
+            patterned_stroke(self, style),
+        ) {
+            for scanline in StyledScanlines::new(self, style) {
+                scanline.draw_patterned(target, &pattern, stroke_color, style.fill_color)?;
+            }
+
+            return Ok(());
+        }
+
         match (style.effective_stroke_color(), style.fill_color) {
             (Some(stroke_color), None) => {
-                for scanline in
-                    StyledScanlines::new(&style.stroke_area(self), &style.fill_area(self))
-                {
+                for scanline in StyledScanlines::new(self, style) {
                     scanline.draw_stroke(target, stroke_color)?;
                 }
             }
             (Some(stroke_color), Some(fill_color)) => {
-                for scanline in
-                    StyledScanlines::new(&style.stroke_area(self), &style.fill_area(self))
-                {
+                for scanline in StyledScanlines::new(self, style) {
                     scanline.draw_stroke_and_fill(target, stroke_color, fill_color)?;
                 }
             }
@@ -155,13 +248,17 @@ impl<C: PixelColor> StyledDimensions<PrimitiveStyle<C>> for RoundedRectangle {
 struct StyledScanlines {
     scanlines: Scanlines,
     fill_area: RoundedRectangleContains,
+    joins: Option<CornerJoins>,
 }
 
 impl StyledScanlines {
-    pub fn new(stroke_area: &RoundedRectangle, fill_area: &RoundedRectangle) -> Self {
+    pub fn new<C: PixelColor>(primitive: &RoundedRectangle, style: &PrimitiveStyle<C>) -> Self {
+        let (stroke_area, joins) = stroke_area(primitive, style);
+
         Self {
-            scanlines: Scanlines::new(stroke_area),
-            fill_area: RoundedRectangleContains::new(fill_area),
+            scanlines: Scanlines::new(&stroke_area),
+            fill_area: RoundedRectangleContains::new(&style.fill_area(primitive)),
+            joins,
         }
     }
 }
@@ -171,6 +268,11 @@ impl Iterator for StyledScanlines {
 
     fn next(&mut self) -> Option<Self::Item> {
         self.scanlines.next().map(|scanline| {
+            let scanline = match &self.joins {
+                Some(joins) => joins.clip(&scanline),
+                None => scanline,
+            };
+
             if self.fill_area.rows.contains(&scanline.y) {
                 let fill_start = scanline
                     .x
@@ -202,7 +304,8 @@ mod tests {
         mock_display::MockDisplay,
         pixelcolor::{BinaryColor, Rgb888, RgbColor},
         primitives::{
-            rectangle::Rectangle, CornerRadii, Primitive, PrimitiveStyleBuilder, StrokeAlignment,
+            rectangle::Rectangle, CornerRadii, LineJoin, Primitive, PrimitiveStyleBuilder,
+            StrokeAlignment,
         },
         Drawable,
     };
@@ -453,4 +556,66 @@ mod tests {
 
         assert_eq!(transparent_rect.bounding_box(), filled_rect.bounding_box(),);
     }
+
+    #[test]
+    fn dashed_stroke() {
+        crate::primitives::common::check_dashed_stroke(RoundedRectangle::new(
+            Rectangle::new(Point::new(6, 7), Size::new(40, 25)),
+            CornerRadii::new(Size::new(8, 6)),
+        ));
+    }
+
+    #[test]
+    fn dotted_stroke() {
+        crate::primitives::common::check_dotted_stroke(RoundedRectangle::new(
+            Rectangle::new(Point::new(6, 7), Size::new(40, 25)),
+            CornerRadii::new(Size::new(8, 6)),
+        ));
+    }
+
+    #[test]
+    fn line_joins_at_zero_radius_corners() {
+        let rect = Rectangle::new(Point::new(5, 4), Size::new(30, 20));
+
+        for line_join in [LineJoin::Bevel, LineJoin::Round] {
+            let style = PrimitiveStyleBuilder::new()
+                .stroke_color(Rgb888::RED)
+                .stroke_width(8)
+                .fill_color(Rgb888::GREEN)
+                .line_join(line_join)
+                .build();
+
+            let mut expected = MockDisplay::new();
+            rect.into_styled(style).draw(&mut expected).unwrap();
+
+            let rounded_rect = RoundedRectangle::with_equal_corners(rect, Size::zero());
+
+            let mut display = MockDisplay::new();
+            rounded_rect.into_styled(style).draw(&mut display).unwrap();
+            display.assert_eq_with_message(&expected, |f| write!(f, "{:?}", line_join));
+
+            // Only the corners without a radius use the line join.
+            let rounded_rect = RoundedRectangle::new(
+                rect,
+                CornerRadii {
+                    top_left: Size::new_equal(10),
+                    ..CornerRadii::new(Size::zero())
+                },
+            )
+            .into_styled(style);
+
+            let mut display = MockDisplay::new();
+            rounded_rect.draw(&mut display).unwrap();
+
+            for point in [Point::new(38, 0), Point::new(38, 27), Point::new(1, 27)] {
+                assert_eq!(display.get_pixel(point), None, "{:?}", point);
+            }
+
+            let mut pixels = MockDisplay::new();
+            rounded_rect.pixels().draw(&mut pixels).unwrap();
+            pixels.assert_eq(&display);
+
+            assert_eq!(display.affected_area(), rounded_rect.bounding_box());
+        }
+    }
 }
@@ -5,7 +5,7 @@ use crate::{
     primitives::{
         common::PlaneSector, Circle, ContainsPoint, OffsetOutline, PointsIter, Primitive, Rectangle,
     },
-    transform::Transform,
+    transform::{GeometricTransform, Transform, Transformation},
 };
 
 mod points;
@@ -207,6 +207,21 @@ impl Transform for Sector {
     }
 }
 
+impl GeometricTransform for Sector {
+    type Output = Sector;
+
+    fn transform(&self, transformation: &Transformation) -> Self::Output {
+        let (angle_start, angle_sweep) =
+            transformation.transform_angles(self.angle_start, self.angle_sweep);
+
+        Self::from_circle(
+            self.to_circle().transform(transformation),
+            angle_start,
+            angle_sweep,
+        )
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -5,7 +5,8 @@ use crate::{
     pixelcolor::PixelColor,
     primitives::{
         common::{
-            DistanceIterator, LineSide, LinearEquation, PlaneSector, PointType, NORMAL_VECTOR_SCALE,
+            Band, DistanceIterator, LineSide, LinearEquation, PatternedStroke, PlaneSector,
+            PointType, SectorOutline, NORMAL_VECTOR_SCALE,
         },
         styled::{StyledDimensions, StyledDrawable, StyledPixels},
         PrimitiveStyle, Rectangle, Sector,
@@ -32,6 +33,8 @@ pub struct StyledPixelsIterator<C> {
 
     stroke_color: Option<C>,
     fill_color: Option<C>,
+
+    pattern: Option<PatternedStroke<SectorOutline, Band<Sector>, Sector>>,
 }
 
 impl<C: PixelColor> StyledPixelsIterator<C> {
@@ -97,6 +100,7 @@ impl<C: PixelColor> StyledPixelsIterator<C> {
             bevel,
             stroke_color: style.stroke_color,
             fill_color: style.fill_color,
+            pattern: patterned_stroke(primitive, style),
         }
     }
 }
@@ -139,9 +143,12 @@ impl<C: PixelColor> Iterator for StyledPixelsIterator<C> {
                 point_type = PointType::Stroke;
             }
 
-            let color = match point_type {
-                PointType::Stroke => self.stroke_color,
-                PointType::Fill => self.fill_color,
+            let color = match (point_type, &self.pattern) {
+                (PointType::Stroke, Some(pattern)) => self
+                    .stroke_color
+                    .and_then(|stroke_color| pattern.color(point, stroke_color, self.fill_color)),
+                (PointType::Stroke, None) => self.stroke_color,
+                (PointType::Fill, _) => self.fill_color,
             };
 
             if let Some(color) = color {
@@ -151,6 +158,32 @@ impl<C: PixelColor> Iterator for StyledPixelsIterator<C> {
     }
 }
 
+/// Returns the dashed or dotted stroke if the style uses a non solid stroke.
+fn patterned_stroke<C: PixelColor>(
+    primitive: &Sector,
+    style: &PrimitiveStyle<C>,
+) -> Option<PatternedStroke<SectorOutline, Band<Sector>, Sector>> {
+    let center_outline = style.stroke_center_outline(primitive);
+    let outline = SectorOutline::new(
+        &center_outline.to_circle(),
+        center_outline.angle_start,
+        center_outline.angle_sweep,
+    );
+
+    if let Some(dot_size) = style.dot_size() {
+        let area = Band::new(
+            style.stroke_area(primitive),
+            style.stroke_inner_area(primitive),
+        );
+
+        return Some(PatternedStroke::dotted(outline, area, dot_size, *primitive));
+    }
+
+    style
+        .dash_pattern()
+        .map(|pattern| PatternedStroke::dashed(outline, pattern, *primitive))
+}
+
 impl<C: PixelColor> StyledPixels<PrimitiveStyle<C>> for Sector {
     type Iter = StyledPixelsIterator<C>;
 
@@ -695,4 +728,24 @@ mod tests {
             .draw(&mut display)
             .unwrap();
     }
+
+    #[test]
+    fn dashed_stroke() {
+        crate::primitives::common::check_dashed_stroke(Sector::new(
+            Point::new(6, 7),
+            40,
+            30.0.deg(),
+            120.0.deg(),
+        ));
+    }
+
+    #[test]
+    fn dotted_stroke() {
+        crate::primitives::common::check_dotted_stroke(Sector::new(
+            Point::new(6, 7),
+            40,
+            30.0.deg(),
+            120.0.deg(),
+        ));
+    }
 }
@@ -5,10 +5,10 @@ use core::cmp::{max, min, Ordering};
 use crate::{
     geometry::{Dimensions, Point},
     primitives::{
-        common::{LineJoin, LineSide, LinearEquation, Scanline, StrokeOffset},
-        ContainsPoint, Line, PointsIter, Primitive, Rectangle,
+        common::{LineSide, LinearEquation, Scanline, SegmentJoin, StrokeOffset},
+        ContainsPoint, Line, LineJoin, PointsIter, Primitive, Rectangle,
     },
-    transform::Transform,
+    transform::{GeometricTransform, Transform, Transformation},
 };
 
 mod points;
@@ -232,13 +232,17 @@ impl Triangle {
     }
 
     /// Generate a line join for each corner of the triangle.
-    fn joins(&self, stroke_width: u32, stroke_offset: StrokeOffset) -> [LineJoin; 3] {
+    ///
+    /// The inner corners of the joins don't depend on the line join style, so the default line
+    /// join is used.
+    fn joins(&self, stroke_width: u32, stroke_offset: StrokeOffset) -> [SegmentJoin; 3] {
         let [p1, p2, p3] = self.vertices;
+        let line_join = LineJoin::default();
 
         [
-            LineJoin::from_points(p3, p1, p2, stroke_width, stroke_offset),
-            LineJoin::from_points(p1, p2, p3, stroke_width, stroke_offset),
-            LineJoin::from_points(p2, p3, p1, stroke_width, stroke_offset),
+            SegmentJoin::from_points(p3, p1, p2, stroke_width, stroke_offset, line_join),
+            SegmentJoin::from_points(p1, p2, p3, stroke_width, stroke_offset, line_join),
+            SegmentJoin::from_points(p2, p3, p1, stroke_width, stroke_offset, line_join),
         ]
     }
 
@@ -322,6 +326,16 @@ impl Transform for Triangle {
     }
 }
 
+impl GeometricTransform for Triangle {
+    type Output = Triangle;
+
+    fn transform(&self, transformation: &Transformation) -> Self::Output {
+        let [vertex1, vertex2, vertex3] = self.vertices.map(|v| transformation.transform_point(v));
+
+        Self::new(vertex1, vertex2, vertex3)
+    }
+}
+
 const fn sort_two_yx(p1: Point, p2: Point) -> (Point, Point) {
     // If p1.y is less than p2.y, return it first. Otherwise, if they have the same Y coordinate,
     // the first point becomes the one with the lesser X coordinate.
@@ -3,6 +3,7 @@ use crate::{
     primitives::{
         common::{Scanline, StrokeOffset},
         triangle::{scanline_iterator::ScanlineIterator, Triangle},
+        LineJoin,
     },
 };
 
@@ -20,6 +21,7 @@ impl Points {
             triangle,
             0,
             StrokeOffset::None,
+            LineJoin::default(),
             true,
             &triangle.bounding_box(),
         );
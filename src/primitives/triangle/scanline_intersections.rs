@@ -3,8 +3,8 @@
 use crate::{
     geometry::Point,
     primitives::{
-        common::{LineJoin, PointType, Scanline, StrokeOffset, ThickSegment},
-        Triangle,
+        common::{PointType, Scanline, SegmentJoin, StrokeOffset, ThickSegment},
+        LineJoin, Triangle,
     },
 };
 
@@ -25,6 +25,7 @@ pub struct ScanlineIntersections {
     triangle: Triangle,
     stroke_width: u32,
     stroke_offset: StrokeOffset,
+    line_join: LineJoin,
     has_fill: bool,
     is_collapsed: bool,
 }
@@ -35,6 +36,7 @@ impl ScanlineIntersections {
         triangle: &Triangle,
         stroke_width: u32,
         stroke_offset: StrokeOffset,
+        line_join: LineJoin,
         has_fill: bool,
         scanline_y: i32,
     ) -> Self {
@@ -48,6 +50,7 @@ impl ScanlineIntersections {
             has_fill,
             triangle: *triangle,
             stroke_offset,
+            line_join,
             stroke_width,
             is_collapsed,
             ..Self::empty()
@@ -71,6 +74,7 @@ impl ScanlineIntersections {
             triangle: Triangle::new(Point::zero(), Point::zero(), Point::zero()),
             stroke_width: 0,
             stroke_offset: StrokeOffset::None,
+            line_join: LineJoin::const_default(),
             is_collapsed: false,
         }
     }
@@ -83,35 +87,42 @@ impl ScanlineIntersections {
     }
 
     fn edge_intersections(&self, scanline_y: i32) -> impl Iterator<Item = Scanline> + '_ {
-        let mut idx = 0;
         let mut left = Scanline::new_empty(scanline_y);
         let mut right = Scanline::new_empty(scanline_y);
 
+        let mut scanlines = (0..3).flat_map(move |idx| {
+            let start = SegmentJoin::from_points(
+                self.triangle.vertices[idx % 3],
+                self.triangle.vertices[(idx + 1) % 3],
+                self.triangle.vertices[(idx + 2) % 3],
+                self.stroke_width,
+                self.stroke_offset,
+                self.line_join,
+            );
+            let end = SegmentJoin::from_points(
+                self.triangle.vertices[(idx + 1) % 3],
+                self.triangle.vertices[(idx + 2) % 3],
+                self.triangle.vertices[(idx + 3) % 3],
+                self.stroke_width,
+                self.stroke_offset,
+                self.line_join,
+            );
+
+            // Round joins are filled by a disc at the end of each segment.
+            let join = end
+                .round_join()
+                .and_then(|join| join.run(scanline_y))
+                .map(|x| Scanline::new(scanline_y, x));
+
+            core::iter::once(ThickSegment::new(start, end).intersection(scanline_y)).chain(join)
+        });
+
         core::iter::from_fn(move || {
             if self.stroke_width == 0 {
                 return None;
             }
 
-            while idx < 3 {
-                let start = LineJoin::from_points(
-                    self.triangle.vertices[idx % 3],
-                    self.triangle.vertices[(idx + 1) % 3],
-                    self.triangle.vertices[(idx + 2) % 3],
-                    self.stroke_width,
-                    self.stroke_offset,
-                );
-                let end = LineJoin::from_points(
-                    self.triangle.vertices[(idx + 1) % 3],
-                    self.triangle.vertices[(idx + 2) % 3],
-                    self.triangle.vertices[(idx + 3) % 3],
-                    self.stroke_width,
-                    self.stroke_offset,
-                );
-
-                idx += 1;
-
-                let scanline = ThickSegment::new(start, end).intersection(scanline_y);
-
+            for scanline in scanlines.by_ref() {
                 if !left.is_empty() {
                     if left.try_extend(&scanline) {
                         continue;
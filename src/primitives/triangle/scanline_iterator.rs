@@ -3,7 +3,7 @@
 use crate::primitives::{
     common::{PointType, Scanline, StrokeOffset},
     triangle::scanline_intersections::ScanlineIntersections,
-    Rectangle, Triangle,
+    LineJoin, Rectangle, Triangle,
 };
 use core::ops::Range;
 
@@ -22,6 +22,7 @@ impl ScanlineIterator {
         triangle: &Triangle,
         stroke_width: u32,
         stroke_offset: StrokeOffset,
+        line_join: LineJoin,
         has_fill: bool,
         bounding_box: &Rectangle,
     ) -> Self {
@@ -34,6 +35,7 @@ impl ScanlineIterator {
                 &triangle,
                 stroke_width,
                 stroke_offset,
+                line_join,
                 has_fill,
                 scanline_y,
             );
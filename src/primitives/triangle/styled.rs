@@ -3,7 +3,10 @@ use crate::{
     geometry::{Dimensions, Point},
     pixelcolor::PixelColor,
     primitives::{
-        common::{ClosedThickSegmentIter, PointType, Scanline, StrokeOffset},
+        common::{
+            ClosedThickSegmentIter, PatternedStroke, PointType, Scanline, SegmentsArea,
+            SegmentsOutline, StrokeOffset,
+        },
         styled::{StyledDimensions, StyledDrawable, StyledPixels},
         triangle::{scanline_iterator::ScanlineIterator, Triangle},
         PrimitiveStyle, Rectangle, StrokeAlignment,
@@ -11,6 +14,10 @@ use crate::{
     Pixel,
 };
 
+/// Dashed or dotted triangle stroke.
+type TriangleStroke =
+    PatternedStroke<SegmentsOutline<[Point; 3]>, SegmentsArea<[Point; 3]>, Triangle>;
+
 /// Pixel iterator for each pixel in the triangle border
 #[derive(Clone, Eq, PartialEq, Hash, Debug)]
 #[cfg_attr(feature = "defmt", derive(::defmt::Format))]
@@ -18,8 +25,10 @@ pub struct StyledPixelsIterator<C> {
     lines_iter: ScanlineIterator,
     current_line: Scanline,
     current_color: Option<C>,
+    current_type: PointType,
     fill_color: Option<C>,
     stroke_color: Option<C>,
+    pattern: Option<TriangleStroke>,
 }
 
 impl<C: PixelColor> StyledPixelsIterator<C> {
@@ -28,6 +37,7 @@ impl<C: PixelColor> StyledPixelsIterator<C> {
             primitive,
             style.stroke_width,
             StrokeOffset::from(style.stroke_alignment),
+            style.effective_line_join(),
             style.fill_color.is_some(),
             &primitive.styled_bounding_box(style),
         );
@@ -45,8 +55,10 @@ impl<C: PixelColor> StyledPixelsIterator<C> {
             lines_iter,
             current_line,
             current_color,
+            current_type: point_type,
             fill_color: style.fill_color,
             stroke_color: style.effective_stroke_color(),
+            pattern: patterned_stroke(primitive, style),
         }
     }
 }
@@ -57,11 +69,18 @@ impl<C: PixelColor> Iterator for StyledPixelsIterator<C> {
     fn next(&mut self) -> Option<Self::Item> {
         loop {
             if let Some(p) = self.current_line.next() {
-                return Some(Pixel(p, self.current_color?));
+                if let (Some(pattern), PointType::Stroke) = (&self.pattern, self.current_type) {
+                    if let Some(color) = pattern.color(p, self.current_color?, self.fill_color) {
+                        return Some(Pixel(p, color));
+                    }
+                } else {
+                    return Some(Pixel(p, self.current_color?));
+                }
             } else {
                 let (next_line, next_type) = self.lines_iter.next()?;
 
                 self.current_line = next_line;
+                self.current_type = next_type;
 
                 self.current_color = match next_type {
                     PointType::Stroke => self.stroke_color,
@@ -72,6 +91,42 @@ impl<C: PixelColor> Iterator for StyledPixelsIterator<C> {
     }
 }
 
+/// Returns the vertices in clockwise order, starting at the first vertex.
+fn clockwise_vertices(primitive: &Triangle) -> [Point; 3] {
+    let [p1, p2, p3] = primitive.vertices;
+
+    if primitive.area_doubled() < 0 {
+        [p1, p3, p2]
+    } else {
+        [p1, p2, p3]
+    }
+}
+
+/// Returns the dashed or dotted stroke if the style uses a non solid stroke.
+fn patterned_stroke<C: PixelColor>(
+    primitive: &Triangle,
+    style: &PrimitiveStyle<C>,
+) -> Option<TriangleStroke> {
+    // The outline starts at the first vertex and runs clockwise.
+    let vertices = clockwise_vertices(primitive);
+    let outline = SegmentsOutline::closed(vertices);
+
+    if let Some(dot_size) = style.dot_size() {
+        // The dots are placed on the center line of the solid stroke.
+        let outline = outline.stroke_center(
+            style.stroke_width,
+            StrokeOffset::from(style.stroke_alignment),
+        );
+        let area = SegmentsArea::closed(vertices, style.stroke_width);
+
+        return Some(PatternedStroke::dotted(outline, area, dot_size, *primitive));
+    }
+
+    style
+        .dash_pattern()
+        .map(|pattern| PatternedStroke::dashed(outline, pattern, *primitive))
+}
+
 impl<C: PixelColor> StyledPixels<PrimitiveStyle<C>> for Triangle {
     type Iter = StyledPixelsIterator<C>;
 
@@ -96,10 +151,13 @@ impl<C: PixelColor> StyledDrawable<PrimitiveStyle<C>> for Triangle {
             return Ok(());
         }
 
+        let pattern = patterned_stroke(self, style);
+
         for (line, kind) in ScanlineIterator::new(
             self,
             style.stroke_width,
             StrokeOffset::from(style.stroke_alignment),
+            style.effective_line_join(),
             style.fill_color.is_some(),
             &self.styled_bounding_box(style),
         ) {
@@ -108,7 +166,12 @@ impl<C: PixelColor> StyledDrawable<PrimitiveStyle<C>> for Triangle {
                 PointType::Fill => style.fill_color,
             };
 
-            if let Some(color) = color {
+            if let (Some(pattern), Some(stroke_color), PointType::Stroke) = (&pattern, color, kind)
+            {
+                line.draw_runs(target, |point| {
+                    pattern.color(point, stroke_color, style.fill_color)
+                })?;
+            } else if let Some(color) = color {
                 let rect = line.to_rectangle();
 
                 if !rect.is_zero_sized() {
@@ -134,12 +197,19 @@ impl<C: PixelColor> StyledDimensions<PrimitiveStyle<C>> for Triangle {
             &t.vertices,
             style.stroke_width,
             StrokeOffset::from(style.stroke_alignment),
+            style.effective_line_join(),
         )
+        .flat_map(|segment| {
+            let join = segment
+                .end_join()
+                .round_join()
+                .map(|join| join.bounding_box());
+
+            core::iter::once(segment.edges_bounding_box()).chain(join)
+        })
         .fold(
             (Point::new_equal(i32::MAX), Point::new_equal(i32::MIN)),
-            |(min, max), segment| {
-                let bb = segment.edges_bounding_box();
-
+            |(min, max), bb| {
                 (
                     min.component_min(bb.top_left),
                     max.component_max(bb.bottom_right().unwrap_or(bb.top_left)),
@@ -158,7 +228,9 @@ mod tests {
         geometry::Point,
         mock_display::MockDisplay,
         pixelcolor::{BinaryColor, Rgb565, Rgb888, RgbColor},
-        primitives::{Line, Primitive, PrimitiveStyleBuilder, StrokeAlignment},
+        primitives::{
+            common::check_dotted_stroke, Line, Primitive, PrimitiveStyleBuilder, StrokeAlignment,
+        },
         transform::Transform,
         Drawable,
     };
@@ -550,4 +622,22 @@ mod tests {
             "R            ",
         ]);
     }
+
+    #[test]
+    fn dashed_stroke() {
+        crate::primitives::common::check_dashed_stroke(Triangle::new(
+            Point::new(6, 7),
+            Point::new(45, 15),
+            Point::new(20, 40),
+        ));
+    }
+
+    #[test]
+    fn dotted_stroke() {
+        check_dotted_stroke(Triangle::new(
+            Point::new(10, 11),
+            Point::new(40, 17),
+            Point::new(19, 35),
+        ));
+    }
 }
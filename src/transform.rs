@@ -1,6 +1,14 @@
 //! Transformations for graphics objects
 
-use crate::geometry::Point;
+use crate::{
+    geometry::{
+        angle_consts::{ANGLE_180DEG, ANGLE_90DEG},
+        Angle, Point, Size, Trigonometry,
+    },
+    primitives::{Polygon, Rectangle},
+};
+#[allow(unused_imports)]
+use micromath::F32Ext;
 
 /// Transform operations
 pub trait Transform {
@@ -11,3 +19,616 @@ pub trait Transform {
     /// in place
     fn translate_mut(&mut self, by: Point) -> &mut Self;
 }
+
+/// Rotation, mirroring and scaling of graphics objects.
+///
+/// Objects are transformed by applying the [`Transformation`] to the points which define the
+/// object. Objects which might not keep their shape, like rectangles which are rotated by an angle
+/// that isn't a multiple of 90°, return an `Option` and are `None` if the result can't be
+/// represented by the same type.
+///
+/// Primitives which store their vertices in a slice, like [`Polyline`] and [`Polygon`], need a
+/// buffer to store the transformed vertices and provide a `transform_into` method instead.
+///
+/// # Examples
+///
+/// ## Rotate a triangle
+///
+/// ```
+/// use embedded_graphics::{prelude::*, primitives::Triangle, transform::GeometricTransform};
+///
+/// let triangle = Triangle::new(Point::new(10, 10), Point::new(20, 10), Point::new(10, 15));
+///
+/// assert_eq!(
+///     triangle.rotate(Point::new(10, 10), 90.0.deg()),
+///     Triangle::new(Point::new(10, 10), Point::new(10, 20), Point::new(5, 10))
+/// );
+/// ```
+///
+/// ## Mirror and scale a rectangle
+///
+/// ```
+/// use embedded_graphics::{prelude::*, primitives::Rectangle, transform::GeometricTransform};
+///
+/// let rectangle = Rectangle::new(Point::new(2, 4), Size::new(10, 5));
+///
+/// assert_eq!(
+///     rectangle.mirror_horizontal(20),
+///     Some(Rectangle::new(Point::new(29, 4), Size::new(10, 5)))
+/// );
+///
+/// assert_eq!(
+///     rectangle.scale(Point::zero(), 3),
+///     Some(Rectangle::new(Point::new(6, 12), Size::new(30, 15)))
+/// );
+///
+/// // The result of a rotation by 45° can't be represented by a `Rectangle`.
+/// assert_eq!(rectangle.rotate(Point::zero(), 45.0.deg()), None);
+/// ```
+///
+/// Rectangles can be rotated by any angle with [`TransformIntoPolygon`], which returns a
+/// [`Polygon`] instead.
+///
+/// [`Polyline`]: crate::primitives::Polyline
+/// [`Polygon`]: crate::primitives::Polygon
+pub trait GeometricTransform {
+    /// The type of the transformed object.
+    type Output;
+
+    /// Applies a transformation to this object.
+    fn transform(&self, transformation: &Transformation) -> Self::Output;
+
+    /// Rotates this object clockwise by `angle` around `center`.
+    fn rotate(&self, center: Point, angle: Angle) -> Self::Output {
+        self.transform(&Transformation::rotation(center, angle))
+    }
+
+    /// Mirrors this object horizontally at the vertical axis through `axis_x`.
+    fn mirror_horizontal(&self, axis_x: i32) -> Self::Output {
+        self.transform(&Transformation::mirror_horizontal(axis_x))
+    }
+
+    /// Mirrors this object vertically at the horizontal axis through `axis_y`.
+    fn mirror_vertical(&self, axis_y: i32) -> Self::Output {
+        self.transform(&Transformation::mirror_vertical(axis_y))
+    }
+
+    /// Scales this object by an integer factor relative to `origin`.
+    fn scale(&self, origin: Point, factor: u32) -> Self::Output {
+        self.transform(&Transformation::scale(origin, factor))
+    }
+
+    /// Scales this object by the ratio `numerator / denominator` relative to `origin`.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `denominator` is zero.
+    fn scale_ratio(&self, origin: Point, numerator: u32, denominator: u32) -> Self::Output {
+        self.transform(&Transformation::scale_ratio(origin, numerator, denominator))
+    }
+}
+
+/// Transformation of objects into a [`Polygon`].
+///
+/// Objects which can't keep their shape under all transformations can be converted into a
+/// polygon instead. Like the `transform_into` methods of [`Polyline`] and [`Polygon`], the
+/// transformed vertices are stored in a buffer which is provided by the caller.
+///
+/// # Examples
+///
+/// ## Rotate a rectangle by 30°
+///
+/// ```
+/// use embedded_graphics::{
+///     prelude::*,
+///     primitives::Rectangle,
+///     transform::{TransformIntoPolygon, Transformation},
+/// };
+///
+/// let rectangle = Rectangle::new(Point::new(10, 10), Size::new(21, 11));
+/// let rotation = Transformation::rotation(rectangle.center(), 30.0.deg());
+///
+/// let mut buffer = [Point::zero(); 4];
+/// let polygon = rectangle.transform_into(&rotation, &mut buffer).unwrap();
+///
+/// assert_eq!(polygon.vertices[0], Point::new(14, 6));
+/// ```
+///
+/// [`Polyline`]: crate::primitives::Polyline
+/// [`Polygon`]: crate::primitives::Polygon
+pub trait TransformIntoPolygon {
+    /// Applies a transformation to this object and returns the result as a polygon.
+    ///
+    /// The transformed vertices are stored in `buffer`. Returns `None` if `buffer` is too small
+    /// or if the object has no vertices.
+    fn transform_into<'b>(
+        &self,
+        transformation: &Transformation,
+        buffer: &'b mut [Point],
+    ) -> Option<Polygon<'b>>;
+}
+
+/// Rotation, mirroring or scaling transformation.
+///
+/// Rotations by multiples of 90°, mirroring and integer scaling are exact. Rotations by other
+/// angles and scaling by non integer ratios round the transformed coordinates to the nearest
+/// pixel.
+///
+/// See [`GeometricTransform`] for examples.
+#[derive(Copy, Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub struct Transformation {
+    origin: Point,
+    operation: Operation,
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+enum Operation {
+    /// Clockwise rotation by a multiple of 90°.
+    QuarterTurns(u8),
+
+    /// Clockwise rotation by an arbitrary angle.
+    Rotation { angle: Angle, cos: f32, sin: f32 },
+
+    /// Swaps left and right.
+    MirrorHorizontal,
+
+    /// Swaps top and bottom.
+    MirrorVertical,
+
+    /// Scaling by `numerator / denominator`.
+    Scale { numerator: u32, denominator: u32 },
+}
+
+impl Transformation {
+    /// Creates a clockwise rotation by `angle` around `center`.
+    ///
+    /// Angles which are a multiple of 90° are detected and use an exact integer implementation.
+    pub fn rotation(center: Point, angle: Angle) -> Self {
+        let quarter_turns = angle.to_degrees() / 90.0;
+        let rounded = quarter_turns.round();
+
+        let operation = if (quarter_turns - rounded).abs() < 1e-3 {
+            Operation::QuarterTurns((rounded as i32).rem_euclid(4) as u8)
+        } else {
+            Operation::Rotation {
+                angle,
+                cos: f32::from(angle.cos()),
+                sin: f32::from(angle.sin()),
+            }
+        };
+
+        Self {
+            origin: center,
+            operation,
+        }
+    }
+
+    /// Creates a horizontal mirroring at the vertical axis through `axis_x`.
+    ///
+    /// The pixels in the column `axis_x` stay in place and the left and right side of the axis
+    /// are swapped.
+    pub const fn mirror_horizontal(axis_x: i32) -> Self {
+        Self {
+            origin: Point::new(axis_x, 0),
+            operation: Operation::MirrorHorizontal,
+        }
+    }
+
+    /// Creates a vertical mirroring at the horizontal axis through `axis_y`.
+    ///
+    /// The pixels in the row `axis_y` stay in place and the top and bottom side of the axis are
+    /// swapped.
+    pub const fn mirror_vertical(axis_y: i32) -> Self {
+        Self {
+            origin: Point::new(0, axis_y),
+            operation: Operation::MirrorVertical,
+        }
+    }
+
+    /// Creates a scaling by an integer factor relative to `origin`.
+    pub const fn scale(origin: Point, factor: u32) -> Self {
+        Self::scale_ratio(origin, factor, 1)
+    }
+
+    /// Creates a scaling by the ratio `numerator / denominator` relative to `origin`.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `denominator` is zero.
+    pub const fn scale_ratio(origin: Point, numerator: u32, denominator: u32) -> Self {
+        assert!(denominator > 0, "denominator must not be zero");
+
+        Self {
+            origin,
+            operation: Operation::Scale {
+                numerator,
+                denominator,
+            },
+        }
+    }
+
+    /// Returns `true` if horizontal and vertical lines stay horizontal or vertical.
+    ///
+    /// Only rotations by angles which aren't a multiple of 90° aren't axis aligned.
+    pub fn is_axis_aligned(&self) -> bool {
+        !matches!(self.operation, Operation::Rotation { .. })
+    }
+
+    /// Transforms a point.
+    pub fn transform_point(&self, point: Point) -> Point {
+        self.map(point, self.origin)
+    }
+
+    /// Transforms a rectangle.
+    ///
+    /// Returns `None` if the transformation isn't axis aligned.
+    pub fn transform_rectangle(&self, rectangle: &Rectangle) -> Option<Rectangle> {
+        if !self.is_axis_aligned() {
+            return None;
+        }
+
+        let size = self.transform_size(rectangle.size);
+
+        if let (Operation::Scale { .. }, _) | (_, None) = (self.operation, rectangle.bottom_right())
+        {
+            let top_left = self.transform_point(rectangle.top_left);
+
+            // Mirrored empty rectangles are moved to the other side of the axis.
+            return Some(Rectangle::new(top_left, size));
+        }
+
+        let corner1 = self.transform_point(rectangle.top_left);
+        let corner2 = self.transform_point(rectangle.bottom_right().unwrap());
+
+        Some(Rectangle::with_corners(corner1, corner2))
+    }
+
+    /// Transforms a point which is scaled by a factor of 2.
+    pub(crate) fn transform_point_2x(&self, point_2x: Point) -> Point {
+        self.map(point_2x, self.origin * 2)
+    }
+
+    /// Transforms a length.
+    ///
+    /// Lengths are only changed by scaling.
+    pub(crate) fn transform_length(&self, length: u32) -> u32 {
+        match self.operation {
+            Operation::Scale {
+                numerator,
+                denominator,
+            } => scale_round(i64::from(length), numerator, denominator)
+                .clamp(0, i64::from(u32::MAX)) as u32,
+            _ => length,
+        }
+    }
+
+    /// Transforms a size.
+    ///
+    /// The width and height are swapped by odd numbers of quarter turns.
+    pub(crate) fn transform_size(&self, size: Size) -> Size {
+        match self.operation {
+            Operation::QuarterTurns(turns) if turns % 2 == 1 => Size::new(size.height, size.width),
+            _ => Size::new(
+                self.transform_length(size.width),
+                self.transform_length(size.height),
+            ),
+        }
+    }
+
+    /// Transforms the start and sweep angle of an arc.
+    pub(crate) fn transform_angles(
+        &self,
+        angle_start: Angle,
+        angle_sweep: Angle,
+    ) -> (Angle, Angle) {
+        match self.operation {
+            Operation::QuarterTurns(turns) => {
+                let mut angle_start = angle_start;
+                for _ in 0..turns {
+                    angle_start += ANGLE_90DEG;
+                }
+
+                (angle_start, angle_sweep)
+            }
+            Operation::Rotation { angle, .. } => (angle_start + angle, angle_sweep),
+            Operation::MirrorHorizontal => (ANGLE_180DEG - angle_start, -angle_sweep),
+            Operation::MirrorVertical => (-angle_start, -angle_sweep),
+            Operation::Scale { .. } => (angle_start, angle_sweep),
+        }
+    }
+
+    /// Returns the number of clockwise quarter turns or `None` if this isn't a rotation by a
+    /// multiple of 90°.
+    pub(crate) const fn quarter_turns(&self) -> Option<u8> {
+        match self.operation {
+            Operation::QuarterTurns(turns) => Some(turns),
+            _ => None,
+        }
+    }
+
+    /// Returns whether this transformation swaps left and right and whether it swaps top and
+    /// bottom.
+    pub(crate) const fn mirroring(&self) -> (bool, bool) {
+        match self.operation {
+            Operation::MirrorHorizontal => (true, false),
+            Operation::MirrorVertical => (false, true),
+            _ => (false, false),
+        }
+    }
+
+    /// Transforms translated vertices into `buffer`.
+    ///
+    /// Returns `None` if the buffer is smaller than the number of vertices.
+    pub(crate) fn transform_vertices<'b>(
+        &self,
+        vertices: &[Point],
+        translate: Point,
+        buffer: &'b mut [Point],
+    ) -> Option<&'b [Point]> {
+        let buffer = buffer.get_mut(0..vertices.len())?;
+
+        for (output, vertex) in buffer.iter_mut().zip(vertices) {
+            *output = self.transform_point(*vertex + translate);
+        }
+
+        Some(buffer)
+    }
+
+    fn map(&self, point: Point, origin: Point) -> Point {
+        let delta = point - origin;
+
+        let delta = match self.operation {
+            Operation::QuarterTurns(turns) => match turns {
+                1 => Point::new(-delta.y, delta.x),
+                2 => Point::new(-delta.x, -delta.y),
+                3 => Point::new(delta.y, -delta.x),
+                _ => delta,
+            },
+            Operation::Rotation { cos, sin, .. } => {
+                let x = delta.x as f32;
+                let y = delta.y as f32;
+
+                Point::new(
+                    (x * cos - y * sin).round() as i32,
+                    (x * sin + y * cos).round() as i32,
+                )
+            }
+            Operation::MirrorHorizontal => Point::new(-delta.x, delta.y),
+            Operation::MirrorVertical => Point::new(delta.x, -delta.y),
+            Operation::Scale {
+                numerator,
+                denominator,
+            } => Point::new(
+                scale_round(i64::from(delta.x), numerator, denominator) as i32,
+                scale_round(i64::from(delta.y), numerator, denominator) as i32,
+            ),
+        };
+
+        origin + delta
+    }
+}
+
+/// Multiplies `value` by `numerator / denominator` and rounds the result to the nearest integer.
+fn scale_round(value: i64, numerator: u32, denominator: u32) -> i64 {
+    let numerator = value * i64::from(numerator);
+    let denominator = i64::from(denominator);
+
+    (numerator * 2 + denominator).div_euclid(denominator * 2)
+}
+
+impl GeometricTransform for Point {
+    type Output = Point;
+
+    fn transform(&self, transformation: &Transformation) -> Self::Output {
+        transformation.transform_point(*self)
+    }
+}
+
+impl GeometricTransform for Rectangle {
+    type Output = Option<Rectangle>;
+
+    fn transform(&self, transformation: &Transformation) -> Self::Output {
+        transformation.transform_rectangle(self)
+    }
+}
+
+impl TransformIntoPolygon for Rectangle {
+    /// Transforms the rectangle into a polygon.
+    ///
+    /// The vertices of the polygon are the centers of the four corner pixels of the rectangle,
+    /// which requires a buffer with at least 4 points. `None` is returned for empty rectangles.
+    fn transform_into<'b>(
+        &self,
+        transformation: &Transformation,
+        buffer: &'b mut [Point],
+    ) -> Option<Polygon<'b>> {
+        let top_left = self.top_left;
+        let bottom_right = self.bottom_right()?;
+
+        let corners = [
+            top_left,
+            Point::new(bottom_right.x, top_left.y),
+            bottom_right,
+            Point::new(top_left.x, bottom_right.y),
+        ];
+
+        transformation
+            .transform_vertices(&corners, Point::zero(), buffer)
+            .map(Polygon::new)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{geometry::AngleUnit, primitives::PointsIter};
+
+    #[test]
+    fn quarter_turns() {
+        let center = Point::new(10, 20);
+        let point = Point::new(13, 21);
+
+        for (angle, expected) in [
+            (0.0, Point::new(13, 21)),
+            (90.0, Point::new(9, 23)),
+            (180.0, Point::new(7, 19)),
+            (270.0, Point::new(11, 17)),
+            (-90.0, Point::new(11, 17)),
+            (450.0, Point::new(9, 23)),
+        ] {
+            let transformation = Transformation::rotation(center, angle.deg());
+
+            assert!(transformation.quarter_turns().is_some(), "{}", angle);
+            assert_eq!(point.rotate(center, angle.deg()), expected, "{}", angle);
+        }
+    }
+
+    #[test]
+    fn rotation() {
+        let transformation = Transformation::rotation(Point::new(1, 1), 45.0.deg());
+
+        assert!(!transformation.is_axis_aligned());
+        assert_eq!(
+            transformation.transform_point(Point::new(11, 1)),
+            Point::new(8, 8)
+        );
+        assert_eq!(
+            transformation.transform_point(Point::new(1, 1)),
+            Point::new(1, 1)
+        );
+    }
+
+    #[test]
+    fn mirror() {
+        let point = Point::new(3, 4);
+
+        assert_eq!(point.mirror_horizontal(5), Point::new(7, 4));
+        assert_eq!(point.mirror_horizontal(3), Point::new(3, 4));
+        assert_eq!(point.mirror_vertical(0), Point::new(3, -4));
+    }
+
+    #[test]
+    fn scale() {
+        let origin = Point::new(2, 2);
+
+        assert_eq!(Point::new(5, 3).scale(origin, 3), Point::new(11, 5));
+        assert_eq!(Point::new(5, 3).scale_ratio(origin, 1, 2), Point::new(4, 3));
+        assert_eq!(
+            Point::new(-1, 1).scale_ratio(origin, 1, 2),
+            Point::new(1, 2)
+        );
+        assert_eq!(Point::new(5, 3).scale(origin, 0), origin);
+    }
+
+    #[test]
+    #[should_panic(expected = "denominator must not be zero")]
+    fn zero_denominator() {
+        Transformation::scale_ratio(Point::zero(), 1, 0);
+    }
+
+    #[test]
+    fn rectangle() {
+        let rectangle = Rectangle::new(Point::new(2, 3), Size::new(4, 2));
+
+        assert_eq!(
+            rectangle.rotate(Point::new(2, 3), 90.0.deg()),
+            Some(Rectangle::new(Point::new(1, 3), Size::new(2, 4)))
+        );
+        assert_eq!(
+            rectangle.rotate(Point::new(2, 3), 180.0.deg()),
+            Some(Rectangle::new(Point::new(-1, 2), Size::new(4, 2)))
+        );
+        assert_eq!(
+            rectangle.mirror_vertical(5),
+            Some(Rectangle::new(Point::new(2, 6), Size::new(4, 2)))
+        );
+        assert_eq!(
+            rectangle.scale_ratio(Point::zero(), 3, 2),
+            Some(Rectangle::new(Point::new(3, 5), Size::new(6, 3)))
+        );
+        assert_eq!(rectangle.rotate(Point::zero(), 30.0.deg()), None);
+    }
+
+    #[test]
+    fn rectangle_into_polygon() {
+        let rectangle = Rectangle::new(Point::new(2, 3), Size::new(5, 3));
+        let mut buffer = [Point::zero(); 4];
+
+        let rotation = Transformation::rotation(Point::new(2, 3), 90.0.deg());
+        let polygon = rectangle.transform_into(&rotation, &mut buffer).unwrap();
+        assert_eq!(
+            polygon.vertices,
+            [
+                Point::new(2, 3),
+                Point::new(2, 7),
+                Point::new(0, 7),
+                Point::new(0, 3)
+            ]
+        );
+
+        // The polygon covers the same pixels as the rotated rectangle.
+        let rotated = rectangle.transform(&rotation).unwrap();
+        assert!(polygon.points().eq(rotated.points()));
+
+        let rotation = Transformation::rotation(Point::zero(), 30.0.deg());
+        let polygon = rectangle.transform_into(&rotation, &mut buffer).unwrap();
+        assert_eq!(
+            polygon.vertices[2],
+            rotation.transform_point(Point::new(6, 5))
+        );
+
+        assert_eq!(
+            rectangle.transform_into(&rotation, &mut [Point::zero(); 3]),
+            None
+        );
+        assert_eq!(
+            Rectangle::new(Point::new(2, 3), Size::new(0, 5))
+                .transform_into(&rotation, &mut buffer),
+            None
+        );
+    }
+
+    #[test]
+    fn empty_rectangle() {
+        let rectangle = Rectangle::new(Point::new(2, 3), Size::new(0, 5));
+
+        assert_eq!(
+            rectangle.rotate(Point::new(2, 3), 90.0.deg()),
+            Some(Rectangle::new(Point::new(2, 3), Size::new(5, 0)))
+        );
+    }
+
+    #[test]
+    fn angles() {
+        let start = 30.0.deg();
+        let sweep = 45.0.deg();
+
+        let (s, w) =
+            Transformation::rotation(Point::zero(), 90.0.deg()).transform_angles(start, sweep);
+        assert_eq!(
+            (s.to_degrees().round(), w.to_degrees().round()),
+            (120.0, 45.0)
+        );
+
+        let (s, w) =
+            Transformation::rotation(Point::zero(), 10.0.deg()).transform_angles(start, sweep);
+        assert_eq!(
+            (s.to_degrees().round(), w.to_degrees().round()),
+            (40.0, 45.0)
+        );
+
+        let (s, w) = Transformation::mirror_horizontal(0).transform_angles(start, sweep);
+        assert_eq!(
+            (s.to_degrees().round(), w.to_degrees().round()),
+            (150.0, -45.0)
+        );
+
+        let (s, w) = Transformation::mirror_vertical(0).transform_angles(start, sweep);
+        assert_eq!(
+            (s.to_degrees().round(), w.to_degrees().round()),
+            (-30.0, -45.0)
+        );
+    }
+}